@@ -0,0 +1,335 @@
+//! Boss bars — the bars shown at the top of the player's screen.
+//!
+//! A [`BossBar`] is a shared handle: clones refer to the same bar. Show it to
+//! players with [`Player::show_boss_bar`](crate::player::Player::show_boss_bar)
+//! or to limbo sessions with
+//! [`LimboSession::show_boss_bar`](crate::limbo::LimboSession::show_boss_bar),
+//! then change it with the setters — every viewer is updated live.
+//!
+//! Boss bars need 1.9+ clients; older clients silently ignore them.
+
+use std::sync::{Arc, Mutex, RwLock, Weak};
+
+use crate::types::Component;
+
+/// Bar color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BossBarColor {
+    Pink,
+    Blue,
+    Red,
+    Green,
+    Yellow,
+    Purple,
+    White,
+}
+
+/// Bar style: a plain bar or one split into notches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BossBarStyle {
+    Progress,
+    Notched6,
+    Notched10,
+    Notched12,
+    Notched20,
+}
+
+/// Effects applied while the bar is shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BossBarFlags {
+    pub darken_screen: bool,
+    pub play_boss_music: bool,
+    pub create_fog: bool,
+}
+
+/// What changed on a [`BossBar`], passed to its viewers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BossBarChange {
+    Title,
+    Progress,
+    /// Color or style.
+    Style,
+    Flags,
+}
+
+pub mod private {
+    /// Sealed — only the proxy implements [`BossBarViewer`](super::BossBarViewer).
+    pub trait Sealed {}
+}
+
+/// Something a boss bar is shown to (a player or a limbo session).
+///
+/// Implemented by the proxy; plugins use [`BossBar`] and the `show_boss_bar` /
+/// `hide_boss_bar` methods instead.
+pub trait BossBarViewer: Send + Sync + private::Sealed {
+    /// Called after `bar` changed, with its new state readable from `bar`.
+    fn boss_bar_changed(&self, bar: &BossBar, change: BossBarChange);
+}
+
+#[derive(Debug)]
+struct BossBarState {
+    title: Component,
+    progress: f32,
+    color: BossBarColor,
+    style: BossBarStyle,
+    flags: BossBarFlags,
+}
+
+struct BossBarInner {
+    id: uuid::Uuid,
+    state: RwLock<BossBarState>,
+    viewers: Mutex<Vec<Weak<dyn BossBarViewer>>>,
+}
+
+/// A boss bar that can be shown to players and limbo sessions.
+///
+/// # Example
+/// ```
+/// use infrarust_api::boss_bar::{BossBar, BossBarColor, BossBarStyle};
+/// use infrarust_api::types::Component;
+///
+/// let bar = BossBar::new(
+///     Component::text("Starting server..."),
+///     0.0,
+///     BossBarColor::Yellow,
+///     BossBarStyle::Notched10,
+/// );
+/// bar.set_progress(0.5);
+/// assert_eq!(bar.progress(), 0.5);
+/// ```
+#[derive(Clone)]
+pub struct BossBar {
+    inner: Arc<BossBarInner>,
+}
+
+impl BossBar {
+    /// Creates a bar with no flags. `progress` is clamped to `0.0..=1.0`.
+    pub fn new(title: Component, progress: f32, color: BossBarColor, style: BossBarStyle) -> Self {
+        Self {
+            inner: Arc::new(BossBarInner {
+                id: uuid::Uuid::new_v4(),
+                state: RwLock::new(BossBarState {
+                    title,
+                    progress: clamp_progress(progress),
+                    color,
+                    style,
+                    flags: BossBarFlags::default(),
+                }),
+                viewers: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Unique id of the bar, used on the wire.
+    pub fn id(&self) -> uuid::Uuid {
+        self.inner.id
+    }
+
+    pub fn title(&self) -> Component {
+        self.state().title.clone()
+    }
+
+    /// Fill level, from `0.0` (empty) to `1.0` (full).
+    pub fn progress(&self) -> f32 {
+        self.state().progress
+    }
+
+    pub fn color(&self) -> BossBarColor {
+        self.state().color
+    }
+
+    pub fn style(&self) -> BossBarStyle {
+        self.state().style
+    }
+
+    pub fn flags(&self) -> BossBarFlags {
+        self.state().flags
+    }
+
+    pub fn set_title(&self, title: Component) {
+        self.state_mut().title = title;
+        self.notify(BossBarChange::Title);
+    }
+
+    /// Sets the fill level, clamped to `0.0..=1.0`.
+    pub fn set_progress(&self, progress: f32) {
+        let progress = clamp_progress(progress);
+        if self.update(|state| std::mem::replace(&mut state.progress, progress) != progress) {
+            self.notify(BossBarChange::Progress);
+        }
+    }
+
+    pub fn set_color(&self, color: BossBarColor) {
+        if self.update(|state| std::mem::replace(&mut state.color, color) != color) {
+            self.notify(BossBarChange::Style);
+        }
+    }
+
+    pub fn set_style(&self, style: BossBarStyle) {
+        if self.update(|state| std::mem::replace(&mut state.style, style) != style) {
+            self.notify(BossBarChange::Style);
+        }
+    }
+
+    pub fn set_flags(&self, flags: BossBarFlags) {
+        if self.update(|state| std::mem::replace(&mut state.flags, flags) != flags) {
+            self.notify(BossBarChange::Flags);
+        }
+    }
+
+    /// Registers a viewer to be told about changes.
+    ///
+    /// Called by the proxy when the bar is shown; dropped viewers are
+    /// forgotten automatically.
+    pub fn add_viewer(&self, viewer: Weak<dyn BossBarViewer>) {
+        let mut viewers = self.viewers();
+        viewers.retain(|v| v.strong_count() > 0 && !Weak::ptr_eq(v, &viewer));
+        viewers.push(viewer);
+    }
+
+    /// Unregisters a viewer added with [`add_viewer`](Self::add_viewer).
+    pub fn remove_viewer(&self, viewer: &Weak<dyn BossBarViewer>) {
+        self.viewers()
+            .retain(|v| v.strong_count() > 0 && !Weak::ptr_eq(v, viewer));
+    }
+
+    /// Number of players and limbo sessions currently shown this bar.
+    pub fn viewer_count(&self) -> usize {
+        self.viewers()
+            .iter()
+            .filter(|v| v.strong_count() > 0)
+            .count()
+    }
+
+    fn state(&self) -> std::sync::RwLockReadGuard<'_, BossBarState> {
+        self.inner.state.read().expect("lock poisoned")
+    }
+
+    fn state_mut(&self) -> std::sync::RwLockWriteGuard<'_, BossBarState> {
+        self.inner.state.write().expect("lock poisoned")
+    }
+
+    fn update(&self, f: impl FnOnce(&mut BossBarState) -> bool) -> bool {
+        f(&mut self.state_mut())
+    }
+
+    fn viewers(&self) -> std::sync::MutexGuard<'_, Vec<Weak<dyn BossBarViewer>>> {
+        self.inner.viewers.lock().expect("lock poisoned")
+    }
+
+    fn notify(&self, change: BossBarChange) {
+        // Collected first so viewers run without the lock held.
+        let viewers: Vec<_> = {
+            let mut viewers = self.viewers();
+            viewers.retain(|v| v.strong_count() > 0);
+            viewers.iter().filter_map(Weak::upgrade).collect()
+        };
+        for viewer in viewers {
+            viewer.boss_bar_changed(self, change);
+        }
+    }
+}
+
+impl PartialEq for BossBar {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for BossBar {}
+
+impl std::fmt::Debug for BossBar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BossBar")
+            .field("id", &self.inner.id)
+            .field("state", &*self.state())
+            .finish()
+    }
+}
+
+fn clamp_progress(progress: f32) -> f32 {
+    if progress.is_nan() {
+        0.0
+    } else {
+        progress.clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct CountingViewer {
+        changes: AtomicUsize,
+    }
+
+    impl private::Sealed for CountingViewer {}
+
+    impl BossBarViewer for CountingViewer {
+        fn boss_bar_changed(&self, _bar: &BossBar, _change: BossBarChange) {
+            self.changes.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn bar() -> BossBar {
+        BossBar::new(
+            Component::text("Test"),
+            0.0,
+            BossBarColor::Pink,
+            BossBarStyle::Progress,
+        )
+    }
+
+    #[test]
+    fn progress_is_clamped() {
+        let bar = bar();
+        bar.set_progress(2.0);
+        assert_eq!(bar.progress(), 1.0);
+        bar.set_progress(f32::NAN);
+        assert_eq!(bar.progress(), 0.0);
+    }
+
+    #[test]
+    fn viewers_are_notified_of_changes_only() {
+        let bar = bar();
+        let viewer = Arc::new(CountingViewer::default());
+        let weak: Weak<dyn BossBarViewer> = Arc::downgrade(&viewer) as _;
+        bar.add_viewer(weak.clone());
+        bar.add_viewer(weak.clone());
+        assert_eq!(bar.viewer_count(), 1);
+
+        bar.set_progress(0.5);
+        bar.set_progress(0.5); // unchanged
+        bar.set_color(BossBarColor::Red);
+        assert_eq!(viewer.changes.load(Ordering::SeqCst), 2);
+
+        bar.remove_viewer(&weak);
+        bar.set_title(Component::text("Other"));
+        assert_eq!(viewer.changes.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn dropped_viewers_are_forgotten() {
+        let bar = bar();
+        let viewer = Arc::new(CountingViewer::default());
+        bar.add_viewer(Arc::downgrade(&viewer) as Weak<dyn BossBarViewer>);
+        drop(viewer);
+        assert_eq!(bar.viewer_count(), 0);
+        bar.set_progress(1.0);
+    }
+
+    #[test]
+    fn clones_share_state() {
+        let bar = bar();
+        let clone = bar.clone();
+        clone.set_style(BossBarStyle::Notched20);
+        assert_eq!(bar.style(), BossBarStyle::Notched20);
+        assert_eq!(bar, clone);
+        assert_eq!(bar.id(), clone.id());
+    }
+}
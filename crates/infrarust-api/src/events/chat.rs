@@ -1,4 +1,4 @@
-//! Chat message events.
+//! Chat message and command events.
 
 use crate::event::{Event, ResultedEvent};
 use crate::types::{Component, PlayerId};
@@ -66,6 +66,67 @@ impl ResultedEvent for ChatMessageEvent {
     }
 }
 
+/// Fired when a player runs a command, before proxy commands are dispatched.
+///
+/// `command` has no leading `/`. Denied commands are neither run by the
+/// proxy nor forwarded to the backend; modified ones run with the new text.
+pub struct CommandExecuteEvent {
+    /// The player who ran the command.
+    pub player_id: PlayerId,
+    /// The command text, without the leading `/`.
+    pub command: String,
+    result: CommandExecuteResult,
+}
+
+impl CommandExecuteEvent {
+    pub fn new(player_id: PlayerId, command: String) -> Self {
+        Self {
+            player_id,
+            command,
+            result: CommandExecuteResult::default(),
+        }
+    }
+
+    /// Shortcut: deny the command.
+    pub fn deny(&mut self) {
+        self.result = CommandExecuteResult::Deny;
+    }
+
+    /// Shortcut: replace the command text.
+    pub fn modify(&mut self, new_command: String) {
+        self.result = CommandExecuteResult::Modify { new_command };
+    }
+}
+
+/// The result of a [`CommandExecuteEvent`].
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub enum CommandExecuteResult {
+    /// Run the command unmodified.
+    #[default]
+    Allow,
+    /// Drop the command. Listeners tell the player why themselves.
+    Deny,
+    /// Run this command text instead.
+    Modify {
+        /// The new command text, without the leading `/`.
+        new_command: String,
+    },
+}
+
+impl Event for CommandExecuteEvent {}
+impl ResultedEvent for CommandExecuteEvent {
+    type Result = CommandExecuteResult;
+
+    fn result(&self) -> &Self::Result {
+        &self.result
+    }
+
+    fn set_result(&mut self, result: Self::Result) {
+        self.result = result;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
@@ -93,4 +154,15 @@ mod tests {
             _ => panic!("expected Modify"),
         }
     }
+
+    #[test]
+    fn modify_command() {
+        let mut event = CommandExecuteEvent::new(PlayerId::new(1), "me waves".into());
+        assert!(matches!(event.result(), CommandExecuteResult::Allow));
+        event.modify("me smiles".into());
+        match event.result() {
+            CommandExecuteResult::Modify { new_command } => assert_eq!(new_command, "me smiles"),
+            _ => panic!("expected Modify"),
+        }
+    }
 }
@@ -4,16 +4,18 @@
 //! - [`lifecycle`] — Login, post-login, disconnect
 //! - [`connection`] — Server routing and kicks
 //! - [`proxy`] — Proxy-level events (ping, init, shutdown, config)
-//! - [`chat`] — Chat message interception
+//! - [`chat`] — Chat message and command interception
+//! - [`player`] — Client-side player state (settings, brand, resource packs)
 //! - [`packet`] — Raw packet events (Tier 3)
 
 pub mod chat;
 pub mod connection;
 pub mod lifecycle;
 pub mod packet;
+pub mod player;
 pub mod proxy;
 
-pub use chat::{ChatMessageEvent, ChatMessageResult};
+pub use chat::{ChatMessageEvent, ChatMessageResult, CommandExecuteEvent, CommandExecuteResult};
 pub use connection::{
     KickedFromServerEvent, KickedFromServerResult, PlayerChooseInitialServerEvent,
     PlayerChooseInitialServerResult, ServerConnectedEvent, ServerPreConnectEvent,
@@ -21,7 +23,10 @@ pub use connection::{
 };
 pub use lifecycle::{DisconnectEvent, PostLoginEvent, PreLoginEvent, PreLoginResult};
 pub use packet::{PacketDirection, RawPacketEvent, RawPacketResult};
+pub use player::{
+    PlayerClientBrandEvent, PlayerSettingsChangedEvent, ResourcePackStatus, ResourcePackStatusEvent,
+};
 pub use proxy::{
     ConfigReloadEvent, PingResponse, ProxyInitializeEvent, ProxyPingEvent, ProxyShutdownEvent,
-    ServerStateChangeEvent,
+    ServerCrashLoopEvent, ServerStateChangeEvent,
 };
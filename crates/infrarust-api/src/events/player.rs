@@ -0,0 +1,100 @@
+//! Client-side player events.
+
+use crate::event::Event;
+use crate::types::{ClientSettings, PlayerId};
+
+/// What a client reported about a resource pack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResourcePackStatus {
+    /// The player accepted the download prompt.
+    Accepted,
+    /// The pack was downloaded (1.20.3+).
+    Downloaded,
+    /// The pack is applied.
+    Loaded,
+    /// The player declined the pack.
+    Declined,
+    /// The download failed.
+    FailedDownload,
+    /// The URL could not be parsed (1.20.3+).
+    InvalidUrl,
+    /// The pack was downloaded but could not be applied (1.20.3+).
+    FailedReload,
+    /// The pack was dropped, e.g. when another pack replaced it (1.20.3+).
+    Discarded,
+}
+
+impl ResourcePackStatus {
+    /// Returns `true` if the pack will not be applied.
+    pub fn is_failure(self) -> bool {
+        matches!(
+            self,
+            Self::Declined
+                | Self::FailedDownload
+                | Self::InvalidUrl
+                | Self::FailedReload
+                | Self::Discarded
+        )
+    }
+}
+
+/// Fired when a player's client reports the status of a resource pack.
+///
+/// Informational. Covers packs pushed by the proxy and by backends; kick
+/// players with [`Player::disconnect`](crate::player::Player::disconnect)
+/// to enforce a pack the proxy does not already require.
+pub struct ResourcePackStatusEvent {
+    /// The player's session ID.
+    pub player_id: PlayerId,
+    /// The pack id (always set for proxy packs; for backend packs only on 1.20.3+).
+    pub pack_id: Option<uuid::Uuid>,
+    /// The pack URL, when the proxy saw it being pushed.
+    pub url: Option<String>,
+    /// The reported status.
+    pub status: ResourcePackStatus,
+    /// Whether the pack was pushed by the proxy rather than a backend.
+    pub from_proxy: bool,
+}
+
+impl Event for ResourcePackStatusEvent {}
+
+/// Fired when a player's client sends settings that differ from the last
+/// ones: once after joining, then whenever the player changes an option.
+///
+/// Informational. Only fired in active modes.
+pub struct PlayerSettingsChangedEvent {
+    /// The player's session ID.
+    pub player_id: PlayerId,
+    /// The new settings, also returned by [`Player::settings`](crate::player::Player::settings).
+    pub settings: ClientSettings,
+}
+
+impl Event for PlayerSettingsChangedEvent {}
+
+/// Fired when a player's client announces its brand (e.g. `vanilla`, `fabric`).
+///
+/// Informational. Kick players with
+/// [`Player::disconnect`](crate::player::Player::disconnect) to enforce a
+/// brand policy. Only fired in active modes.
+pub struct PlayerClientBrandEvent {
+    /// The player's session ID.
+    pub player_id: PlayerId,
+    /// The announced brand.
+    pub brand: String,
+}
+
+impl Event for PlayerClientBrandEvent {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_exclude_progress() {
+        assert!(ResourcePackStatus::Declined.is_failure());
+        assert!(ResourcePackStatus::Discarded.is_failure());
+        assert!(!ResourcePackStatus::Accepted.is_failure());
+        assert!(!ResourcePackStatus::Loaded.is_failure());
+    }
+}
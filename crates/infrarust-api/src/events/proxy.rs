@@ -94,6 +94,20 @@ pub struct ServerStateChangeEvent {
 
 impl Event for ServerStateChangeEvent {}
 
+/// Fired when a managed server keeps crashing and auto-restart gives up.
+///
+/// The server stays `Crashed` until it is started again manually.
+pub struct ServerCrashLoopEvent {
+    /// The crash-looping server.
+    pub server: ServerId,
+    /// Restarts attempted within `window` before giving up.
+    pub restarts: u32,
+    /// The sliding window the restarts were counted in.
+    pub window: std::time::Duration,
+}
+
+impl Event for ServerCrashLoopEvent {}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used)]
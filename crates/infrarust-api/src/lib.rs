@@ -54,6 +54,8 @@
 //! - [`filter`] — Codec and transport filter system
 //! - [`plugin`] — Plugin trait and lifecycle
 //! - [`player`] — Player trait
+//! - [`boss_bar`] — Boss bars shown to players and limbo sessions
+//! - [`scoreboard`] — Sidebars shown to players and limbo sessions
 //! - [`services`] — Proxy service traits
 //! - [`limbo`] — Limbo handler system (Tier 2)
 //! - [`virtual_backend`] — Virtual backend system (Tier 3)
@@ -61,6 +63,7 @@
 //! - [`error`] — Error types
 //! - [`prelude`] — Convenience re-exports
 
+pub mod boss_bar;
 pub mod command;
 pub mod error;
 pub mod event;
@@ -74,6 +77,7 @@ pub mod player;
 pub mod plugin;
 pub mod prelude;
 pub mod provider;
+pub mod scoreboard;
 pub mod services;
 pub mod types;
 pub mod virtual_backend;
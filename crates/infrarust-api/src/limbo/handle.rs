@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
+use crate::boss_bar::BossBar;
 use crate::error::PlayerError;
+use crate::scoreboard::Sidebar;
 use crate::types::{Component, PlayerId, TitleData};
 
 use super::handler::HandlerResult;
@@ -38,6 +40,22 @@ impl SessionHandle {
         self.inner.send_action_bar(message)
     }
 
+    pub fn show_boss_bar(&self, bar: &BossBar) -> Result<(), PlayerError> {
+        self.inner.show_boss_bar(bar)
+    }
+
+    pub fn hide_boss_bar(&self, bar: &BossBar) -> Result<(), PlayerError> {
+        self.inner.hide_boss_bar(bar)
+    }
+
+    pub fn show_sidebar(&self, sidebar: &Sidebar) -> Result<(), PlayerError> {
+        self.inner.show_sidebar(sidebar)
+    }
+
+    pub fn hide_sidebar(&self) -> Result<(), PlayerError> {
+        self.inner.hide_sidebar()
+    }
+
     pub fn complete(&self, result: HandlerResult) {
         self.inner.complete(result);
     }
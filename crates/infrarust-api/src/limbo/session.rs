@@ -1,6 +1,8 @@
 //! Limbo session trait.
 
+use crate::boss_bar::BossBar;
 use crate::error::PlayerError;
+use crate::scoreboard::Sidebar;
 use crate::types::{Component, GameProfile, PlayerId, TitleData};
 
 use super::context::LimboEntryContext;
@@ -46,6 +48,37 @@ pub trait LimboSession: Send + Sync + private::Sealed {
     /// Returns `Err(PlayerError::SendFailed)` if the message could not be delivered.
     fn send_action_bar(&self, message: Component) -> Result<(), PlayerError>;
 
+    /// Shows a boss bar to the player. Later changes to `bar` are sent
+    /// automatically; the bar is hidden when the player leaves limbo.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(PlayerError::SendFailed)` if the bar could not be delivered.
+    fn show_boss_bar(&self, bar: &BossBar) -> Result<(), PlayerError>;
+
+    /// Hides a boss bar shown with [`show_boss_bar`](LimboSession::show_boss_bar).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(PlayerError::SendFailed)` if the update could not be delivered.
+    fn hide_boss_bar(&self, bar: &BossBar) -> Result<(), PlayerError>;
+
+    /// Shows a sidebar, replacing the one shown before. Later changes to
+    /// `sidebar` are sent automatically; it is removed when the player
+    /// leaves limbo.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(PlayerError::SendFailed)` if the sidebar could not be delivered.
+    fn show_sidebar(&self, sidebar: &Sidebar) -> Result<(), PlayerError>;
+
+    /// Removes the sidebar shown with [`show_sidebar`](LimboSession::show_sidebar).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(PlayerError::SendFailed)` if the update could not be delivered.
+    fn hide_sidebar(&self) -> Result<(), PlayerError>;
+
     /// Signals that this handler is done processing the player.
     ///
     /// Call this when the handler returned [`HandlerResult::Hold`] and
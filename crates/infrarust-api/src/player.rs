@@ -1,13 +1,16 @@
 //! Player trait — the primary interface for interacting with connected players.
 
 use std::net::SocketAddr;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
+use crate::boss_bar::BossBar;
 use crate::error::PlayerError;
 use crate::event::BoxFuture;
 use crate::permissions::PermissionLevel;
+use crate::scoreboard::Sidebar;
 use crate::types::{
-    Component, GameProfile, PlayerId, ProtocolVersion, RawPacket, ServerId, TitleData,
+    ClientSettings, Component, GameProfile, PlayerId, ProtocolVersion, RawPacket, ServerId,
+    TabListEntry, TitleData,
 };
 
 pub mod private {
@@ -37,6 +40,32 @@ pub trait Player: Send + Sync + private::Sealed {
 
     fn remote_addr(&self) -> SocketAddr;
 
+    /// Options reported by the client, or `None` until it sent them.
+    ///
+    /// Only known in active modes, where the proxy reads client packets.
+    fn settings(&self) -> Option<ClientSettings>;
+
+    /// The client's language, e.g. `en_us`.
+    fn locale(&self) -> Option<String> {
+        self.settings().map(|settings| settings.locale)
+    }
+
+    /// The render distance chosen by the player, in chunks.
+    fn view_distance(&self) -> Option<u8> {
+        self.settings().map(|settings| settings.view_distance)
+    }
+
+    /// The brand the client announced, e.g. `vanilla` or `fabric`.
+    ///
+    /// Only known in active modes. Clients may send any brand.
+    fn client_brand(&self) -> Option<String>;
+
+    /// Round-trip time to the client, measured from keepalives.
+    ///
+    /// `None` until the first keepalive was answered, and always in passive
+    /// modes.
+    fn ping(&self) -> Option<Duration>;
+
     /// `None` if the player hasn't been routed to a backend yet.
     fn current_server(&self) -> Option<ServerId>;
 
@@ -80,6 +109,72 @@ pub trait Player: Send + Sync + private::Sealed {
     /// Returns `Err(PlayerError::NotActive)` in passive mode.
     fn send_packet(&self, packet: RawPacket) -> Result<(), PlayerError>;
 
+    /// Sets the header and footer shown above and below the tab list.
+    ///
+    /// Empty components clear them. The backend or the proxy's own tab list
+    /// may overwrite them later.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(PlayerError::NotActive)` in passive mode.
+    fn send_tab_list_header_footer(
+        &self,
+        header: Component,
+        footer: Component,
+    ) -> Result<(), PlayerError>;
+
+    /// Adds entries to the player's tab list, or updates existing ones.
+    ///
+    /// Ignored by clients older than 1.8.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(PlayerError::NotActive)` in passive mode.
+    fn add_tab_list_entries(&self, entries: Vec<TabListEntry>) -> Result<(), PlayerError>;
+
+    /// Removes entries from the player's tab list by UUID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(PlayerError::NotActive)` in passive mode.
+    fn remove_tab_list_entries(&self, uuids: Vec<uuid::Uuid>) -> Result<(), PlayerError>;
+
+    /// Shows a boss bar to the player. Later changes to `bar` are sent
+    /// automatically, and the bar stays shown across server switches.
+    ///
+    /// Ignored by clients older than 1.9.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(PlayerError::NotActive)` in passive mode.
+    fn show_boss_bar(&self, bar: &BossBar) -> Result<(), PlayerError>;
+
+    /// Hides a boss bar shown with [`show_boss_bar`](Player::show_boss_bar).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(PlayerError::NotActive)` in passive mode.
+    fn hide_boss_bar(&self, bar: &BossBar) -> Result<(), PlayerError>;
+
+    /// Shows a sidebar to the player, replacing the one shown before.
+    /// Later changes to `sidebar` are sent automatically.
+    ///
+    /// The sidebar is removed when the player switches server, so it does
+    /// not collide with the new backend's scoreboards. Ignored by clients
+    /// older than 1.8.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(PlayerError::NotActive)` in passive mode.
+    fn show_sidebar(&self, sidebar: &Sidebar) -> Result<(), PlayerError>;
+
+    /// Removes the sidebar shown with [`show_sidebar`](Player::show_sidebar).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(PlayerError::NotActive)` in passive mode.
+    fn hide_sidebar(&self) -> Result<(), PlayerError>;
+
     /// Switches the player to a different backend server.
     ///
     /// # Errors
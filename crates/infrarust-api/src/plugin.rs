@@ -16,9 +16,9 @@ use crate::event::bus::EventBus;
 use crate::filter::registry::{CodecFilterRegistry, TransportFilterRegistry};
 use crate::limbo::LimboHandler;
 use crate::services::{
-    ban_service::BanService, config_service::ConfigService, player_registry::PlayerRegistry,
-    plugin_registry::PluginRegistry, proxy_info::ProxyInfo, scheduler::Scheduler,
-    server_manager::ServerManager,
+    ban_service::BanService, config_service::ConfigService, player_data::PlayerDataStore,
+    player_registry::PlayerRegistry, plugin_registry::PluginRegistry, proxy_info::ProxyInfo,
+    scheduler::Scheduler, server_manager::ServerManager, tab_list::TabList,
 };
 
 /// Metadata describing a plugin.
@@ -172,6 +172,15 @@ pub trait PluginContext: Send + Sync + private::Sealed {
 
     fn scheduler(&self) -> &dyn Scheduler;
 
+    fn tab_list(&self) -> &dyn TabList;
+
+    fn tab_list_handle(&self) -> Arc<dyn TabList>;
+
+    /// Returns the persistent per-player data store.
+    fn player_data(&self) -> &dyn PlayerDataStore;
+
+    fn player_data_handle(&self) -> Arc<dyn PlayerDataStore>;
+
     fn event_bus_handle(&self) -> Arc<dyn EventBus>;
 
     /// Registers a limbo handler for this plugin.
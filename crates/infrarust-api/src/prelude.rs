@@ -6,8 +6,9 @@
 
 // Core types
 pub use crate::types::{
-    ClickEvent, Component, GameProfile, HoverEvent, Permission, PlayerId, ProfileProperty,
-    ProtocolVersion, RawPacket, ServerAddress, ServerId, ServerInfo, TitleData,
+    ClickEvent, ClientSettings, Component, GameProfile, HoverEvent, Permission, PlayerId,
+    ProfileProperty, ProtocolVersion, RawPacket, ServerAddress, ServerId, ServerInfo, TabListEntry,
+    TitleData,
 };
 
 // Error types
@@ -29,10 +30,16 @@ pub use crate::plugin::{Plugin, PluginContext, PluginDependency, PluginMetadata}
 // Player
 pub use crate::player::Player;
 
+// Boss bars
+pub use crate::boss_bar::{BossBar, BossBarColor, BossBarFlags, BossBarStyle};
+
+// Sidebars
+pub use crate::scoreboard::Sidebar;
+
 // Services
 pub use crate::services::{
-    BanEntry, BanService, BanTarget, ConfigService, PlayerRegistry, ProxyMode, Scheduler,
-    ServerConfig, ServerManager, ServerState, TaskHandle,
+    BanEntry, BanService, BanTarget, ConfigService, PlayerDataStore, PlayerRegistry, ProxyMode,
+    Scheduler, ServerConfig, ServerManager, ServerState, TabList, TaskHandle,
 };
 
 // Limbo
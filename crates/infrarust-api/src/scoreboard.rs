@@ -0,0 +1,256 @@
+//! Scoreboard sidebars — the panel of lines on the right of the screen.
+//!
+//! A [`Sidebar`] is a shared handle: clones refer to the same sidebar. Show
+//! it to players with [`Player::show_sidebar`](crate::player::Player::show_sidebar)
+//! or to limbo sessions with
+//! [`LimboSession::show_sidebar`](crate::limbo::LimboSession::show_sidebar),
+//! then change it with the setters — every viewer is updated live. Give each
+//! player their own `Sidebar` for per-player content.
+//!
+//! The proxy draws sidebars with its own objective and teams, separate from
+//! the backend's scoreboards, and removes them when the player switches
+//! server. Sidebars need 1.8+ clients.
+
+use std::sync::{Arc, Mutex, RwLock, Weak};
+
+use crate::types::Component;
+
+/// Maximum number of lines a sidebar shows.
+pub const MAX_SIDEBAR_LINES: usize = 15;
+
+/// What changed on a [`Sidebar`], passed to its viewers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SidebarChange {
+    Title,
+    Lines,
+}
+
+pub mod private {
+    /// Sealed — only the proxy implements [`SidebarViewer`](super::SidebarViewer).
+    pub trait Sealed {}
+}
+
+/// Something a sidebar is shown to (a player or a limbo session).
+///
+/// Implemented by the proxy; plugins use [`Sidebar`] and the `show_sidebar` /
+/// `hide_sidebar` methods instead.
+pub trait SidebarViewer: Send + Sync + private::Sealed {
+    /// Called after `sidebar` changed, with its new state readable from `sidebar`.
+    fn sidebar_changed(&self, sidebar: &Sidebar, change: SidebarChange);
+}
+
+#[derive(Debug)]
+struct SidebarState {
+    title: Component,
+    lines: Vec<Component>,
+}
+
+struct SidebarInner {
+    id: uuid::Uuid,
+    state: RwLock<SidebarState>,
+    viewers: Mutex<Vec<Weak<dyn SidebarViewer>>>,
+}
+
+/// A sidebar with a title and up to [`MAX_SIDEBAR_LINES`] lines, top to bottom.
+///
+/// # Example
+/// ```
+/// use infrarust_api::scoreboard::Sidebar;
+/// use infrarust_api::types::Component;
+///
+/// let sidebar = Sidebar::new(Component::text("My Network").color("gold"));
+/// sidebar.set_lines(vec![
+///     Component::text("Online: 42"),
+///     Component::text(""),
+///     Component::text("play.example.com").color("yellow"),
+/// ]);
+/// sidebar.set_line(0, Component::text("Online: 43"));
+/// assert_eq!(sidebar.lines().len(), 3);
+/// ```
+#[derive(Clone)]
+pub struct Sidebar {
+    inner: Arc<SidebarInner>,
+}
+
+impl Sidebar {
+    /// Creates a sidebar with no lines.
+    pub fn new(title: Component) -> Self {
+        Self {
+            inner: Arc::new(SidebarInner {
+                id: uuid::Uuid::new_v4(),
+                state: RwLock::new(SidebarState {
+                    title,
+                    lines: Vec::new(),
+                }),
+                viewers: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Unique id of the sidebar, used to name its objective and teams.
+    pub fn id(&self) -> uuid::Uuid {
+        self.inner.id
+    }
+
+    pub fn title(&self) -> Component {
+        self.state().title.clone()
+    }
+
+    pub fn lines(&self) -> Vec<Component> {
+        self.state().lines.clone()
+    }
+
+    pub fn set_title(&self, title: Component) {
+        self.state_mut().title = title;
+        self.notify(SidebarChange::Title);
+    }
+
+    /// Replaces every line. Lines past [`MAX_SIDEBAR_LINES`] are dropped.
+    pub fn set_lines(&self, mut lines: Vec<Component>) {
+        lines.truncate(MAX_SIDEBAR_LINES);
+        self.state_mut().lines = lines;
+        self.notify(SidebarChange::Lines);
+    }
+
+    /// Replaces one line, adding empty lines before it if needed.
+    ///
+    /// Indexes past [`MAX_SIDEBAR_LINES`] are ignored.
+    pub fn set_line(&self, index: usize, line: Component) {
+        if index >= MAX_SIDEBAR_LINES {
+            return;
+        }
+        {
+            let mut state = self.state_mut();
+            if state.lines.len() <= index {
+                state.lines.resize(index + 1, Component::text(""));
+            }
+            state.lines[index] = line;
+        }
+        self.notify(SidebarChange::Lines);
+    }
+
+    /// Registers a viewer to be told about changes.
+    ///
+    /// Called by the proxy when the sidebar is shown; dropped viewers are
+    /// forgotten automatically.
+    pub fn add_viewer(&self, viewer: Weak<dyn SidebarViewer>) {
+        let mut viewers = self.viewers();
+        viewers.retain(|v| v.strong_count() > 0 && !Weak::ptr_eq(v, &viewer));
+        viewers.push(viewer);
+    }
+
+    /// Unregisters a viewer added with [`add_viewer`](Self::add_viewer).
+    pub fn remove_viewer(&self, viewer: &Weak<dyn SidebarViewer>) {
+        self.viewers()
+            .retain(|v| v.strong_count() > 0 && !Weak::ptr_eq(v, viewer));
+    }
+
+    /// Number of players and limbo sessions currently shown this sidebar.
+    pub fn viewer_count(&self) -> usize {
+        self.viewers()
+            .iter()
+            .filter(|v| v.strong_count() > 0)
+            .count()
+    }
+
+    fn state(&self) -> std::sync::RwLockReadGuard<'_, SidebarState> {
+        self.inner.state.read().expect("lock poisoned")
+    }
+
+    fn state_mut(&self) -> std::sync::RwLockWriteGuard<'_, SidebarState> {
+        self.inner.state.write().expect("lock poisoned")
+    }
+
+    fn viewers(&self) -> std::sync::MutexGuard<'_, Vec<Weak<dyn SidebarViewer>>> {
+        self.inner.viewers.lock().expect("lock poisoned")
+    }
+
+    fn notify(&self, change: SidebarChange) {
+        // Collected first so viewers run without the lock held.
+        let viewers: Vec<_> = {
+            let mut viewers = self.viewers();
+            viewers.retain(|v| v.strong_count() > 0);
+            viewers.iter().filter_map(Weak::upgrade).collect()
+        };
+        for viewer in viewers {
+            viewer.sidebar_changed(self, change);
+        }
+    }
+}
+
+impl PartialEq for Sidebar {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Sidebar {}
+
+impl std::fmt::Debug for Sidebar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sidebar")
+            .field("id", &self.inner.id)
+            .field("state", &*self.state())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct CountingViewer {
+        changes: AtomicUsize,
+    }
+
+    impl private::Sealed for CountingViewer {}
+
+    impl SidebarViewer for CountingViewer {
+        fn sidebar_changed(&self, _sidebar: &Sidebar, _change: SidebarChange) {
+            self.changes.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn lines_are_capped() {
+        let sidebar = Sidebar::new(Component::text("Test"));
+        sidebar.set_lines(vec![Component::text("x"); 20]);
+        assert_eq!(sidebar.lines().len(), MAX_SIDEBAR_LINES);
+
+        sidebar.set_line(MAX_SIDEBAR_LINES, Component::text("ignored"));
+        assert_eq!(sidebar.lines().len(), MAX_SIDEBAR_LINES);
+    }
+
+    #[test]
+    fn set_line_pads_with_empty_lines() {
+        let sidebar = Sidebar::new(Component::text("Test"));
+        sidebar.set_line(2, Component::text("third"));
+        let lines = sidebar.lines();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].to_string(), "");
+        assert_eq!(lines[2].to_string(), "third");
+    }
+
+    #[test]
+    fn viewers_are_notified_until_removed() {
+        let sidebar = Sidebar::new(Component::text("Test"));
+        let viewer = Arc::new(CountingViewer::default());
+        let weak: Weak<dyn SidebarViewer> = Arc::downgrade(&viewer) as _;
+        sidebar.add_viewer(weak.clone());
+        assert_eq!(sidebar.viewer_count(), 1);
+
+        sidebar.set_title(Component::text("New"));
+        sidebar.set_line(0, Component::text("line"));
+        assert_eq!(viewer.changes.load(Ordering::SeqCst), 2);
+
+        sidebar.remove_viewer(&weak);
+        sidebar.set_lines(Vec::new());
+        assert_eq!(viewer.changes.load(Ordering::SeqCst), 2);
+        assert_eq!(sidebar.clone(), sidebar);
+    }
+}
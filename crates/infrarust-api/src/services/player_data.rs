@@ -0,0 +1,107 @@
+//! Per-player persistent data store.
+
+use std::time::{Duration, SystemTime};
+
+use uuid::Uuid;
+
+use crate::error::ServiceError;
+
+pub mod private {
+    /// Sealed — only the proxy implements [`PlayerDataStore`](super::PlayerDataStore).
+    pub trait Sealed {}
+}
+
+/// A value held by the [`PlayerDataStore`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerDataEntry {
+    pub namespace: String,
+    pub key: String,
+    /// The value as JSON text.
+    pub value: String,
+    /// When the value expires. `None` means it is kept until removed.
+    pub expires_at: Option<SystemTime>,
+}
+
+/// Key/value data kept per player UUID across restarts.
+///
+/// Values are JSON, grouped by namespace so plugins do not overwrite each
+/// other's keys. A plugin should use its own id as namespace; it may read
+/// the namespaces of other plugins to share data with them.
+///
+/// Values set with a TTL are no longer returned once it elapsed, and are
+/// deleted by a sweep that runs every 10 minutes.
+///
+/// With the `serde` feature, [`get`](Self::get) and [`set`](Self::set) read
+/// and write any serde type.
+///
+/// Obtained via [`PluginContext::player_data()`](crate::plugin::PluginContext::player_data).
+pub trait PlayerDataStore: Send + Sync + private::Sealed {
+    /// Returns the JSON text stored under `namespace`/`key`.
+    fn get_raw(&self, player: &Uuid, namespace: &str, key: &str) -> Option<String>;
+
+    /// Stores JSON text under `namespace`/`key`, replacing any previous value.
+    ///
+    /// A `None` TTL keeps the value until removed.
+    ///
+    /// # Errors
+    /// Returns [`ServiceError::OperationFailed`] if `json` is not valid JSON
+    /// or the namespace or key is empty.
+    fn set_raw(
+        &self,
+        player: &Uuid,
+        namespace: &str,
+        key: &str,
+        json: String,
+        ttl: Option<Duration>,
+    ) -> Result<(), ServiceError>;
+
+    /// Removes a value. Returns `false` if there was none.
+    fn remove(&self, player: &Uuid, namespace: &str, key: &str) -> bool;
+
+    /// Returns the keys a player has in `namespace`.
+    fn keys(&self, player: &Uuid, namespace: &str) -> Vec<String>;
+
+    /// Removes all of a player's values in `namespace`, returning how many
+    /// were removed.
+    fn clear(&self, player: &Uuid, namespace: &str) -> usize;
+
+    /// Returns all of a player's values, in every namespace.
+    fn entries(&self, player: &Uuid) -> Vec<PlayerDataEntry>;
+}
+
+#[cfg(feature = "serde")]
+impl dyn PlayerDataStore {
+    /// Returns the value stored under `namespace`/`key`, deserialized as `T`.
+    ///
+    /// # Errors
+    /// Returns [`ServiceError::OperationFailed`] if the stored value is not a `T`.
+    pub fn get<T: serde::de::DeserializeOwned>(
+        &self,
+        player: &Uuid,
+        namespace: &str,
+        key: &str,
+    ) -> Result<Option<T>, ServiceError> {
+        self.get_raw(player, namespace, key)
+            .map(|json| serde_json::from_str(&json))
+            .transpose()
+            .map_err(|e| ServiceError::OperationFailed(format!("{namespace}/{key}: {e}")))
+    }
+
+    /// Serializes `value` and stores it under `namespace`/`key`.
+    ///
+    /// # Errors
+    /// Returns [`ServiceError::OperationFailed`] if `value` cannot be
+    /// serialized or the namespace or key is empty.
+    pub fn set<T: serde::Serialize + ?Sized>(
+        &self,
+        player: &Uuid,
+        namespace: &str,
+        key: &str,
+        value: &T,
+        ttl: Option<Duration>,
+    ) -> Result<(), ServiceError> {
+        let json = serde_json::to_string(value)
+            .map_err(|e| ServiceError::OperationFailed(format!("{namespace}/{key}: {e}")))?;
+        self.set_raw(player, namespace, key, json, ttl)
+    }
+}
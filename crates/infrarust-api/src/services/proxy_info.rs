@@ -43,6 +43,9 @@ pub struct ProxyInfo {
     /// Whether a Docker provider is configured.
     pub docker_enabled: bool,
 
+    /// Whether a Kubernetes provider is configured.
+    pub kubernetes_enabled: bool,
+
     /// Whether the web admin API is enabled.
     pub web_api_enabled: bool,
 
@@ -119,6 +122,7 @@ impl Default for ProxyInfo {
             },
             telemetry_enabled: false,
             docker_enabled: false,
+            kubernetes_enabled: false,
             web_api_enabled: false,
             web_ui_enabled: false,
             unknown_domain_behavior: UnknownDomainBehavior::DefaultMotd,
@@ -30,6 +30,28 @@ pub enum ServerState {
 /// A callback for server state change notifications.
 pub type StateChangeCallback = Box<dyn Fn(&ServerId, ServerState, ServerState) + Send + Sync>;
 
+/// The output stream a console line was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConsoleStream {
+    /// Standard output of the server process.
+    Stdout,
+    /// Standard error of the server process.
+    Stderr,
+}
+
+/// A line of console output from a managed server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsoleLine {
+    /// The stream the line was read from.
+    pub stream: ConsoleStream,
+    /// The line, without its trailing newline.
+    pub line: String,
+}
+
+/// A callback receiving console output lines.
+pub type ConsoleCallback = Box<dyn Fn(&ConsoleLine) + Send + Sync>;
+
 /// Service for managing backend server lifecycle.
 ///
 /// Obtained via [`PluginContext::server_manager()`](crate::plugin::PluginContext::server_manager).
@@ -45,6 +67,12 @@ pub trait ServerManager: Send + Sync + private::Sealed {
     /// or the ID is unknown.
     fn stop(&self, server: &ServerId) -> BoxFuture<'_, Result<(), ServiceError>>;
 
+    /// Restarts an online server, moving its players to the configured
+    /// `evacuate_to` target first and back once it is online again.
+    ///
+    /// Resolves when the server is back online.
+    fn restart(&self, server: &ServerId) -> BoxFuture<'_, Result<(), ServiceError>>;
+
     /// Registers a callback for server state changes.
     ///
     /// The callback receives the server ID, old state, and new state.
@@ -52,6 +80,38 @@ pub trait ServerManager: Send + Sync + private::Sealed {
 
     /// Returns all servers and their current states.
     fn get_all_servers(&self) -> Vec<(ServerId, ServerState)>;
+
+    /// Returns the kick message if the server's schedule refuses
+    /// wake-on-join right now, `None` if players may start it.
+    ///
+    /// [`start`](Self::start) ignores the schedule; plugins that start a
+    /// server for a joining player should check this first.
+    fn closed_message(&self, server: &ServerId) -> Option<String>;
+
+    /// Sends a command to the server console, as if typed by an operator.
+    ///
+    /// Fails if the server is not running or its provider has no console
+    /// access (only locally managed servers have one).
+    fn send_command(
+        &self,
+        server: &ServerId,
+        command: &str,
+    ) -> BoxFuture<'_, Result<(), ServiceError>>;
+
+    /// Subscribes to the console output of a server.
+    ///
+    /// Returns the recently buffered lines; the callback then receives every
+    /// line printed after them. Remove the listener with
+    /// [`remove_console_listener`](Self::remove_console_listener).
+    fn on_console_output(
+        &self,
+        server: &ServerId,
+        callback: ConsoleCallback,
+    ) -> Result<(Vec<ConsoleLine>, ListenerHandle), ServiceError>;
+
+    /// Removes a console listener registered with
+    /// [`on_console_output`](Self::on_console_output).
+    fn remove_console_listener(&self, handle: ListenerHandle);
 }
 
 #[cfg(test)]
@@ -0,0 +1,46 @@
+//! Tab list service.
+
+use crate::types::{PlayerId, TabListEntry};
+
+pub mod private {
+    /// Sealed — only the proxy implements [`TabList`](super::TabList).
+    pub trait Sealed {}
+}
+
+/// Proxy-managed tab list: header/footer templates and extra entries.
+///
+/// Headers and footers are templates using `&` color codes and the
+/// placeholders `{player}`, `{server}`, `{network}`, `{online}`,
+/// `{network_online}` and `{server_online}`. They are re-rendered on every
+/// refresh, so counts stay up to date.
+///
+/// A player's own template takes priority over the default set with
+/// [`set_default_header_footer`](TabList::set_default_header_footer), which
+/// takes priority over the `[tab_list]` proxy configuration.
+///
+/// Obtained via [`PluginContext::tab_list()`](crate::plugin::PluginContext::tab_list).
+pub trait TabList: Send + Sync + private::Sealed {
+    /// Sets the header/footer template for one player.
+    fn set_header_footer(&self, player: PlayerId, header: &str, footer: &str);
+
+    /// Removes a player's own template, falling back to the default.
+    fn clear_header_footer(&self, player: PlayerId);
+
+    /// Sets the header/footer template for every player without their own.
+    fn set_default_header_footer(&self, header: &str, footer: &str);
+
+    /// Adds an entry shown to every active player, replacing any entry with
+    /// the same UUID.
+    fn add_entry(&self, entry: TabListEntry);
+
+    /// Removes an entry added with [`add_entry`](TabList::add_entry).
+    ///
+    /// Returns `false` if no entry had this UUID.
+    fn remove_entry(&self, uuid: &uuid::Uuid) -> bool;
+
+    /// Returns the entries added with [`add_entry`](TabList::add_entry).
+    fn entries(&self) -> Vec<TabListEntry>;
+
+    /// Pushes pending changes to players now instead of at the next refresh.
+    fn refresh(&self);
+}
@@ -0,0 +1,97 @@
+//! Client settings types.
+
+/// Which chat messages the player wants to see.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChatMode {
+    /// All messages.
+    Enabled,
+    /// Only command feedback.
+    CommandsOnly,
+    /// No messages at all.
+    Hidden,
+}
+
+/// The hand the player uses as main hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MainHand {
+    Left,
+    Right,
+}
+
+/// Skin layers the player displays, as sent by the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SkinParts(u8);
+
+impl SkinParts {
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub const fn cape(self) -> bool {
+        self.0 & 0x01 != 0
+    }
+
+    pub const fn jacket(self) -> bool {
+        self.0 & 0x02 != 0
+    }
+
+    pub const fn left_sleeve(self) -> bool {
+        self.0 & 0x04 != 0
+    }
+
+    pub const fn right_sleeve(self) -> bool {
+        self.0 & 0x08 != 0
+    }
+
+    pub const fn left_pants(self) -> bool {
+        self.0 & 0x10 != 0
+    }
+
+    pub const fn right_pants(self) -> bool {
+        self.0 & 0x20 != 0
+    }
+
+    pub const fn hat(self) -> bool {
+        self.0 & 0x40 != 0
+    }
+}
+
+/// Options reported by a player's client.
+///
+/// Options unknown to older clients hold the vanilla default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientSettings {
+    /// Client language, e.g. `en_us`.
+    pub locale: String,
+    /// Render distance in chunks.
+    pub view_distance: u8,
+    pub chat_mode: ChatMode,
+    /// Whether chat colors are shown.
+    pub chat_colors: bool,
+    pub skin_parts: SkinParts,
+    /// Right before 1.9.
+    pub main_hand: MainHand,
+    /// Whether the client asked for text filtering (1.17+).
+    pub text_filtering: bool,
+    /// Whether the player may appear in server list samples (1.18+).
+    pub allow_server_listings: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skin_parts_bits() {
+        let parts = SkinParts::from_bits(0x41);
+        assert!(parts.cape());
+        assert!(parts.hat());
+        assert!(!parts.jacket());
+        assert_eq!(parts.bits(), 0x41);
+    }
+}
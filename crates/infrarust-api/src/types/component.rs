@@ -374,6 +374,45 @@ impl fmt::Display for Component {
     }
 }
 
+/// Maps a color name to its Minecraft format code character.
+fn legacy_color_code(name: &str) -> Option<char> {
+    "0123456789abcdef"
+        .chars()
+        .find(|&c| legacy_color_name(c) == Some(name))
+}
+
+/// Style in effect while writing legacy `§` text.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+struct LegacyStyle {
+    color: Option<char>,
+    bold: bool,
+    italic: bool,
+    underlined: bool,
+    strikethrough: bool,
+    obfuscated: bool,
+}
+
+impl LegacyStyle {
+    fn write_codes(self, out: &mut String) {
+        if let Some(color) = self.color {
+            out.push('§');
+            out.push(color);
+        }
+        for (on, code) in [
+            (self.bold, 'l'),
+            (self.italic, 'o'),
+            (self.underlined, 'n'),
+            (self.strikethrough, 'm'),
+            (self.obfuscated, 'k'),
+        ] {
+            if on {
+                out.push('§');
+                out.push(code);
+            }
+        }
+    }
+}
+
 /// Maps a Minecraft `&` format code character to its color name.
 fn legacy_color_name(c: char) -> Option<&'static str> {
     match c {
@@ -502,11 +541,106 @@ impl Component {
         }
     }
 
+    /// Serializes this component to legacy `§` formatted text, as used by
+    /// pre-1.13 clients where components are not accepted.
+    ///
+    /// Hex colors, click and hover events are dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use infrarust_api::types::Component;
+    ///
+    /// let c = Component::text("Hi ").color("gold").append(Component::text("there").bold());
+    /// assert_eq!(c.to_legacy(), "§6Hi §6§lthere");
+    /// ```
+    #[must_use]
+    pub fn to_legacy(&self) -> String {
+        let mut out = String::new();
+        let mut current = LegacyStyle::default();
+        self.write_legacy(&mut out, LegacyStyle::default(), &mut current);
+        out
+    }
+
+    fn write_legacy(&self, out: &mut String, parent: LegacyStyle, current: &mut LegacyStyle) {
+        let style = LegacyStyle {
+            color: self
+                .color
+                .as_deref()
+                .and_then(legacy_color_code)
+                .or(parent.color),
+            bold: self.bold.unwrap_or(parent.bold),
+            italic: self.italic.unwrap_or(parent.italic),
+            underlined: self.underlined.unwrap_or(parent.underlined),
+            strikethrough: self.strikethrough.unwrap_or(parent.strikethrough),
+            obfuscated: self.obfuscated.unwrap_or(parent.obfuscated),
+        };
+        if !self.text.is_empty() {
+            if style != *current {
+                // Color codes reset formatting, so the full style is re-applied
+                if style.color.is_none() {
+                    out.push_str("§r");
+                }
+                style.write_codes(out);
+                *current = style;
+            }
+            out.push_str(&self.text);
+        }
+        for child in &self.extra {
+            child.write_legacy(out, style, current);
+        }
+    }
+
     /// Convenience: replaces `{key}` placeholders, then parses legacy color codes.
     #[must_use]
     pub fn from_legacy_format(template: &str, vars: &[(&str, &str)]) -> Self {
         Self::from_legacy(&format_placeholders(template, vars))
     }
+
+    /// Like [`from_legacy_format`](Self::from_legacy_format), but the values
+    /// are inserted as plain text: only the template's `&` codes are parsed.
+    ///
+    /// Use it for values typed by players, such as chat messages. A value
+    /// takes the style of the template text around its placeholder.
+    ///
+    /// # Example
+    /// ```
+    /// use infrarust_api::types::Component;
+    ///
+    /// let c = Component::from_legacy_format_plain("&7{player}: &f{message}", &[
+    ///     ("player", "Steve"),
+    ///     ("message", "&chi"),
+    /// ]);
+    /// assert_eq!(c.text, "Steve: ");
+    /// assert_eq!(c.extra[0].text, "&chi");
+    /// assert_eq!(c.extra[0].color.as_deref(), Some("white"));
+    /// ```
+    #[must_use]
+    pub fn from_legacy_format_plain(template: &str, vars: &[(&str, &str)]) -> Self {
+        // Placeholders become private-use markers, swapped for the values
+        // once the template is parsed
+        let markers: Vec<String> = (0..vars.len())
+            .map(|i| format!("\u{E000}{i}\u{E001}"))
+            .collect();
+        let marked: Vec<(&str, &str)> = vars
+            .iter()
+            .zip(&markers)
+            .map(|((key, _), marker)| (*key, marker.as_str()))
+            .collect();
+        let mut component = Self::from_legacy(&format_placeholders(template, &marked));
+        component.replace_markers(&markers, vars);
+        component
+    }
+
+    fn replace_markers(&mut self, markers: &[String], vars: &[(&str, &str)]) {
+        if self.text.contains('\u{E000}') {
+            for (marker, (_, value)) in markers.iter().zip(vars) {
+                self.text = self.text.replace(marker.as_str(), value);
+            }
+        }
+        for child in &mut self.extra {
+            child.replace_markers(markers, vars);
+        }
+    }
 }
 
 /// Replaces `{key}` placeholders in a template string.
@@ -632,6 +766,22 @@ mod tests {
         assert_eq!(c.to_string(), "ABC");
     }
 
+    #[test]
+    fn to_legacy_round_trips_legacy_codes() {
+        assert_eq!(
+            Component::from_legacy("&aGreen &c&lRed").to_legacy(),
+            "§aGreen §c§lRed"
+        );
+        assert_eq!(
+            Component::text("a")
+                .color("red")
+                .append(Component::text("b").color("#123456"))
+                .to_legacy(),
+            "§cab"
+        );
+        assert_eq!(Component::text("plain").to_legacy(), "plain");
+    }
+
     #[test]
     fn join_components() {
         let parts = vec![
@@ -844,4 +994,21 @@ mod tests {
         assert_eq!(c.extra[0].text, "...");
         assert_eq!(c.extra[0].color.as_deref(), Some("white"));
     }
+
+    #[test]
+    fn from_legacy_format_plain_ignores_codes_in_values() {
+        let c = Component::from_legacy_format_plain(
+            "&e[{server}] &f{player}&7: {message}",
+            &[
+                ("server", "lobby"),
+                ("player", "Steve"),
+                ("message", "&4&lfree {player} & stuff"),
+            ],
+        );
+        assert_eq!(c.text, "[lobby] ");
+        assert_eq!(c.extra[0].text, "Steve");
+        assert_eq!(c.extra[1].text, ": &4&lfree {player} & stuff");
+        assert_eq!(c.extra[1].color.as_deref(), Some("gray"));
+        assert!(c.extra[1].bold.is_none());
+    }
 }
@@ -2,8 +2,10 @@
 //!
 //! This module contains the core data types used throughout the API:
 //! player identifiers, server identifiers, rich text components,
-//! protocol versions, raw packets, and permissions.
+//! protocol versions, raw packets, permissions, tab list entries and
+//! client settings.
 
+mod client_settings;
 mod component;
 mod extensions;
 mod permission;
@@ -11,7 +13,9 @@ mod player_id;
 mod protocol_version;
 mod raw_packet;
 mod server_id;
+mod tab_list;
 
+pub use client_settings::{ChatMode, ClientSettings, MainHand, SkinParts};
 pub use component::{ClickEvent, Component, HoverEvent, TitleData, format_placeholders};
 pub use extensions::Extensions;
 pub use permission::Permission;
@@ -19,6 +23,7 @@ pub use player_id::PlayerId;
 pub use protocol_version::ProtocolVersion;
 pub use raw_packet::RawPacket;
 pub use server_id::{ServerAddress, ServerId, ServerInfo};
+pub use tab_list::TabListEntry;
 
 /// A player's Mojang game profile.
 ///
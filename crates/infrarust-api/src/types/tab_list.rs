@@ -0,0 +1,86 @@
+//! Tab list entry type.
+
+use super::{Component, ProfileProperty};
+
+/// An entry shown in a player's tab list.
+///
+/// Entries added by plugins do not need to match a connected player:
+/// any UUID and name can be used to show "fake" lines.
+///
+/// # Example
+/// ```
+/// use infrarust_api::types::{Component, TabListEntry};
+///
+/// let entry = TabListEntry::new(uuid::Uuid::new_v4(), "Staff")
+///     .display_name(Component::text("Staff online: 3").color("gold"))
+///     .latency(0);
+/// assert!(entry.listed);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TabListEntry {
+    /// Profile UUID of the entry.
+    pub uuid: uuid::Uuid,
+    /// Profile name (at most 16 characters).
+    pub name: String,
+    /// Profile properties (e.g. skin textures).
+    pub properties: Vec<ProfileProperty>,
+    /// Text shown instead of the name, if any.
+    pub display_name: Option<Component>,
+    /// Latency in milliseconds, used for the signal bars (default: 0).
+    pub latency_ms: i32,
+    /// Game mode id: 0 survival, 1 creative, 2 adventure, 3 spectator (default: 0).
+    pub game_mode: u8,
+    /// Whether the entry is visible in the list (default: `true`).
+    pub listed: bool,
+}
+
+impl TabListEntry {
+    /// Creates a listed survival entry with no display name.
+    #[must_use]
+    pub fn new(uuid: uuid::Uuid, name: impl Into<String>) -> Self {
+        Self {
+            uuid,
+            name: name.into(),
+            properties: Vec::new(),
+            display_name: None,
+            latency_ms: 0,
+            game_mode: 0,
+            listed: true,
+        }
+    }
+
+    /// Sets the text shown instead of the name.
+    #[must_use]
+    pub fn display_name(mut self, display_name: Component) -> Self {
+        self.display_name = Some(display_name);
+        self
+    }
+
+    /// Sets the profile properties (e.g. to give the entry a skin).
+    #[must_use]
+    pub fn properties(mut self, properties: Vec<ProfileProperty>) -> Self {
+        self.properties = properties;
+        self
+    }
+
+    /// Sets the latency in milliseconds.
+    #[must_use]
+    pub const fn latency(mut self, latency_ms: i32) -> Self {
+        self.latency_ms = latency_ms;
+        self
+    }
+
+    /// Sets the game mode id.
+    #[must_use]
+    pub const fn game_mode(mut self, game_mode: u8) -> Self {
+        self.game_mode = game_mode;
+        self
+    }
+
+    /// Sets whether the entry is visible in the list.
+    #[must_use]
+    pub const fn listed(mut self, listed: bool) -> Self {
+        self.listed = listed;
+        self
+    }
+}
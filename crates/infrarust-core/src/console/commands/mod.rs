@@ -29,6 +29,8 @@ pub fn register_all(dispatcher: &mut CommandDispatcher) {
     dispatcher.register(Box::new(servers::ServerCommand));
     dispatcher.register(Box::new(servers::StartServerCommand));
     dispatcher.register(Box::new(servers::StopServerCommand));
+    dispatcher.register(Box::new(servers::RestartServerCommand));
+    dispatcher.register(Box::new(servers::ServerConsoleCommand));
 
     dispatcher.register(Box::new(config::ReloadCommand));
     dispatcher.register(Box::new(config::ConfigCommand));
@@ -2,6 +2,7 @@
 
 use std::future::Future;
 use std::pin::Pin;
+use std::time::Duration;
 
 use comfy_table::Cell;
 use infrarust_api::services::player_registry::PlayerRegistry;
@@ -54,7 +55,7 @@ impl ConsoleCommand for ListPlayersCommand {
 
             let renderer = crate::console::output::OutputRenderer::new();
             let mut table = renderer.create_table();
-            table.set_header(vec!["Player", "IP", "Server", "Mode", "Protocol"]);
+            table.set_header(vec!["Player", "IP", "Server", "Mode", "Protocol", "Ping"]);
 
             for player in &players {
                 let server = player
@@ -72,6 +73,7 @@ impl ConsoleCommand for ListPlayersCommand {
                     Cell::new(server),
                     Cell::new(mode),
                     Cell::new(player.protocol_version().to_string()),
+                    Cell::new(format_ping(player.ping())),
                 ]);
             }
 
@@ -83,6 +85,11 @@ impl ConsoleCommand for ListPlayersCommand {
     }
 }
 
+/// Formats a measured ping, `-` until the first keepalive was answered.
+fn format_ping(ping: Option<Duration>) -> String {
+    ping.map_or_else(|| "-".to_string(), |p| format!("{} ms", p.as_millis()))
+}
+
 pub struct FindPlayerCommand;
 
 impl ConsoleCommand for FindPlayerCommand {
@@ -131,6 +138,7 @@ impl ConsoleCommand for FindPlayerCommand {
                         OutputLine::Info(format!("  Server: {server}")),
                         OutputLine::Info(format!("  Mode: {mode}")),
                         OutputLine::Info(format!("  Protocol: {}", player.protocol_version())),
+                        OutputLine::Info(format!("  Ping: {}", format_ping(player.ping()))),
                         OutputLine::Info(format!("  Connected: {}", player.is_connected())),
                     ])
                 }
@@ -1,7 +1,8 @@
-//! Server commands: servers, server, start, stop-server.
+//! Server commands: servers, server, start, stop-server, server-console.
 
 use std::future::Future;
 use std::pin::Pin;
+use std::time::Duration;
 
 use comfy_table::Cell;
 use infrarust_api::services::config_service::ConfigService;
@@ -277,6 +278,147 @@ impl ConsoleCommand for StopServerCommand {
     }
 }
 
+pub struct RestartServerCommand;
+
+impl ConsoleCommand for RestartServerCommand {
+    fn name(&self) -> &str {
+        "restart-server"
+    }
+
+    fn aliases(&self) -> &[&str] {
+        &["restartserver"]
+    }
+
+    fn description(&self) -> &str {
+        "Restart a server, evacuating its players"
+    }
+
+    fn usage(&self) -> &str {
+        "restart-server <server_id>"
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Servers
+    }
+
+    fn execute<'a>(
+        &'a self,
+        args: &'a [&'a str],
+        services: &'a ConsoleServices,
+    ) -> Pin<Box<dyn Future<Output = CommandOutput> + Send + 'a>> {
+        Box::pin(async move {
+            let sm = match services.server_manager.as_ref() {
+                Some(sm) => sm,
+                None => {
+                    return CommandOutput::Error("Server management is not configured".to_string());
+                }
+            };
+
+            let id = match args.first() {
+                Some(id) => *id,
+                None => {
+                    return CommandOutput::Error("Usage: restart-server <server_id>".to_string());
+                }
+            };
+
+            tracing::info!(target: "console", server = id, "Server restart requested from console");
+
+            match sm.restart_server(id).await {
+                Ok(()) => CommandOutput::Success(format!("Server '{id}' restarted")),
+                Err(e) => CommandOutput::Error(format!("Failed to restart server '{id}': {e}")),
+            }
+        })
+    }
+}
+
+/// How long `server-console` waits for the first line of output.
+const CONSOLE_REPLY_TIMEOUT: Duration = Duration::from_secs(2);
+/// Output is considered complete after this much silence.
+const CONSOLE_REPLY_QUIET: Duration = Duration::from_millis(250);
+
+pub struct ServerConsoleCommand;
+
+impl ConsoleCommand for ServerConsoleCommand {
+    fn name(&self) -> &str {
+        "server-console"
+    }
+
+    fn aliases(&self) -> &[&str] {
+        &["sc"]
+    }
+
+    fn description(&self) -> &str {
+        "Run a command on a managed server's console"
+    }
+
+    fn usage(&self) -> &str {
+        "server-console <server_id> <command...>"
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Servers
+    }
+
+    fn execute<'a>(
+        &'a self,
+        args: &'a [&'a str],
+        services: &'a ConsoleServices,
+    ) -> Pin<Box<dyn Future<Output = CommandOutput> + Send + 'a>> {
+        Box::pin(async move {
+            let sm = match services.server_manager.as_ref() {
+                Some(sm) => sm,
+                None => {
+                    return CommandOutput::Error("Server management is not configured".to_string());
+                }
+            };
+
+            let (id, command) = match args {
+                [id, command @ ..] if !command.is_empty() => (*id, command.join(" ")),
+                _ => {
+                    return CommandOutput::Error(
+                        "Usage: server-console <server_id> <command...>".to_string(),
+                    );
+                }
+            };
+
+            // Subscribe before sending so the reply cannot be missed.
+            let mut rx = match sm.subscribe_console(id) {
+                Ok((_, rx)) => Some(rx),
+                Err(infrarust_server_manager::ServerManagerError::Unsupported { .. }) => None,
+                Err(e) => return CommandOutput::Error(format!("Server '{id}': {e}")),
+            };
+
+            tracing::info!(target: "console", server = id, command = %command, "Console command sent to server");
+
+            if let Err(e) = sm.send_command(id, &command).await {
+                return CommandOutput::Error(format!("Failed to send command to '{id}': {e}"));
+            }
+
+            let Some(rx) = rx.as_mut() else {
+                return CommandOutput::Success(format!("Command sent to '{id}'"));
+            };
+
+            let mut lines = Vec::new();
+            let mut wait = CONSOLE_REPLY_TIMEOUT;
+            while let Ok(Ok(line)) = tokio::time::timeout(wait, rx.recv()).await {
+                lines.push(match line.stream {
+                    infrarust_server_manager::ConsoleStream::Stdout => OutputLine::Info(line.line),
+                    infrarust_server_manager::ConsoleStream::Stderr => {
+                        OutputLine::Warning(line.line)
+                    }
+                });
+                wait = CONSOLE_REPLY_QUIET;
+            }
+
+            if lines.is_empty() {
+                CommandOutput::Success(format!("Command sent to '{id}' (no output)"))
+            } else {
+                CommandOutput::Lines(lines)
+            }
+        })
+    }
+}
+
 fn format_server_state(state: &infrarust_server_manager::ServerState, is_tty: bool) -> String {
     use infrarust_server_manager::ServerState;
     if is_tty {
@@ -44,6 +44,9 @@ pub enum CoreError {
     #[error("docker connection error: {0}")]
     DockerConnection(String),
 
+    #[error("kubernetes api error: {0}")]
+    KubernetesApi(String),
+
     #[error("telemetry initialization error: {0}")]
     TelemetryInit(String),
 
@@ -190,6 +190,32 @@ pub fn protocol_direction_to_api(
     }
 }
 
+/// Converts the protocol client settings to the API representation.
+pub fn client_settings_to_api(
+    info: &infrarust_protocol::packets::ClientInformation,
+) -> infrarust_api::types::ClientSettings {
+    use infrarust_api::types::{ChatMode, ClientSettings, MainHand, SkinParts};
+
+    ClientSettings {
+        locale: info.locale.clone(),
+        view_distance: u8::try_from(info.view_distance).unwrap_or(0),
+        chat_mode: match info.chat_mode {
+            1 => ChatMode::CommandsOnly,
+            2 => ChatMode::Hidden,
+            _ => ChatMode::Enabled,
+        },
+        chat_colors: info.chat_colors,
+        skin_parts: SkinParts::from_bits(info.skin_parts),
+        main_hand: if info.main_hand == 0 {
+            MainHand::Left
+        } else {
+            MainHand::Right
+        },
+        text_filtering: info.text_filtering,
+        allow_server_listings: info.allow_server_listings,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used)]
@@ -42,7 +42,7 @@ pub(crate) fn log_proxy_loop_outcome(session_id: &Uuid, outcome: &ProxyLoopOutco
         ProxyLoopOutcome::ClientDisconnected => {
             tracing::info!(session = %session_id, "client disconnected");
         }
-        ProxyLoopOutcome::BackendDisconnected { reason } => {
+        ProxyLoopOutcome::BackendDisconnected { reason, .. } => {
             tracing::info!(session = %session_id, ?reason, "backend disconnected");
         }
         ProxyLoopOutcome::Shutdown => {
@@ -5,9 +5,10 @@ use std::sync::Arc;
 use infrarust_api::event::ResultedEvent;
 use infrarust_api::limbo::context::LimboEntryContext;
 use infrarust_api::limbo::handler::LimboHandler;
+use infrarust_config::ServerConfig;
 use infrarust_protocol::packets::login::SLoginAcknowledged;
 use infrarust_protocol::version::{ConnectionState, ProtocolVersion};
-use infrarust_transport::BackendConnector;
+use infrarust_transport::{BackendConnection, BackendConnector};
 
 use super::auth::AuthResult;
 use crate::error::CoreError;
@@ -17,6 +18,7 @@ use crate::pipeline::types::{HandshakeData, RoutingData};
 use crate::services::ProxyServices;
 use crate::session::backend_bridge::BackendBridge;
 use crate::session::client_bridge::ClientBridge;
+use crate::session::server_switch::fallback::{Fallback, fallback_chain};
 
 pub(super) enum ConnectionMode {
     Backend(BackendBridge),
@@ -78,20 +80,36 @@ pub(super) async fn resolve_initial_mode(
     backend_connector: &BackendConnector,
     connection_info: &infrarust_transport::ConnectionInfo,
 ) -> Result<InitialMode, CoreError> {
-    let server_config = &routing.server_config;
     let player_id = auth_result.player_id;
     let api_profile = &auth_result.api_profile;
 
     let initial_server = infrarust_api::types::ServerId::new(routing.config_id.clone());
-    let choose = infrarust_api::events::connection::PlayerChooseInitialServerEvent::new(
+    let mut choose = infrarust_api::events::connection::PlayerChooseInitialServerEvent::new(
         player_id,
         api_profile.clone(),
         initial_server.clone(),
     );
+    // Returning players default to their last server; plugins can still override it.
+    if let Some(last_server) = services.last_servers.as_ref().and_then(|store| {
+        store.choose_for(
+            services,
+            &api_profile.uuid,
+            &handshake.domain,
+            &routing.config_id,
+            &routing.server_config,
+        )
+    }) {
+        tracing::debug!(player = %api_profile.username, server = %last_server, "sending player to their last server");
+        choose.set_result(
+            infrarust_api::events::connection::PlayerChooseInitialServerResult::Redirect(
+                last_server,
+            ),
+        );
+    }
     let choose = services.event_bus.fire(choose).await;
 
     let mut initial_mode: Option<ConnectionMode> = None;
-    let target_server_id = match choose.result() {
+    let mut target_server_id = match choose.result() {
         infrarust_api::events::connection::PlayerChooseInitialServerResult::Allowed => {
             initial_server.clone()
         }
@@ -119,6 +137,23 @@ pub(super) async fn resolve_initial_mode(
         _ => initial_server.clone(),
     };
 
+    let target_config = if target_server_id == initial_server {
+        Arc::clone(&routing.server_config)
+    } else if let Some(config) = services
+        .domain_router
+        .find_by_server_id(target_server_id.as_str())
+    {
+        config
+    } else {
+        tracing::warn!(
+            server = %target_server_id,
+            "initial server redirect targets an unknown server, using the routed server"
+        );
+        target_server_id = initial_server.clone();
+        Arc::clone(&routing.server_config)
+    };
+    let server_config = &target_config;
+
     if initial_mode.is_none() {
         let pre_connect = infrarust_api::events::connection::ServerPreConnectEvent::new(
             player_id,
@@ -180,26 +215,63 @@ pub(super) async fn resolve_initial_mode(
     let mode = if let Some(limbo_mode) = initial_mode {
         limbo_mode
     } else {
-        match connect_to_backend(
-            client,
-            auth_result,
-            *login_completed,
-            routing,
-            handshake,
-            version,
-            services,
+        let primary = match dial_backend(
+            target_server_id.as_str(),
+            server_config,
             backend_connector,
             connection_info,
         )
         .await
         {
-            Ok(backend) => ConnectionMode::Backend(backend),
+            Ok(backend_conn) => login_to_backend(
+                auth_result,
+                *login_completed,
+                backend_conn,
+                server_config,
+                handshake,
+                version,
+                services,
+                connection_info,
+            )
+            .await
+            .map(ConnectionMode::Backend),
+            Err(e) => Err(e),
+        };
+
+        // A failed login gets the same fallbacks as an unreachable backend.
+        let connected = match primary {
+            Ok(mode) => Ok(mode),
+            Err(e) => match connect_to_fallback(
+                client,
+                auth_result,
+                login_completed,
+                &target_server_id,
+                server_config,
+                &e,
+                handshake,
+                version,
+                services,
+                backend_connector,
+                connection_info,
+            )
+            .await?
+            {
+                Some((mode, server_id)) => {
+                    target_server_id = server_id;
+                    Ok(mode)
+                }
+                None => Err(e),
+            },
+        };
+
+        match connected {
+            Ok(mode) => mode,
             Err(e) => {
                 if !server_config.limbo_handlers.is_empty() {
                     tracing::info!(
-                        server = %routing.config_id,
+                        server = %target_server_id,
                         error = %e,
-                        "backend unreachable, falling back to limbo"
+                        "backend connection failed, falling back to limbo"
                     );
 
                     prepare_client_for_limbo(
@@ -219,7 +291,7 @@ pub(super) async fn resolve_initial_mode(
                             LimboEntryContext::KickedFromServer {
                                 server: target_server_id.clone(),
                                 reason: infrarust_api::types::Component::text(format!(
-                                    "Backend unreachable: {e}"
+                                    "Backend connection failed: {e}"
                                 )),
                             },
                         )
@@ -230,9 +302,9 @@ pub(super) async fn resolve_initial_mode(
                     }
                 } else {
                     tracing::warn!(
-                        server = %routing.config_id,
+                        server = %target_server_id,
                         error = %e,
-                        "backend unreachable, sending disconnect to client"
+                        "backend connection failed, sending disconnect to client"
                     );
                     let msg = server_config.effective_disconnect_message();
                     client.disconnect(msg, &services.packet_registry).await.ok();
@@ -257,30 +329,125 @@ pub(super) async fn resolve_initial_mode(
     })
 }
 
+/// Tries the fallbacks of a server whose backend could not be reached or
+/// refused the login.
+///
+/// Returns `None` when no fallback accepted the player.
 #[allow(clippy::too_many_arguments)]
-async fn connect_to_backend(
+async fn connect_to_fallback(
     client: &mut ClientBridge,
     auth_result: &AuthResult,
-    login_completed: bool,
-    routing: &RoutingData,
+    login_completed: &mut bool,
+    server_id: &infrarust_api::types::ServerId,
+    server_config: &ServerConfig,
+    error: &CoreError,
     handshake: &HandshakeData,
     version: ProtocolVersion,
     services: &ProxyServices,
     backend_connector: &BackendConnector,
     connection_info: &infrarust_transport::ConnectionInfo,
-) -> Result<BackendBridge, CoreError> {
-    let server_config = &routing.server_config;
+) -> Result<Option<(ConnectionMode, infrarust_api::types::ServerId)>, CoreError> {
+    for fallback in fallback_chain(services, server_config) {
+        match fallback {
+            Fallback::Server(fallback_id, fallback_config) => {
+                let backend_conn = match dial_backend(
+                    fallback_id.as_str(),
+                    &fallback_config,
+                    backend_connector,
+                    connection_info,
+                )
+                .await
+                {
+                    Ok(conn) => conn,
+                    Err(e) => {
+                        tracing::debug!(fallback = %fallback_id, error = %e, "fallback unreachable");
+                        continue;
+                    }
+                };
+                tracing::info!(
+                    server = %server_id,
+                    fallback = %fallback_id,
+                    error = %error,
+                    "backend connection failed, connecting to fallback"
+                );
+                match login_to_backend(
+                    auth_result,
+                    *login_completed,
+                    backend_conn,
+                    &fallback_config,
+                    handshake,
+                    version,
+                    services,
+                    connection_info,
+                )
+                .await
+                {
+                    Ok(backend) => {
+                        return Ok(Some((ConnectionMode::Backend(backend), fallback_id)));
+                    }
+                    Err(e) => {
+                        tracing::debug!(fallback = %fallback_id, error = %e, "fallback refused login");
+                    }
+                }
+            }
+            Fallback::Limbo => {
+                let Some(handlers) = resolve_limbo_lenient(
+                    &services.limbo_handler_registry,
+                    &server_config.limbo_handlers,
+                ) else {
+                    continue;
+                };
+                tracing::info!(
+                    server = %server_id,
+                    error = %error,
+                    "backend connection failed, falling back to limbo"
+                );
+                prepare_client_for_limbo(client, auth_result, login_completed, version, services)
+                    .await?;
+                let ctx = LimboEntryContext::KickedFromServer {
+                    server: server_id.clone(),
+                    reason: infrarust_api::types::Component::text(format!(
+                        "Backend connection failed: {error}"
+                    )),
+                };
+                return Ok(Some((
+                    ConnectionMode::Limbo(handlers, ctx),
+                    server_id.clone(),
+                )));
+            }
+        }
+    }
+    Ok(None)
+}
 
-    let backend_conn = backend_connector
+async fn dial_backend(
+    server_id: &str,
+    server_config: &ServerConfig,
+    backend_connector: &BackendConnector,
+    connection_info: &infrarust_transport::ConnectionInfo,
+) -> Result<BackendConnection, CoreError> {
+    Ok(backend_connector
         .connect(
-            &routing.config_id,
+            server_id,
             &server_config.addresses,
             server_config.timeouts.as_ref().map(|t| t.connect),
             server_config.send_proxy_protocol,
             connection_info,
         )
-        .await?;
+        .await?)
+}
 
+#[allow(clippy::too_many_arguments)]
+async fn login_to_backend(
+    auth_result: &AuthResult,
+    login_completed: bool,
+    backend_conn: BackendConnection,
+    server_config: &ServerConfig,
+    handshake: &HandshakeData,
+    version: ProtocolVersion,
+    services: &ProxyServices,
+    connection_info: &infrarust_transport::ConnectionInfo,
+) -> Result<BackendBridge, CoreError> {
     let mut backend = BackendBridge::new(backend_conn.into_stream(), version);
 
     if login_completed {
@@ -306,16 +473,10 @@ async fn connect_to_backend(
 
         let velocity_ctx = services.forwarding_secret().map(|s| (&fwd_data, s));
 
-        if let Err(e) = backend
+        // On failure the caller picks a fallback or disconnects the client
+        backend
             .consume_backend_login(&services.packet_registry, version, velocity_ctx)
-            .await
-        {
-            client
-                .disconnect("Backend refused connection", &services.packet_registry)
-                .await
-                .ok();
-            return Err(e);
-        }
+            .await?;
 
         if version.no_less_than(ProtocolVersion::V1_20_2) {
             let ack = SLoginAcknowledged;
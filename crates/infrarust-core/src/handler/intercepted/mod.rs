@@ -9,6 +9,7 @@ use std::sync::Arc;
 use infrarust_api::event::ResultedEvent;
 use infrarust_api::events::lifecycle::{PermissionsSetupEvent, PermissionsSetupResult};
 use infrarust_api::permissions::PermissionChecker;
+use infrarust_api::player::Player;
 use tokio_util::sync::CancellationToken;
 
 use infrarust_transport::BackendConnector;
@@ -150,9 +151,7 @@ impl InterceptedHandler {
             auth_result.api_profile.clone(),
             infrarust_api::types::ProtocolVersion::new(version.0),
             ctx.peer_addr,
-            Some(infrarust_api::types::ServerId::new(
-                routing.config_id.clone(),
-            )),
+            Some(target_server_id.clone()),
             true, // active: intercepted modes support packet injection
             auth_result.online_mode,
             cmd_tx,
@@ -205,13 +204,27 @@ impl InterceptedHandler {
         )
         .await;
 
+        let last_server = self
+            .services
+            .connection_registry
+            .get(&session_id)
+            .and_then(|session| session.current_server())
+            .unwrap_or_else(|| infrarust_api::types::ServerId::new(routing.config_id.clone()));
+
+        if let Some(ref store) = self.services.last_servers
+            && let Some(config) = self
+                .services
+                .domain_router
+                .find_by_server_id(last_server.as_str())
+        {
+            store.record(auth_result.api_profile.uuid, last_server.as_str(), &config);
+        }
+
         super::helpers::fire_disconnect_event(
             &self.services.event_bus,
             auth_result.player_id,
             auth_result.username.clone(),
-            Some(infrarust_api::types::ServerId::new(
-                routing.config_id.clone(),
-            )),
+            Some(last_server),
         )
         .await;
 
@@ -5,21 +5,28 @@ use std::sync::Arc;
 use infrarust_api::limbo::context::LimboEntryContext;
 use infrarust_api::limbo::handler::LimboHandler;
 use infrarust_api::types::PlayerId;
+use infrarust_config::LIMBO_SERVER_ID;
+use infrarust_protocol::io::PacketFrame;
 use infrarust_protocol::version::ProtocolVersion;
 use infrarust_transport::BackendConnector;
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
 use infrarust_api::event::ResultedEvent;
+use infrarust_api::events::connection::KickedFromServerResult;
 
 use crate::error::CoreError;
 use crate::filter::codec_chain::CodecFilterChain;
 use crate::limbo::engine::{LimboExitResult, enter_limbo};
+use crate::limbo::reconnect::reconnect_handler;
 use crate::pipeline::types::HandshakeData;
 use crate::player::PlayerCommand;
+use crate::player::packets::build_sidebar_hide;
 use crate::services::ProxyServices;
 use crate::session::client_bridge::ClientBridge;
 use crate::session::proxy_loop::{ProxyLoopOutcome, proxy_loop};
+use crate::session::resource_packs::ResourcePackTracker;
+use crate::session::server_switch::fallback::{Fallback, fallback_chain};
 
 use super::initial_connect::ConnectionMode;
 
@@ -39,17 +46,26 @@ pub(super) async fn run_session_loop(
     mut current_server_id: infrarust_api::types::ServerId,
     session_id: &uuid::Uuid,
     services: &ProxyServices,
-    backend_connector: &BackendConnector,
+    backend_connector: &Arc<BackendConnector>,
     session_token: CancellationToken,
     cmd_rx: &mut mpsc::Receiver<PlayerCommand>,
     client_codec_chain: &mut CodecFilterChain,
     server_codec_chain: &mut CodecFilterChain,
 ) -> ProxyLoopOutcome {
     let mut mode = initial_mode;
+    let mut resource_packs = ResourcePackTracker::default();
+    let player = services.connection_registry.get(session_id);
 
     loop {
         match mode {
             ConnectionMode::Backend(ref mut backend) => {
+                resource_packs.set_server(
+                    &services.config,
+                    services
+                        .domain_router
+                        .find_by_server_id(current_server_id.as_str())
+                        .as_deref(),
+                );
                 let outcome = proxy_loop(
                     client,
                     backend,
@@ -60,12 +76,24 @@ pub(super) async fn run_session_loop(
                     player_id,
                     client_codec_chain,
                     server_codec_chain,
+                    &mut resource_packs,
+                    player.as_deref(),
                 )
                 .await;
 
+                if matches!(
+                    outcome,
+                    ProxyLoopOutcome::SwitchRequested { .. }
+                        | ProxyLoopOutcome::BackendDisconnected { .. }
+                ) {
+                    hide_sidebar(client, session_id, services, version).await;
+                }
+
                 match outcome {
-                    ProxyLoopOutcome::SwitchRequested { target } if target.as_str() == "$limbo" => {
-                        // "$limbo" sentinel: enter limbo for current server's handlers
+                    ProxyLoopOutcome::SwitchRequested { target }
+                        if target.as_str() == LIMBO_SERVER_ID =>
+                    {
+                        // Limbo sentinel: enter limbo for current server's handlers
                         let server_config = services
                             .domain_router
                             .find_by_server_id(current_server_id.as_str());
@@ -154,10 +182,37 @@ pub(super) async fn run_session_loop(
                             }
                         }
                     }
-                    ProxyLoopOutcome::BackendDisconnected { reason } => {
+                    ProxyLoopOutcome::BackendDisconnected { reason, kick } => {
+                        if let Some(handler) = reconnect_handler(
+                            services,
+                            backend_connector,
+                            &current_server_id,
+                            reason.as_deref(),
+                            handshake,
+                            version,
+                            peer_addr,
+                            real_ip,
+                        ) {
+                            tracing::info!(
+                                server = %current_server_id,
+                                ?reason,
+                                "backend went away, holding player until it is back"
+                            );
+                            mode = ConnectionMode::Limbo(
+                                vec![handler],
+                                LimboEntryContext::KickedFromServer {
+                                    server: current_server_id.clone(),
+                                    reason: infrarust_api::types::Component::text(
+                                        reason.as_deref().unwrap_or("Disconnected"),
+                                    ),
+                                },
+                            );
+                            continue;
+                        }
                         match handle_backend_disconnect(
                             client,
                             reason,
+                            kick,
                             player_id,
                             &current_server_id,
                             handshake,
@@ -352,6 +407,7 @@ enum DisconnectAction {
 async fn handle_backend_disconnect(
     client: &mut ClientBridge,
     reason: Option<String>,
+    kick: Option<PacketFrame>,
     player_id: PlayerId,
     current_server_id: &infrarust_api::types::ServerId,
     handshake: &HandshakeData,
@@ -364,20 +420,51 @@ async fn handle_backend_disconnect(
     real_ip: Option<std::net::IpAddr>,
 ) -> DisconnectAction {
     let kick_reason = reason.as_deref().unwrap_or("Disconnected");
-    let kicked = infrarust_api::events::connection::KickedFromServerEvent::new(
+    let fallbacks = services
+        .domain_router
+        .find_by_server_id(current_server_id.as_str())
+        .map(|config| fallback_chain(services, &config))
+        .unwrap_or_default();
+
+    let mut kicked = infrarust_api::events::connection::KickedFromServerEvent::new(
         player_id,
         current_server_id.clone(),
         infrarust_api::types::Component::text(kick_reason),
     );
+    // The first fallback is the default outcome; plugins can still override it.
+    if let Some(first) = fallbacks.first() {
+        kicked.set_result(fallback_result(first));
+    }
     let kicked = services.event_bus.fire(kicked).await;
 
+    if fallbacks
+        .first()
+        .is_some_and(|first| is_fallback_result(kicked.result(), first))
+    {
+        return handle_fallbacks(
+            client,
+            fallbacks,
+            kick_reason,
+            kick,
+            player_id,
+            current_server_id,
+            handshake,
+            game_profile_name,
+            api_profile,
+            version,
+            services,
+            backend_connector,
+            peer_addr,
+            real_ip,
+        )
+        .await;
+    }
+
     match kicked.result() {
         infrarust_api::events::connection::KickedFromServerResult::DisconnectPlayer { reason } => {
-            if let Ok(frame) =
-                crate::player::packets::build_disconnect(reason, version, &services.packet_registry)
-            {
-                let _ = client.write_frame(&frame).await;
-            }
+            // The backend's own kick is relayed unless a plugin picked another reason.
+            let kick = kick.filter(|_| is_default_kick_reason(reason));
+            send_kick(client, kick, reason, version, services).await;
             DisconnectAction::Break(ProxyLoopOutcome::ClientDisconnected)
         }
         infrarust_api::events::connection::KickedFromServerResult::RedirectTo(server) => {
@@ -402,6 +489,7 @@ async fn handle_backend_disconnect(
                 }
                 SwitchAction::Limbo(handlers, ctx) => {
                     if handlers.is_empty() {
+                        relay_kick(client, kick).await;
                         DisconnectAction::Break(ProxyLoopOutcome::ClientDisconnected)
                     } else {
                         DisconnectAction::SwitchLimbo(handlers, ctx)
@@ -409,8 +497,10 @@ async fn handle_backend_disconnect(
                 }
                 SwitchAction::Error(e) => {
                     tracing::warn!("redirect after kick failed: {e}");
+                    relay_kick(client, kick).await;
                     DisconnectAction::Break(ProxyLoopOutcome::BackendDisconnected {
                         reason: Some(e.to_string()),
+                        kick: None,
                     })
                 }
             }
@@ -441,13 +531,7 @@ async fn handle_backend_disconnect(
             } else {
                 tracing::warn!("SendToLimbo but no limbo handlers resolved, disconnecting");
                 let kick_component = infrarust_api::types::Component::text(kick_reason);
-                if let Ok(frame) = crate::player::packets::build_disconnect(
-                    &kick_component,
-                    version,
-                    &services.packet_registry,
-                ) {
-                    let _ = client.write_frame(&frame).await;
-                }
+                send_kick(client, kick, &kick_component, version, services).await;
                 DisconnectAction::Break(ProxyLoopOutcome::ClientDisconnected)
             }
         }
@@ -459,8 +543,179 @@ async fn handle_backend_disconnect(
             ) {
                 let _ = client.write_frame(&frame).await;
             }
-            DisconnectAction::Break(ProxyLoopOutcome::BackendDisconnected { reason: None })
+            relay_kick(client, kick).await;
+            DisconnectAction::Break(ProxyLoopOutcome::BackendDisconnected {
+                reason: None,
+                kick: None,
+            })
+        }
+        _ => {
+            relay_kick(client, kick).await;
+            DisconnectAction::Break(ProxyLoopOutcome::BackendDisconnected { reason, kick: None })
         }
-        _ => DisconnectAction::Break(ProxyLoopOutcome::BackendDisconnected { reason }),
     }
 }
+
+/// Whether no plugin replaced the default reason of a [`KickedFromServerEvent`].
+///
+/// [`KickedFromServerEvent`]: infrarust_api::events::connection::KickedFromServerEvent
+fn is_default_kick_reason(reason: &infrarust_api::types::Component) -> bool {
+    match KickedFromServerResult::default() {
+        KickedFromServerResult::DisconnectPlayer { reason: default } => {
+            default.to_json() == reason.to_json()
+        }
+        _ => false,
+    }
+}
+
+/// Removes the proxy sidebar before the player leaves their backend, so it
+/// never lingers over the next server's own scoreboards.
+async fn hide_sidebar(
+    client: &mut ClientBridge,
+    session_id: &uuid::Uuid,
+    services: &ProxyServices,
+    version: ProtocolVersion,
+) {
+    let Some(sidebar) = services
+        .connection_registry
+        .get(session_id)
+        .and_then(|session| session.take_sidebar())
+    else {
+        return;
+    };
+    let frames = build_sidebar_hide(&sidebar, version, &services.packet_registry);
+    for frame in frames.unwrap_or_default() {
+        let _ = client.write_frame(&frame).await;
+    }
+}
+
+/// Relays the backend's held-back disconnect packet, if any.
+async fn relay_kick(client: &mut ClientBridge, kick: Option<PacketFrame>) {
+    if let Some(frame) = kick {
+        let _ = client.write_frame(&frame).await;
+    }
+}
+
+/// Disconnects the client with the backend's own packet, or with `reason` when there is none.
+async fn send_kick(
+    client: &mut ClientBridge,
+    kick: Option<PacketFrame>,
+    reason: &infrarust_api::types::Component,
+    version: ProtocolVersion,
+    services: &ProxyServices,
+) {
+    let frame = match kick {
+        Some(frame) => frame,
+        None => match crate::player::packets::build_disconnect(
+            reason,
+            version,
+            &services.packet_registry,
+        ) {
+            Ok(frame) => frame,
+            Err(_) => return,
+        },
+    };
+    let _ = client.write_frame(&frame).await;
+}
+
+fn fallback_result(fallback: &Fallback) -> KickedFromServerResult {
+    match fallback {
+        Fallback::Server(id, _) => KickedFromServerResult::RedirectTo(id.clone()),
+        Fallback::Limbo => KickedFromServerResult::SendToLimbo {
+            limbo_handlers: Vec::new(),
+        },
+    }
+}
+
+fn is_fallback_result(result: &KickedFromServerResult, fallback: &Fallback) -> bool {
+    match (result, fallback) {
+        (KickedFromServerResult::RedirectTo(target), Fallback::Server(id, _)) => target == id,
+        (KickedFromServerResult::SendToLimbo { limbo_handlers }, Fallback::Limbo) => {
+            limbo_handlers.is_empty()
+        }
+        _ => false,
+    }
+}
+
+/// Sends a kicked player to the first fallback that accepts them,
+/// disconnecting them once the chain is exhausted.
+#[allow(clippy::too_many_arguments)]
+async fn handle_fallbacks(
+    client: &mut ClientBridge,
+    fallbacks: Vec<Fallback>,
+    kick_reason: &str,
+    kick: Option<PacketFrame>,
+    player_id: PlayerId,
+    current_server_id: &infrarust_api::types::ServerId,
+    handshake: &HandshakeData,
+    game_profile_name: &str,
+    api_profile: &infrarust_api::types::GameProfile,
+    version: ProtocolVersion,
+    services: &ProxyServices,
+    backend_connector: &BackendConnector,
+    peer_addr: std::net::SocketAddr,
+    real_ip: Option<std::net::IpAddr>,
+) -> DisconnectAction {
+    for fallback in fallbacks {
+        match fallback {
+            Fallback::Server(target, _) => {
+                match handle_switch(
+                    client,
+                    current_server_id,
+                    target.clone(),
+                    handshake,
+                    game_profile_name,
+                    player_id,
+                    api_profile,
+                    services,
+                    backend_connector,
+                    peer_addr,
+                    real_ip,
+                    version,
+                )
+                .await
+                {
+                    SwitchAction::Backend(new_backend, new_server) => {
+                        tracing::info!(
+                            from = %current_server_id,
+                            fallback = %new_server,
+                            "kicked player sent to fallback"
+                        );
+                        return DisconnectAction::SwitchBackend(new_backend, new_server);
+                    }
+                    SwitchAction::Limbo(handlers, ctx) if !handlers.is_empty() => {
+                        return DisconnectAction::SwitchLimbo(handlers, ctx);
+                    }
+                    SwitchAction::Limbo(..) => {}
+                    SwitchAction::Error(e) => {
+                        tracing::debug!(fallback = %target, "fallback failed: {e}");
+                    }
+                }
+            }
+            Fallback::Limbo => {
+                let handler_names = services
+                    .domain_router
+                    .find_by_server_id(current_server_id.as_str())
+                    .map(|c| c.limbo_handlers.clone())
+                    .unwrap_or_default();
+                let handlers = services
+                    .limbo_handler_registry
+                    .resolve_handlers_lenient(&handler_names);
+                if !handlers.is_empty() {
+                    return DisconnectAction::SwitchLimbo(
+                        handlers,
+                        LimboEntryContext::KickedFromServer {
+                            server: current_server_id.clone(),
+                            reason: infrarust_api::types::Component::text(kick_reason),
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    tracing::warn!(server = %current_server_id, "no fallback accepted the kicked player");
+    let kick_component = infrarust_api::types::Component::text(kick_reason);
+    send_kick(client, kick, &kick_component, version, services).await;
+    DisconnectAction::Break(ProxyLoopOutcome::ClientDisconnected)
+}
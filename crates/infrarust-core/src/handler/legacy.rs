@@ -214,6 +214,11 @@ impl LegacyHandler {
                 && let Some(state) = sm.get_state(&config_id)
                 && state != ServerState::Online
             {
+                let state = if state == ServerState::Sleeping && sm.wake_on_ping(&config_id) {
+                    ServerState::Starting
+                } else {
+                    state
+                };
                 return self
                     .build_state_response(variant, cfg, state, &config_id)
                     .unwrap_or_default();
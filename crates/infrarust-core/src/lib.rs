@@ -1,7 +1,7 @@
 //! Core proxy logic for Infrarust.
 //!
 //! Provides the middleware pipeline, connection handlers (passthrough, client-only, offline),
-//! configuration providers (file, docker), server routing, status handling, authentication,
+//! configuration providers (file, docker, kubernetes), server routing, status handling, authentication,
 //! ban management, and the event bus system.
 
 pub mod auth;
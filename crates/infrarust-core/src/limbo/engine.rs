@@ -18,7 +18,7 @@ use super::handler_chain::{LimboChainResult, LimboLoopState, run_handler_chain};
 use super::keepalive::KeepAliveState;
 use super::session::LimboSessionImpl;
 use super::virtual_session::VirtualSessionCore;
-use crate::player::packets::build_disconnect;
+use crate::player::packets::{build_boss_bar_remove, build_disconnect, build_sidebar_hide};
 use crate::services::ProxyServices;
 use crate::session::client_bridge::ClientBridge;
 
@@ -74,7 +74,7 @@ pub(crate) async fn enter_limbo(
 
     let chain_result = run_handler_chain(
         &handlers,
-        session,
+        Arc::clone(&session),
         client,
         &mut core,
         &mut limbo_state,
@@ -86,6 +86,9 @@ pub(crate) async fn enter_limbo(
     )
     .await;
 
+    hide_boss_bars(client, &session, version, registry).await;
+    hide_sidebar(client, &session, version, registry).await;
+
     map_chain_result(
         chain_result,
         client,
@@ -128,6 +131,35 @@ async fn map_chain_result(
     }
 }
 
+/// Hides the boss bars shown by limbo handlers before the player moves on.
+async fn hide_boss_bars(
+    client: &mut ClientBridge,
+    session: &LimboSessionImpl,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) {
+    for id in session.detach_boss_bars() {
+        if let Ok(Some(frame)) = build_boss_bar_remove(id, version, registry) {
+            let _ = client.write_frame(&frame).await;
+        }
+    }
+}
+
+/// Removes the sidebar shown by limbo handlers before the player moves on.
+async fn hide_sidebar(
+    client: &mut ClientBridge,
+    session: &LimboSessionImpl,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) {
+    let Some(sidebar) = session.detach_sidebar() else {
+        return;
+    };
+    for frame in build_sidebar_hide(&sidebar, version, registry).unwrap_or_default() {
+        let _ = client.write_frame(&frame).await;
+    }
+}
+
 async fn send_disconnect(
     client: &mut ClientBridge,
     reason: &Component,
@@ -22,6 +22,7 @@ use super::spawn::send_spawn_sequence;
 use super::virtual_session::VirtualSessionCore;
 use crate::services::ProxyServices;
 use crate::session::client_bridge::ClientBridge;
+use crate::session::latency::record_latency;
 
 pub(crate) struct LimboLoopState {
     pub complete_rx: watch::Receiver<Option<HandlerResult>>,
@@ -133,8 +134,11 @@ async fn wait_for_hold(
                 match frame {
                     Ok(Some(frame)) => {
                         if is_keepalive_response(&frame, &core.packet_registry, core.protocol_version) {
-                            if let Some(id) = extract_keepalive_id(&frame, core.protocol_version) {
-                                limbo_state.keepalive.on_response(id);
+                            if let Some(rtt) = extract_keepalive_id(&frame, core.protocol_version)
+                                .and_then(|id| limbo_state.keepalive.on_response(id))
+                                && let Some(player) = services.connection_registry.find_by_player_id(core.player_id)
+                            {
+                                record_latency(&player, services, rtt);
                             }
                         } else if let Some(msg) = parse_client_message(&frame, &core.packet_registry, core.protocol_version) {
                             match msg {
@@ -0,0 +1,263 @@
+//! Reconnect limbo — built-in handler that holds players while their backend restarts.
+//!
+//! Entered directly by the session loop (not through `limbo_handlers`) when a
+//! server with `[reconnect]` goes away. The handler polls the server manager
+//! state and a status ping, then completes so the player is switched back.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use infrarust_api::event::BoxFuture;
+use infrarust_api::limbo::handler::{HandlerResult, LimboHandler};
+use infrarust_api::limbo::session::LimboSession;
+use infrarust_api::types::{Component, PlayerId, ServerId, TitleData};
+use infrarust_config::{ReconnectConfig, ServerConfig};
+use infrarust_protocol::version::ProtocolVersion;
+use infrarust_server_manager::{ServerManagerService, ServerState};
+use infrarust_transport::{BackendConnector, ConnectionInfo};
+
+use crate::pipeline::types::HandshakeData;
+use crate::services::ProxyServices;
+use crate::status::relay::StatusRelayClient;
+
+/// Upper bound for a single status ping while polling.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Returns `true` if a disconnect looks like a restart rather than a kick.
+///
+/// A lost connection always counts, as does a server the manager reports as
+/// going down or coming back up.
+pub(crate) fn is_restart(
+    config: &ReconnectConfig,
+    reason: Option<&str>,
+    state: Option<ServerState>,
+) -> bool {
+    let Some(reason) = reason else {
+        return true;
+    };
+    if matches!(
+        state,
+        Some(ServerState::Stopping | ServerState::Starting | ServerState::Crashed)
+    ) {
+        return true;
+    }
+    let reason = reason.to_lowercase();
+    config
+        .kick_reasons
+        .iter()
+        .any(|pattern| reason.contains(&pattern.to_lowercase()))
+}
+
+/// Builds the reconnect handler for a player whose backend went away,
+/// if the server has `[reconnect]` set and the disconnect looks like a restart.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn reconnect_handler(
+    services: &ProxyServices,
+    backend_connector: &Arc<BackendConnector>,
+    server_id: &ServerId,
+    reason: Option<&str>,
+    handshake: &HandshakeData,
+    version: ProtocolVersion,
+    peer_addr: std::net::SocketAddr,
+    real_ip: Option<std::net::IpAddr>,
+) -> Option<Arc<dyn LimboHandler>> {
+    let server_config = services
+        .domain_router
+        .find_by_server_id(server_id.as_str())?;
+    let config = server_config.reconnect.clone()?;
+    let state = services
+        .server_manager
+        .as_ref()
+        .and_then(|sm| sm.get_state(server_id.as_str()));
+    if !is_restart(&config, reason, state) {
+        return None;
+    }
+
+    let wait = ReconnectWait {
+        server_id: server_id.clone(),
+        server_config,
+        config,
+        server_manager: services.server_manager.clone(),
+        relay: StatusRelayClient::new(
+            Arc::clone(backend_connector),
+            Arc::clone(&services.packet_registry),
+            PROBE_TIMEOUT,
+        ),
+        domain: handshake.domain.clone(),
+        version,
+        connection_info: ConnectionInfo {
+            peer_addr,
+            real_ip,
+            real_port: None,
+            local_addr: peer_addr, // Not critical for outgoing backend connections
+            connected_at: tokio::time::Instant::now(),
+        },
+    };
+    Some(Arc::new(ReconnectHandler {
+        wait: Arc::new(wait),
+        cancel: CancellationToken::new(),
+    }))
+}
+
+struct ReconnectWait {
+    server_id: ServerId,
+    server_config: Arc<ServerConfig>,
+    config: ReconnectConfig,
+    server_manager: Option<Arc<ServerManagerService>>,
+    relay: StatusRelayClient,
+    domain: String,
+    version: ProtocolVersion,
+    connection_info: ConnectionInfo,
+}
+
+impl ReconnectWait {
+    /// The server is back once the manager (if any) reports it online and it answers a ping.
+    async fn is_back(&self) -> bool {
+        let state = self
+            .server_manager
+            .as_ref()
+            .and_then(|sm| sm.get_state(self.server_id.as_str()));
+        if state.is_some_and(|s| s != ServerState::Online) {
+            return false;
+        }
+        self.relay
+            .relay(
+                self.server_id.as_str(),
+                &self.server_config,
+                &self.domain,
+                self.version,
+                &self.connection_info,
+            )
+            .await
+            .is_ok()
+    }
+
+    fn title(&self, elapsed: Duration) -> TitleData {
+        let elapsed = elapsed.as_secs().to_string();
+        let vars = &[
+            ("server", self.server_id.as_str()),
+            ("elapsed", elapsed.as_str()),
+        ];
+        // Stays up until the next refresh.
+        let stay = i32::try_from(self.config.poll_interval.as_millis() / 50)
+            .unwrap_or(i32::MAX)
+            .saturating_add(40);
+        TitleData::new(
+            Component::from_legacy_format(&self.config.title, vars),
+            Component::from_legacy_format(&self.config.subtitle, vars),
+        )
+        .fade_in(0)
+        .stay(stay)
+    }
+
+    fn timeout_result(&self) -> HandlerResult {
+        match &self.config.fallback {
+            Some(fallback) => HandlerResult::Redirect(ServerId::new(fallback.clone())),
+            None => HandlerResult::Deny(Component::from_legacy(&self.config.timeout_message)),
+        }
+    }
+}
+
+/// Holds a player until their server is back, then lets the session switch them to it.
+pub(crate) struct ReconnectHandler {
+    wait: Arc<ReconnectWait>,
+    cancel: CancellationToken,
+}
+
+impl LimboHandler for ReconnectHandler {
+    fn name(&self) -> &str {
+        "reconnect"
+    }
+
+    fn on_player_enter<'a>(
+        &'a self,
+        session: &'a dyn LimboSession,
+    ) -> BoxFuture<'a, HandlerResult> {
+        Box::pin(async move {
+            let _ = session.send_title(self.wait.title(Duration::ZERO));
+
+            let wait = Arc::clone(&self.wait);
+            let handle = session.handle();
+            let cancel = self.cancel.clone();
+            tokio::spawn(async move {
+                let started = tokio::time::Instant::now();
+                loop {
+                    tokio::select! {
+                        () = cancel.cancelled() => return,
+                        () = tokio::time::sleep(wait.config.poll_interval) => {}
+                    }
+
+                    if wait.is_back().await {
+                        tracing::info!(server = %wait.server_id, "server is back, reconnecting player");
+                        handle.complete(HandlerResult::Accept);
+                        return;
+                    }
+                    if started.elapsed() >= wait.config.timeout {
+                        tracing::info!(
+                            server = %wait.server_id,
+                            fallback = ?wait.config.fallback,
+                            "server did not come back in time"
+                        );
+                        handle.complete(wait.timeout_result());
+                        return;
+                    }
+                    let _ = handle.send_title(wait.title(started.elapsed()));
+                }
+            });
+
+            HandlerResult::Hold
+        })
+    }
+
+    fn on_disconnect(&self, _player_id: PlayerId) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            self.cancel.cancel();
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lost_connection_is_restart() {
+        let config = ReconnectConfig::default();
+        assert!(is_restart(&config, None, None));
+        assert!(is_restart(&config, None, Some(ServerState::Online)));
+    }
+
+    #[test]
+    fn test_kick_reason_matching() {
+        let config = ReconnectConfig::default();
+        assert!(is_restart(
+            &config,
+            Some(r#"{"text":"Server closed"}"#),
+            None
+        ));
+        assert!(is_restart(
+            &config,
+            Some(r#"{"translate":"multiplayer.disconnect.server_shutdown"}"#),
+            None
+        ));
+        assert!(is_restart(&config, Some("Server is RESTARTING"), None));
+        assert!(!is_restart(&config, Some("You are banned"), None));
+    }
+
+    #[test]
+    fn test_managed_state_overrides_reason() {
+        let config = ReconnectConfig::default();
+        assert!(is_restart(
+            &config,
+            Some("You are banned"),
+            Some(ServerState::Stopping)
+        ));
+        assert!(!is_restart(
+            &config,
+            Some("You are banned"),
+            Some(ServerState::Online)
+        ));
+    }
+}
@@ -3,21 +3,25 @@
 //! Bridges the API-level [`LimboSession`] trait to concrete packet encoding
 //! and an mpsc channel that the limbo engine loop drains.
 
-use std::sync::{Arc, OnceLock, Weak};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock, Weak};
 
 use tokio::sync::{mpsc, watch};
 
+use infrarust_api::boss_bar::{BossBar, BossBarChange, BossBarViewer};
 use infrarust_api::error::PlayerError;
 use infrarust_api::limbo::context::LimboEntryContext;
 use infrarust_api::limbo::handle::SessionHandle;
 use infrarust_api::limbo::handler::HandlerResult;
 use infrarust_api::limbo::session::{LimboSession, private};
+use infrarust_api::scoreboard::{Sidebar, SidebarChange, SidebarViewer};
 use infrarust_api::types::{Component, GameProfile, PlayerId, TitleData};
 use infrarust_protocol::io::PacketFrame;
 use infrarust_protocol::registry::PacketRegistry;
 use infrarust_protocol::version::ProtocolVersion;
 
-use crate::player::packets;
+use crate::error::CoreError;
+use crate::player::{SidebarSnapshot, packets};
 
 /// Concrete implementation of [`LimboSession`] used by the limbo engine.
 ///
@@ -32,6 +36,8 @@ pub(crate) struct LimboSessionImpl {
     complete_sender: watch::Sender<Option<HandlerResult>>,
     packet_registry: Arc<PacketRegistry>,
     self_ref: OnceLock<Weak<Self>>,
+    boss_bars: Mutex<HashMap<uuid::Uuid, BossBar>>,
+    sidebar: Mutex<Option<(Sidebar, SidebarSnapshot)>>,
 }
 
 impl LimboSessionImpl {
@@ -53,16 +59,121 @@ impl LimboSessionImpl {
             complete_sender,
             packet_registry,
             self_ref: OnceLock::new(),
+            boss_bars: Mutex::new(HashMap::new()),
+            sidebar: Mutex::new(None),
         }
     }
 
     pub(crate) fn set_self_ref(&self, weak: Weak<Self>) {
         let _ = self.self_ref.set(weak);
     }
+
+    /// Stops tracking every boss bar shown to this session and returns their
+    /// ids, so the engine can hide them when the player leaves limbo.
+    pub(crate) fn detach_boss_bars(&self) -> Vec<uuid::Uuid> {
+        let bars: Vec<BossBar> = self
+            .boss_bars
+            .lock()
+            .expect("lock poisoned")
+            .drain()
+            .map(|(_, bar)| bar)
+            .collect();
+        let viewer = self.boss_bar_viewer();
+        bars.iter()
+            .map(|bar| {
+                bar.remove_viewer(&viewer);
+                bar.id()
+            })
+            .collect()
+    }
+
+    /// Stops tracking the sidebar shown to this session and returns it as
+    /// last sent, so the engine can remove it when the player leaves limbo.
+    pub(crate) fn detach_sidebar(&self) -> Option<SidebarSnapshot> {
+        let (sidebar, sent) = self.sidebar.lock().expect("lock poisoned").take()?;
+        sidebar.remove_viewer(&self.sidebar_viewer());
+        Some(sent)
+    }
+
+    fn sidebar_viewer(&self) -> Weak<dyn SidebarViewer> {
+        self.self_ref
+            .get()
+            .expect("LimboSessionImpl::set_self_ref must be called before showing sidebars")
+            .clone() as Weak<dyn SidebarViewer>
+    }
+
+    fn send_frames(&self, frames: Result<Vec<PacketFrame>, CoreError>) -> Result<(), PlayerError> {
+        for frame in frames.map_err(|e| PlayerError::SendFailed(e.to_string()))? {
+            self.send_frame(Some(frame))?;
+        }
+        Ok(())
+    }
+
+    fn boss_bar_viewer(&self) -> Weak<dyn BossBarViewer> {
+        self.self_ref
+            .get()
+            .expect("LimboSessionImpl::set_self_ref must be called before showing boss bars")
+            .clone() as Weak<dyn BossBarViewer>
+    }
+
+    fn send_frame(&self, frame: Option<PacketFrame>) -> Result<(), PlayerError> {
+        match frame {
+            Some(frame) => self
+                .client_sender
+                .try_send(frame)
+                .map_err(|e| PlayerError::SendFailed(e.to_string())),
+            None => Ok(()),
+        }
+    }
 }
 
 impl private::Sealed for LimboSessionImpl {}
 
+impl infrarust_api::boss_bar::private::Sealed for LimboSessionImpl {}
+
+impl BossBarViewer for LimboSessionImpl {
+    fn boss_bar_changed(&self, bar: &BossBar, change: BossBarChange) {
+        let result = packets::build_boss_bar_update(
+            bar,
+            change,
+            self.protocol_version,
+            &self.packet_registry,
+        )
+        .map_err(|e| PlayerError::SendFailed(e.to_string()))
+        .and_then(|frame| self.send_frame(frame));
+        if let Err(e) = result {
+            tracing::trace!(bar = %bar.id(), error = %e, "limbo boss bar update dropped");
+        }
+    }
+}
+
+impl infrarust_api::scoreboard::private::Sealed for LimboSessionImpl {}
+
+impl SidebarViewer for LimboSessionImpl {
+    fn sidebar_changed(&self, sidebar: &Sidebar, _change: SidebarChange) {
+        let mut shown = self.sidebar.lock().expect("lock poisoned");
+        let Some((current, sent)) = shown.as_mut() else {
+            return;
+        };
+        if current != sidebar {
+            return;
+        }
+        let snapshot = SidebarSnapshot::of(sidebar);
+        let frames = packets::build_sidebar_update(
+            sent,
+            &snapshot,
+            self.protocol_version,
+            &self.packet_registry,
+        );
+        match self.send_frames(frames) {
+            Ok(()) => *sent = snapshot,
+            Err(e) => {
+                tracing::trace!(sidebar = %sidebar.id(), error = %e, "limbo sidebar update dropped");
+            }
+        }
+    }
+}
+
 impl LimboSession for LimboSessionImpl {
     fn player_id(&self) -> PlayerId {
         self.player_id
@@ -112,6 +223,69 @@ impl LimboSession for LimboSessionImpl {
             .map_err(|e| PlayerError::SendFailed(e.to_string()))
     }
 
+    fn show_boss_bar(&self, bar: &BossBar) -> Result<(), PlayerError> {
+        let frame = packets::build_boss_bar_add(bar, self.protocol_version, &self.packet_registry)
+            .map_err(|e| PlayerError::SendFailed(e.to_string()))?;
+        self.send_frame(frame)?;
+        bar.add_viewer(self.boss_bar_viewer());
+        self.boss_bars
+            .lock()
+            .expect("lock poisoned")
+            .insert(bar.id(), bar.clone());
+        Ok(())
+    }
+
+    fn hide_boss_bar(&self, bar: &BossBar) -> Result<(), PlayerError> {
+        bar.remove_viewer(&self.boss_bar_viewer());
+        if self
+            .boss_bars
+            .lock()
+            .expect("lock poisoned")
+            .remove(&bar.id())
+            .is_none()
+        {
+            return Ok(());
+        }
+        let frame =
+            packets::build_boss_bar_remove(bar.id(), self.protocol_version, &self.packet_registry)
+                .map_err(|e| PlayerError::SendFailed(e.to_string()))?;
+        self.send_frame(frame)
+    }
+
+    fn show_sidebar(&self, sidebar: &Sidebar) -> Result<(), PlayerError> {
+        let viewer = self.sidebar_viewer();
+        let mut shown = self.sidebar.lock().expect("lock poisoned");
+        if let Some((previous, sent)) = shown.take() {
+            previous.remove_viewer(&viewer);
+            self.send_frames(packets::build_sidebar_hide(
+                &sent,
+                self.protocol_version,
+                &self.packet_registry,
+            ))?;
+        }
+        sidebar.add_viewer(viewer.clone());
+        let snapshot = SidebarSnapshot::of(sidebar);
+        let frames =
+            packets::build_sidebar_show(&snapshot, self.protocol_version, &self.packet_registry);
+        if let Err(e) = self.send_frames(frames) {
+            sidebar.remove_viewer(&viewer);
+            return Err(e);
+        }
+        *shown = Some((sidebar.clone(), snapshot));
+        Ok(())
+    }
+
+    fn hide_sidebar(&self) -> Result<(), PlayerError> {
+        match self.detach_sidebar() {
+            Some(sent) => self.send_frames(packets::build_sidebar_hide(
+                &sent,
+                self.protocol_version,
+                &self.packet_registry,
+            )),
+            None => Ok(()),
+        }
+    }
+
     fn complete(&self, result: HandlerResult) {
         let _ = self.complete_sender.send(Some(result));
     }
@@ -215,6 +389,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn boss_bar_is_shown_updated_and_detached() {
+        use infrarust_api::boss_bar::{BossBarColor, BossBarStyle};
+
+        let (session, mut rx, _crx) = make_session();
+        let session = Arc::new(session);
+        session.set_self_ref(Arc::downgrade(&session));
+        let bar = BossBar::new(
+            Component::text("Queue"),
+            0.0,
+            BossBarColor::Blue,
+            BossBarStyle::Progress,
+        );
+
+        session.show_boss_bar(&bar).unwrap();
+        assert!(rx.try_recv().is_ok(), "add frame expected");
+        assert_eq!(bar.viewer_count(), 1);
+
+        bar.set_progress(0.5);
+        assert!(rx.try_recv().is_ok(), "update frame expected");
+
+        assert_eq!(session.detach_boss_bars(), vec![bar.id()]);
+        assert_eq!(bar.viewer_count(), 0);
+        bar.set_progress(1.0);
+        assert!(rx.try_recv().is_err(), "detached session gets no updates");
+    }
+
+    #[test]
+    fn sidebar_is_shown_updated_and_detached() {
+        let (session, mut rx, _crx) = make_session();
+        let session = Arc::new(session);
+        session.set_self_ref(Arc::downgrade(&session));
+        let sidebar = Sidebar::new(Component::text("Queue"));
+        sidebar.set_lines(vec![Component::text("Position: 3")]);
+
+        session.show_sidebar(&sidebar).unwrap();
+        let mut sent = 0;
+        while rx.try_recv().is_ok() {
+            sent += 1;
+        }
+        // Objective, score, display slot
+        assert_eq!(sent, 3);
+
+        sidebar.set_line(0, Component::text("Position: 2"));
+        assert!(rx.try_recv().is_ok(), "update frame expected");
+        assert!(rx.try_recv().is_err(), "only the changed line is sent");
+
+        let detached = session.detach_sidebar().expect("sidebar was shown");
+        assert_eq!(detached.lines[0].to_string(), "Position: 2");
+        assert_eq!(sidebar.viewer_count(), 0);
+    }
+
     #[test]
     fn send_message_fails_when_channel_closed() {
         let (tx, rx) = mpsc::channel(1);
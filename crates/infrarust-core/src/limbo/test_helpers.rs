@@ -83,6 +83,12 @@ pub fn test_proxy_services() -> ProxyServices {
     let ban_storage: Arc<dyn BanStorage> = Arc::new(NullBanStorage);
     let provider: Arc<dyn crate::registry_data::RegistryDataProvider> =
         Arc::new(crate::registry_data::embedded::EmbeddedRegistryDataProvider);
+    let domain_router = Arc::new(DomainRouter::new());
+    let tab_list = Arc::new(crate::services::tab_list::TabListManager::new(
+        None,
+        Arc::clone(&connection_registry),
+        Arc::clone(&domain_router),
+    ));
 
     ProxyServices {
         event_bus: Arc::new(EventBusImpl::new()),
@@ -96,7 +102,7 @@ pub fn test_proxy_services() -> ProxyServices {
             Arc::new(ConnectionRegistry::new()),
         )),
         config: Arc::new(toml::from_str("").unwrap()),
-        domain_router: Arc::new(DomainRouter::new()),
+        domain_router,
         codec_filter_registry: Arc::new(CodecFilterRegistryImpl::new()),
         transport_filter_chain: TransportFilterChain::empty(),
         limbo_handler_registry: Arc::new(LimboHandlerRegistry::new()),
@@ -107,6 +113,11 @@ pub fn test_proxy_services() -> ProxyServices {
         permission_service: Arc::new(crate::permissions::PermissionService::new_sync(
             &Default::default(),
         )),
+        last_servers: None,
+        tab_list,
+        player_data: Arc::new(crate::player::data_store::SqlitePlayerDataStore::in_memory()),
+        #[cfg(feature = "telemetry")]
+        metrics: None,
     }
 }
 
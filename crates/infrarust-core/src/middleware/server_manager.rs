@@ -52,6 +52,7 @@ impl Middleware for ServerManagerMiddleware {
             let Some(state) = self.server_manager.get_state(&server_id) else {
                 return Ok(MiddlewareResult::Continue);
             };
+            self.server_manager.record_join(&server_id);
 
             match state {
                 ServerState::Stopping => Ok(MiddlewareResult::Reject(
@@ -65,6 +66,9 @@ impl Middleware for ServerManagerMiddleware {
                                 "Server failed to start in time. Please try again.".into(),
                             ))
                         }
+                        Err(ServerManagerError::OutsideSchedule { message, .. }) => {
+                            Ok(MiddlewareResult::Reject(message))
+                        }
                         Err(e) => {
                             tracing::error!(server = %server_id, "server manager error: {e}");
                             Ok(MiddlewareResult::Reject(
@@ -0,0 +1,390 @@
+//! Persistent per-player key/value data, exposed to plugins as [`PlayerDataStore`].
+
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rusqlite::{Connection, OptionalExtension, params};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use infrarust_api::error::ServiceError;
+use infrarust_api::services::player_data::{PlayerDataEntry, PlayerDataStore};
+
+/// How often expired values are deleted from the database.
+const PURGE_INTERVAL: Duration = Duration::from_secs(600);
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS player_data (
+        player    TEXT    NOT NULL,
+        namespace TEXT    NOT NULL,
+        key       TEXT    NOT NULL,
+        value     TEXT    NOT NULL,
+        expires   INTEGER,
+        PRIMARY KEY (player, namespace, key)
+    ) WITHOUT ROWID;
+    CREATE INDEX IF NOT EXISTS player_data_expires
+        ON player_data (expires) WHERE expires IS NOT NULL;
+";
+
+/// Player data kept in an SQLite database.
+///
+/// Every change is written straight to the database, so a change costs the
+/// same no matter how many players are stored. Values hold compact JSON and
+/// `expires` is a unix time in milliseconds.
+///
+/// Queries block, so on a multi-threaded runtime they run through
+/// [`tokio::task::block_in_place`] and the worker's other tasks move on to
+/// another thread meanwhile.
+pub struct SqlitePlayerDataStore {
+    conn: Mutex<Connection>,
+}
+
+impl SqlitePlayerDataStore {
+    /// Opens (or creates) the database at `path`.
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be opened or isn't a database.
+    pub fn open(path: &Path) -> rusqlite::Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
+        Self::with_connection(conn)
+    }
+
+    /// Creates a store that only lives in memory, for tests.
+    pub fn in_memory() -> Self {
+        Connection::open_in_memory()
+            .and_then(Self::with_connection)
+            .expect("in-memory database")
+    }
+
+    fn with_connection(conn: Connection) -> rusqlite::Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    fn conn(&self) -> MutexGuard<'_, Connection> {
+        self.conn.lock().expect("lock poisoned")
+    }
+
+    /// Deletes expired values periodically, and checkpoints the database
+    /// on shutdown.
+    pub fn start_purge_task(
+        self: &Arc<Self>,
+        shutdown: CancellationToken,
+    ) -> tokio::task::JoinHandle<()> {
+        let store = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(PURGE_INTERVAL);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                tokio::select! {
+                    biased;
+                    () = shutdown.cancelled() => break,
+                    _ = ticker.tick() => {
+                        let purge = Arc::clone(&store);
+                        match tokio::task::spawn_blocking(move || purge.purge_expired(unix_millis())).await {
+                            Ok(Ok(0)) => {}
+                            Ok(Ok(n)) => tracing::debug!(count = n, "purged expired player data"),
+                            Ok(Err(e)) => tracing::warn!(error = %e, "failed to purge expired player data"),
+                            Err(e) => tracing::warn!(error = %e, "player data purge task failed"),
+                        }
+                    }
+                }
+            }
+            let checkpoint = tokio::task::spawn_blocking(move || {
+                store
+                    .conn()
+                    .execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")
+            })
+            .await;
+            if let Ok(Err(e)) = checkpoint {
+                tracing::warn!(error = %e, "failed to checkpoint player data");
+            }
+        })
+    }
+
+    fn purge_expired(&self, now: i64) -> rusqlite::Result<usize> {
+        self.conn()
+            .execute("DELETE FROM player_data WHERE expires <= ?1", params![now])
+    }
+
+    fn try_get_raw(
+        &self,
+        player: &Uuid,
+        namespace: &str,
+        key: &str,
+    ) -> rusqlite::Result<Option<String>> {
+        self.conn()
+            .query_row(
+                "SELECT value FROM player_data
+                 WHERE player = ?1 AND namespace = ?2 AND key = ?3
+                   AND (expires IS NULL OR expires > ?4)",
+                params![player.to_string(), namespace, key, unix_millis()],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+
+    fn try_keys(&self, player: &Uuid, namespace: &str) -> rusqlite::Result<Vec<String>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare_cached(
+            "SELECT key FROM player_data
+             WHERE player = ?1 AND namespace = ?2 AND (expires IS NULL OR expires > ?3)
+             ORDER BY key",
+        )?;
+        stmt.query_map(
+            params![player.to_string(), namespace, unix_millis()],
+            |row| row.get(0),
+        )?
+        .collect()
+    }
+
+    fn try_clear(&self, player: &Uuid, namespace: &str) -> rusqlite::Result<usize> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        let player = player.to_string();
+        let live = tx.execute(
+            "DELETE FROM player_data
+             WHERE player = ?1 AND namespace = ?2 AND (expires IS NULL OR expires > ?3)",
+            params![player, namespace, unix_millis()],
+        )?;
+        tx.execute(
+            "DELETE FROM player_data WHERE player = ?1 AND namespace = ?2",
+            params![player, namespace],
+        )?;
+        tx.commit()?;
+        Ok(live)
+    }
+
+    fn try_entries(&self, player: &Uuid) -> rusqlite::Result<Vec<PlayerDataEntry>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare_cached(
+            "SELECT namespace, key, value, expires FROM player_data
+             WHERE player = ?1 AND (expires IS NULL OR expires > ?2)
+             ORDER BY namespace, key",
+        )?;
+        stmt.query_map(params![player.to_string(), unix_millis()], |row| {
+            let expires: Option<i64> = row.get(3)?;
+            Ok(PlayerDataEntry {
+                namespace: row.get(0)?,
+                key: row.get(1)?,
+                value: row.get(2)?,
+                expires_at: expires
+                    .map(|ms| UNIX_EPOCH + Duration::from_millis(u64::try_from(ms).unwrap_or(0))),
+            })
+        })?
+        .collect()
+    }
+}
+
+impl infrarust_api::services::player_data::private::Sealed for SqlitePlayerDataStore {}
+
+impl PlayerDataStore for SqlitePlayerDataStore {
+    fn get_raw(&self, player: &Uuid, namespace: &str, key: &str) -> Option<String> {
+        blocking(|| self.try_get_raw(player, namespace, key))
+            .inspect_err(|e| tracing::warn!(error = %e, "failed to read player data"))
+            .ok()
+            .flatten()
+    }
+
+    fn set_raw(
+        &self,
+        player: &Uuid,
+        namespace: &str,
+        key: &str,
+        json: String,
+        ttl: Option<Duration>,
+    ) -> Result<(), ServiceError> {
+        if namespace.is_empty() || key.is_empty() {
+            return Err(ServiceError::OperationFailed(
+                "namespace and key must not be empty".to_string(),
+            ));
+        }
+        let value: serde_json::Value = serde_json::from_str(&json)
+            .map_err(|e| ServiceError::OperationFailed(format!("{namespace}/{key}: {e}")))?;
+        let expires = ttl.map(|ttl| {
+            unix_millis().saturating_add(i64::try_from(ttl.as_millis()).unwrap_or(i64::MAX))
+        });
+
+        blocking(|| {
+            self.conn().execute(
+                "INSERT OR REPLACE INTO player_data (player, namespace, key, value, expires)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    player.to_string(),
+                    namespace,
+                    key,
+                    value.to_string(),
+                    expires
+                ],
+            )
+        })
+        .map_err(|e| ServiceError::OperationFailed(format!("{namespace}/{key}: {e}")))?;
+        Ok(())
+    }
+
+    fn remove(&self, player: &Uuid, namespace: &str, key: &str) -> bool {
+        blocking(|| {
+            self.conn().execute(
+                "DELETE FROM player_data WHERE player = ?1 AND namespace = ?2 AND key = ?3",
+                params![player.to_string(), namespace, key],
+            )
+        })
+        .inspect_err(|e| tracing::warn!(error = %e, "failed to remove player data"))
+        .is_ok_and(|removed| removed > 0)
+    }
+
+    fn keys(&self, player: &Uuid, namespace: &str) -> Vec<String> {
+        blocking(|| self.try_keys(player, namespace))
+            .inspect_err(|e| tracing::warn!(error = %e, "failed to read player data"))
+            .unwrap_or_default()
+    }
+
+    fn clear(&self, player: &Uuid, namespace: &str) -> usize {
+        blocking(|| self.try_clear(player, namespace))
+            .inspect_err(|e| tracing::warn!(error = %e, "failed to clear player data"))
+            .unwrap_or(0)
+    }
+
+    fn entries(&self, player: &Uuid) -> Vec<PlayerDataEntry> {
+        blocking(|| self.try_entries(player))
+            .inspect_err(|e| tracing::warn!(error = %e, "failed to read player data"))
+            .unwrap_or_default()
+    }
+}
+
+/// Runs blocking database work without stalling the other tasks of the
+/// current runtime worker. Current-thread runtimes have no other worker to
+/// hand them to, so the work just runs there.
+fn blocking<T>(f: impl FnOnce() -> T) -> T {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(f)
+        }
+        _ => f(),
+    }
+}
+
+fn unix_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| i64::try_from(d.as_millis()).unwrap_or(i64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_namespaces_and_ttl() {
+        let store = SqlitePlayerDataStore::in_memory();
+        let uuid = Uuid::new_v4();
+
+        store
+            .set_raw(&uuid, "economy", "coins", "42".to_string(), None)
+            .unwrap();
+        store
+            .set_raw(&uuid, "rewards", "coins", "\"daily\"".to_string(), None)
+            .unwrap();
+        assert_eq!(
+            store.get_raw(&uuid, "economy", "coins").as_deref(),
+            Some("42")
+        );
+        assert_eq!(
+            store.get_raw(&uuid, "rewards", "coins").as_deref(),
+            Some("\"daily\"")
+        );
+
+        assert!(
+            store
+                .set_raw(&uuid, "economy", "coins", "not json".to_string(), None)
+                .is_err()
+        );
+        assert!(
+            store
+                .set_raw(&uuid, "", "coins", "1".to_string(), None)
+                .is_err()
+        );
+
+        store
+            .set_raw(
+                &uuid,
+                "economy",
+                "boost",
+                "true".to_string(),
+                Some(Duration::ZERO),
+            )
+            .unwrap();
+        assert_eq!(store.get_raw(&uuid, "economy", "boost"), None);
+        assert_eq!(store.keys(&uuid, "economy"), vec!["coins".to_string()]);
+
+        assert!(store.remove(&uuid, "rewards", "coins"));
+        assert!(!store.remove(&uuid, "rewards", "coins"));
+        assert_eq!(store.clear(&uuid, "economy"), 1);
+        assert!(store.entries(&uuid).is_empty());
+        assert_eq!(store.purge_expired(i64::MAX).unwrap(), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_queries_on_a_runtime_worker() {
+        let store = SqlitePlayerDataStore::in_memory();
+        let uuid = Uuid::new_v4();
+
+        store
+            .set_raw(&uuid, "economy", "coins", "7".to_string(), None)
+            .unwrap();
+        assert_eq!(
+            store.get_raw(&uuid, "economy", "coins").as_deref(),
+            Some("7")
+        );
+        assert!(store.remove(&uuid, "economy", "coins"));
+    }
+
+    #[test]
+    fn test_reopen_and_purge() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("player_data.db");
+        let uuid = Uuid::new_v4();
+        {
+            let store = SqlitePlayerDataStore::open(&path).unwrap();
+            store
+                .set_raw(
+                    &uuid,
+                    "stats",
+                    "kills",
+                    "{ \"zombie\": 3 }".to_string(),
+                    None,
+                )
+                .unwrap();
+            store
+                .set_raw(
+                    &uuid,
+                    "stats",
+                    "streak",
+                    "5".to_string(),
+                    Some(Duration::from_secs(3600)),
+                )
+                .unwrap();
+            store
+                .set_raw(&uuid, "stats", "old", "1".to_string(), Some(Duration::ZERO))
+                .unwrap();
+        }
+
+        let store = SqlitePlayerDataStore::open(&path).unwrap();
+        let entries = store.entries(&uuid);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].key, "kills");
+        // Values are stored as compact JSON
+        assert_eq!(entries[0].value, "{\"zombie\":3}");
+        assert!(entries[0].expires_at.is_none());
+        assert_eq!(entries[1].key, "streak");
+        assert!(entries[1].expires_at.is_some());
+
+        assert_eq!(store.purge_expired(unix_millis()).unwrap(), 1);
+    }
+}
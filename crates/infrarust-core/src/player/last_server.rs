@@ -0,0 +1,305 @@
+//! Remembers the server each player logged out from, so they can be sent back there.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use infrarust_api::types::ServerId;
+use infrarust_config::{LastServerConfig, ServerConfig};
+use infrarust_server_manager::ServerState;
+
+use crate::services::ProxyServices;
+
+/// A player's last server and when they left it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LastServerEntry {
+    server: String,
+    /// Unix time in seconds.
+    seen: u64,
+}
+
+/// Persisted map of player UUID to last server.
+pub struct LastServerStore {
+    config: LastServerConfig,
+    path: PathBuf,
+    entries: DashMap<Uuid, LastServerEntry>,
+    dirty: Notify,
+}
+
+impl LastServerStore {
+    /// Loads the store from `path`, starting empty if the file is missing or invalid.
+    pub async fn load(config: LastServerConfig, path: PathBuf) -> Self {
+        let mut entries = DashMap::new();
+        match tokio::fs::read_to_string(&path).await {
+            Ok(contents) => match serde_json::from_str::<HashMap<Uuid, LastServerEntry>>(&contents)
+            {
+                Ok(map) => entries.extend(map),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), "ignoring invalid last server file: {e}");
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                tracing::warn!(path = %path.display(), "failed to read last server file: {e}");
+            }
+        }
+
+        let store = Self {
+            config,
+            path,
+            entries,
+            dirty: Notify::new(),
+        };
+        store.purge_expired(unix_now());
+        store
+    }
+
+    /// Records the server a player is leaving, if it belongs to an enabled network.
+    pub fn record(&self, uuid: Uuid, server_id: &str, server: &ServerConfig) {
+        if !self.network_enabled(server) {
+            return;
+        }
+        self.entries.insert(
+            uuid,
+            LastServerEntry {
+                server: server_id.to_string(),
+                seen: unix_now(),
+            },
+        );
+        self.dirty.notify_one();
+    }
+
+    /// Returns the player's last server, unless it has expired.
+    pub fn last_server(&self, uuid: &Uuid) -> Option<String> {
+        let entry = self.entries.get(uuid)?;
+        (!self.is_expired(&entry, unix_now())).then(|| entry.server.clone())
+    }
+
+    /// Picks the server a joining player should be sent back to.
+    ///
+    /// `None` when the feature does not apply: a forced host was used, the
+    /// network is not enabled, nothing is remembered, or the remembered
+    /// server is gone, in another network or not online.
+    pub(crate) fn choose(
+        &self,
+        uuid: &Uuid,
+        domain: &str,
+        initial_id: &str,
+        initial: &ServerConfig,
+        lookup: impl Fn(&str) -> Option<Arc<ServerConfig>>,
+        state: impl Fn(&str) -> Option<ServerState>,
+    ) -> Option<ServerId> {
+        if self.is_forced_host(domain) || !self.network_enabled(initial) {
+            return None;
+        }
+        let last = self.last_server(uuid)?;
+        if last == initial_id {
+            return None;
+        }
+        let target = lookup(&last)?;
+        if target.network != initial.network || target.proxy_mode.is_forwarding() {
+            return None;
+        }
+        if state(&last).is_some_and(|s| s != ServerState::Online) {
+            tracing::debug!(server = %last, "last server is not online, using the routed server");
+            return None;
+        }
+        Some(ServerId::new(last))
+    }
+
+    /// [`choose`](Self::choose) against the live routing table and server manager.
+    pub(crate) fn choose_for(
+        &self,
+        services: &ProxyServices,
+        uuid: &Uuid,
+        domain: &str,
+        initial_id: &str,
+        initial: &ServerConfig,
+    ) -> Option<ServerId> {
+        self.choose(
+            uuid,
+            domain,
+            initial_id,
+            initial,
+            |id| services.domain_router.find_by_server_id(id),
+            |id| {
+                services
+                    .server_manager
+                    .as_ref()
+                    .and_then(|sm| sm.get_state(id))
+            },
+        )
+    }
+
+    /// Saves the store whenever it changes, and once more on shutdown.
+    pub fn start_save_task(
+        self: &Arc<Self>,
+        shutdown: CancellationToken,
+    ) -> tokio::task::JoinHandle<()> {
+        let store = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    () = shutdown.cancelled() => break,
+                    () = store.dirty.notified() => {}
+                }
+                store.purge_expired(unix_now());
+                if let Err(e) = store.save().await {
+                    tracing::warn!(path = %store.path.display(), "failed to save last servers: {e}");
+                }
+            }
+            if let Err(e) = store.save().await {
+                tracing::warn!(path = %store.path.display(), "failed to save last servers: {e}");
+            }
+        })
+    }
+
+    /// Writes the store (crash-safe: write tmp then rename).
+    async fn save(&self) -> std::io::Result<()> {
+        let map: HashMap<Uuid, LastServerEntry> = self
+            .entries
+            .iter()
+            .map(|entry| (*entry.key(), entry.value().clone()))
+            .collect();
+        let data = serde_json::to_string_pretty(&map).map_err(std::io::Error::other)?;
+        write_atomic(&self.path, data).await
+    }
+
+    fn network_enabled(&self, server: &ServerConfig) -> bool {
+        server
+            .network
+            .as_ref()
+            .is_some_and(|network| self.config.networks.contains(network))
+    }
+
+    fn is_forced_host(&self, domain: &str) -> bool {
+        self.config
+            .forced_hosts
+            .iter()
+            .any(|host| host.eq_ignore_ascii_case(domain))
+    }
+
+    fn is_expired(&self, entry: &LastServerEntry, now: u64) -> bool {
+        now.saturating_sub(entry.seen) > self.config.expiry.as_secs()
+    }
+
+    fn purge_expired(&self, now: u64) {
+        self.entries.retain(|_, entry| !self.is_expired(entry, now));
+    }
+}
+
+async fn write_atomic(path: &Path, data: String) -> std::io::Result<()> {
+    if let Some(dir) = path.parent()
+        && !dir.as_os_str().is_empty()
+    {
+        tokio::fs::create_dir_all(dir).await?;
+    }
+    let tmp_path = path.with_extension("json.tmp");
+    tokio::fs::write(&tmp_path, data).await?;
+    tokio::fs::rename(&tmp_path, path).await
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+
+    fn server(name: &str, network: &str) -> ServerConfig {
+        toml::from_str(&format!(
+            "name = \"{name}\"\nnetwork = \"{network}\"\nproxy_mode = \"client_only\"\naddresses = [\"127.0.0.1:25565\"]"
+        ))
+        .unwrap()
+    }
+
+    fn config() -> LastServerConfig {
+        LastServerConfig {
+            networks: vec!["main".to_string()],
+            expiry: Duration::from_secs(3600),
+            forced_hosts: vec!["survival.mc.example.com".to_string()],
+        }
+    }
+
+    async fn store(dir: &Path) -> LastServerStore {
+        LastServerStore::load(config(), dir.join("last_servers.json")).await
+    }
+
+    #[tokio::test]
+    async fn test_choose_rules() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = store(dir.path()).await;
+        let uuid = Uuid::new_v4();
+        let lobby = server("lobby", "main");
+        let survival = Arc::new(server("survival", "main"));
+        store.record(uuid, "survival", &survival);
+
+        let lookup = |id: &str| (id == "survival").then(|| Arc::clone(&survival));
+        let choose = |domain: &str, state: Option<ServerState>| {
+            store.choose(&uuid, domain, "lobby", &lobby, lookup, |_| state)
+        };
+
+        assert_eq!(
+            choose("mc.example.com", None),
+            Some(ServerId::new("survival"))
+        );
+        assert_eq!(
+            choose("mc.example.com", Some(ServerState::Online)),
+            Some(ServerId::new("survival"))
+        );
+        // Remembered server is down
+        assert_eq!(choose("mc.example.com", Some(ServerState::Sleeping)), None);
+        // Forced host, matched case-insensitively
+        assert_eq!(choose("Survival.mc.example.com", None), None);
+        // Another network is not enabled
+        let other = server("hub", "other");
+        assert_eq!(
+            store.choose(&uuid, "mc.example.com", "hub", &other, lookup, |_| None),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = store(dir.path()).await;
+        let uuid = Uuid::new_v4();
+        store.entries.insert(
+            uuid,
+            LastServerEntry {
+                server: "survival".to_string(),
+                seen: unix_now() - 7200,
+            },
+        );
+        assert_eq!(store.last_server(&uuid), None);
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let uuid = Uuid::new_v4();
+        {
+            let store = store(dir.path()).await;
+            store.record(uuid, "survival", &server("survival", "main"));
+            // Servers outside the enabled networks are not remembered
+            store.record(Uuid::new_v4(), "hub", &server("hub", "other"));
+            store.save().await.unwrap();
+        }
+
+        let store = store(dir.path()).await;
+        assert_eq!(store.entries.len(), 1);
+        assert_eq!(store.last_server(&uuid).as_deref(), Some("survival"));
+    }
+}
@@ -3,24 +3,30 @@
 //! Provides [`PlayerSession`] (the concrete implementation of `dyn Player`)
 //! and [`PlayerCommand`] (the command channel enum for packet injection).
 
+pub mod data_store;
+pub mod last_server;
 pub(crate) mod packets;
 pub mod registry;
 
+use std::collections::HashMap;
 use std::net::SocketAddr;
-use std::sync::Arc;
 use std::sync::RwLock;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::time::SystemTime;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, SystemTime};
 
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
+use infrarust_api::boss_bar::{BossBar, BossBarChange, BossBarViewer};
 use infrarust_api::error::PlayerError;
 use infrarust_api::event::BoxFuture;
 use infrarust_api::permissions::{DefaultPermissionChecker, PermissionChecker, PermissionLevel};
 use infrarust_api::player::Player;
+use infrarust_api::scoreboard::{Sidebar, SidebarChange, SidebarViewer};
 use infrarust_api::types::{
-    Component, GameProfile, PlayerId, ProtocolVersion, RawPacket, ServerId, TitleData,
+    ClientSettings, Component, GameProfile, PlayerId, ProtocolVersion, RawPacket, ServerId,
+    TabListEntry, TitleData,
 };
 
 /// Channel buffer size for player commands.
@@ -42,12 +48,115 @@ pub enum PlayerCommand {
     SendActionBar(Component),
     /// Send a raw packet to the player's client.
     SendPacket(RawPacket),
+    /// Set the tab list header and footer.
+    SetTabListHeaderFooter(Component, Component),
+    /// Add or replace tab list entries.
+    AddTabListEntries(Vec<TabListEntry>),
+    /// Remove tab list entries by UUID.
+    RemoveTabListEntries(Vec<uuid::Uuid>),
+    /// Show a boss bar with its current state.
+    ShowBossBar(BossBar),
+    /// Send one change of a shown boss bar.
+    UpdateBossBar(BossBar, BossBarChange),
+    /// Hide a boss bar by id.
+    HideBossBar(uuid::Uuid),
+    /// Create a sidebar and show it in the sidebar slot.
+    ShowSidebar(SidebarSnapshot),
+    /// Update a shown sidebar from the first state to the second.
+    UpdateSidebar(SidebarSnapshot, SidebarSnapshot),
+    /// Remove a sidebar and its teams.
+    HideSidebar(SidebarSnapshot),
     /// Kick the player with a reason.
     Kick(Component),
     /// Switch the player to a different backend server.
     SwitchServer(ServerId),
 }
 
+/// Boss bars shown to a player; registered as the viewer of each bar.
+///
+/// Changes are forwarded to the proxy loop as [`PlayerCommand::UpdateBossBar`].
+struct PlayerBossBars {
+    command_tx: mpsc::Sender<PlayerCommand>,
+    shown: Mutex<HashMap<uuid::Uuid, BossBar>>,
+}
+
+impl PlayerBossBars {
+    fn shown(&self) -> std::sync::MutexGuard<'_, HashMap<uuid::Uuid, BossBar>> {
+        self.shown.lock().expect("lock poisoned")
+    }
+}
+
+impl infrarust_api::boss_bar::private::Sealed for PlayerBossBars {}
+
+impl BossBarViewer for PlayerBossBars {
+    fn boss_bar_changed(&self, bar: &BossBar, change: BossBarChange) {
+        if let Err(e) = self
+            .command_tx
+            .try_send(PlayerCommand::UpdateBossBar(bar.clone(), change))
+        {
+            tracing::trace!(bar = %bar.id(), error = %e, "boss bar update dropped");
+        }
+    }
+}
+
+/// State of a [`Sidebar`] as sent to a client, used to send only changes.
+#[derive(Debug, Clone)]
+pub struct SidebarSnapshot {
+    pub id: uuid::Uuid,
+    pub title: Component,
+    pub lines: Vec<Component>,
+}
+
+impl SidebarSnapshot {
+    pub fn of(sidebar: &Sidebar) -> Self {
+        Self {
+            id: sidebar.id(),
+            title: sidebar.title(),
+            lines: sidebar.lines(),
+        }
+    }
+}
+
+/// The sidebar shown to a player; registered as the viewer of that sidebar.
+///
+/// Changes are forwarded to the proxy loop as [`PlayerCommand::UpdateSidebar`].
+struct PlayerSidebar {
+    command_tx: mpsc::Sender<PlayerCommand>,
+    shown: Mutex<Option<(Sidebar, SidebarSnapshot)>>,
+}
+
+impl PlayerSidebar {
+    fn shown(&self) -> std::sync::MutexGuard<'_, Option<(Sidebar, SidebarSnapshot)>> {
+        self.shown.lock().expect("lock poisoned")
+    }
+}
+
+impl infrarust_api::scoreboard::private::Sealed for PlayerSidebar {}
+
+impl SidebarViewer for PlayerSidebar {
+    fn sidebar_changed(&self, sidebar: &Sidebar, _change: SidebarChange) {
+        let mut shown = self.shown();
+        let Some((current, sent)) = shown.as_mut() else {
+            return;
+        };
+        if current != sidebar {
+            return;
+        }
+        let snapshot = SidebarSnapshot::of(sidebar);
+        // Only recorded as sent once queued, so a dropped update is retried
+        // with the next change
+        match self
+            .command_tx
+            .try_send(PlayerCommand::UpdateSidebar(sent.clone(), snapshot.clone()))
+        {
+            Ok(()) => *sent = snapshot,
+            Err(e) => {
+                tracing::trace!(sidebar = %sidebar.id(), error = %e, "sidebar update dropped")
+            }
+        }
+    }
+}
+
 /// Concrete implementation of [`Player`].
 ///
 /// Holds identity data and a command channel to the proxy loop.
@@ -66,6 +175,12 @@ pub struct PlayerSession {
     command_tx: mpsc::Sender<PlayerCommand>,
     shutdown_token: CancellationToken,
     permission_checker: Arc<dyn PermissionChecker>,
+    boss_bars: Arc<PlayerBossBars>,
+    sidebar: Arc<PlayerSidebar>,
+    settings: RwLock<Option<ClientSettings>>,
+    client_brand: RwLock<Option<String>>,
+    /// Last measured round-trip time in milliseconds, `u64::MAX` if unknown.
+    ping_ms: AtomicU64,
 }
 
 impl std::fmt::Debug for PlayerSession {
@@ -103,6 +218,17 @@ impl PlayerSession {
             active,
             online_mode,
             connected_at: SystemTime::now(),
+            boss_bars: Arc::new(PlayerBossBars {
+                command_tx: command_tx.clone(),
+                shown: Mutex::new(HashMap::new()),
+            }),
+            sidebar: Arc::new(PlayerSidebar {
+                command_tx: command_tx.clone(),
+                shown: Mutex::new(None),
+            }),
+            settings: RwLock::new(None),
+            client_brand: RwLock::new(None),
+            ping_ms: AtomicU64::new(u64::MAX),
             command_tx,
             shutdown_token,
             permission_checker,
@@ -143,9 +269,60 @@ impl PlayerSession {
     }
 
     /// Updates the current server (called by the proxy loop on server switch).
+    ///
+    /// Boss bars shown by plugins are sent again, since the client drops
+    /// them when it (re)enters the configuration phase.
     pub fn set_current_server(&self, server: ServerId) {
-        let mut guard = self.current_server.write().expect("lock poisoned");
-        *guard = Some(server);
+        *self.current_server.write().expect("lock poisoned") = Some(server);
+        for bar in self.boss_bars.shown().values() {
+            let _ = self.try_send_command(PlayerCommand::ShowBossBar(bar.clone()));
+        }
+    }
+
+    /// Records the settings sent by the client.
+    ///
+    /// Returns `false` if they are the same as the last ones.
+    pub fn set_settings(&self, settings: ClientSettings) -> bool {
+        let mut current = self.settings.write().expect("lock poisoned");
+        if current.as_ref() == Some(&settings) {
+            return false;
+        }
+        *current = Some(settings);
+        true
+    }
+
+    /// Records the brand announced by the client.
+    ///
+    /// Returns `false` if it is the same as the last one.
+    pub fn set_client_brand(&self, brand: String) -> bool {
+        let mut current = self.client_brand.write().expect("lock poisoned");
+        if current.as_deref() == Some(brand.as_str()) {
+            return false;
+        }
+        *current = Some(brand);
+        true
+    }
+
+    /// Records a round-trip time measured from a keepalive.
+    pub fn record_ping(&self, rtt: Duration) {
+        let ms = u64::try_from(rtt.as_millis()).unwrap_or(u64::MAX - 1);
+        self.ping_ms.store(ms, Ordering::Relaxed);
+    }
+
+    fn boss_bar_viewer(&self) -> Weak<dyn BossBarViewer> {
+        Arc::downgrade(&self.boss_bars) as Weak<dyn BossBarViewer>
+    }
+
+    fn sidebar_viewer(&self) -> Weak<dyn SidebarViewer> {
+        Arc::downgrade(&self.sidebar) as Weak<dyn SidebarViewer>
+    }
+
+    /// Forgets the sidebar shown to the player and returns it as last sent,
+    /// so the caller can remove it from the client before a server switch.
+    pub fn take_sidebar(&self) -> Option<SidebarSnapshot> {
+        let (sidebar, sent) = self.sidebar.shown().take()?;
+        sidebar.remove_viewer(&self.sidebar_viewer());
+        Some(sent)
     }
 
     pub fn shutdown_token(&self) -> &CancellationToken {
@@ -190,6 +367,21 @@ impl Player for PlayerSession {
         self.remote_addr
     }
 
+    fn settings(&self) -> Option<ClientSettings> {
+        self.settings.read().expect("lock poisoned").clone()
+    }
+
+    fn client_brand(&self) -> Option<String> {
+        self.client_brand.read().expect("lock poisoned").clone()
+    }
+
+    fn ping(&self) -> Option<Duration> {
+        match self.ping_ms.load(Ordering::Relaxed) {
+            u64::MAX => None,
+            ms => Some(Duration::from_millis(ms)),
+        }
+    }
+
     fn current_server(&self) -> Option<ServerId> {
         self.current_server.read().expect("lock poisoned").clone()
     }
@@ -225,6 +417,63 @@ impl Player for PlayerSession {
         self.try_send_command(PlayerCommand::SendPacket(packet))
     }
 
+    fn send_tab_list_header_footer(
+        &self,
+        header: Component,
+        footer: Component,
+    ) -> Result<(), PlayerError> {
+        self.try_send_command(PlayerCommand::SetTabListHeaderFooter(header, footer))
+    }
+
+    fn add_tab_list_entries(&self, entries: Vec<TabListEntry>) -> Result<(), PlayerError> {
+        self.try_send_command(PlayerCommand::AddTabListEntries(entries))
+    }
+
+    fn remove_tab_list_entries(&self, uuids: Vec<uuid::Uuid>) -> Result<(), PlayerError> {
+        self.try_send_command(PlayerCommand::RemoveTabListEntries(uuids))
+    }
+
+    fn show_boss_bar(&self, bar: &BossBar) -> Result<(), PlayerError> {
+        self.try_send_command(PlayerCommand::ShowBossBar(bar.clone()))?;
+        bar.add_viewer(self.boss_bar_viewer());
+        self.boss_bars.shown().insert(bar.id(), bar.clone());
+        Ok(())
+    }
+
+    fn hide_boss_bar(&self, bar: &BossBar) -> Result<(), PlayerError> {
+        bar.remove_viewer(&self.boss_bar_viewer());
+        if self.boss_bars.shown().remove(&bar.id()).is_none() {
+            return Ok(());
+        }
+        self.try_send_command(PlayerCommand::HideBossBar(bar.id()))
+    }
+
+    fn show_sidebar(&self, sidebar: &Sidebar) -> Result<(), PlayerError> {
+        let viewer = self.sidebar_viewer();
+        let mut shown = self.sidebar.shown();
+        if let Some((previous, sent)) = shown.take() {
+            previous.remove_viewer(&viewer);
+            self.try_send_command(PlayerCommand::HideSidebar(sent))?;
+        }
+        // Registered before the snapshot: changes made meanwhile wait for
+        // the lock and are diffed against it
+        sidebar.add_viewer(viewer.clone());
+        let snapshot = SidebarSnapshot::of(sidebar);
+        if let Err(e) = self.try_send_command(PlayerCommand::ShowSidebar(snapshot.clone())) {
+            sidebar.remove_viewer(&viewer);
+            return Err(e);
+        }
+        *shown = Some((sidebar.clone(), snapshot));
+        Ok(())
+    }
+
+    fn hide_sidebar(&self) -> Result<(), PlayerError> {
+        match self.take_sidebar() {
+            Some(sent) => self.try_send_command(PlayerCommand::HideSidebar(sent)),
+            None => Ok(()),
+        }
+    }
+
     fn switch_server(&self, target: ServerId) -> BoxFuture<'_, Result<(), PlayerError>> {
         Box::pin(async move {
             if !self.active {
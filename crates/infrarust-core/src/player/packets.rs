@@ -1,15 +1,33 @@
 //! Packet building helpers for the player command system.
 //!
-//! Converts API types (`Component`, `TitleData`) into `PacketFrame` values
+//! Converts API types (`Component`, `TitleData`, `TabListEntry`, `BossBar`, sidebars, resource packs) into `PacketFrame` values
 //! ready to be written to the client bridge.
 
 use bytes::Bytes;
 
-use infrarust_api::types::{Component, TitleData};
+use infrarust_api::boss_bar::{BossBar, BossBarChange, BossBarColor, BossBarFlags, BossBarStyle};
+use infrarust_api::types::{Component, TabListEntry, TitleData};
 use infrarust_protocol::io::PacketFrame;
 use infrarust_protocol::packets::Packet;
+use infrarust_protocol::packets::login::Property;
+use infrarust_protocol::packets::play::boss_bar::{
+    BOSS_BAR_FLAG_CREATE_FOG, BOSS_BAR_FLAG_DARKEN_SKY, BOSS_BAR_FLAG_PLAY_MUSIC, BossBarAction,
+    CBossBar,
+};
 use infrarust_protocol::packets::play::chat::{CChatMessageLegacy, CSystemChatMessage};
 use infrarust_protocol::packets::play::disconnect::CDisconnect;
+use infrarust_protocol::packets::play::player_list::{
+    CPlayerInfoRemove, CPlayerInfoUpdate, CPlayerListHeaderFooter, CPlayerListItemLegacy,
+    LegacyPlayerListAction, PlayerInfoActions, PlayerInfoEntry,
+};
+use infrarust_protocol::packets::play::resource_pack::{
+    CResourcePackPop, CResourcePackPush, ResourcePackRequest,
+};
+use infrarust_protocol::packets::play::scoreboard::{
+    CDisplayObjective, CResetScore, CScoreboardObjective, CTeams, CUpdateScore,
+    DISPLAY_SLOT_SIDEBAR, NumberFormat, ObjectiveAction, ObjectiveInfo, ScoreAction, TeamAction,
+    TeamInfo,
+};
 use infrarust_protocol::packets::play::title::{
     CSetSubtitle, CSetTitle, CSetTitleTimes, CTitleLegacy,
 };
@@ -17,6 +35,8 @@ use infrarust_protocol::registry::PacketRegistry;
 use infrarust_protocol::version::{ConnectionState, Direction, ProtocolVersion};
 
 use crate::error::CoreError;
+use crate::player::SidebarSnapshot;
+use crate::session::resource_packs::ProxyResourcePack;
 
 /// Builds a system chat message packet frame.
 ///
@@ -154,6 +174,580 @@ pub fn build_title_packets(
     Ok(frames)
 }
 
+/// Builds the tab list header/footer packet.
+///
+/// Pre-1.8: no header/footer support, returns `None`.
+pub fn build_tab_list_header_footer(
+    header: &Component,
+    footer: &Component,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Option<PacketFrame>, CoreError> {
+    if version.less_than(ProtocolVersion::V1_8) {
+        return Ok(None);
+    }
+    let packet = if version.less_than(ProtocolVersion::V1_20_3) {
+        CPlayerListHeaderFooter::from_json(&header.to_json(), &footer.to_json())
+    } else {
+        CPlayerListHeaderFooter::from_nbt(header.to_nbt_network(), footer.to_nbt_network())
+    };
+    encode_packet(&packet, version, registry).map(Some)
+}
+
+/// Builds the packet adding (or replacing) tab list entries.
+///
+/// Pre-1.8: returns `None`.
+/// 1.8–1.19.1: legacy player list item packet; unlisted entries are skipped.
+/// 1.19.3+: player info update with add, game mode, listed, latency and display name.
+pub fn build_tab_list_add(
+    entries: &[TabListEntry],
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Option<PacketFrame>, CoreError> {
+    if version.less_than(ProtocolVersion::V1_8) || entries.is_empty() {
+        return Ok(None);
+    }
+    if version.less_than(ProtocolVersion::V1_19_3) {
+        let entries: Vec<_> = entries
+            .iter()
+            .filter(|e| e.listed)
+            .map(|e| to_info_entry(e, version))
+            .collect();
+        if entries.is_empty() {
+            return Ok(None);
+        }
+        let packet = CPlayerListItemLegacy {
+            action: LegacyPlayerListAction::AddPlayer,
+            entries,
+        };
+        return encode_packet(&packet, version, registry).map(Some);
+    }
+    let packet = CPlayerInfoUpdate {
+        actions: PlayerInfoActions::ADD_PLAYER
+            | PlayerInfoActions::UPDATE_GAME_MODE
+            | PlayerInfoActions::UPDATE_LISTED
+            | PlayerInfoActions::UPDATE_LATENCY
+            | PlayerInfoActions::UPDATE_DISPLAY_NAME,
+        entries: entries.iter().map(|e| to_info_entry(e, version)).collect(),
+    };
+    encode_packet(&packet, version, registry).map(Some)
+}
+
+/// Builds the packet removing tab list entries.
+///
+/// Pre-1.8: returns `None`.
+/// 1.8–1.19.1: legacy player list item packet with the remove action.
+/// 1.19.3+: player info remove.
+pub fn build_tab_list_remove(
+    uuids: &[uuid::Uuid],
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Option<PacketFrame>, CoreError> {
+    if version.less_than(ProtocolVersion::V1_8) || uuids.is_empty() {
+        return Ok(None);
+    }
+    if version.less_than(ProtocolVersion::V1_19_3) {
+        let packet = CPlayerListItemLegacy {
+            action: LegacyPlayerListAction::RemovePlayer,
+            entries: uuids
+                .iter()
+                .map(|uuid| PlayerInfoEntry::new(*uuid, String::new()))
+                .collect(),
+        };
+        return encode_packet(&packet, version, registry).map(Some);
+    }
+    let packet = CPlayerInfoRemove {
+        uuids: uuids.to_vec(),
+    };
+    encode_packet(&packet, version, registry).map(Some)
+}
+
+fn to_info_entry(entry: &TabListEntry, version: ProtocolVersion) -> PlayerInfoEntry {
+    let mut info = PlayerInfoEntry::new(entry.uuid, entry.name.clone());
+    info.properties = entry
+        .properties
+        .iter()
+        .map(|p| Property {
+            name: p.name.clone(),
+            value: p.value.clone(),
+            signature: p.signature.clone(),
+        })
+        .collect();
+    info.game_mode = i32::from(entry.game_mode);
+    info.listed = entry.listed;
+    info.latency = entry.latency_ms;
+    info.display_name = entry
+        .display_name
+        .as_ref()
+        .map(|name| component_bytes(name, version));
+    info
+}
+
+/// Builds the packet showing a boss bar with its current state.
+///
+/// Pre-1.9: no boss bar support, returns `None`.
+pub fn build_boss_bar_add(
+    bar: &BossBar,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Option<PacketFrame>, CoreError> {
+    let action = BossBarAction::Add {
+        title: component_bytes(&bar.title(), version),
+        health: bar.progress(),
+        color: boss_bar_color_id(bar.color()),
+        division: boss_bar_style_id(bar.style()),
+        flags: boss_bar_flags(bar.flags()),
+    };
+    build_boss_bar(bar.id(), action, version, registry)
+}
+
+/// Builds the packet sending one change of a shown boss bar.
+///
+/// Pre-1.9: returns `None`.
+pub fn build_boss_bar_update(
+    bar: &BossBar,
+    change: BossBarChange,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Option<PacketFrame>, CoreError> {
+    let action = match change {
+        BossBarChange::Title => BossBarAction::UpdateTitle(component_bytes(&bar.title(), version)),
+        BossBarChange::Progress => BossBarAction::UpdateHealth(bar.progress()),
+        BossBarChange::Style => BossBarAction::UpdateStyle {
+            color: boss_bar_color_id(bar.color()),
+            division: boss_bar_style_id(bar.style()),
+        },
+        BossBarChange::Flags => BossBarAction::UpdateFlags(boss_bar_flags(bar.flags())),
+        // Changes added later: resend the whole bar
+        _ => return build_boss_bar_add(bar, version, registry),
+    };
+    build_boss_bar(bar.id(), action, version, registry)
+}
+
+/// Builds the packet hiding a boss bar.
+///
+/// Pre-1.9: returns `None`.
+pub fn build_boss_bar_remove(
+    id: uuid::Uuid,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Option<PacketFrame>, CoreError> {
+    build_boss_bar(id, BossBarAction::Remove, version, registry)
+}
+
+fn build_boss_bar(
+    uuid: uuid::Uuid,
+    action: BossBarAction,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Option<PacketFrame>, CoreError> {
+    if version.less_than(ProtocolVersion::V1_9) {
+        return Ok(None);
+    }
+    encode_packet(&CBossBar { uuid, action }, version, registry).map(Some)
+}
+
+const fn boss_bar_color_id(color: BossBarColor) -> i32 {
+    match color {
+        BossBarColor::Pink => 0,
+        BossBarColor::Blue => 1,
+        BossBarColor::Red => 2,
+        BossBarColor::Green => 3,
+        BossBarColor::Yellow => 4,
+        BossBarColor::Purple => 5,
+        BossBarColor::White => 6,
+    }
+}
+
+fn boss_bar_flags(flags: BossBarFlags) -> u8 {
+    let mut bits = 0;
+    if flags.darken_screen {
+        bits |= BOSS_BAR_FLAG_DARKEN_SKY;
+    }
+    if flags.play_boss_music {
+        bits |= BOSS_BAR_FLAG_PLAY_MUSIC;
+    }
+    if flags.create_fog {
+        bits |= BOSS_BAR_FLAG_CREATE_FOG;
+    }
+    bits
+}
+
+const fn boss_bar_style_id(style: BossBarStyle) -> i32 {
+    match style {
+        BossBarStyle::Progress => 0,
+        BossBarStyle::Notched6 => 1,
+        BossBarStyle::Notched10 => 2,
+        BossBarStyle::Notched12 => 3,
+        BossBarStyle::Notched20 => 4,
+    }
+}
+
+/// Builds the packet offering a proxy resource pack. Pre-1.8: returns `None`.
+///
+/// Before 1.20.3 the pack replaces whatever server pack the client had.
+pub fn build_resource_pack_push(
+    pack: &ProxyResourcePack,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Option<PacketFrame>, CoreError> {
+    if version.less_than(ProtocolVersion::V1_8) {
+        return Ok(None);
+    }
+    let push = CResourcePackPush {
+        pack: ResourcePackRequest {
+            id: pack.id,
+            url: pack.url.clone(),
+            hash: pack.hash.clone(),
+            forced: pack.required,
+            prompt: pack
+                .prompt
+                .as_ref()
+                .map(|prompt| component_bytes(prompt, version)),
+        },
+    };
+    encode_packet(&push, version, registry).map(Some)
+}
+
+/// Builds the packet removing a resource pack by id. Pre-1.20.3: returns `None`.
+pub fn build_resource_pack_pop(
+    id: uuid::Uuid,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Option<PacketFrame>, CoreError> {
+    if version.less_than(ProtocolVersion::V1_20_3) {
+        return Ok(None);
+    }
+    encode_packet(&CResourcePackPop { id: Some(id) }, version, registry).map(Some)
+}
+
+/// Builds the packets creating a sidebar and showing it in the sidebar slot.
+///
+/// Before 1.20.3, each line is the prefix and suffix of its own team; from
+/// 1.20.3, the line is the display name of its score. Pre-1.8: no packets.
+pub fn build_sidebar_show(
+    sidebar: &SidebarSnapshot,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Vec<PacketFrame>, CoreError> {
+    if version.less_than(ProtocolVersion::V1_8) {
+        return Ok(Vec::new());
+    }
+    let objective = sidebar_objective(sidebar.id);
+    let mut frames = vec![encode_packet(
+        &CScoreboardObjective {
+            name: objective.clone(),
+            action: ObjectiveAction::Create(sidebar_objective_info(&sidebar.title, version)),
+        },
+        version,
+        registry,
+    )?];
+    for index in 0..sidebar.lines.len() {
+        frames.extend(build_sidebar_line_add(sidebar, index, version, registry)?);
+    }
+    frames.push(encode_packet(
+        &CDisplayObjective {
+            position: DISPLAY_SLOT_SIDEBAR,
+            objective_name: objective,
+        },
+        version,
+        registry,
+    )?);
+    Ok(frames)
+}
+
+/// Builds the packets turning a shown sidebar from `old` into `new`.
+pub fn build_sidebar_update(
+    old: &SidebarSnapshot,
+    new: &SidebarSnapshot,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Vec<PacketFrame>, CoreError> {
+    if version.less_than(ProtocolVersion::V1_8) {
+        return Ok(Vec::new());
+    }
+    let mut frames = Vec::new();
+    if old.title.to_json() != new.title.to_json() {
+        frames.push(encode_packet(
+            &CScoreboardObjective {
+                name: sidebar_objective(new.id),
+                action: ObjectiveAction::Update(sidebar_objective_info(&new.title, version)),
+            },
+            version,
+            registry,
+        )?);
+    }
+
+    // Scores count down from the top line, so they all move when the count changes
+    let count_changed = old.lines.len() != new.lines.len();
+    for (index, line) in new.lines.iter().enumerate() {
+        let Some(old_line) = old.lines.get(index) else {
+            frames.extend(build_sidebar_line_add(new, index, version, registry)?);
+            continue;
+        };
+        let text_changed = old_line.to_json() != line.to_json();
+        if version.no_less_than(ProtocolVersion::V1_20_3) {
+            if text_changed || count_changed {
+                frames.push(build_sidebar_score(new, index, version, registry)?);
+            }
+            continue;
+        }
+        if text_changed {
+            frames.push(encode_packet(
+                &CTeams {
+                    name: sidebar_team(new.id, index),
+                    action: TeamAction::UpdateInfo(sidebar_team_info(new.id, line, index, version)),
+                },
+                version,
+                registry,
+            )?);
+        }
+        if count_changed {
+            frames.push(build_sidebar_score(new, index, version, registry)?);
+        }
+    }
+    for index in new.lines.len()..old.lines.len() {
+        frames.extend(build_sidebar_line_remove(old.id, index, version, registry)?);
+    }
+    Ok(frames)
+}
+
+/// Builds the packets removing a sidebar and its teams.
+pub fn build_sidebar_hide(
+    sidebar: &SidebarSnapshot,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Vec<PacketFrame>, CoreError> {
+    if version.less_than(ProtocolVersion::V1_8) {
+        return Ok(Vec::new());
+    }
+    // Removing the objective also removes its scores
+    let mut frames = vec![encode_packet(
+        &CScoreboardObjective {
+            name: sidebar_objective(sidebar.id),
+            action: ObjectiveAction::Remove,
+        },
+        version,
+        registry,
+    )?];
+    if version.less_than(ProtocolVersion::V1_20_3) {
+        for index in 0..sidebar.lines.len() {
+            frames.push(encode_packet(
+                &CTeams {
+                    name: sidebar_team(sidebar.id, index),
+                    action: TeamAction::Remove,
+                },
+                version,
+                registry,
+            )?);
+        }
+    }
+    Ok(frames)
+}
+
+fn build_sidebar_line_add(
+    sidebar: &SidebarSnapshot,
+    index: usize,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Vec<PacketFrame>, CoreError> {
+    let mut frames = Vec::with_capacity(2);
+    if version.less_than(ProtocolVersion::V1_20_3) {
+        frames.push(encode_packet(
+            &CTeams {
+                name: sidebar_team(sidebar.id, index),
+                action: TeamAction::Create(
+                    sidebar_team_info(sidebar.id, &sidebar.lines[index], index, version),
+                    vec![sidebar_entry(sidebar.id, index)],
+                ),
+            },
+            version,
+            registry,
+        )?);
+    }
+    frames.push(build_sidebar_score(sidebar, index, version, registry)?);
+    Ok(frames)
+}
+
+fn build_sidebar_line_remove(
+    id: uuid::Uuid,
+    index: usize,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<Vec<PacketFrame>, CoreError> {
+    let entity_name = sidebar_entry(id, index);
+    let objective_name = sidebar_objective(id);
+    if version.no_less_than(ProtocolVersion::V1_20_3) {
+        return Ok(vec![encode_packet(
+            &CResetScore {
+                entity_name,
+                objective_name: Some(objective_name),
+            },
+            version,
+            registry,
+        )?]);
+    }
+    Ok(vec![
+        encode_packet(
+            &CUpdateScore {
+                entity_name,
+                objective_name,
+                action: ScoreAction::Remove,
+            },
+            version,
+            registry,
+        )?,
+        encode_packet(
+            &CTeams {
+                name: sidebar_team(id, index),
+                action: TeamAction::Remove,
+            },
+            version,
+            registry,
+        )?,
+    ])
+}
+
+fn build_sidebar_score(
+    sidebar: &SidebarSnapshot,
+    index: usize,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Result<PacketFrame, CoreError> {
+    let display_name = version
+        .no_less_than(ProtocolVersion::V1_20_3)
+        .then(|| component_bytes(&sidebar.lines[index], version));
+    encode_packet(
+        &CUpdateScore {
+            entity_name: sidebar_entry(sidebar.id, index),
+            objective_name: sidebar_objective(sidebar.id),
+            action: ScoreAction::Set {
+                value: (sidebar.lines.len() - index) as i32,
+                display_name,
+                number_format: None,
+            },
+        },
+        version,
+        registry,
+    )
+}
+
+/// Objective name of a sidebar: `ir` and 8 hex digits of its id.
+fn sidebar_objective(id: uuid::Uuid) -> String {
+    format!("ir{}", &id.simple().to_string()[..8])
+}
+
+fn sidebar_team(id: uuid::Uuid, index: usize) -> String {
+    format!("{}_{index:x}", sidebar_objective(id))
+}
+
+/// Score holder of a line: color codes only, so it renders as nothing
+/// between the team prefix and suffix.
+fn sidebar_entry(id: uuid::Uuid, index: usize) -> String {
+    let mut entry: String = id.simple().to_string()[..4]
+        .chars()
+        .flat_map(|c| ['§', c])
+        .collect();
+    entry.push_str(&format!("§{index:x}§r"));
+    entry
+}
+
+fn sidebar_objective_info(title: &Component, version: ProtocolVersion) -> ObjectiveInfo {
+    let modern = version.no_less_than(ProtocolVersion::V1_20_3);
+    ObjectiveInfo {
+        display_name: if version.less_than(ProtocolVersion::V1_13) {
+            truncate_legacy(&title.to_legacy(), 32).into_bytes()
+        } else {
+            component_bytes(title, version)
+        },
+        hearts: false,
+        // Hides the score numbers
+        number_format: modern.then_some(NumberFormat::Blank),
+    }
+}
+
+fn sidebar_team_info(
+    id: uuid::Uuid,
+    line: &Component,
+    index: usize,
+    version: ProtocolVersion,
+) -> TeamInfo {
+    let (display_name, prefix, suffix, color) = if version.less_than(ProtocolVersion::V1_13) {
+        let (prefix, suffix) = split_legacy(&line.to_legacy());
+        (
+            sidebar_team(id, index).into_bytes(),
+            prefix.into_bytes(),
+            suffix.into_bytes(),
+            -1,
+        )
+    } else {
+        let empty = component_bytes(&Component::text(""), version);
+        (empty.clone(), component_bytes(line, version), empty, 21)
+    };
+    TeamInfo {
+        display_name,
+        prefix,
+        suffix,
+        friendly_flags: 0,
+        name_tag_visibility: "always".to_string(),
+        collision_rule: "always".to_string(),
+        color,
+    }
+}
+
+/// Splits legacy text over a pre-1.13 team prefix and suffix (16 characters
+/// each), carrying the formatting in effect over to the suffix.
+fn split_legacy(text: &str) -> (String, String) {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.len() <= 16 {
+        return (text.to_string(), String::new());
+    }
+    let mut cut = 16;
+    if chars[cut - 1] == '§' {
+        cut -= 1;
+    }
+    let prefix: String = chars[..cut].iter().collect();
+
+    let mut codes = String::new();
+    let mut i = 0;
+    while i + 1 < cut {
+        if chars[i] == '§' {
+            let code = chars[i + 1];
+            // Colors and resets clear the formatting before them
+            if code.is_ascii_hexdigit() || code == 'r' {
+                codes.clear();
+            }
+            if code != 'r' {
+                codes.push('§');
+                codes.push(code);
+            }
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    codes.extend(&chars[cut..]);
+    (prefix, truncate_legacy(&codes, 16))
+}
+
+/// Truncates legacy text to `max` characters without splitting a `§` code.
+fn truncate_legacy(text: &str, max: usize) -> String {
+    let mut chars: Vec<char> = text.chars().take(max).collect();
+    if chars.last() == Some(&'§') {
+        chars.pop();
+    }
+    chars.into_iter().collect()
+}
+
+/// JSON text before 1.20.3, network NBT from 1.20.3.
+fn component_bytes(component: &Component, version: ProtocolVersion) -> Vec<u8> {
+    if version.less_than(ProtocolVersion::V1_20_3) {
+        component.to_json().into_bytes()
+    } else {
+        component.to_nbt_network()
+    }
+}
+
 /// Encodes a typed packet into a `PacketFrame`.
 pub(crate) fn encode_packet<P: Packet + 'static>(
     packet: &P,
@@ -179,3 +773,240 @@ pub(crate) fn encode_packet<P: Packet + 'static>(
         payload: Bytes::from(payload),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use infrarust_protocol::registry::build_default_registry;
+
+    use super::*;
+
+    fn packet_id<P: Packet + 'static>(registry: &PacketRegistry, version: ProtocolVersion) -> i32 {
+        registry
+            .get_packet_id::<P>(ConnectionState::Play, Direction::Clientbound, version)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_tab_list_packets_follow_version() {
+        let registry = build_default_registry();
+        let entries = [TabListEntry::new(uuid::Uuid::new_v4(), "Fake")];
+
+        let legacy = ProtocolVersion::V1_12_2;
+        let frame = build_tab_list_add(&entries, legacy, &registry)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            frame.id,
+            packet_id::<CPlayerListItemLegacy>(&registry, legacy)
+        );
+
+        let modern = ProtocolVersion::V1_21;
+        let frame = build_tab_list_add(&entries, modern, &registry)
+            .unwrap()
+            .unwrap();
+        assert_eq!(frame.id, packet_id::<CPlayerInfoUpdate>(&registry, modern));
+        let frame = build_tab_list_remove(&[entries[0].uuid], modern, &registry)
+            .unwrap()
+            .unwrap();
+        assert_eq!(frame.id, packet_id::<CPlayerInfoRemove>(&registry, modern));
+    }
+
+    #[test]
+    fn test_tab_list_packets_skipped_when_unsupported() {
+        let registry = build_default_registry();
+        let hidden = [TabListEntry::new(uuid::Uuid::new_v4(), "Fake").listed(false)];
+        let text = Component::text("header");
+
+        assert!(
+            build_tab_list_header_footer(&text, &text, ProtocolVersion::V1_7_2, &registry)
+                .unwrap()
+                .is_none()
+        );
+        // Pre-1.19.3 clients have no "listed" flag
+        assert!(
+            build_tab_list_add(&hidden, ProtocolVersion::V1_16, &registry)
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            build_tab_list_add(&hidden, ProtocolVersion::V1_20_3, &registry)
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_boss_bar_packets_follow_version() {
+        let registry = build_default_registry();
+        let bar = BossBar::new(
+            Component::text("Queue"),
+            0.25,
+            BossBarColor::Green,
+            BossBarStyle::Notched6,
+        );
+
+        assert!(
+            build_boss_bar_add(&bar, ProtocolVersion::V1_8, &registry)
+                .unwrap()
+                .is_none()
+        );
+
+        let version = ProtocolVersion::V1_21;
+        let frame = build_boss_bar_update(&bar, BossBarChange::Style, version, &registry)
+            .unwrap()
+            .unwrap();
+        assert_eq!(frame.id, packet_id::<CBossBar>(&registry, version));
+        let decoded = CBossBar::decode(&mut frame.payload.as_ref(), version).unwrap();
+        assert_eq!(decoded.uuid, bar.id());
+        assert_eq!(
+            decoded.action,
+            BossBarAction::UpdateStyle {
+                color: 3,
+                division: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_resource_pack_packets_follow_version() {
+        let registry = build_default_registry();
+        let pack = ProxyResourcePack {
+            id: uuid::Uuid::from_u128(4),
+            url: "https://cdn.example.com/pack.zip".to_string(),
+            hash: String::new(),
+            prompt: Some(Component::text("Please")),
+            required: true,
+            kick_message: Component::text("Bye"),
+        };
+
+        let version = ProtocolVersion::V1_19_4;
+        let frame = build_resource_pack_push(&pack, version, &registry)
+            .unwrap()
+            .unwrap();
+        assert_eq!(frame.id, packet_id::<CResourcePackPush>(&registry, version));
+        let decoded = CResourcePackPush::decode(&mut frame.payload.as_ref(), version).unwrap();
+        assert!(decoded.pack.forced);
+        assert_eq!(
+            decoded.pack.prompt.as_deref(),
+            Some(br#"{"text":"Please"}"#.as_slice())
+        );
+        assert!(
+            build_resource_pack_pop(pack.id, version, &registry)
+                .unwrap()
+                .is_none()
+        );
+
+        let version = ProtocolVersion::V1_21;
+        let frame = build_resource_pack_push(&pack, version, &registry)
+            .unwrap()
+            .unwrap();
+        let decoded = CResourcePackPush::decode(&mut frame.payload.as_ref(), version).unwrap();
+        assert_eq!(decoded.pack.id, pack.id);
+        assert!(
+            build_resource_pack_pop(pack.id, version, &registry)
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    fn snapshot(lines: &[&str]) -> SidebarSnapshot {
+        SidebarSnapshot {
+            id: uuid::Uuid::from_u128(0xabcd_ef01_2345_6789_abcd_ef01_2345_6789),
+            title: Component::text("Network"),
+            lines: lines.iter().map(|l| Component::text(*l)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_sidebar_show_uses_teams_before_1_20_3() {
+        let registry = build_default_registry();
+        let sidebar = snapshot(&["a", "b"]);
+
+        // Objective, team + score per line, display slot
+        let legacy = build_sidebar_show(&sidebar, ProtocolVersion::V1_12_2, &registry).unwrap();
+        assert_eq!(legacy.len(), 6);
+        assert_eq!(
+            legacy[1].id,
+            packet_id::<CTeams>(&registry, ProtocolVersion::V1_12_2)
+        );
+
+        let modern = build_sidebar_show(&sidebar, ProtocolVersion::V1_21, &registry).unwrap();
+        assert_eq!(modern.len(), 4);
+        assert_eq!(
+            modern[3].id,
+            packet_id::<CDisplayObjective>(&registry, ProtocolVersion::V1_21)
+        );
+
+        assert!(
+            build_sidebar_show(&sidebar, ProtocolVersion::V1_7_2, &registry)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_sidebar_update_sends_only_changes() {
+        let registry = build_default_registry();
+        let version = ProtocolVersion::V1_16;
+        let old = snapshot(&["a", "b", "c"]);
+
+        // Same line count: only the changed line's team
+        let frames =
+            build_sidebar_update(&old, &snapshot(&["a", "x", "c"]), version, &registry).unwrap();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].id, packet_id::<CTeams>(&registry, version));
+
+        // Removed line: scores of the others move, its score and team go
+        let frames =
+            build_sidebar_update(&old, &snapshot(&["a", "b"]), version, &registry).unwrap();
+        assert_eq!(frames.len(), 4);
+
+        let modern = ProtocolVersion::V1_21;
+        let frames = build_sidebar_update(&old, &snapshot(&["a", "b"]), modern, &registry).unwrap();
+        assert_eq!(
+            frames.last().unwrap().id,
+            packet_id::<CResetScore>(&registry, modern)
+        );
+    }
+
+    #[test]
+    fn test_sidebar_hide_removes_teams() {
+        let registry = build_default_registry();
+        let sidebar = snapshot(&["a", "b"]);
+        assert_eq!(
+            build_sidebar_hide(&sidebar, ProtocolVersion::V1_8, &registry)
+                .unwrap()
+                .len(),
+            3
+        );
+        assert_eq!(
+            build_sidebar_hide(&sidebar, ProtocolVersion::V1_21, &registry)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_sidebar_names_fit_legacy_limits() {
+        let id = uuid::Uuid::new_v4();
+        assert!(sidebar_objective(id).len() <= 16);
+        assert!(sidebar_team(id, 14).len() <= 16);
+        assert!(sidebar_entry(id, 14).chars().count() <= 40);
+    }
+
+    #[test]
+    fn test_split_legacy_carries_formatting() {
+        assert_eq!(split_legacy("short"), ("short".to_string(), String::new()));
+
+        let (prefix, suffix) = split_legacy("§aOnline players: 42");
+        assert_eq!(prefix, "§aOnline players");
+        assert_eq!(suffix, "§a: 42");
+
+        // A code is never cut in half
+        let (prefix, suffix) = split_legacy("123456789012345§cred");
+        assert_eq!(prefix, "123456789012345");
+        assert_eq!(suffix, "§cred");
+    }
+}
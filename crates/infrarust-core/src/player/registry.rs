@@ -37,9 +37,7 @@ impl PlayerRegistry for PlayerRegistryImpl {
 
     fn get_player_by_id(&self, id: PlayerId) -> Option<Arc<dyn Player>> {
         self.registry
-            .all()
-            .into_iter()
-            .find(|s| s.id() == id)
+            .find_by_player_id(id)
             .map(|s| s as Arc<dyn Player>)
     }
 
@@ -15,8 +15,9 @@ use infrarust_api::provider::PluginConfigProvider;
 use infrarust_api::services::proxy_info::ProxyInfo;
 use infrarust_api::services::scheduler::{Scheduler, TaskHandle};
 use infrarust_api::services::{
-    ban_service::BanService, config_service::ConfigService, player_registry::PlayerRegistry,
-    plugin_registry::PluginRegistry, server_manager::ServerManager,
+    ban_service::BanService, config_service::ConfigService, player_data::PlayerDataStore,
+    player_registry::PlayerRegistry, plugin_registry::PluginRegistry,
+    server_manager::ServerManager, tab_list::TabList,
 };
 
 use crate::filter::codec_registry::CodecFilterRegistryImpl;
@@ -41,6 +42,8 @@ pub struct PluginContextImpl {
     plugin_registry: Arc<dyn PluginRegistry>,
     command_manager: Arc<TrackingCommandManager>,
     scheduler: Arc<TrackingScheduler>,
+    tab_list: Arc<dyn TabList>,
+    player_data: Arc<dyn PlayerDataStore>,
     limbo_handlers: Mutex<Vec<Box<dyn LimboHandler>>>,
     config_providers: Mutex<Vec<Box<dyn PluginConfigProvider>>>,
     codec_filter_registry: Arc<CodecFilterRegistryImpl>,
@@ -71,6 +74,8 @@ impl PluginContextImpl {
         plugin_registry: Arc<dyn PluginRegistry>,
         command_manager: Arc<dyn CommandManager>,
         scheduler: Arc<dyn Scheduler>,
+        tab_list: Arc<dyn TabList>,
+        player_data: Arc<dyn PlayerDataStore>,
         codec_filter_registry: Arc<CodecFilterRegistryImpl>,
         transport_filter_registry: Arc<TransportFilterRegistryImpl>,
         domain_router: Arc<DomainRouter>,
@@ -105,6 +110,8 @@ impl PluginContextImpl {
             plugin_registry,
             command_manager: tracking_cmd,
             scheduler: tracking_sched,
+            tab_list,
+            player_data,
             limbo_handlers: Mutex::new(Vec::new()),
             config_providers: Mutex::new(Vec::new()),
             codec_filter_registry,
@@ -238,6 +245,22 @@ impl PluginContext for PluginContextImpl {
         self.scheduler.as_ref()
     }
 
+    fn tab_list(&self) -> &dyn TabList {
+        self.tab_list.as_ref()
+    }
+
+    fn tab_list_handle(&self) -> Arc<dyn TabList> {
+        Arc::clone(&self.tab_list)
+    }
+
+    fn player_data(&self) -> &dyn PlayerDataStore {
+        self.player_data.as_ref()
+    }
+
+    fn player_data_handle(&self) -> Arc<dyn PlayerDataStore> {
+        Arc::clone(&self.player_data)
+    }
+
     fn event_bus_handle(&self) -> Arc<dyn EventBus> {
         Arc::clone(&self.event_bus) as Arc<dyn EventBus>
     }
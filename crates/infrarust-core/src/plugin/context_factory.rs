@@ -50,6 +50,8 @@ impl PluginContextFactory for PluginContextFactoryImpl {
             Arc::clone(&self.services.plugin_registry),
             Arc::clone(&self.services.command_manager),
             Arc::clone(&self.services.scheduler),
+            Arc::clone(&self.services.tab_list),
+            Arc::clone(&self.services.player_data),
             Arc::clone(&self.services.codec_filter_registry),
             Arc::clone(&self.services.transport_filter_registry),
             Arc::clone(&self.services.domain_router),
@@ -9,9 +9,9 @@ use infrarust_api::error::PluginError;
 use infrarust_api::event::bus::EventBus;
 use infrarust_api::plugin::{Plugin, PluginContext, PluginMetadata};
 use infrarust_api::services::{
-    ban_service::BanService, config_service::ConfigService, player_registry::PlayerRegistry,
-    plugin_registry::PluginRegistry, proxy_info::ProxyInfo, scheduler::Scheduler,
-    server_manager::ServerManager,
+    ban_service::BanService, config_service::ConfigService, player_data::PlayerDataStore,
+    player_registry::PlayerRegistry, plugin_registry::PluginRegistry, proxy_info::ProxyInfo,
+    scheduler::Scheduler, server_manager::ServerManager, tab_list::TabList,
 };
 use tokio_util::sync::CancellationToken;
 
@@ -32,6 +32,8 @@ pub struct PluginServices {
     pub ban_service: Arc<dyn BanService>,
     pub command_manager: Arc<dyn CommandManager>,
     pub scheduler: Arc<dyn Scheduler>,
+    pub tab_list: Arc<dyn TabList>,
+    pub player_data: Arc<dyn PlayerDataStore>,
     pub config_service: Arc<dyn ConfigService>,
     pub plugin_registry: Arc<dyn PluginRegistry>,
     pub codec_filter_registry: Arc<CodecFilterRegistryImpl>,
@@ -367,6 +369,24 @@ mod tests {
             unimplemented!("mock")
         }
 
+        fn tab_list(&self) -> &dyn infrarust_api::services::tab_list::TabList {
+            unimplemented!("mock")
+        }
+
+        fn tab_list_handle(&self) -> Arc<dyn infrarust_api::services::tab_list::TabList> {
+            unimplemented!("mock")
+        }
+
+        fn player_data(&self) -> &dyn infrarust_api::services::player_data::PlayerDataStore {
+            unimplemented!("mock")
+        }
+
+        fn player_data_handle(
+            &self,
+        ) -> Arc<dyn infrarust_api::services::player_data::PlayerDataStore> {
+            unimplemented!("mock")
+        }
+
         fn register_limbo_handler(&self, _handler: Box<dyn infrarust_api::limbo::LimboHandler>) {
             unimplemented!("mock")
         }
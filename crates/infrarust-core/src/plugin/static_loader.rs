@@ -197,6 +197,24 @@ mod tests {
             unimplemented!("mock")
         }
 
+        fn tab_list(&self) -> &dyn infrarust_api::services::tab_list::TabList {
+            unimplemented!("mock")
+        }
+
+        fn tab_list_handle(&self) -> Arc<dyn infrarust_api::services::tab_list::TabList> {
+            unimplemented!("mock")
+        }
+
+        fn player_data(&self) -> &dyn infrarust_api::services::player_data::PlayerDataStore {
+            unimplemented!("mock")
+        }
+
+        fn player_data_handle(
+            &self,
+        ) -> Arc<dyn infrarust_api::services::player_data::PlayerDataStore> {
+            unimplemented!("mock")
+        }
+
         fn register_limbo_handler(&self, _handler: Box<dyn infrarust_api::limbo::LimboHandler>) {
             unimplemented!("mock")
         }
@@ -0,0 +1,178 @@
+//! Minimal Kubernetes API client (list + watch) over `reqwest`.
+
+use std::path::PathBuf;
+
+use serde::de::DeserializeOwned;
+
+use infrarust_config::KubernetesProviderConfig;
+
+use crate::error::CoreError;
+
+use super::resources::{ObjectList, WatchEvent};
+
+/// Server-side timeout for a single watch request. The stream is
+/// re-established from the last seen `resourceVersion` when it ends.
+const WATCH_TIMEOUT_SECS: &str = "290";
+
+/// Talks to the Kubernetes API server.
+pub struct KubeClient {
+    http: reqwest::Client,
+    base_url: String,
+    token_file: PathBuf,
+}
+
+impl KubeClient {
+    /// Builds a client from the provider configuration.
+    ///
+    /// Missing token or CA files are not an error: the client then sends
+    /// unauthenticated requests and relies on the system trust store.
+    pub fn new(config: &KubernetesProviderConfig) -> Result<Self, CoreError> {
+        let base_url = match &config.api_server {
+            Some(url) => url.trim_end_matches('/').to_string(),
+            None => in_cluster_url()?,
+        };
+
+        let mut builder = reqwest::Client::builder();
+        if let Ok(pem) = std::fs::read(&config.ca_file) {
+            let cert = reqwest::Certificate::from_pem(&pem)
+                .map_err(|e| CoreError::KubernetesApi(format!("invalid CA bundle: {e}")))?;
+            builder = builder.add_root_certificate(cert);
+        }
+
+        let http = builder
+            .build()
+            .map_err(|e| CoreError::KubernetesApi(e.to_string()))?;
+
+        Ok(Self {
+            http,
+            base_url,
+            token_file: config.token_file.clone(),
+        })
+    }
+
+    /// Lists all objects under `path` (e.g. `/api/v1/services`).
+    pub async fn list<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        label_selector: Option<&str>,
+    ) -> Result<ObjectList<T>, CoreError> {
+        let mut query = Vec::new();
+        if let Some(selector) = label_selector {
+            query.push(("labelSelector", selector));
+        }
+
+        let response = self.get(path, &query).await?;
+        response
+            .json()
+            .await
+            .map_err(|e| CoreError::KubernetesApi(format!("invalid list response: {e}")))
+    }
+
+    /// Opens a watch stream on `path` starting after `resource_version`.
+    pub async fn watch(
+        &self,
+        path: &str,
+        label_selector: Option<&str>,
+        resource_version: &str,
+    ) -> Result<WatchStream, CoreError> {
+        let mut query = vec![
+            ("watch", "true"),
+            ("allowWatchBookmarks", "true"),
+            ("timeoutSeconds", WATCH_TIMEOUT_SECS),
+            ("resourceVersion", resource_version),
+        ];
+        if let Some(selector) = label_selector {
+            query.push(("labelSelector", selector));
+        }
+
+        let response = self.get(path, &query).await?;
+        Ok(WatchStream {
+            response,
+            buffer: Vec::new(),
+        })
+    }
+
+    async fn get(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<reqwest::Response, CoreError> {
+        let mut request = self
+            .http
+            .get(format!("{}{path}", self.base_url))
+            .query(query);
+
+        // Re-read on every request: projected service account tokens rotate.
+        if let Ok(token) = tokio::fs::read_to_string(&self.token_file).await {
+            request = request.bearer_auth(token.trim());
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| CoreError::KubernetesApi(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(CoreError::KubernetesApi(format!(
+                "GET {path} returned {status}: {body}"
+            )));
+        }
+
+        Ok(response)
+    }
+}
+
+/// Newline-delimited JSON stream of [`WatchEvent`]s.
+pub struct WatchStream {
+    response: reqwest::Response,
+    buffer: Vec<u8>,
+}
+
+impl WatchStream {
+    /// Returns the next event, or `None` when the server closed the stream.
+    pub async fn next_event(&mut self) -> Result<Option<WatchEvent>, CoreError> {
+        loop {
+            if let Some(pos) = self.buffer.iter().position(|b| *b == b'\n') {
+                let line: Vec<u8> = self.buffer.drain(..=pos).collect();
+                let line = &line[..line.len() - 1];
+                if line.iter().all(u8::is_ascii_whitespace) {
+                    continue;
+                }
+                return serde_json::from_slice(line)
+                    .map(Some)
+                    .map_err(|e| CoreError::KubernetesApi(format!("invalid watch event: {e}")));
+            }
+
+            let chunk = self
+                .response
+                .chunk()
+                .await
+                .map_err(|e| CoreError::KubernetesApi(e.to_string()))?;
+
+            match chunk {
+                Some(bytes) => self.buffer.extend_from_slice(&bytes),
+                None if self.buffer.iter().all(u8::is_ascii_whitespace) => return Ok(None),
+                // Trailing event without a final newline
+                None => self.buffer.push(b'\n'),
+            }
+        }
+    }
+}
+
+/// Builds the API server URL from the in-cluster environment.
+fn in_cluster_url() -> Result<String, CoreError> {
+    let host = std::env::var("KUBERNETES_SERVICE_HOST").map_err(|_| {
+        CoreError::KubernetesApi(
+            "no api_server configured and KUBERNETES_SERVICE_HOST is not set".to_string(),
+        )
+    })?;
+    let port = std::env::var("KUBERNETES_SERVICE_PORT").unwrap_or_else(|_| "443".to_string());
+
+    if host.contains(':') {
+        Ok(format!("https://[{host}]:{port}"))
+    } else {
+        Ok(format!("https://{host}:{port}"))
+    }
+}
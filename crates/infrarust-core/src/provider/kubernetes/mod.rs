@@ -0,0 +1,389 @@
+//! Kubernetes-based configuration provider.
+//!
+//! Discovers Minecraft servers from Services and Pods carrying `infrarust.*`
+//! annotations (same schema as the Docker labels) and follows the API
+//! server's watch streams for real-time updates.
+//!
+//! Entirely feature-gated behind `kubernetes`.
+
+pub mod client;
+pub mod resources;
+
+pub use client::KubeClient;
+pub use resources::{
+    Pod, Service, pod_to_server_config, resolve_pod_address, resolve_service_address,
+    service_to_server_config,
+};
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use tokio::sync::{Mutex, mpsc};
+use tokio_util::sync::CancellationToken;
+
+use infrarust_config::{KubernetesProviderConfig, ServerConfig};
+
+use crate::error::CoreError;
+use crate::provider::{ConfigProvider, ProviderConfig, ProviderEvent, ProviderId};
+
+use self::resources::{ObjectMeta, Status, WatchEvent};
+
+/// HTTP status sent by the API server when a watch `resourceVersion` is too old.
+const GONE: u16 = 410;
+
+/// Kind of Kubernetes object the provider discovers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceKind {
+    Service,
+    Pod,
+}
+
+impl ResourceKind {
+    /// Prefix used in the provider unique id (`service/<ns>/<name>`).
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Service => "service",
+            Self::Pod => "pod",
+        }
+    }
+
+    fn plural(self) -> &'static str {
+        match self {
+            Self::Service => "services",
+            Self::Pod => "pods",
+        }
+    }
+
+    /// API path for this kind, optionally scoped to a namespace.
+    pub fn path(self, namespace: Option<&str>) -> String {
+        match namespace {
+            Some(ns) => format!("/api/v1/namespaces/{ns}/{}", self.plural()),
+            None => format!("/api/v1/{}", self.plural()),
+        }
+    }
+
+    fn unique_id(self, meta: &ObjectMeta) -> String {
+        format!("{}/{}/{}", self.prefix(), meta.namespace, meta.name)
+    }
+}
+
+/// Kubernetes provider that auto-discovers annotated Services and Pods.
+pub struct KubernetesProvider {
+    config: KubernetesProviderConfig,
+    client: KubeClient,
+    /// Known objects: unique id (`kind/namespace/name`) → ServerConfig
+    known: Mutex<HashMap<String, ServerConfig>>,
+}
+
+impl KubernetesProvider {
+    pub fn new(config: &KubernetesProviderConfig) -> Result<Self, CoreError> {
+        Ok(Self {
+            config: config.clone(),
+            client: KubeClient::new(config)?,
+            known: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Resource kinds enabled in the configuration.
+    fn kinds(&self) -> Vec<ResourceKind> {
+        let mut kinds = Vec::with_capacity(2);
+        if self.config.watch_services {
+            kinds.push(ResourceKind::Service);
+        }
+        if self.config.watch_pods {
+            kinds.push(ResourceKind::Pod);
+        }
+        kinds
+    }
+
+    /// Lists every object of `kind` and builds configs for the annotated ones.
+    ///
+    /// Returns the configs and the list `resourceVersion` to watch from.
+    async fn scan(&self, kind: ResourceKind) -> Result<(Vec<ProviderConfig>, String), CoreError> {
+        let path = kind.path(self.config.namespace.as_deref());
+        let selector = self.config.label_selector.as_deref();
+
+        let (configs, version) = match kind {
+            ResourceKind::Service => {
+                let list = self.client.list::<Service>(&path, selector).await?;
+                let configs = list
+                    .items
+                    .iter()
+                    .filter_map(|s| build(kind, &s.metadata, service_to_server_config(s)))
+                    .collect();
+                (configs, list.metadata.resource_version)
+            }
+            ResourceKind::Pod => {
+                let list = self.client.list::<Pod>(&path, selector).await?;
+                let configs = list
+                    .items
+                    .iter()
+                    .filter_map(|p| build(kind, &p.metadata, pod_to_server_config(p)))
+                    .collect();
+                (configs, list.metadata.resource_version)
+            }
+        };
+
+        Ok((configs, version.unwrap_or_default()))
+    }
+
+    /// Re-lists `kind` and emits diffs against known state.
+    ///
+    /// Returns the `resourceVersion` to resume watching from.
+    async fn resync(
+        &self,
+        kind: ResourceKind,
+        sender: &mpsc::Sender<ProviderEvent>,
+    ) -> Result<String, CoreError> {
+        let (current, version) = self.scan(kind).await?;
+        let current: HashMap<String, ProviderConfig> = current
+            .into_iter()
+            .map(|pc| (pc.id.unique_id.clone(), pc))
+            .collect();
+
+        let mut known = self.known.lock().await;
+
+        for (id, pc) in &current {
+            match known.get(id) {
+                Some(existing) if *existing == pc.config => {}
+                Some(_) => {
+                    let _ = sender.send(ProviderEvent::Updated(pc.clone())).await;
+                }
+                None => {
+                    let _ = sender.send(ProviderEvent::Added(pc.clone())).await;
+                }
+            }
+            known.insert(id.clone(), pc.config.clone());
+        }
+
+        let prefix = format!("{}/", kind.prefix());
+        let removed: Vec<String> = known
+            .keys()
+            .filter(|id| id.starts_with(&prefix) && !current.contains_key(*id))
+            .cloned()
+            .collect();
+        for id in removed {
+            known.remove(&id);
+            let _ = sender
+                .send(ProviderEvent::Removed(ProviderId::kubernetes(&id)))
+                .await;
+        }
+
+        Ok(version)
+    }
+
+    /// Watches one resource kind with automatic relist and reconnection.
+    async fn watch_kind(
+        &self,
+        kind: ResourceKind,
+        sender: &mpsc::Sender<ProviderEvent>,
+        shutdown: &CancellationToken,
+    ) {
+        let mut reconnect_delay = self.config.reconnect_delay;
+        let max_delay = Duration::from_secs(60);
+
+        loop {
+            let result = tokio::select! {
+                biased;
+                () = shutdown.cancelled() => return,
+                result = self.resync_and_watch(kind, sender) => result,
+            };
+
+            match result {
+                // Resource version expired — relist immediately
+                Ok(()) => reconnect_delay = self.config.reconnect_delay,
+                Err(e) => {
+                    tracing::warn!(
+                        kind = kind.plural(),
+                        error = %e,
+                        delay = ?reconnect_delay,
+                        "kubernetes watch failed, retrying"
+                    );
+                    tokio::select! {
+                        biased;
+                        () = shutdown.cancelled() => return,
+                        () = tokio::time::sleep(reconnect_delay) => {}
+                    }
+                    reconnect_delay = (reconnect_delay * 2).min(max_delay);
+                }
+            }
+        }
+    }
+
+    /// Relists `kind`, then follows its watch stream until the
+    /// `resourceVersion` expires (`Ok`) or the connection fails (`Err`).
+    async fn resync_and_watch(
+        &self,
+        kind: ResourceKind,
+        sender: &mpsc::Sender<ProviderEvent>,
+    ) -> Result<(), CoreError> {
+        let mut version = self.resync(kind, sender).await?;
+        let path = kind.path(self.config.namespace.as_deref());
+        let selector = self.config.label_selector.as_deref();
+
+        loop {
+            let mut stream = self.client.watch(&path, selector, &version).await?;
+
+            // A `None` is the server-side timeout: re-watch from `version`.
+            while let Some(event) = stream.next_event().await? {
+                if event.event_type == "ERROR" {
+                    let status: Status = serde_json::from_value(event.object).unwrap_or_default();
+                    if status.code == GONE {
+                        tracing::debug!(kind = kind.plural(), "watch expired, relisting");
+                        return Ok(());
+                    }
+                    return Err(CoreError::KubernetesApi(format!(
+                        "watch error {}: {}",
+                        status.code, status.message
+                    )));
+                }
+
+                if let Some(v) = self.handle_event(kind, event, sender).await? {
+                    version = v;
+                }
+            }
+        }
+    }
+
+    /// Applies a single watch event. Returns the object's `resourceVersion`.
+    async fn handle_event(
+        &self,
+        kind: ResourceKind,
+        event: WatchEvent,
+        sender: &mpsc::Sender<ProviderEvent>,
+    ) -> Result<Option<String>, CoreError> {
+        let (meta, config) = match kind {
+            ResourceKind::Service => {
+                let service: Service = parse_object(event.object)?;
+                let config = service_to_server_config(&service);
+                (service.metadata, config)
+            }
+            ResourceKind::Pod => {
+                let pod: Pod = parse_object(event.object)?;
+                let config = pod_to_server_config(&pod);
+                (pod.metadata, config)
+            }
+        };
+
+        if event.event_type == "BOOKMARK" {
+            return Ok(meta.resource_version);
+        }
+
+        let id = kind.unique_id(&meta);
+        let config = match event.event_type.as_str() {
+            "ADDED" | "MODIFIED" => build(kind, &meta, config),
+            _ => None, // DELETED
+        };
+
+        let mut known = self.known.lock().await;
+        match config {
+            Some(pc) => {
+                let previous = known.insert(id, pc.config.clone());
+                match previous {
+                    Some(existing) if existing == pc.config => {}
+                    Some(_) => {
+                        let _ = sender.send(ProviderEvent::Updated(pc)).await;
+                    }
+                    None => {
+                        let _ = sender.send(ProviderEvent::Added(pc)).await;
+                    }
+                }
+            }
+            None => {
+                // Deleted, no longer annotated, or not ready anymore
+                if known.remove(&id).is_some() {
+                    let _ = sender
+                        .send(ProviderEvent::Removed(ProviderId::kubernetes(&id)))
+                        .await;
+                }
+            }
+        }
+
+        Ok(meta.resource_version)
+    }
+}
+
+/// Validates a built config and tags it with its provider id.
+fn build(
+    kind: ResourceKind,
+    meta: &ObjectMeta,
+    config: Option<ServerConfig>,
+) -> Option<ProviderConfig> {
+    let config = config?;
+    let id = kind.unique_id(meta);
+
+    if let Err(e) = infrarust_config::validate_server_config(&config) {
+        tracing::warn!(
+            object = %id,
+            error = %e,
+            "skipping kubernetes object with invalid config"
+        );
+        return None;
+    }
+
+    Some(ProviderConfig {
+        id: ProviderId::kubernetes(id),
+        config,
+    })
+}
+
+fn parse_object<T: DeserializeOwned>(object: serde_json::Value) -> Result<T, CoreError> {
+    serde_json::from_value(object)
+        .map_err(|e| CoreError::KubernetesApi(format!("invalid watch object: {e}")))
+}
+
+impl ConfigProvider for KubernetesProvider {
+    fn provider_type(&self) -> &str {
+        "kubernetes"
+    }
+
+    fn load_initial(
+        &self,
+    ) -> Pin<
+        Box<dyn std::future::Future<Output = Result<Vec<ProviderConfig>, CoreError>> + Send + '_>,
+    > {
+        Box::pin(async move {
+            let mut configs = Vec::new();
+            for kind in self.kinds() {
+                let (found, _) = self.scan(kind).await?;
+                configs.extend(found);
+            }
+
+            // Store in known map
+            let mut known = self.known.lock().await;
+            for pc in &configs {
+                known.insert(pc.id.unique_id.clone(), pc.config.clone());
+            }
+
+            tracing::info!(
+                count = configs.len(),
+                "kubernetes provider loaded initial configs"
+            );
+            Ok(configs)
+        })
+    }
+
+    fn watch(
+        &self,
+        sender: mpsc::Sender<ProviderEvent>,
+        shutdown: CancellationToken,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<(), CoreError>> + Send + '_>> {
+        Box::pin(async move {
+            let services = async {
+                if self.config.watch_services {
+                    self.watch_kind(ResourceKind::Service, &sender, &shutdown)
+                        .await;
+                }
+            };
+            let pods = async {
+                if self.config.watch_pods {
+                    self.watch_kind(ResourceKind::Pod, &sender, &shutdown).await;
+                }
+            };
+            tokio::join!(services, pods);
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,234 @@
+//! Minimal Kubernetes resource models and `infrarust.*` annotation mapping.
+//!
+//! Only the fields needed for discovery are deserialized; everything else
+//! in the API objects is ignored.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use infrarust_config::ServerConfig;
+
+use crate::provider::labels;
+
+/// Default Minecraft port.
+const DEFAULT_MC_PORT: u16 = 25565;
+
+/// Name of the port used when `infrarust.port` is absent.
+const MINECRAFT_PORT_NAME: &str = "minecraft";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectMeta {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub namespace: String,
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
+    #[serde(default)]
+    pub resource_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListMeta {
+    #[serde(default)]
+    pub resource_version: Option<String>,
+}
+
+/// Response body of a `list` call.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObjectList<T> {
+    #[serde(default)]
+    pub metadata: ListMeta,
+    #[serde(default = "Vec::new")]
+    pub items: Vec<T>,
+}
+
+/// One line of a `watch=true` response stream.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WatchEvent {
+    /// `ADDED`, `MODIFIED`, `DELETED`, `BOOKMARK` or `ERROR`.
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub object: serde_json::Value,
+}
+
+/// `Status` object carried by `ERROR` watch events.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Status {
+    #[serde(default)]
+    pub code: u16,
+    #[serde(default)]
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Service {
+    #[serde(default)]
+    pub metadata: ObjectMeta,
+    #[serde(default)]
+    pub spec: ServiceSpec,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ServiceSpec {
+    #[serde(default, rename = "clusterIP")]
+    pub cluster_ip: Option<String>,
+    #[serde(default)]
+    pub ports: Vec<ServicePort>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ServicePort {
+    #[serde(default)]
+    pub name: Option<String>,
+    pub port: u16,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Pod {
+    #[serde(default)]
+    pub metadata: ObjectMeta,
+    #[serde(default)]
+    pub spec: PodSpec,
+    #[serde(default)]
+    pub status: PodStatus,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PodSpec {
+    #[serde(default)]
+    pub containers: Vec<Container>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Container {
+    #[serde(default)]
+    pub ports: Vec<ContainerPort>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContainerPort {
+    #[serde(default)]
+    pub name: Option<String>,
+    pub container_port: u16,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodStatus {
+    #[serde(default)]
+    pub phase: Option<String>,
+    #[serde(default, rename = "podIP")]
+    pub pod_ip: Option<String>,
+    #[serde(default)]
+    pub conditions: Vec<PodCondition>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PodCondition {
+    #[serde(rename = "type")]
+    pub condition_type: String,
+    pub status: String,
+}
+
+impl Pod {
+    /// Returns `true` if the pod is running and passes its readiness probe.
+    pub fn is_ready(&self) -> bool {
+        self.status.phase.as_deref() == Some("Running")
+            && self
+                .status
+                .conditions
+                .iter()
+                .any(|c| c.condition_type == "Ready" && c.status == "True")
+    }
+}
+
+/// Resolves the backend address of a Service.
+///
+/// Priority for the port:
+/// 1. `infrarust.port` annotation
+/// 2. Service port named `minecraft`
+/// 3. First service port (or 25565)
+///
+/// Headless services (`clusterIP: None`) resolve to their cluster DNS name.
+pub fn resolve_service_address(service: &Service) -> String {
+    let port = labels::port(&service.metadata.annotations).unwrap_or_else(|| {
+        service
+            .spec
+            .ports
+            .iter()
+            .find(|p| p.name.as_deref() == Some(MINECRAFT_PORT_NAME))
+            .or_else(|| service.spec.ports.first())
+            .map_or(DEFAULT_MC_PORT, |p| p.port)
+    });
+
+    match service.spec.cluster_ip.as_deref() {
+        Some(ip) if !ip.is_empty() && ip != "None" => format!("{ip}:{port}"),
+        _ => format!(
+            "{}.{}.svc:{port}",
+            service.metadata.name, service.metadata.namespace
+        ),
+    }
+}
+
+/// Resolves the backend address of a Pod, or `None` if it has no IP yet.
+///
+/// Priority for the port:
+/// 1. `infrarust.port` annotation
+/// 2. Container port named `minecraft`
+/// 3. 25565
+pub fn resolve_pod_address(pod: &Pod) -> Option<String> {
+    let ip = pod.status.pod_ip.as_deref().filter(|ip| !ip.is_empty())?;
+
+    let port = labels::port(&pod.metadata.annotations).unwrap_or_else(|| {
+        pod.spec
+            .containers
+            .iter()
+            .flat_map(|c| c.ports.iter())
+            .find(|p| p.name.as_deref() == Some(MINECRAFT_PORT_NAME))
+            .map_or(DEFAULT_MC_PORT, |p| p.container_port)
+    });
+
+    Some(format!("{ip}:{port}"))
+}
+
+/// Converts an annotated Service to a `ServerConfig`.
+///
+/// Returns `None` if the service does not opt in with `infrarust.enable=true`.
+pub fn service_to_server_config(service: &Service) -> Option<ServerConfig> {
+    let meta = &service.metadata;
+    if !labels::is_enabled(&meta.annotations) {
+        return None;
+    }
+
+    let address = resolve_service_address(service);
+    Some(labels::labels_to_server_config(
+        &meta.name,
+        &meta.annotations,
+        &address,
+        &format!("{}.{}.k8s.local", meta.name, meta.namespace),
+    ))
+}
+
+/// Converts an annotated Pod to a `ServerConfig`.
+///
+/// Returns `None` if the pod does not opt in with `infrarust.enable=true`,
+/// is not ready, or has no IP assigned yet.
+pub fn pod_to_server_config(pod: &Pod) -> Option<ServerConfig> {
+    let meta = &pod.metadata;
+    if !labels::is_enabled(&meta.annotations) || !pod.is_ready() {
+        return None;
+    }
+
+    let address = resolve_pod_address(pod)?;
+    Some(labels::labels_to_server_config(
+        &meta.name,
+        &meta.annotations,
+        &address,
+        &format!("{}.{}.k8s.local", meta.name, meta.namespace),
+    ))
+}
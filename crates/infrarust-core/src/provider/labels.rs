@@ -0,0 +1,126 @@
+//! Shared `infrarust.*` metadata schema.
+//!
+//! Discovery providers (Docker labels, Kubernetes annotations) describe a
+//! backend with the same set of `infrarust.*` keys. This module turns that
+//! key/value map into a `ServerConfig`.
+
+use std::collections::HashMap;
+
+use infrarust_config::{ProxyMode, ServerAddress, ServerConfig};
+
+/// Default Minecraft port.
+const DEFAULT_MC_PORT: u16 = 25565;
+
+/// Returns `true` if the metadata opts in with `infrarust.enable=true`.
+pub fn is_enabled(labels: &HashMap<String, String>) -> bool {
+    labels.get("infrarust.enable").map(String::as_str) == Some("true")
+}
+
+/// Reads the `infrarust.port` key, if present and valid.
+pub fn port(labels: &HashMap<String, String>) -> Option<u16> {
+    labels
+        .get("infrarust.port")
+        .and_then(|p| p.parse::<u16>().ok())
+}
+
+/// Converts `infrarust.*` key/value metadata to a `ServerConfig`.
+///
+/// `name` identifies the discovered workload (container, service, pod) and
+/// `default_domain` is used when `infrarust.domains` is absent.
+pub fn labels_to_server_config(
+    name: &str,
+    labels: &HashMap<String, String>,
+    address: &str,
+    default_domain: &str,
+) -> ServerConfig {
+    let domains = labels
+        .get("infrarust.domains")
+        .map(|d| d.split(',').map(|s| s.trim().to_string()).collect())
+        .unwrap_or_else(|| vec![default_domain.to_string()]);
+
+    let proxy_mode = labels
+        .get("infrarust.proxy_mode")
+        .and_then(|m| match m.as_str() {
+            "passthrough" => Some(ProxyMode::Passthrough),
+            "client_only" => Some(ProxyMode::ClientOnly),
+            "offline" => Some(ProxyMode::Offline),
+            "server_only" => Some(ProxyMode::ServerOnly),
+            "zero_copy" => Some(ProxyMode::ZeroCopy),
+            _ => None,
+        })
+        .unwrap_or(ProxyMode::Passthrough);
+
+    let server_name = labels.get("infrarust.name").cloned();
+    let network = labels.get("infrarust.network").cloned();
+
+    let send_proxy_protocol = labels
+        .get("infrarust.send_proxy_protocol")
+        .map(|v| v == "true" || v == "1")
+        .unwrap_or(false);
+
+    let server_address: ServerAddress = address.parse().unwrap_or_else(|_| ServerAddress {
+        host: address.to_string(),
+        port: DEFAULT_MC_PORT,
+    });
+
+    // Build a TOML table and deserialize it to satisfy deny_unknown_fields.
+    // This ensures we get proper defaults for all fields.
+    let mut table = toml::Table::new();
+    table.insert("domains".to_string(), domains.clone().into());
+    table.insert(
+        "addresses".to_string(),
+        vec![server_address.to_string()].into(),
+    );
+    if let Ok(mode) = toml::Value::try_from(proxy_mode) {
+        table.insert("proxy_mode".to_string(), mode);
+    }
+    table.insert(
+        "send_proxy_protocol".to_string(),
+        send_proxy_protocol.into(),
+    );
+
+    if let Some(ref name) = server_name {
+        table.insert("name".to_string(), name.clone().into());
+    }
+
+    if let Some(ref network) = network {
+        table.insert("network".to_string(), network.clone().into());
+    }
+
+    // Add MOTD text if specified
+    if let Some(motd_text) = labels.get("infrarust.motd.text") {
+        let mut online = toml::Table::new();
+        online.insert("text".to_string(), motd_text.clone().into());
+        let mut motd = toml::Table::new();
+        motd.insert("online".to_string(), online.into());
+        table.insert("motd".to_string(), motd.into());
+    }
+
+    // Try to parse, fall back to manual construction
+    toml::Value::Table(table)
+        .try_into::<ServerConfig>()
+        .unwrap_or_else(|_| {
+            // Manual fallback — less complete but functional
+            ServerConfig {
+                id: Some(name.to_string()),
+                name: server_name,
+                network,
+                domains,
+                addresses: vec![server_address],
+                proxy_mode,
+                forwarding_mode: None,
+                send_proxy_protocol,
+                domain_rewrite: Default::default(),
+                motd: Default::default(),
+                server_manager: None,
+                timeouts: None,
+                max_players: 0,
+                ip_filter: None,
+                disconnect_message: None,
+                limbo_handlers: Vec::new(),
+                fallbacks: Vec::new(),
+                reconnect: None,
+                resource_packs: Vec::new(),
+            }
+        })
+}
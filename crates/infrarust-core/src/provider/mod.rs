@@ -1,12 +1,15 @@
 //! Configuration provider system.
 //!
-//! Providers are sources of `ServerConfig` data (files, Docker, future API).
+//! Providers are sources of `ServerConfig` data (files, Docker, Kubernetes, future API).
 //! Each provider implements [`ConfigProvider`] and is registered with the
 //! [`ProviderRegistry`] which orchestrates loading and hot-reload.
 
 #[cfg(feature = "docker")]
 pub mod docker;
 pub mod file;
+#[cfg(feature = "kubernetes")]
+pub mod kubernetes;
+pub mod labels;
 pub mod plugin_adapter;
 pub mod provider_id;
 pub mod registry;
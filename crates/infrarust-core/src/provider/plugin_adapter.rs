@@ -93,6 +93,9 @@ fn convert_api_to_config(
         ip_filter: None,
         disconnect_message: api.disconnect_message.clone(),
         limbo_handlers: api.limbo_handlers.clone(),
+        fallbacks: Vec::new(),
+        reconnect: None,
+        resource_packs: Vec::new(),
     }
 }
 
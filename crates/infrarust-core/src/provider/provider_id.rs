@@ -1,7 +1,8 @@
 //! `ProviderId` — unique identifier for a server config's provenance.
 //!
 //! Format: `provider_type@unique_id`
-//! Examples: `file@survival.toml`, `docker@mc-survival-1`
+//! Examples: `file@survival.toml`, `docker@mc-survival-1`,
+//! `kubernetes@service/minecraft/lobby`
 
 use std::fmt;
 use std::str::FromStr;
@@ -39,6 +40,11 @@ impl ProviderId {
     pub fn docker(container_name: impl Into<String>) -> Self {
         Self::new("docker", container_name)
     }
+
+    /// Shorthand for a Kubernetes-based provider id (`kind/namespace/name`).
+    pub fn kubernetes(object: impl Into<String>) -> Self {
+        Self::new("kubernetes", object)
+    }
 }
 
 impl fmt::Display for ProviderId {
@@ -1,14 +1,22 @@
 //! Thread-safe registry of active proxy sessions.
 
+use std::future::Future;
 use std::net::IpAddr;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
 
 use dashmap::DashMap;
 use infrarust_api::player::Player;
+use infrarust_api::types::{Component, PlayerId, ServerId};
+use infrarust_config::LIMBO_SERVER_ID;
 use uuid::Uuid;
 
 use crate::player::PlayerSession;
 
+/// How long a graceful restart waits for evacuated players to leave the server.
+const EVACUATION_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// Thread-safe registry of active proxy sessions.
 ///
 /// Pure data structure backed by `DashMap` — no background tasks.
@@ -61,6 +69,14 @@ impl ConnectionRegistry {
             .map(|r| Arc::clone(&r))
     }
 
+    /// Finds a session by its player ID.
+    pub fn find_by_player_id(&self, player_id: PlayerId) -> Option<Arc<PlayerSession>> {
+        self.sessions
+            .iter()
+            .find(|r| r.id() == player_id)
+            .map(|r| Arc::clone(&r))
+    }
+
     /// Returns all sessions connected to the given server.
     pub fn find_by_server(&self, server_id: &str) -> Vec<Arc<PlayerSession>> {
         self.sessions
@@ -113,6 +129,75 @@ impl infrarust_server_manager::PlayerCounter for ConnectionRegistry {
     fn count_by_server(&self, server_id: &str) -> usize {
         self.count_by_server(server_id)
     }
+
+    fn broadcast(&self, server_id: &str, message: &str) {
+        let component = Component::from_legacy(message);
+        for session in self.find_by_server(server_id) {
+            let _ = session.send_message(component.clone());
+        }
+    }
+
+    fn evacuate<'a>(
+        &'a self,
+        server_id: &'a str,
+        target: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Vec<u64>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut moved = Vec::new();
+            for session in self.find_by_server(server_id) {
+                match session.switch_server(ServerId::new(target)).await {
+                    Ok(()) => moved.push(session),
+                    Err(e) => tracing::debug!(
+                        player = %session.profile().username,
+                        "cannot evacuate player: {e}"
+                    ),
+                }
+            }
+
+            // Limbo players keep their server, only a backend switch can be observed
+            if target != LIMBO_SERVER_ID {
+                let deadline = tokio::time::Instant::now() + EVACUATION_TIMEOUT;
+                while tokio::time::Instant::now() < deadline
+                    && moved.iter().any(|s| {
+                        s.is_connected()
+                            && s.current_server().is_some_and(|c| c.as_str() == server_id)
+                    })
+                {
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                }
+            }
+
+            moved.iter().map(|s| s.id().as_u64()).collect()
+        })
+    }
+
+    fn return_players<'a>(
+        &'a self,
+        server_id: &'a str,
+        target: &'a str,
+        players: Vec<u64>,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            // Players evacuated to limbo are sent back by the limbo handlers
+            if target == LIMBO_SERVER_ID {
+                return;
+            }
+            let sessions: Vec<_> = self
+                .all()
+                .into_iter()
+                .filter(|s| players.contains(&s.id().as_u64()))
+                .filter(|s| s.current_server().is_some_and(|c| c.as_str() == target))
+                .collect();
+            for session in sessions {
+                if let Err(e) = session.switch_server(ServerId::new(server_id)).await {
+                    tracing::debug!(
+                        player = %session.profile().username,
+                        "cannot return player after restart: {e}"
+                    );
+                }
+            }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use tokio_util::sync::CancellationToken;
 
@@ -10,7 +11,7 @@ use infrarust_protocol::version::ProtocolVersion;
 use infrarust_transport::{BackendConnector, Listener, ListenerConfig};
 use tracing::Instrument;
 
-use infrarust_api::events::proxy::ServerStateChangeEvent;
+use infrarust_api::events::proxy::{ServerCrashLoopEvent, ServerStateChangeEvent};
 use infrarust_api::types::ServerId;
 use infrarust_server_manager::ServerManagerService;
 
@@ -38,6 +39,8 @@ use crate::pipeline::Pipeline;
 use crate::pipeline::context::ConnectionContext;
 use crate::pipeline::middleware::MiddlewareResult;
 use crate::pipeline::types::{ConnectionIntent, HandshakeData, LegacyDetected, RoutingData};
+use crate::player::data_store::SqlitePlayerDataStore;
+use crate::player::last_server::LastServerStore;
 use crate::player::registry::PlayerRegistryImpl;
 use crate::provider::file::FileProvider;
 use crate::provider::registry::ProviderRegistry;
@@ -45,8 +48,12 @@ use crate::registry::ConnectionRegistry;
 use crate::routing::DomainRouter;
 use crate::services::ProxyServices;
 use crate::services::command_manager::CommandManagerImpl;
+use crate::services::tab_list::TabListManager;
 use crate::status::{FaviconCache, StatusCache, StatusHandler, StatusRelayClient};
 
+/// How long shutdown waits for background tasks to save their state.
+const SHUTDOWN_TASKS_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// The main proxy server orchestrator.
 ///
 /// Wires together the listener, pipelines, handlers, and config hot-reload.
@@ -122,6 +129,26 @@ impl ProxyServer {
             );
         }
 
+        // Kubernetes provider (feature-gated)
+        #[cfg(feature = "kubernetes")]
+        if let Some(ref kube_config) = config.kubernetes {
+            match crate::provider::kubernetes::KubernetesProvider::new(kube_config) {
+                Ok(kube_provider) => {
+                    provider_registry.add_provider(Box::new(kube_provider));
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "failed to initialize kubernetes provider, continuing without");
+                }
+            }
+        }
+
+        #[cfg(not(feature = "kubernetes"))]
+        if config.kubernetes.is_some() {
+            tracing::warn!(
+                "kubernetes configuration found but kubernetes feature is not enabled, ignoring"
+            );
+        }
+
         // Start all providers (loads initial configs + starts watchers)
         let (_provider_handle, provider_event_sender) = provider_registry.start().await?;
 
@@ -140,7 +167,8 @@ impl ProxyServer {
             None
         } else {
             let http_client = reqwest::Client::new();
-            let service = ServerManagerService::new(&managed_configs, http_client);
+            let service = ServerManagerService::new(&managed_configs, http_client)
+                .with_state_file(config.data_dir.join("server_manager.json"));
 
             // Wire state change callback to fire ServerStateChangeEvent
             let bus = Arc::clone(&event_bus);
@@ -154,6 +182,16 @@ impl ProxyServer {
                 });
             }));
 
+            // Wire crash loop detection to fire ServerCrashLoopEvent
+            let bus = Arc::clone(&event_bus);
+            service.add_on_crash_loop(Arc::new(move |server_id, restarts, window| {
+                bus.fire_and_forget_arc(ServerCrashLoopEvent {
+                    server: ServerId::new(server_id),
+                    restarts,
+                    window,
+                });
+            }));
+
             tracing::info!(count = managed_configs.len(), "server manager initialized");
             Some(Arc::new(service))
         };
@@ -216,6 +254,31 @@ impl ProxyServer {
         let permission_service =
             Arc::new(crate::permissions::PermissionService::new(&config.permissions).await);
 
+        let last_servers = match config.last_server {
+            Some(ref last_server) => Some(Arc::new(
+                LastServerStore::load(
+                    last_server.clone(),
+                    config.data_dir.join("last_servers.json"),
+                )
+                .await,
+            )),
+            None => None,
+        };
+
+        let player_data_path = config.data_dir.join("player_data.db");
+        tokio::fs::create_dir_all(&config.data_dir).await?;
+        let player_data =
+            Arc::new(SqlitePlayerDataStore::open(&player_data_path).map_err(|e| {
+                CoreError::Other(format!(
+                    "failed to open player data {}: {e}",
+                    player_data_path.display()
+                ))
+            })?);
+
+        // Build ProxyMetrics (telemetry feature only)
+        #[cfg(feature = "telemetry")]
+        let proxy_metrics = Arc::new(crate::telemetry::ProxyMetrics::new());
+
         let services = ProxyServices {
             event_bus: Arc::clone(&event_bus),
             player_registry,
@@ -236,6 +299,15 @@ impl ProxyServer {
             forwarding_mode,
             forwarding_secret,
             permission_service,
+            last_servers,
+            player_data,
+            tab_list: Arc::new(TabListManager::new(
+                config.tab_list.clone(),
+                Arc::clone(&registry),
+                Arc::clone(&domain_router),
+            )),
+            #[cfg(feature = "telemetry")]
+            metrics: Some(Arc::clone(&proxy_metrics)),
         };
 
         // Build common pipeline: IpFilter → BanIpCheck → HandshakeParser → RateLimiter → DomainRouter
@@ -259,10 +331,6 @@ impl ProxyServer {
             login_pipeline.add(Box::new(ServerManagerMiddleware::new(Arc::clone(sm))));
         }
 
-        // Build ProxyMetrics (telemetry feature only)
-        #[cfg(feature = "telemetry")]
-        let proxy_metrics = Arc::new(crate::telemetry::ProxyMetrics::new());
-
         let passthrough_handler =
             PassthroughHandler::new(Arc::clone(&backend_connector), services.clone());
         #[cfg(feature = "telemetry")]
@@ -316,12 +384,15 @@ impl ProxyServer {
 
         tracing::info!(bind = %config.bind, "proxy server listening");
 
+        // Tasks that save state on shutdown, awaited before returning
+        let mut shutdown_tasks = Vec::new();
+
         // Start server manager health check and monitoring
         if let Some(ref sm) = self.services.server_manager {
             sm.initial_health_check().await;
             let player_counter: Arc<dyn infrarust_server_manager::PlayerCounter> =
                 Arc::clone(&self.services.connection_registry) as _;
-            let _monitoring_handles = sm.start_monitoring(player_counter, self.shutdown.clone());
+            shutdown_tasks.extend(sm.start_monitoring(player_counter, self.shutdown.clone()));
             tracing::info!("server manager monitoring started");
         }
 
@@ -331,6 +402,26 @@ impl ProxyServer {
             .ban_manager
             .start_purge_task(config.ban.purge_interval, self.shutdown.clone());
 
+        // Start last server save task
+        let _last_servers_handle = self
+            .services
+            .last_servers
+            .as_ref()
+            .map(|store| store.start_save_task(self.shutdown.clone()));
+
+        // Start player data purge task
+        shutdown_tasks.push(
+            self.services
+                .player_data
+                .start_purge_task(self.shutdown.clone()),
+        );
+
+        // Start tab list refresh task
+        let _tab_list_handle = self
+            .services
+            .tab_list
+            .start_refresh_task(self.shutdown.clone());
+
         // Config hot-reload is handled by the ProviderRegistry (started in new())
 
         // Accept loop
@@ -401,6 +492,20 @@ impl ProxyServer {
             });
         }
 
+        let join_all = async {
+            for task in shutdown_tasks {
+                if let Err(e) = task.await {
+                    tracing::warn!(error = %e, "background task failed during shutdown");
+                }
+            }
+        };
+        if tokio::time::timeout(SHUTDOWN_TASKS_TIMEOUT, join_all)
+            .await
+            .is_err()
+        {
+            tracing::warn!("background tasks did not finish in time, state may not be saved");
+        }
+
         Ok(())
     }
 
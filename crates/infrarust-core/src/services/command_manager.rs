@@ -154,8 +154,12 @@ impl CommandManagerImpl {
 
     pub fn is_plugin_command(&self, name: &str) -> bool {
         let name_lower = name.to_lowercase();
+        let canonical = {
+            let aliases = self.aliases.read().expect("lock poisoned");
+            aliases.get(&name_lower).cloned().unwrap_or(name_lower)
+        };
         let commands = self.commands.read().expect("lock poisoned");
-        commands.get(&name_lower).is_some_and(|cmd| !cmd.is_builtin)
+        commands.get(&canonical).is_some_and(|cmd| !cmd.is_builtin)
     }
 
     pub fn tab_complete(&self, input: &str) -> Vec<String> {
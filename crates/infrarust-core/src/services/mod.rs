@@ -6,5 +6,6 @@ pub mod config_service;
 pub mod proxy;
 pub mod scheduler;
 pub mod server_manager_bridge;
+pub mod tab_list;
 
 pub use proxy::ProxyServices;
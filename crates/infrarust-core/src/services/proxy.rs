@@ -15,11 +15,14 @@ use crate::forwarding::{ForwardingHandler, ForwardingMode, build_forwarding_hand
 use crate::limbo::registry::LimboHandlerRegistry;
 use crate::limbo::registry_cache::RegistryCodecCache;
 use crate::permissions::PermissionService;
+use crate::player::data_store::SqlitePlayerDataStore;
+use crate::player::last_server::LastServerStore;
 use crate::player::registry::PlayerRegistryImpl;
 use crate::provider::ProviderEvent;
 use crate::registry::ConnectionRegistry;
 use crate::routing::DomainRouter;
 use crate::services::command_manager::CommandManagerImpl;
+use crate::services::tab_list::TabListManager;
 
 /// Shared services passed to connection handlers.
 ///
@@ -58,6 +61,15 @@ pub struct ProxyServices {
     pub forwarding_mode: Arc<ForwardingMode>,
     pub forwarding_secret: Option<Arc<[u8]>>,
     pub permission_service: Arc<PermissionService>,
+    /// Remembered last servers, when `[last_server]` is configured.
+    pub last_servers: Option<Arc<LastServerStore>>,
+    /// Proxy-managed tab list (header/footer, plugin entries, global list).
+    pub tab_list: Arc<TabListManager>,
+    /// Persistent per-player data for plugins.
+    pub player_data: Arc<SqlitePlayerDataStore>,
+    /// Metric instruments recorded outside the handlers (player latency).
+    #[cfg(feature = "telemetry")]
+    pub metrics: Option<Arc<crate::telemetry::ProxyMetrics>>,
 }
 
 impl ProxyServices {
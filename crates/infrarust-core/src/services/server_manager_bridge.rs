@@ -1,23 +1,49 @@
 //! [`ServerManager`] bridge — delegates to [`ServerManagerService`].
 
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use dashmap::DashMap;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
 
 use infrarust_api::error::ServiceError;
 use infrarust_api::event::{BoxFuture, ListenerHandle};
-use infrarust_api::services::server_manager::{ServerManager, ServerState, StateChangeCallback};
+use infrarust_api::services::server_manager::{
+    ConsoleCallback, ConsoleLine, ConsoleStream, ServerManager, ServerState, StateChangeCallback,
+};
 use infrarust_api::types::ServerId;
 
+use infrarust_server_manager::ServerManagerError;
+use infrarust_server_manager::console::{
+    ConsoleLine as CoreConsoleLine, ConsoleStream as CoreConsoleStream,
+};
 use infrarust_server_manager::service::ServerManagerService;
 use infrarust_server_manager::state::ServerState as CoreServerState;
 
 /// Bridges the API-level [`ServerManager`] trait to the core [`ServerManagerService`].
 pub struct ServerManagerBridge {
     service: Arc<ServerManagerService>,
+    /// Tasks forwarding console output to plugin callbacks, by listener id.
+    console_listeners: Arc<DashMap<u64, JoinHandle<()>>>,
+    next_console_listener: AtomicU64,
 }
 
 impl ServerManagerBridge {
     pub fn new(service: Arc<ServerManagerService>) -> Self {
-        Self { service }
+        Self {
+            service,
+            console_listeners: Arc::new(DashMap::new()),
+            next_console_listener: AtomicU64::new(1),
+        }
+    }
+}
+
+impl Drop for ServerManagerBridge {
+    fn drop(&mut self) {
+        for entry in self.console_listeners.iter() {
+            entry.value().abort();
+        }
     }
 }
 
@@ -48,6 +74,16 @@ impl ServerManager for ServerManagerBridge {
         })
     }
 
+    fn restart(&self, server: &ServerId) -> BoxFuture<'_, Result<(), ServiceError>> {
+        let server_id = server.as_str().to_string();
+        Box::pin(async move {
+            self.service
+                .restart_server(&server_id)
+                .await
+                .map_err(convert_error)
+        })
+    }
+
     fn on_state_change(&self, callback: StateChangeCallback) -> ListenerHandle {
         let id = self.service.add_on_state_change(Arc::new(
             move |server_id: &str, old: CoreServerState, new: CoreServerState| {
@@ -66,6 +102,73 @@ impl ServerManager for ServerManagerBridge {
             .map(|(id, state)| (ServerId::new(id), convert_state(state)))
             .collect()
     }
+
+    fn closed_message(&self, server: &ServerId) -> Option<String> {
+        self.service.closed_message(server.as_str())
+    }
+
+    fn send_command(
+        &self,
+        server: &ServerId,
+        command: &str,
+    ) -> BoxFuture<'_, Result<(), ServiceError>> {
+        let server_id = server.as_str().to_string();
+        let command = command.to_string();
+        Box::pin(async move {
+            self.service
+                .send_command(&server_id, &command)
+                .await
+                .map_err(convert_error)
+        })
+    }
+
+    fn on_console_output(
+        &self,
+        server: &ServerId,
+        callback: ConsoleCallback,
+    ) -> Result<(Vec<ConsoleLine>, ListenerHandle), ServiceError> {
+        let (history, mut rx) = self
+            .service
+            .subscribe_console(server.as_str())
+            .map_err(convert_error)?;
+
+        let server_id = server.as_str().to_string();
+        let id = self.next_console_listener.fetch_add(1, Ordering::Relaxed);
+        let listeners = Arc::clone(&self.console_listeners);
+        let task = tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(line) => callback(&convert_line(line)),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::debug!(server = %server_id, skipped, "console listener lagged");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            listeners.remove(&id);
+        });
+
+        self.console_listeners.insert(id, task);
+        // The task may have ended before it was inserted.
+        if self
+            .console_listeners
+            .get(&id)
+            .is_some_and(|task| task.is_finished())
+        {
+            self.console_listeners.remove(&id);
+        }
+
+        Ok((
+            history.into_iter().map(convert_line).collect(),
+            ListenerHandle::new(id),
+        ))
+    }
+
+    fn remove_console_listener(&self, handle: ListenerHandle) {
+        if let Some((_, task)) = self.console_listeners.remove(&handle.as_u64()) {
+            task.abort();
+        }
+    }
 }
 
 /// A no-op [`ServerManager`] for proxies with no managed servers.
@@ -88,6 +191,11 @@ impl ServerManager for NoopServerManager {
         Box::pin(async move { Err(ServiceError::NotFound(id)) })
     }
 
+    fn restart(&self, server: &ServerId) -> BoxFuture<'_, Result<(), ServiceError>> {
+        let id = server.as_str().to_string();
+        Box::pin(async move { Err(ServiceError::NotFound(id)) })
+    }
+
     fn on_state_change(&self, _callback: StateChangeCallback) -> ListenerHandle {
         ListenerHandle::new(0)
     }
@@ -95,6 +203,29 @@ impl ServerManager for NoopServerManager {
     fn get_all_servers(&self) -> Vec<(ServerId, ServerState)> {
         Vec::new()
     }
+
+    fn closed_message(&self, _server: &ServerId) -> Option<String> {
+        None
+    }
+
+    fn send_command(
+        &self,
+        server: &ServerId,
+        _command: &str,
+    ) -> BoxFuture<'_, Result<(), ServiceError>> {
+        let id = server.as_str().to_string();
+        Box::pin(async move { Err(ServiceError::NotFound(id)) })
+    }
+
+    fn on_console_output(
+        &self,
+        server: &ServerId,
+        _callback: ConsoleCallback,
+    ) -> Result<(Vec<ConsoleLine>, ListenerHandle), ServiceError> {
+        Err(ServiceError::NotFound(server.as_str().to_string()))
+    }
+
+    fn remove_console_listener(&self, _handle: ListenerHandle) {}
 }
 
 /// Converts core server state to API server state.
@@ -108,3 +239,22 @@ fn convert_state(state: CoreServerState) -> ServerState {
         _ => ServerState::Offline,
     }
 }
+
+fn convert_line(line: CoreConsoleLine) -> ConsoleLine {
+    let stream = match line.stream {
+        CoreConsoleStream::Stdout => ConsoleStream::Stdout,
+        CoreConsoleStream::Stderr => ConsoleStream::Stderr,
+    };
+    ConsoleLine {
+        stream,
+        line: line.line,
+    }
+}
+
+fn convert_error(error: ServerManagerError) -> ServiceError {
+    match error {
+        ServerManagerError::ServerNotFound { server_id } => ServiceError::NotFound(server_id),
+        ServerManagerError::Unsupported { .. } => ServiceError::Unavailable(error.to_string()),
+        other => ServiceError::OperationFailed(other.to_string()),
+    }
+}
@@ -0,0 +1,393 @@
+//! [`TabList`] implementation — header/footer templates, plugin entries and
+//! the network-wide player list.
+//!
+//! A refresh task diffs what each active player should see against what was
+//! already sent to them, and only sends the difference. Entries for players on
+//! the viewer's own server are left to that backend.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use dashmap::DashMap;
+use tokio::sync::Notify;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use infrarust_api::player::Player;
+use infrarust_api::services::tab_list::TabList;
+use infrarust_api::types::{Component, PlayerId, ProtocolVersion, ServerId, TabListEntry};
+use infrarust_config::TabListConfig;
+
+use crate::player::PlayerSession;
+use crate::registry::ConnectionRegistry;
+use crate::routing::DomainRouter;
+
+/// Lower bound for the refresh interval.
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Revision used for entries mirroring connected players.
+const PLAYER_REVISION: u64 = 0;
+
+/// 1.20.2, the first version with a configuration phase.
+const CONFIGURATION_PHASE_VERSION: ProtocolVersion = ProtocolVersion::new(764);
+
+/// What has been sent to one player.
+#[derive(Default)]
+struct ViewerState {
+    server: Option<ServerId>,
+    /// Entries sent by the proxy, with the revision that was sent.
+    shown: HashMap<Uuid, u64>,
+    /// Last header/footer sent, as JSON.
+    header_footer: Option<(String, String)>,
+}
+
+/// Proxy-managed tab list.
+pub struct TabListManager {
+    config: Option<TabListConfig>,
+    registry: Arc<ConnectionRegistry>,
+    domain_router: Arc<DomainRouter>,
+    default_template: RwLock<Option<(String, String)>>,
+    player_templates: DashMap<PlayerId, (String, String)>,
+    /// Plugin entries with their revision, bumped on every replacement.
+    entries: DashMap<Uuid, (u64, TabListEntry)>,
+    next_revision: AtomicU64,
+    viewers: DashMap<PlayerId, ViewerState>,
+    changed: Notify,
+}
+
+impl TabListManager {
+    pub fn new(
+        config: Option<TabListConfig>,
+        registry: Arc<ConnectionRegistry>,
+        domain_router: Arc<DomainRouter>,
+    ) -> Self {
+        Self {
+            config,
+            registry,
+            domain_router,
+            default_template: RwLock::new(None),
+            player_templates: DashMap::new(),
+            entries: DashMap::new(),
+            next_revision: AtomicU64::new(PLAYER_REVISION + 1),
+            viewers: DashMap::new(),
+            changed: Notify::new(),
+        }
+    }
+
+    /// Refreshes every player periodically, and right away after an API change.
+    pub fn start_refresh_task(
+        self: &Arc<Self>,
+        shutdown: CancellationToken,
+    ) -> tokio::task::JoinHandle<()> {
+        let manager = Arc::clone(self);
+        let interval = manager
+            .config
+            .as_ref()
+            .map_or_else(
+                || TabListConfig::default().refresh_interval,
+                |c| c.refresh_interval,
+            )
+            .max(MIN_REFRESH_INTERVAL);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    () = shutdown.cancelled() => break,
+                    _ = ticker.tick() => {}
+                    () = manager.changed.notified() => {}
+                }
+                manager.refresh_all();
+            }
+        })
+    }
+
+    /// Brings every active player's tab list up to date.
+    fn refresh_all(&self) {
+        let sessions = self.registry.all();
+        let mut networks: HashMap<ServerId, Option<String>> = HashMap::new();
+        let players: Vec<(Arc<PlayerSession>, ServerId, Option<String>)> = sessions
+            .into_iter()
+            .filter_map(|session| {
+                let server = session.current_server()?;
+                let network = networks
+                    .entry(server.clone())
+                    .or_insert_with(|| {
+                        self.domain_router
+                            .find_by_server_id(server.as_str())
+                            .and_then(|config| config.network.clone())
+                    })
+                    .clone();
+                Some((session, server, network))
+            })
+            .collect();
+
+        let online = self.registry.count().to_string();
+        let global = self.config.as_ref().is_some_and(|c| c.global);
+        let plugin_entries: Vec<(u64, TabListEntry)> = self
+            .entries
+            .iter()
+            .map(|entry| entry.value().clone())
+            .collect();
+
+        for (viewer, server, network) in &players {
+            if !viewer.is_active() || !viewer.is_connected() {
+                continue;
+            }
+
+            let mut desired: HashMap<Uuid, (u64, TabListEntry)> = HashMap::new();
+            if global && network.is_some() {
+                for (other, other_server, other_network) in &players {
+                    if other_server != server && other_network == network {
+                        let profile = other.profile();
+                        let entry = TabListEntry::new(profile.uuid, profile.username.clone())
+                            .properties(profile.properties.clone());
+                        desired.insert(profile.uuid, (PLAYER_REVISION, entry));
+                    }
+                }
+            }
+            for (revision, entry) in &plugin_entries {
+                desired.insert(entry.uuid, (*revision, entry.clone()));
+            }
+            let on_server: HashSet<Uuid> = players
+                .iter()
+                .filter(|(_, other_server, _)| other_server == server)
+                .map(|(other, _, _)| other.profile().uuid)
+                .collect();
+
+            let server_online = on_server.len().to_string();
+            let network_online = network.as_ref().map_or_else(
+                || server_online.clone(),
+                |network| {
+                    players
+                        .iter()
+                        .filter(|(_, _, n)| n.as_ref() == Some(network))
+                        .count()
+                        .to_string()
+                },
+            );
+            let vars = [
+                ("player", viewer.profile().username.as_str()),
+                ("server", server.as_str()),
+                ("network", network.as_deref().unwrap_or("")),
+                ("online", online.as_str()),
+                ("network_online", network_online.as_str()),
+                ("server_online", server_online.as_str()),
+            ];
+
+            let mut state = self.viewers.entry(viewer.id()).or_default();
+            if state.server.as_ref() != Some(server) {
+                // 1.20.2+ clients go through the configuration phase on a
+                // server switch, which clears their player list, so every
+                // proxy entry has to be sent again. Older clients keep it,
+                // and the diff below removes what no longer applies.
+                if viewer.protocol_version() >= CONFIGURATION_PHASE_VERSION {
+                    state.shown.clear();
+                    state.header_footer = None;
+                }
+                state.server = Some(server.clone());
+            }
+
+            let (add, remove) =
+                diff_entries(&mut state.shown, &desired, |uuid| on_server.contains(uuid));
+            if !remove.is_empty()
+                && let Err(e) = viewer.remove_tab_list_entries(remove)
+            {
+                tracing::debug!(player = %viewer.profile().username, "tab list update dropped: {e}");
+            }
+            if !add.is_empty()
+                && let Err(e) = viewer.add_tab_list_entries(add)
+            {
+                tracing::debug!(player = %viewer.profile().username, "tab list update dropped: {e}");
+            }
+
+            let rendered = self.template_for(viewer.id()).map(|(header, footer)| {
+                (
+                    Component::from_legacy_format(&header, &vars),
+                    Component::from_legacy_format(&footer, &vars),
+                )
+            });
+            let rendered_json = rendered
+                .as_ref()
+                .map(|(header, footer)| (header.to_json(), footer.to_json()));
+            if rendered_json != state.header_footer {
+                // No template any more: clear what was sent before.
+                let (header, footer) = rendered.unwrap_or_default();
+                match viewer.send_tab_list_header_footer(header, footer) {
+                    Ok(()) => state.header_footer = rendered_json,
+                    Err(e) => {
+                        tracing::debug!(player = %viewer.profile().username, "tab list update dropped: {e}");
+                    }
+                }
+            }
+        }
+
+        let connected: HashSet<PlayerId> = players.iter().map(|(p, _, _)| p.id()).collect();
+        self.viewers.retain(|id, _| connected.contains(id));
+        self.player_templates.retain(|id, _| connected.contains(id));
+    }
+
+    /// A player's own template, then the plugin default, then the config.
+    fn template_for(&self, player: PlayerId) -> Option<(String, String)> {
+        if let Some(template) = self.player_templates.get(&player) {
+            return Some(template.clone());
+        }
+        if let Some(template) = self.default_template.read().expect("lock poisoned").clone() {
+            return Some(template);
+        }
+        self.config
+            .as_ref()
+            .filter(|c| !c.header.is_empty() || !c.footer.is_empty())
+            .map(|c| (c.header.clone(), c.footer.clone()))
+    }
+}
+
+/// Works out which entries to add and remove, and updates `shown` to match.
+///
+/// Entries no longer wanted are only removed from the client if the backend
+/// does not own them (`owned_by_backend`); either way the proxy forgets them.
+fn diff_entries(
+    shown: &mut HashMap<Uuid, u64>,
+    desired: &HashMap<Uuid, (u64, TabListEntry)>,
+    owned_by_backend: impl Fn(&Uuid) -> bool,
+) -> (Vec<TabListEntry>, Vec<Uuid>) {
+    let mut remove = Vec::new();
+    shown.retain(|uuid, _| {
+        if desired.contains_key(uuid) {
+            return true;
+        }
+        if !owned_by_backend(uuid) {
+            remove.push(*uuid);
+        }
+        false
+    });
+
+    let mut add = Vec::new();
+    for (uuid, (revision, entry)) in desired {
+        if shown.get(uuid) != Some(revision) {
+            shown.insert(*uuid, *revision);
+            add.push(entry.clone());
+        }
+    }
+    (add, remove)
+}
+
+impl infrarust_api::services::tab_list::private::Sealed for TabListManager {}
+
+impl TabList for TabListManager {
+    fn set_header_footer(&self, player: PlayerId, header: &str, footer: &str) {
+        self.player_templates
+            .insert(player, (header.to_string(), footer.to_string()));
+        self.changed.notify_one();
+    }
+
+    fn clear_header_footer(&self, player: PlayerId) {
+        if self.player_templates.remove(&player).is_some() {
+            self.changed.notify_one();
+        }
+    }
+
+    fn set_default_header_footer(&self, header: &str, footer: &str) {
+        *self.default_template.write().expect("lock poisoned") =
+            Some((header.to_string(), footer.to_string()));
+        self.changed.notify_one();
+    }
+
+    fn add_entry(&self, entry: TabListEntry) {
+        let revision = self.next_revision.fetch_add(1, Ordering::Relaxed);
+        self.entries.insert(entry.uuid, (revision, entry));
+        self.changed.notify_one();
+    }
+
+    fn remove_entry(&self, uuid: &Uuid) -> bool {
+        let removed = self.entries.remove(uuid).is_some();
+        if removed {
+            self.changed.notify_one();
+        }
+        removed
+    }
+
+    fn entries(&self) -> Vec<TabListEntry> {
+        self.entries
+            .iter()
+            .map(|entry| entry.value().1.clone())
+            .collect()
+    }
+
+    fn refresh(&self) {
+        self.changed.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn desired(entries: &[(Uuid, u64)]) -> HashMap<Uuid, (u64, TabListEntry)> {
+        entries
+            .iter()
+            .map(|(uuid, rev)| (*uuid, (*rev, TabListEntry::new(*uuid, "Steve"))))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_adds_new_and_changed_entries() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut shown = HashMap::from([(a, 1)]);
+
+        let (add, remove) = diff_entries(&mut shown, &desired(&[(a, 1), (b, 0)]), |_| false);
+        assert_eq!(add.len(), 1);
+        assert_eq!(add[0].uuid, b);
+        assert!(remove.is_empty());
+
+        // Replaced entry: new revision is sent again
+        let (add, _) = diff_entries(&mut shown, &desired(&[(a, 2), (b, 0)]), |_| false);
+        assert_eq!(add.len(), 1);
+        assert_eq!(add[0].uuid, a);
+        assert_eq!(shown[&a], 2);
+    }
+
+    #[test]
+    fn test_diff_leaves_backend_entries_alone() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut shown = HashMap::from([(a, 0), (b, 0)]);
+
+        // `b` joined the viewer's server: forgotten, but not removed from the client
+        let (add, remove) = diff_entries(&mut shown, &HashMap::new(), |uuid| *uuid == b);
+        assert!(add.is_empty());
+        assert_eq!(remove, vec![a]);
+        assert!(shown.is_empty());
+    }
+
+    #[test]
+    fn test_template_priority() {
+        let manager = TabListManager::new(
+            Some(TabListConfig {
+                header: "&6Config".to_string(),
+                ..TabListConfig::default()
+            }),
+            Arc::new(ConnectionRegistry::new()),
+            Arc::new(DomainRouter::new()),
+        );
+        let player = PlayerId::new(1);
+        let header = |m: &TabListManager| m.template_for(player).unwrap().0;
+
+        assert_eq!(header(&manager), "&6Config");
+        manager.set_default_header_footer("Default", "");
+        assert_eq!(header(&manager), "Default");
+        manager.set_header_footer(player, "Own", "");
+        assert_eq!(header(&manager), "Own");
+        manager.clear_header_footer(player);
+        assert_eq!(header(&manager), "Default");
+
+        let unconfigured = TabListManager::new(
+            Some(TabListConfig::default()),
+            Arc::new(ConnectionRegistry::new()),
+            Arc::new(DomainRouter::new()),
+        );
+        assert!(unconfigured.template_for(player).is_none());
+    }
+}
@@ -1,10 +1,13 @@
 //! Chat/command detection helpers extracted from the proxy loop.
 //!
-//! Determines whether a serverbound packet is a chat message or slash command.
+//! Determines whether a serverbound packet is a chat message or slash command,
+//! and rebuilds it when a listener consumes or rewrites it.
 
 use infrarust_protocol::io::PacketFrame;
 use infrarust_protocol::packets::Packet;
-use infrarust_protocol::packets::play::chat::{SChatCommand, SChatMessage};
+use infrarust_protocol::packets::play::chat::{
+    SChatAcknowledgement, SChatCommand, SChatMessage, SSignedChatCommand,
+};
 use infrarust_protocol::registry::PacketRegistry;
 use infrarust_protocol::version::{ConnectionState, Direction, ProtocolVersion};
 
@@ -16,46 +19,301 @@ pub(crate) enum ChatAction {
     Message(String),
 }
 
+/// A decoded serverbound chat or command packet.
+enum ChatPacket {
+    Message(SChatMessage),
+    Command(SChatCommand),
+    SignedCommand(SSignedChatCommand),
+}
+
+impl ChatPacket {
+    fn decode(
+        frame: &PacketFrame,
+        registry: &PacketRegistry,
+        version: ProtocolVersion,
+    ) -> Option<Self> {
+        let id = |packet_id: Option<i32>| packet_id == Some(frame.id);
+        let mut data = frame.payload.as_ref();
+        if id(registry.get_packet_id::<SChatCommand>(
+            ConnectionState::Play,
+            Direction::Serverbound,
+            version,
+        )) {
+            SChatCommand::decode(&mut data, version)
+                .ok()
+                .map(Self::Command)
+        } else if id(registry.get_packet_id::<SSignedChatCommand>(
+            ConnectionState::Play,
+            Direction::Serverbound,
+            version,
+        )) {
+            SSignedChatCommand::decode(&mut data, version)
+                .ok()
+                .map(Self::SignedCommand)
+        } else if id(registry.get_packet_id::<SChatMessage>(
+            ConnectionState::Play,
+            Direction::Serverbound,
+            version,
+        )) {
+            SChatMessage::decode(&mut data, version)
+                .ok()
+                .map(Self::Message)
+        } else {
+            None
+        }
+    }
+
+    fn action(self) -> ChatAction {
+        match self {
+            Self::Command(packet) => ChatAction::Command(packet.command),
+            Self::SignedCommand(packet) => ChatAction::Command(packet.command),
+            // Pre-1.19 style: commands sent as chat messages with leading /
+            Self::Message(packet) => match packet.message.strip_prefix('/') {
+                Some(command) => ChatAction::Command(command.to_string()),
+                None => ChatAction::Message(packet.message),
+            },
+        }
+    }
+
+    fn last_seen_offset(&self, version: ProtocolVersion) -> Option<i32> {
+        match self {
+            Self::Message(packet) => packet.last_seen_offset(version),
+            Self::Command(packet) => packet.last_seen_offset(version),
+            Self::SignedCommand(packet) => packet.last_seen_offset(version),
+        }
+    }
+
+    /// Encodes the packet with `text` in place of its message or command,
+    /// without signatures.
+    fn rewrite(&self, text: String, version: ProtocolVersion) -> Option<Vec<u8>> {
+        let mut payload = Vec::new();
+        match self {
+            Self::Message(packet) => {
+                let text = if packet.message.starts_with('/') {
+                    format!("/{text}")
+                } else {
+                    text
+                };
+                packet.rewrite(text, version)?.encode(&mut payload, version)
+            }
+            Self::Command(packet) => packet.rewrite(text, version)?.encode(&mut payload, version),
+            Self::SignedCommand(packet) => {
+                packet.rewrite(text, version)?.encode(&mut payload, version)
+            }
+        }
+        .ok()?;
+        Some(payload)
+    }
+}
+
 /// Detects if a frame is a chat message or slash command.
 ///
 /// Returns `Some(ChatAction)` if the frame matches a serverbound chat
-/// packet (`SChatMessage` or `SChatCommand`), `None` otherwise.
+/// packet (`SChatMessage`, `SChatCommand` or `SSignedChatCommand`),
+/// `None` otherwise.
 pub(crate) fn detect_chat_or_command(
     frame: &PacketFrame,
     registry: &PacketRegistry,
     version: ProtocolVersion,
 ) -> Option<ChatAction> {
-    // Check if it's a SChatCommand packet (1.19+)
-    let chat_cmd_id = registry.get_packet_id::<SChatCommand>(
+    ChatPacket::decode(frame, registry, version).map(ChatPacket::action)
+}
+
+/// Builds the acknowledgement to send to the backend in place of a chat
+/// message or command the proxy consumed.
+///
+/// From 1.19.3, each chat message and signed command acknowledges the
+/// messages the client has seen since its last one. If the backend never
+/// receives it, its count drifts and it disconnects the client with "chat
+/// message validation failure". Returns `None` when there is nothing to
+/// acknowledge.
+pub(crate) fn consumed_chat_acknowledgement(
+    frame: &PacketFrame,
+    registry: &PacketRegistry,
+    version: ProtocolVersion,
+) -> Option<PacketFrame> {
+    let offset = ChatPacket::decode(frame, registry, version)?
+        .last_seen_offset(version)
+        .filter(|o| *o > 0)?;
+    let id = registry.get_packet_id::<SChatAcknowledgement>(
         ConnectionState::Play,
         Direction::Serverbound,
         version,
-    );
-    if Some(frame.id) == chat_cmd_id {
-        // Decode just the command string
-        let mut data = frame.payload.as_ref();
-        if let Ok(decoded) = SChatCommand::decode(&mut data, version) {
-            return Some(ChatAction::Command(decoded.command));
+    )?;
+    let mut payload = Vec::new();
+    SChatAcknowledgement { offset }
+        .encode(&mut payload, version)
+        .ok()?;
+    Some(PacketFrame {
+        id,
+        payload: payload.into(),
+    })
+}
+
+/// Returns `true` if the frame is a signed chat message or command from a
+/// 1.19.1 or 1.19.2 client.
+///
+/// These clients chain each message and argument signature to the previous
+/// one, so the backend has to receive everything they sign as sent: the
+/// proxy can't consume or rewrite it.
+pub(crate) fn is_chained_chat(
+    frame: &PacketFrame,
+    registry: &PacketRegistry,
+    version: ProtocolVersion,
+) -> bool {
+    if version.less_than(ProtocolVersion::V1_19_1) || version.no_less_than(ProtocolVersion::V1_19_3)
+    {
+        return false;
+    }
+    match ChatPacket::decode(frame, registry, version) {
+        Some(ChatPacket::Message(packet)) => packet.is_signed(version),
+        Some(ChatPacket::Command(packet)) => packet.is_signed(version),
+        _ => false,
+    }
+}
+
+/// Rebuilds a chat message or command frame with new text.
+///
+/// Signatures are dropped since they no longer match; backends behind the
+/// proxy accept unsigned chat as long as they don't enforce secure profiles.
+/// Acknowledgements are kept. Returns `None` if the frame can't be rebuilt.
+pub(crate) fn rewrite_chat_frame(
+    frame: &PacketFrame,
+    registry: &PacketRegistry,
+    version: ProtocolVersion,
+    text: String,
+) -> Option<PacketFrame> {
+    let payload = ChatPacket::decode(frame, registry, version)?.rewrite(text, version)?;
+    Some(PacketFrame {
+        id: frame.id,
+        payload: payload.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use infrarust_protocol::codec::{McBufWriteExt, VarInt};
+    use infrarust_protocol::registry::build_default_registry;
+
+    use super::*;
+
+    #[test]
+    fn signed_command_is_detected_acknowledged_and_rewritten() {
+        let registry = build_default_registry();
+        let version = ProtocolVersion::V1_21;
+
+        let mut remaining = vec![7; 16];
+        remaining.write_var_int(&VarInt(1)).unwrap();
+        remaining.write_string("message").unwrap();
+        remaining.extend_from_slice(&[0xAB; 256]);
+        remaining.write_var_int(&VarInt(2)).unwrap();
+        remaining.extend_from_slice(&[0, 0, 0]);
+        let mut payload = Vec::new();
+        SSignedChatCommand {
+            command: "msg bob darn".to_string(),
+            remaining,
+        }
+        .encode(&mut payload, version)
+        .unwrap();
+        let frame = PacketFrame {
+            id: registry
+                .get_packet_id::<SSignedChatCommand>(
+                    ConnectionState::Play,
+                    Direction::Serverbound,
+                    version,
+                )
+                .unwrap(),
+            payload: payload.into(),
+        };
+
+        match detect_chat_or_command(&frame, &registry, version) {
+            Some(ChatAction::Command(command)) => assert_eq!(command, "msg bob darn"),
+            _ => panic!("expected a command"),
         }
+
+        let ack = consumed_chat_acknowledgement(&frame, &registry, version).unwrap();
+        let ack = SChatAcknowledgement::decode(&mut ack.payload.as_ref(), version).unwrap();
+        assert_eq!(ack.offset, 2);
+
+        let rewritten =
+            rewrite_chat_frame(&frame, &registry, version, "msg bob d**n".to_string()).unwrap();
+        assert_eq!(rewritten.id, frame.id);
+        match detect_chat_or_command(&rewritten, &registry, version) {
+            Some(ChatAction::Command(command)) => assert_eq!(command, "msg bob d**n"),
+            _ => panic!("expected a command"),
+        }
+        // The rewritten command still acknowledges the same messages
+        assert!(consumed_chat_acknowledgement(&rewritten, &registry, version).is_some());
     }
 
-    // Check if it's a SChatMessage packet
-    let chat_msg_id = registry.get_packet_id::<SChatMessage>(
-        ConnectionState::Play,
-        Direction::Serverbound,
-        version,
-    );
-    if Some(frame.id) == chat_msg_id {
-        // Decode just the message string
-        let mut data = frame.payload.as_ref();
-        if let Ok(decoded) = SChatMessage::decode(&mut data, version) {
-            if decoded.message.starts_with('/') {
-                // Pre-1.19 style: commands sent as chat messages with leading /
-                return Some(ChatAction::Command(decoded.message[1..].to_string()));
+    #[test]
+    fn signed_1_19_1_message_is_chained() {
+        let registry = build_default_registry();
+        let version = ProtocolVersion::V1_19_1;
+        let id = registry
+            .get_packet_id::<SChatMessage>(ConnectionState::Play, Direction::Serverbound, version)
+            .unwrap();
+        let frame = |signature: &[u8]| {
+            let mut remaining = vec![7; 16];
+            remaining
+                .write_var_int(&VarInt(signature.len() as i32))
+                .unwrap();
+            remaining.extend_from_slice(signature);
+            remaining.extend_from_slice(&[0, 0, 0]);
+            let mut payload = Vec::new();
+            SChatMessage {
+                message: "hi".to_string(),
+                remaining,
             }
-            return Some(ChatAction::Message(decoded.message));
-        }
+            .encode(&mut payload, version)
+            .unwrap();
+            PacketFrame {
+                id,
+                payload: payload.into(),
+            }
+        };
+
+        assert!(is_chained_chat(&frame(&[0xAB; 256]), &registry, version));
+        assert!(!is_chained_chat(&frame(&[]), &registry, version));
+        assert!(consumed_chat_acknowledgement(&frame(&[0xAB; 256]), &registry, version).is_none());
     }
 
-    None
+    #[test]
+    fn signed_1_19_1_command_is_chained() {
+        let registry = build_default_registry();
+        let version = ProtocolVersion::V1_19_1;
+        let id = registry
+            .get_packet_id::<SChatCommand>(ConnectionState::Play, Direction::Serverbound, version)
+            .unwrap();
+        let frame = |signatures: i32| {
+            let mut remaining = vec![7; 16];
+            remaining.write_var_int(&VarInt(signatures)).unwrap();
+            for _ in 0..signatures {
+                remaining.write_string("message").unwrap();
+                remaining.write_var_int(&VarInt(256)).unwrap();
+                remaining.extend_from_slice(&[0xAB; 256]);
+            }
+            remaining.extend_from_slice(&[0, 0, 0]);
+            let mut payload = Vec::new();
+            SChatCommand {
+                command: "msg bob hi".to_string(),
+                remaining,
+            }
+            .encode(&mut payload, version)
+            .unwrap();
+            PacketFrame {
+                id,
+                payload: payload.into(),
+            }
+        };
+
+        assert!(is_chained_chat(&frame(1), &registry, version));
+        assert!(!is_chained_chat(&frame(0), &registry, version));
+        assert!(!is_chained_chat(
+            &frame(1),
+            &registry,
+            ProtocolVersion::V1_19_3
+        ));
+    }
 }
@@ -0,0 +1,198 @@
+//! Client settings and brand, read from serverbound packets.
+//!
+//! Both are sent by the client in the configuration state (1.20.2+) or once
+//! in play (older versions), and settings again whenever the player changes
+//! an option. The packets are always forwarded to the backend.
+
+use infrarust_api::events::player::{PlayerClientBrandEvent, PlayerSettingsChangedEvent};
+use infrarust_api::player::Player;
+use infrarust_protocol::io::PacketFrame;
+use infrarust_protocol::packets::Packet;
+use infrarust_protocol::packets::config::{SConfigClientInformation, SConfigPluginMessage};
+use infrarust_protocol::packets::play::client_information::{
+    ClientInformation, SClientInformation,
+};
+use infrarust_protocol::packets::play::plugin_message::{SPluginMessage, read_brand};
+use infrarust_protocol::registry::PacketRegistry;
+use infrarust_protocol::version::{ConnectionState, Direction, ProtocolVersion};
+
+use crate::event_bus::conversion::client_settings_to_api;
+use crate::player::PlayerSession;
+use crate::services::ProxyServices;
+
+/// What a client frame told about the client.
+#[derive(Debug, PartialEq)]
+enum ClientInfo {
+    Settings(ClientInformation),
+    Brand(String),
+}
+
+/// Records the settings or brand carried by `frame` on `player`, firing
+/// `PlayerSettingsChangedEvent` / `PlayerClientBrandEvent` when they change.
+pub(crate) fn observe_client_frame(
+    frame: &PacketFrame,
+    state: ConnectionState,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+    player: &PlayerSession,
+    services: &ProxyServices,
+) {
+    match read_client_info(frame, state, version, registry) {
+        Some(ClientInfo::Settings(info)) => {
+            let settings = client_settings_to_api(&info);
+            if player.set_settings(settings.clone()) {
+                services
+                    .event_bus
+                    .fire_and_forget_arc(PlayerSettingsChangedEvent {
+                        player_id: player.id(),
+                        settings,
+                    });
+            }
+        }
+        Some(ClientInfo::Brand(brand)) if player.set_client_brand(brand.clone()) => {
+            tracing::debug!(player = %player.id(), brand = %brand, "client brand");
+            services
+                .event_bus
+                .fire_and_forget_arc(PlayerClientBrandEvent {
+                    player_id: player.id(),
+                    brand,
+                });
+        }
+        Some(ClientInfo::Brand(_)) | None => {}
+    }
+}
+
+fn read_client_info(
+    frame: &PacketFrame,
+    state: ConnectionState,
+    version: ProtocolVersion,
+    registry: &PacketRegistry,
+) -> Option<ClientInfo> {
+    let is = |id: Option<i32>| id == Some(frame.id);
+    let mut payload = frame.payload.as_ref();
+    match state {
+        ConnectionState::Play => {
+            if is(packet_id::<SClientInformation>(registry, state, version)) {
+                let packet = SClientInformation::decode(&mut payload, version).ok()?;
+                Some(ClientInfo::Settings(packet.info))
+            } else if version.no_less_than(ProtocolVersion::V1_8)
+                && is(packet_id::<SPluginMessage>(registry, state, version))
+            {
+                let packet = SPluginMessage::decode(&mut payload, version).ok()?;
+                read_brand(&packet.channel, &packet.data).map(ClientInfo::Brand)
+            } else {
+                None
+            }
+        }
+        ConnectionState::Config => {
+            if is(packet_id::<SConfigClientInformation>(
+                registry, state, version,
+            )) {
+                let packet = SConfigClientInformation::decode(&mut payload, version).ok()?;
+                Some(ClientInfo::Settings(packet.info))
+            } else if is(packet_id::<SConfigPluginMessage>(registry, state, version)) {
+                let packet = SConfigPluginMessage::decode(&mut payload, version).ok()?;
+                read_brand(&packet.channel, &packet.data).map(ClientInfo::Brand)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn packet_id<P: Packet + 'static>(
+    registry: &PacketRegistry,
+    state: ConnectionState,
+    version: ProtocolVersion,
+) -> Option<i32> {
+    registry.get_packet_id::<P>(state, Direction::Serverbound, version)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use infrarust_protocol::registry::build_default_registry;
+
+    use super::*;
+
+    fn frame<P: Packet + 'static>(
+        registry: &PacketRegistry,
+        packet: &P,
+        state: ConnectionState,
+        version: ProtocolVersion,
+    ) -> PacketFrame {
+        let mut payload = Vec::new();
+        packet.encode(&mut payload, version).unwrap();
+        PacketFrame {
+            id: packet_id::<P>(registry, state, version).unwrap(),
+            payload: payload.into(),
+        }
+    }
+
+    #[test]
+    fn reads_settings_and_brand_in_both_states() {
+        let registry = build_default_registry();
+        let info = ClientInformation {
+            locale: "de_de".to_string(),
+            view_distance: 8,
+            chat_mode: 0,
+            chat_colors: true,
+            skin_parts: 0x7F,
+            main_hand: 1,
+            text_filtering: false,
+            allow_server_listings: true,
+            particle_status: 0,
+        };
+
+        let version = ProtocolVersion::V1_21;
+        let config = frame(
+            &registry,
+            &SConfigClientInformation { info: info.clone() },
+            ConnectionState::Config,
+            version,
+        );
+        assert_eq!(
+            read_client_info(&config, ConnectionState::Config, version, &registry),
+            Some(ClientInfo::Settings(info.clone()))
+        );
+        let brand = frame(
+            &registry,
+            &SConfigPluginMessage {
+                channel: "minecraft:brand".to_string(),
+                data: vec![0x07, b'v', b'a', b'n', b'i', b'l', b'l', b'a'],
+            },
+            ConnectionState::Config,
+            version,
+        );
+        assert_eq!(
+            read_client_info(&brand, ConnectionState::Config, version, &registry),
+            Some(ClientInfo::Brand("vanilla".to_string()))
+        );
+
+        let legacy = ProtocolVersion::V1_12_2;
+        let play = frame(
+            &registry,
+            &SClientInformation { info: info.clone() },
+            ConnectionState::Play,
+            legacy,
+        );
+        assert_eq!(
+            read_client_info(&play, ConnectionState::Play, legacy, &registry),
+            Some(ClientInfo::Settings(info))
+        );
+        let legacy_brand = frame(
+            &registry,
+            &SPluginMessage {
+                channel: "MC|Brand".to_string(),
+                data: vec![0x05, b'f', b'o', b'r', b'g', b'e'],
+            },
+            ConnectionState::Play,
+            legacy,
+        );
+        assert_eq!(
+            read_client_info(&legacy_brand, ConnectionState::Play, legacy, &registry),
+            Some(ClientInfo::Brand("forge".to_string()))
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Player latency, measured from keepalives.
+//!
+//! In the proxy loop, backend keepalives are timed from the moment they are
+//! relayed to the client until the client answers them. Limbo sends its own
+//! keepalives and times them in [`KeepAliveState`](crate::limbo::keepalive).
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use infrarust_protocol::io::PacketFrame;
+use infrarust_protocol::packets::Packet;
+use infrarust_protocol::packets::play::keepalive::{CKeepAlive, SKeepAlive};
+use infrarust_protocol::registry::PacketRegistry;
+use infrarust_protocol::version::{ConnectionState, Direction, ProtocolVersion};
+
+use crate::player::PlayerSession;
+use crate::services::ProxyServices;
+
+/// Keepalives kept waiting for an answer; older ones are forgotten.
+const MAX_PENDING: usize = 8;
+
+/// Times backend keepalives relayed to one client.
+#[derive(Debug, Default)]
+pub(crate) struct KeepAliveTimer {
+    pending: VecDeque<(i64, Instant)>,
+}
+
+impl KeepAliveTimer {
+    /// Starts timing `frame` if it is a backend keepalive.
+    pub(crate) fn on_backend_frame(
+        &mut self,
+        frame: &PacketFrame,
+        state: ConnectionState,
+        version: ProtocolVersion,
+        registry: &PacketRegistry,
+    ) {
+        if state != ConnectionState::Play
+            || registry.get_packet_id::<CKeepAlive>(state, Direction::Clientbound, version)
+                != Some(frame.id)
+        {
+            return;
+        }
+        if let Ok(keepalive) = CKeepAlive::decode(&mut frame.payload.as_ref(), version) {
+            if self.pending.len() == MAX_PENDING {
+                self.pending.pop_front();
+            }
+            self.pending.push_back((keepalive.id, Instant::now()));
+        }
+    }
+
+    /// Returns the round-trip time if `frame` answers a timed keepalive.
+    pub(crate) fn on_client_frame(
+        &mut self,
+        frame: &PacketFrame,
+        state: ConnectionState,
+        version: ProtocolVersion,
+        registry: &PacketRegistry,
+    ) -> Option<Duration> {
+        if state != ConnectionState::Play
+            || registry.get_packet_id::<SKeepAlive>(state, Direction::Serverbound, version)
+                != Some(frame.id)
+        {
+            return None;
+        }
+        let id = SKeepAlive::decode(&mut frame.payload.as_ref(), version)
+            .ok()?
+            .id;
+        let index = self.pending.iter().position(|(sent, _)| *sent == id)?;
+        // Keepalives sent before this one will not be answered anymore
+        let (_, sent_at) = self.pending.drain(..=index).next_back()?;
+        Some(sent_at.elapsed())
+    }
+}
+
+/// Records a latency sample on the player and in the latency histogram.
+pub(crate) fn record_latency(player: &PlayerSession, services: &ProxyServices, rtt: Duration) {
+    player.record_ping(rtt);
+
+    #[cfg(feature = "telemetry")]
+    if let Some(metrics) = &services.metrics {
+        use infrarust_api::player::Player;
+
+        let server = player.current_server();
+        metrics.record_player_latency(
+            rtt.as_secs_f64(),
+            server.as_ref().map_or("-", |s| s.as_str()),
+        );
+    }
+    #[cfg(not(feature = "telemetry"))]
+    let _ = services;
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use infrarust_protocol::registry::build_default_registry;
+
+    use super::*;
+
+    fn frame<P: Packet + 'static>(
+        registry: &PacketRegistry,
+        packet: &P,
+        direction: Direction,
+        version: ProtocolVersion,
+    ) -> PacketFrame {
+        let mut payload = Vec::new();
+        packet.encode(&mut payload, version).unwrap();
+        PacketFrame {
+            id: registry
+                .get_packet_id::<P>(ConnectionState::Play, direction, version)
+                .unwrap(),
+            payload: payload.into(),
+        }
+    }
+
+    #[test]
+    fn test_answers_are_matched_by_id() {
+        let registry = build_default_registry();
+        let version = ProtocolVersion::V1_21;
+        let mut timer = KeepAliveTimer::default();
+
+        for id in [1, 2, 3] {
+            let sent = frame(
+                &registry,
+                &CKeepAlive { id },
+                Direction::Clientbound,
+                version,
+            );
+            timer.on_backend_frame(&sent, ConnectionState::Play, version, &registry);
+        }
+
+        let unknown = frame(
+            &registry,
+            &SKeepAlive { id: 9 },
+            Direction::Serverbound,
+            version,
+        );
+        assert!(
+            timer
+                .on_client_frame(&unknown, ConnectionState::Play, version, &registry)
+                .is_none()
+        );
+
+        let answer = frame(
+            &registry,
+            &SKeepAlive { id: 2 },
+            Direction::Serverbound,
+            version,
+        );
+        assert!(
+            timer
+                .on_client_frame(&answer, ConnectionState::Play, version, &registry)
+                .is_some()
+        );
+        // Keepalive 1 was dropped along with 2
+        assert_eq!(timer.pending.len(), 1);
+    }
+}
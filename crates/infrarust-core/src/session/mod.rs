@@ -1,5 +1,8 @@
 pub(crate) mod backend_bridge;
 pub(crate) mod chat_utils;
 pub(crate) mod client_bridge;
+pub(crate) mod client_info;
+pub(crate) mod latency;
 pub(crate) mod proxy_loop;
+pub(crate) mod resource_packs;
 pub(crate) mod server_switch;
@@ -9,13 +9,16 @@
 
 use infrarust_api::event::ResultedEvent;
 use infrarust_api::event::bus::EventBus;
+use infrarust_api::events::player::ResourcePackStatusEvent;
 use infrarust_api::services::player_registry::PlayerRegistry;
 use infrarust_api::types::{PlayerId, RawPacket, ServerId};
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
 use infrarust_protocol::io::PacketFrame;
-use infrarust_protocol::packets::config::{CFinishConfig, SAcknowledgeFinishConfig};
+use infrarust_protocol::packets::config::{
+    CConfigResourcePackPop, CConfigResourcePackPush, CFinishConfig, SAcknowledgeFinishConfig,
+};
 use infrarust_protocol::packets::login::{
     CLoginDisconnect, CLoginSuccess, CSetCompression, SLoginAcknowledged,
 };
@@ -26,15 +29,18 @@ use infrarust_protocol::packets::play::tab_complete::{
     CTabCompleteResponse, STabCompleteRequest, TabCompleteMatch,
 };
 use infrarust_protocol::registry::{DecodedPacket, PacketRegistry};
-use infrarust_protocol::version::{ConnectionState, Direction};
+use infrarust_protocol::version::{ConnectionState, Direction, ProtocolVersion};
 
 use crate::error::CoreError;
 use crate::event_bus::conversion::{protocol_direction_to_api, protocol_state_to_api};
 use crate::filter::codec_chain::{CodecFilterChain, FilterResult};
-use crate::player::PlayerCommand;
+use crate::player::{PlayerCommand, PlayerSession};
 use crate::services::ProxyServices;
 use crate::session::backend_bridge::BackendBridge;
 use crate::session::client_bridge::ClientBridge;
+use crate::session::client_info::observe_client_frame;
+use crate::session::latency::{KeepAliveTimer, record_latency};
+use crate::session::resource_packs::ResourcePackTracker;
 
 /// Result of the proxy loop, determining what happens after the loop ends.
 #[derive(Debug)]
@@ -44,7 +50,11 @@ pub enum ProxyLoopOutcome {
     ClientDisconnected,
     /// Backend closed its connection.
     /// In Phase 2A: cleanup. In Phase 4+: server switch / limbo.
-    BackendDisconnected { reason: Option<String> },
+    BackendDisconnected {
+        reason: Option<String>,
+        /// Disconnect packet sent by the backend, not yet relayed to the client.
+        kick: Option<PacketFrame>,
+    },
     /// Global proxy shutdown.
     Shutdown,
     /// I/O or protocol error.
@@ -59,11 +69,14 @@ pub enum ProxyLoopOutcome {
 enum BackendAction {
     /// Continue the loop normally.
     Continue,
-    /// Backend sent a disconnect packet.
-    Disconnected(Option<String>),
+    /// Backend sent a disconnect packet, with the frame if it was not relayed.
+    Disconnected(Option<String>, Option<PacketFrame>),
 }
 
-use super::chat_utils::{ChatAction, detect_chat_or_command};
+use super::chat_utils::{
+    ChatAction, consumed_chat_acknowledgement, detect_chat_or_command, is_chained_chat,
+    rewrite_chat_frame,
+};
 
 #[inline]
 fn frame_to_raw(frame: &PacketFrame) -> RawPacket {
@@ -101,12 +114,35 @@ pub async fn proxy_loop(
     player_id: PlayerId,
     client_codec_chain: &mut CodecFilterChain,
     server_codec_chain: &mut CodecFilterChain,
+    resource_packs: &mut ResourcePackTracker,
+    player: Option<&PlayerSession>,
 ) -> ProxyLoopOutcome {
+    // After a switch both sides are already in Play; a fresh login gets the
+    // proxy packs once the backend's first Play packet reached the client.
+    let mut packs_synced = backend.state == ConnectionState::Play;
+    if packs_synced {
+        sync_resource_packs(client, resource_packs, registry).await;
+    }
+
+    let mut keepalives = KeepAliveTimer::default();
+
     let outcome = loop {
         tokio::select! {
             frame = client.read_frame() => {
                 match frame {
                     Ok(Some(frame)) => {
+                        if let Some(player) = player {
+                            observe_client_frame(&frame, client.state(), client.protocol_version, registry, player, services);
+                            if let Some(rtt) = keepalives.on_client_frame(&frame, client.state(), client.protocol_version, registry) {
+                                record_latency(player, services, rtt);
+                            }
+                        }
+                        match handle_resource_pack_status(client, &frame, resource_packs, registry, services, player_id).await {
+                            Ok(PackStatusAction::Forward) => {}
+                            Ok(PackStatusAction::Consumed) => continue,
+                            Ok(PackStatusAction::Kicked) => break ProxyLoopOutcome::ClientDisconnected,
+                            Err(e) => break ProxyLoopOutcome::Error(e),
+                        }
                         if let Err(e) = handle_client_to_backend(client, backend, frame, registry, services, player_id, client_codec_chain).await {
 
                             break ProxyLoopOutcome::Error(e);
@@ -119,15 +155,23 @@ pub async fn proxy_loop(
             frame = backend.read_frame() => {
                 match frame {
                     Ok(Some(frame)) => {
+                        let in_play = backend.state == ConnectionState::Play;
+                        resource_packs.observe_backend(&frame, backend.state, client.protocol_version, registry);
+                        keepalives.on_backend_frame(&frame, backend.state, client.protocol_version, registry);
                         match handle_backend_to_client(client, backend, frame, registry, services, player_id, server_codec_chain).await {
-                            Ok(BackendAction::Continue) => {}
-                            Ok(BackendAction::Disconnected(reason)) => {
-                                break ProxyLoopOutcome::BackendDisconnected { reason };
+                            Ok(BackendAction::Continue) => {
+                                if in_play && !packs_synced {
+                                    packs_synced = true;
+                                    sync_resource_packs(client, resource_packs, registry).await;
+                                }
+                            }
+                            Ok(BackendAction::Disconnected(reason, kick)) => {
+                                break ProxyLoopOutcome::BackendDisconnected { reason, kick };
                             }
                             Err(e) => break ProxyLoopOutcome::Error(e),
                         }
                     }
-                    Ok(None) => break ProxyLoopOutcome::BackendDisconnected { reason: None },
+                    Ok(None) => break ProxyLoopOutcome::BackendDisconnected { reason: None, kick: None },
                     Err(e) => break ProxyLoopOutcome::Error(e),
                 }
             }
@@ -156,6 +200,81 @@ pub async fn proxy_loop(
     outcome
 }
 
+/// Pushes the proxy resource packs wanted on the current server.
+async fn sync_resource_packs(
+    client: &mut ClientBridge,
+    resource_packs: &mut ResourcePackTracker,
+    registry: &PacketRegistry,
+) {
+    let frames = match resource_packs.sync_frames(client.protocol_version, registry) {
+        Ok(frames) => frames,
+        Err(e) => {
+            tracing::warn!("failed to build resource pack packets: {e}");
+            return;
+        }
+    };
+    for frame in &frames {
+        if let Err(e) = client.write_frame(frame).await {
+            tracing::debug!("failed to send resource pack: {e}");
+            return;
+        }
+    }
+}
+
+/// What `handle_resource_pack_status` resolved to.
+enum PackStatusAction {
+    /// Not a status for a proxy pack — forward to the backend.
+    Forward,
+    /// Status for a proxy pack — the backend never sent it.
+    Consumed,
+    /// The player refused a required proxy pack and was disconnected.
+    Kicked,
+}
+
+/// Fires `ResourcePackStatusEvent` for resource pack status answers and
+/// enforces required proxy packs.
+async fn handle_resource_pack_status(
+    client: &mut ClientBridge,
+    frame: &PacketFrame,
+    resource_packs: &mut ResourcePackTracker,
+    registry: &PacketRegistry,
+    services: &ProxyServices,
+    player_id: PlayerId,
+) -> Result<PackStatusAction, CoreError> {
+    let version = client.protocol_version;
+    let Some(status) = resource_packs.client_status(frame, client.state(), version, registry)
+    else {
+        return Ok(PackStatusAction::Forward);
+    };
+
+    services
+        .event_bus
+        .fire_and_forget_arc(ResourcePackStatusEvent {
+            player_id,
+            pack_id: status.pack_id,
+            url: status.url.clone(),
+            status: status.status,
+            from_proxy: status.proxy_pack.is_some(),
+        });
+
+    if let Some(reason) = status.kick_reason() {
+        tracing::info!(
+            player = %player_id,
+            url = status.url.as_deref().unwrap_or_default(),
+            "player refused a required resource pack"
+        );
+        let frame = crate::player::packets::build_disconnect(reason, version, registry)?;
+        client.write_frame(&frame).await?;
+        return Ok(PackStatusAction::Kicked);
+    }
+
+    if status.proxy_pack.is_some() {
+        Ok(PackStatusAction::Consumed)
+    } else {
+        Ok(PackStatusAction::Forward)
+    }
+}
+
 /// What `handle_player_command` resolved to.
 enum CommandResult {
     /// Continue the loop normally.
@@ -195,6 +314,53 @@ async fn handle_player_command(
             let frame = raw_to_frame(&raw_packet);
             client.write_frame(&frame).await?;
         }
+        PlayerCommand::SetTabListHeaderFooter(header, footer) => {
+            if let Some(frame) =
+                packets::build_tab_list_header_footer(&header, &footer, version, registry)?
+            {
+                client.write_frame(&frame).await?;
+            }
+        }
+        PlayerCommand::AddTabListEntries(entries) => {
+            if let Some(frame) = packets::build_tab_list_add(&entries, version, registry)? {
+                client.write_frame(&frame).await?;
+            }
+        }
+        PlayerCommand::RemoveTabListEntries(uuids) => {
+            if let Some(frame) = packets::build_tab_list_remove(&uuids, version, registry)? {
+                client.write_frame(&frame).await?;
+            }
+        }
+        PlayerCommand::ShowBossBar(bar) => {
+            if let Some(frame) = packets::build_boss_bar_add(&bar, version, registry)? {
+                client.write_frame(&frame).await?;
+            }
+        }
+        PlayerCommand::UpdateBossBar(bar, change) => {
+            if let Some(frame) = packets::build_boss_bar_update(&bar, change, version, registry)? {
+                client.write_frame(&frame).await?;
+            }
+        }
+        PlayerCommand::HideBossBar(id) => {
+            if let Some(frame) = packets::build_boss_bar_remove(id, version, registry)? {
+                client.write_frame(&frame).await?;
+            }
+        }
+        PlayerCommand::ShowSidebar(sidebar) => {
+            for frame in packets::build_sidebar_show(&sidebar, version, registry)? {
+                client.write_frame(&frame).await?;
+            }
+        }
+        PlayerCommand::UpdateSidebar(old, new) => {
+            for frame in packets::build_sidebar_update(&old, &new, version, registry)? {
+                client.write_frame(&frame).await?;
+            }
+        }
+        PlayerCommand::HideSidebar(sidebar) => {
+            for frame in packets::build_sidebar_hide(&sidebar, version, registry)? {
+                client.write_frame(&frame).await?;
+            }
+        }
         PlayerCommand::Kick(reason) => {
             let frame = packets::build_disconnect(&reason, version, registry)?;
             client.write_frame(&frame).await?;
@@ -285,6 +451,20 @@ async fn apply_codec_filter(
     }
 }
 
+/// Keeps the backend's chat acknowledgements in sync when the proxy consumes
+/// a chat message or command instead of forwarding it.
+async fn send_consumed_chat_ack(
+    backend: &mut BackendBridge,
+    frame: &PacketFrame,
+    registry: &PacketRegistry,
+    version: ProtocolVersion,
+) -> Result<(), CoreError> {
+    if let Some(ack) = consumed_chat_acknowledgement(frame, registry, version) {
+        backend.write_frame(&ack).await?;
+    }
+    Ok(())
+}
+
 /// Handles a packet from the client, forwarding it to the backend.
 ///
 /// Order: CodecFilter → Chat/Command interception → EventBus → forward.
@@ -380,31 +560,91 @@ async fn handle_client_to_backend(
         if let Some(action) = detect_chat_or_command(&frame, registry, version) {
             match action {
                 ChatAction::Command(input) => {
+                    // Signed commands are chained like chat: they are forwarded
+                    // unchanged, and the proxy's own commands don't see them.
+                    let chained = is_chained_chat(&frame, registry, version);
+                    // Fire CommandExecuteEvent
+                    let command_event =
+                        infrarust_api::events::chat::CommandExecuteEvent::new(player_id, input);
+                    let command_event = services.event_bus.fire(command_event).await;
+                    let input = match command_event.result() {
+                        infrarust_api::events::chat::CommandExecuteResult::Allow if chained => None,
+                        _ if chained => {
+                            tracing::warn!(
+                                "signed commands from 1.19.1/1.19.2 clients can't be denied or modified, forwarding it unchanged"
+                            );
+                            None
+                        }
+                        infrarust_api::events::chat::CommandExecuteResult::Deny => {
+                            send_consumed_chat_ack(backend, &frame, registry, version).await?;
+                            return Ok(());
+                        }
+                        infrarust_api::events::chat::CommandExecuteResult::Modify {
+                            new_command,
+                        } => {
+                            let Some(rewritten) =
+                                rewrite_chat_frame(&frame, registry, version, new_command.clone())
+                            else {
+                                tracing::debug!("dropping command that could not be rewritten");
+                                send_consumed_chat_ack(backend, &frame, registry, version).await?;
+                                return Ok(());
+                            };
+                            frame = rewritten;
+                            Some(new_command.clone())
+                        }
+                        _ => Some(command_event.command),
+                    };
+
                     // CommandManager first
-                    let handled = services
-                        .command_manager
-                        .dispatch(Some(player_id), &input, services.player_registry.as_ref())
-                        .await;
-                    if handled {
-                        return Ok(()); // Command consumed, don't forward
+                    if let Some(input) = input {
+                        let handled = services
+                            .command_manager
+                            .dispatch(Some(player_id), &input, services.player_registry.as_ref())
+                            .await;
+                        if handled {
+                            // Command consumed, don't forward
+                            send_consumed_chat_ack(backend, &frame, registry, version).await?;
+                            return Ok(());
+                        }
                     }
                     // Unknown command → forward normally to backend
                 }
                 ChatAction::Message(text) => {
+                    // 1.19.1/1.19.2 chain each signature to the previous one,
+                    // so the backend must receive every signed message as sent.
+                    let chained = is_chained_chat(&frame, registry, version);
                     // Fire ChatMessageEvent
                     let chat_event =
                         infrarust_api::events::chat::ChatMessageEvent::new(player_id, text);
                     let chat_event = services.event_bus.fire(chat_event).await;
                     match chat_event.result() {
+                        infrarust_api::events::chat::ChatMessageResult::Deny { .. }
+                        | infrarust_api::events::chat::ChatMessageResult::Modify { .. }
+                            if chained =>
+                        {
+                            tracing::warn!(
+                                "signed chat from 1.19.1/1.19.2 clients can't be denied or modified, forwarding it unchanged"
+                            );
+                        }
                         infrarust_api::events::chat::ChatMessageResult::Deny { .. } => {
-                            return Ok(()); // Don't forward
+                            send_consumed_chat_ack(backend, &frame, registry, version).await?;
+                            return Ok(());
                         }
                         infrarust_api::events::chat::ChatMessageResult::Allow => {
                             // Forward normally below
                         }
-                        infrarust_api::events::chat::ChatMessageResult::Modify { .. } => {
-                            // Modifying signed messages is not possible (1.19+)
-                            // Forward the original for now
+                        infrarust_api::events::chat::ChatMessageResult::Modify { new_message } => {
+                            // Signatures can't cover the new text, so it is sent unsigned
+                            let Some(rewritten) =
+                                rewrite_chat_frame(&frame, registry, version, new_message.clone())
+                            else {
+                                tracing::debug!(
+                                    "dropping chat message that could not be rewritten"
+                                );
+                                send_consumed_chat_ack(backend, &frame, registry, version).await?;
+                                return Ok(());
+                            };
+                            frame = rewritten;
                         }
                         _ => {} // non-exhaustive
                     }
@@ -548,12 +788,12 @@ async fn handle_backend_to_client(
         match registry.decode_frame(&frame, state, Direction::Clientbound, version) {
             Ok(DecodedPacket::Typed { id, packet }) => {
                 if let Some(disc) = packet.as_any().downcast_ref::<CDisconnect>() {
-                    client.write_frame(&frame).await?;
+                    // Held back so the session can move the player elsewhere instead.
                     let reason = disc
                         .as_json()
                         .map(|s| s.to_string())
                         .unwrap_or_else(|| String::from_utf8_lossy(&disc.reason).to_string());
-                    return Ok(BackendAction::Disconnected(Some(reason)));
+                    return Ok(BackendAction::Disconnected(Some(reason), Some(frame)));
                 }
                 if let Some(commands) = packet.as_any().downcast_ref::<CCommands>() {
                     if services.config.announce_proxy_commands {
@@ -638,15 +878,19 @@ async fn handle_backend_to_client(
             // LoginDisconnect
             if let Some(disconnect) = packet.as_any().downcast_ref::<CLoginDisconnect>() {
                 client.write_frame(&frame).await?;
-                return Ok(BackendAction::Disconnected(Some(disconnect.reason.clone())));
+                return Ok(BackendAction::Disconnected(
+                    Some(disconnect.reason.clone()),
+                    None,
+                ));
             }
 
             // Play Disconnect (should not occur in Login/Config, but handle defensively)
             if packet.as_any().downcast_ref::<CDisconnect>().is_some() {
                 client.write_frame(&frame).await?;
-                return Ok(BackendAction::Disconnected(Some(
-                    "backend disconnect".to_string(),
-                )));
+                return Ok(BackendAction::Disconnected(
+                    Some("backend disconnect".to_string()),
+                    None,
+                ));
             }
 
             // FinishConfig — forward, state transition happens when client ACKs
@@ -667,11 +911,15 @@ async fn handle_backend_to_client(
                     )
                     .is_some_and(|id| id == frame.id);
 
+                // Resource packs are per player, not registry data.
+                let is_resource_pack = packet.as_any().is::<CConfigResourcePackPush>()
+                    || packet.as_any().is::<CConfigResourcePackPop>();
+
                 if is_known_packs {
                     services
                         .registry_codec_cache
                         .collect_known_packs_frame(version, frame.clone());
-                } else {
+                } else if !is_resource_pack {
                     services
                         .registry_codec_cache
                         .collect_registry_frame(version, frame.clone());
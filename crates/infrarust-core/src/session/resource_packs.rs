@@ -0,0 +1,438 @@
+//! Resource packs pushed by the proxy.
+//!
+//! Packs come from the global `resource_packs` setting followed by those of
+//! the player's current server. One tracker lives for the whole client
+//! connection, so a pack the client already holds is not pushed again when
+//! the player switches to another server using it.
+//!
+//! Before 1.20.3 a client holds a single server pack: only the last wanted
+//! pack is pushed, and status answers carry no pack id, so an answer belongs
+//! to the proxy while its own pack is still pending.
+
+use std::collections::HashMap;
+
+use infrarust_api::events::player::ResourcePackStatus;
+use infrarust_api::types::Component;
+use infrarust_config::{ProxyConfig, ResourcePackConfig, ServerConfig};
+use infrarust_protocol::io::PacketFrame;
+use infrarust_protocol::packets::Packet;
+use infrarust_protocol::packets::config::{
+    CConfigResourcePackPop, CConfigResourcePackPush, SConfigResourcePackStatus,
+};
+use infrarust_protocol::packets::play::resource_pack::{
+    CResourcePackPop, CResourcePackPush, ResourcePackResponse, ResourcePackResult,
+    SResourcePackStatus,
+};
+use infrarust_protocol::registry::PacketRegistry;
+use infrarust_protocol::version::{ConnectionState, Direction, ProtocolVersion};
+
+use crate::error::CoreError;
+use crate::player::packets;
+
+/// A resource pack from the proxy configuration.
+#[derive(Debug, Clone)]
+pub(crate) struct ProxyResourcePack {
+    /// Derived from the URL, so a pack keeps its id on every server.
+    pub id: uuid::Uuid,
+    pub url: String,
+    pub hash: String,
+    pub prompt: Option<Component>,
+    pub required: bool,
+    pub kick_message: Component,
+}
+
+impl ProxyResourcePack {
+    pub(crate) fn from_config(config: &ResourcePackConfig) -> Self {
+        Self {
+            id: uuid::Uuid::new_v3(&uuid::Uuid::NAMESPACE_URL, config.url.as_bytes()),
+            url: config.url.clone(),
+            hash: config
+                .hash
+                .as_deref()
+                .unwrap_or_default()
+                .to_ascii_lowercase(),
+            prompt: config.prompt.as_deref().map(Component::from_legacy),
+            required: config.required,
+            kick_message: Component::from_legacy(&config.kick_message),
+        }
+    }
+
+    fn same_pack(&self, other: &Self) -> bool {
+        self.id == other.id && self.hash == other.hash
+    }
+}
+
+/// A client status answer, resolved to the pack it is about.
+#[derive(Debug)]
+pub(crate) struct PackStatus {
+    pub pack_id: Option<uuid::Uuid>,
+    pub url: Option<String>,
+    pub status: ResourcePackStatus,
+    /// Set when the answer is about a proxy pack, which the backend never sent.
+    pub proxy_pack: Option<ProxyResourcePack>,
+}
+
+impl PackStatus {
+    /// Disconnect message when the player refused a required proxy pack.
+    pub(crate) fn kick_reason(&self) -> Option<&Component> {
+        // Discarded packs were dropped by a newer push, not refused.
+        let refused = self.status.is_failure() && self.status != ResourcePackStatus::Discarded;
+        self.proxy_pack
+            .as_ref()
+            .filter(|pack| pack.required && refused)
+            .map(|pack| &pack.kick_message)
+    }
+}
+
+/// Proxy resource packs of one client connection.
+#[derive(Debug, Default)]
+pub(crate) struct ResourcePackTracker {
+    /// Packs wanted on the current server.
+    wanted: Vec<ProxyResourcePack>,
+    /// Proxy packs the client holds.
+    applied: Vec<ProxyResourcePack>,
+    /// Proxy pack awaiting its final answer (before 1.20.3).
+    pending: Option<ProxyResourcePack>,
+    /// URLs of packs pushed by backends, by id (nil before 1.20.3).
+    backend_urls: HashMap<uuid::Uuid, String>,
+}
+
+impl ResourcePackTracker {
+    /// Sets the packs wanted on the server the player is joining.
+    pub(crate) fn set_server(&mut self, proxy: &ProxyConfig, server: Option<&ServerConfig>) {
+        let configs = proxy
+            .resource_packs
+            .iter()
+            .chain(server.into_iter().flat_map(|s| s.resource_packs.iter()));
+        self.wanted.clear();
+        for pack in configs.map(ProxyResourcePack::from_config) {
+            if !self.wanted.iter().any(|w| w.id == pack.id) {
+                self.wanted.push(pack);
+            }
+        }
+    }
+
+    /// Builds the frames bringing the client to the wanted packs: packs of
+    /// the previous server are removed and missing ones pushed.
+    pub(crate) fn sync_frames(
+        &mut self,
+        version: ProtocolVersion,
+        registry: &PacketRegistry,
+    ) -> Result<Vec<PacketFrame>, CoreError> {
+        let mut frames = Vec::new();
+        if version.no_less_than(ProtocolVersion::V1_20_3) {
+            for pack in &self.applied {
+                if !self.wanted.iter().any(|w| w.same_pack(pack))
+                    && let Some(frame) =
+                        packets::build_resource_pack_pop(pack.id, version, registry)?
+                {
+                    frames.push(frame);
+                }
+            }
+            for pack in &self.wanted {
+                if !self.applied.iter().any(|a| a.same_pack(pack))
+                    && let Some(frame) = packets::build_resource_pack_push(pack, version, registry)?
+                {
+                    frames.push(frame);
+                }
+            }
+            self.applied.clone_from(&self.wanted);
+        } else if let Some(pack) = self.wanted.last()
+            && !self.applied.iter().any(|a| a.same_pack(pack))
+            && let Some(frame) = packets::build_resource_pack_push(pack, version, registry)?
+        {
+            // Pre-1.20.3 packs cannot be removed, only replaced.
+            frames.push(frame);
+            self.applied = vec![pack.clone()];
+            self.pending = Some(pack.clone());
+        }
+        Ok(frames)
+    }
+
+    /// Tracks packs pushed or removed by the backend.
+    pub(crate) fn observe_backend(
+        &mut self,
+        frame: &PacketFrame,
+        state: ConnectionState,
+        version: ProtocolVersion,
+        registry: &PacketRegistry,
+    ) {
+        let (push, pop) = match state {
+            ConnectionState::Play => (
+                packet_id::<CResourcePackPush>(registry, state, version),
+                packet_id::<CResourcePackPop>(registry, state, version),
+            ),
+            ConnectionState::Config => (
+                packet_id::<CConfigResourcePackPush>(registry, state, version),
+                packet_id::<CConfigResourcePackPop>(registry, state, version),
+            ),
+            _ => return,
+        };
+
+        if Some(frame.id) == push {
+            // Both states share the same payload.
+            let Ok(pushed) = CResourcePackPush::decode(&mut frame.payload.as_ref(), version) else {
+                return;
+            };
+            if version.less_than(ProtocolVersion::V1_20_3) {
+                // The backend pack replaces the proxy one.
+                self.applied.clear();
+                self.pending = None;
+                self.backend_urls.clear();
+            }
+            self.backend_urls.insert(pushed.pack.id, pushed.pack.url);
+        } else if Some(frame.id) == pop {
+            let Ok(popped) = CResourcePackPop::decode(&mut frame.payload.as_ref(), version) else {
+                return;
+            };
+            match popped.id {
+                Some(id) => {
+                    self.applied.retain(|pack| pack.id != id);
+                    self.backend_urls.remove(&id);
+                }
+                None => {
+                    self.applied.clear();
+                    self.backend_urls.clear();
+                }
+            }
+        }
+    }
+
+    /// Resolves a client status answer, or returns `None` if `frame` is not one.
+    pub(crate) fn client_status(
+        &mut self,
+        frame: &PacketFrame,
+        state: ConnectionState,
+        version: ProtocolVersion,
+        registry: &PacketRegistry,
+    ) -> Option<PackStatus> {
+        let response = match state {
+            ConnectionState::Play
+                if Some(frame.id) == status_id::<SResourcePackStatus>(registry, state, version) =>
+            {
+                SResourcePackStatus::decode(&mut frame.payload.as_ref(), version)
+                    .ok()?
+                    .response
+            }
+            ConnectionState::Config
+                if Some(frame.id)
+                    == status_id::<SConfigResourcePackStatus>(registry, state, version) =>
+            {
+                SConfigResourcePackStatus::decode(&mut frame.payload.as_ref(), version)
+                    .ok()?
+                    .response
+            }
+            _ => return None,
+        };
+        Some(self.resolve(&response, state, version))
+    }
+
+    fn resolve(
+        &mut self,
+        response: &ResourcePackResponse,
+        state: ConnectionState,
+        version: ProtocolVersion,
+    ) -> PackStatus {
+        let status = api_status(response.result);
+        // The proxy only pushes packs in the play state.
+        let proxy_pack = if state != ConnectionState::Play {
+            None
+        } else if version.no_less_than(ProtocolVersion::V1_20_3) {
+            response
+                .id
+                .and_then(|id| self.applied.iter().find(|pack| pack.id == id).cloned())
+        } else {
+            let pending = self.pending.clone();
+            if status == ResourcePackStatus::Loaded || status.is_failure() {
+                self.pending = None;
+            }
+            pending
+        };
+
+        let (pack_id, url) = match &proxy_pack {
+            Some(pack) => (Some(pack.id), Some(pack.url.clone())),
+            None => (
+                response.id,
+                self.backend_urls
+                    .get(&response.id.unwrap_or_default())
+                    .cloned(),
+            ),
+        };
+        PackStatus {
+            pack_id,
+            url,
+            status,
+            proxy_pack,
+        }
+    }
+}
+
+fn packet_id<P: Packet + 'static>(
+    registry: &PacketRegistry,
+    state: ConnectionState,
+    version: ProtocolVersion,
+) -> Option<i32> {
+    registry.get_packet_id::<P>(state, Direction::Clientbound, version)
+}
+
+fn status_id<P: Packet + 'static>(
+    registry: &PacketRegistry,
+    state: ConnectionState,
+    version: ProtocolVersion,
+) -> Option<i32> {
+    registry.get_packet_id::<P>(state, Direction::Serverbound, version)
+}
+
+const fn api_status(result: ResourcePackResult) -> ResourcePackStatus {
+    match result {
+        ResourcePackResult::SuccessfullyLoaded => ResourcePackStatus::Loaded,
+        ResourcePackResult::Declined => ResourcePackStatus::Declined,
+        ResourcePackResult::FailedDownload => ResourcePackStatus::FailedDownload,
+        ResourcePackResult::Accepted => ResourcePackStatus::Accepted,
+        ResourcePackResult::Downloaded => ResourcePackStatus::Downloaded,
+        ResourcePackResult::InvalidUrl => ResourcePackStatus::InvalidUrl,
+        ResourcePackResult::FailedReload => ResourcePackStatus::FailedReload,
+        ResourcePackResult::Discarded => ResourcePackStatus::Discarded,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use infrarust_protocol::registry::build_default_registry;
+
+    use super::*;
+
+    fn proxy_config(urls: &[&str], required: bool) -> ProxyConfig {
+        let mut config: ProxyConfig = toml::from_str("").unwrap();
+        config.resource_packs = urls
+            .iter()
+            .map(|url| ResourcePackConfig {
+                url: (*url).to_string(),
+                hash: None,
+                prompt: None,
+                required,
+                kick_message: "Accept it".to_string(),
+            })
+            .collect();
+        config
+    }
+
+    fn status_frame(
+        registry: &PacketRegistry,
+        version: ProtocolVersion,
+        id: Option<uuid::Uuid>,
+        result: ResourcePackResult,
+    ) -> PacketFrame {
+        let packet = SResourcePackStatus {
+            response: ResourcePackResponse {
+                id,
+                hash: None,
+                result,
+            },
+        };
+        let mut payload = Vec::new();
+        packet.encode(&mut payload, version).unwrap();
+        PacketFrame {
+            id: status_id::<SResourcePackStatus>(registry, ConnectionState::Play, version).unwrap(),
+            payload: payload.into(),
+        }
+    }
+
+    #[test]
+    fn shared_packs_are_not_pushed_again() {
+        let registry = build_default_registry();
+        let version = ProtocolVersion::V1_21;
+        let mut tracker = ResourcePackTracker::default();
+
+        tracker.set_server(
+            &proxy_config(&["https://a/1.zip", "https://a/2.zip"], false),
+            None,
+        );
+        assert_eq!(tracker.sync_frames(version, &registry).unwrap().len(), 2);
+
+        // Same first pack, second one replaced: one pop, one push
+        tracker.set_server(
+            &proxy_config(&["https://a/1.zip", "https://a/3.zip"], false),
+            None,
+        );
+        let frames = tracker.sync_frames(version, &registry).unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(
+            frames[0].id,
+            packet_id::<CResourcePackPop>(&registry, ConnectionState::Play, version).unwrap()
+        );
+
+        tracker.set_server(
+            &proxy_config(&["https://a/1.zip", "https://a/3.zip"], false),
+            None,
+        );
+        assert!(tracker.sync_frames(version, &registry).unwrap().is_empty());
+    }
+
+    #[test]
+    fn legacy_clients_get_the_last_pack_once() {
+        let registry = build_default_registry();
+        let version = ProtocolVersion::V1_19_4;
+        let mut tracker = ResourcePackTracker::default();
+
+        tracker.set_server(
+            &proxy_config(&["https://a/1.zip", "https://a/2.zip"], false),
+            None,
+        );
+        assert_eq!(tracker.sync_frames(version, &registry).unwrap().len(), 1);
+        assert!(tracker.sync_frames(version, &registry).unwrap().is_empty());
+
+        // Without pack ids, answers belong to the proxy until the final one
+        let accepted = status_frame(&registry, version, None, ResourcePackResult::Accepted);
+        let status = tracker
+            .client_status(&accepted, ConnectionState::Play, version, &registry)
+            .unwrap();
+        assert!(status.proxy_pack.is_some());
+        let loaded = status_frame(
+            &registry,
+            version,
+            None,
+            ResourcePackResult::SuccessfullyLoaded,
+        );
+        let status = tracker
+            .client_status(&loaded, ConnectionState::Play, version, &registry)
+            .unwrap();
+        assert_eq!(status.status, ResourcePackStatus::Loaded);
+        assert!(status.proxy_pack.is_some());
+        let status = tracker
+            .client_status(&loaded, ConnectionState::Play, version, &registry)
+            .unwrap();
+        assert!(status.proxy_pack.is_none());
+    }
+
+    #[test]
+    fn refusing_a_required_pack_kicks() {
+        let registry = build_default_registry();
+        let version = ProtocolVersion::V1_21;
+        let mut tracker = ResourcePackTracker::default();
+        tracker.set_server(&proxy_config(&["https://a/1.zip"], true), None);
+        tracker.sync_frames(version, &registry).unwrap();
+        let id = tracker.applied[0].id;
+
+        let declined = status_frame(&registry, version, Some(id), ResourcePackResult::Declined);
+        let status = tracker
+            .client_status(&declined, ConnectionState::Play, version, &registry)
+            .unwrap();
+        assert_eq!(status.pack_id, Some(id));
+        assert!(status.kick_reason().is_some());
+
+        // Answers about backend packs are left to the backend
+        let other = status_frame(
+            &registry,
+            version,
+            Some(uuid::Uuid::from_u128(1)),
+            ResourcePackResult::Declined,
+        );
+        let status = tracker
+            .client_status(&other, ConnectionState::Play, version, &registry)
+            .unwrap();
+        assert!(status.proxy_pack.is_none());
+        assert!(status.kick_reason().is_none());
+    }
+}
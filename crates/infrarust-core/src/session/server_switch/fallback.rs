@@ -0,0 +1,165 @@
+//! Fallback chains for players whose server is unreachable or kicked them.
+//!
+//! A server lists its fallbacks in `fallbacks`. Candidates outside the
+//! server's network, unknown servers and forwarding-mode servers are skipped;
+//! the rest keep their configured order within each health tier.
+
+use std::sync::Arc;
+
+use infrarust_api::types::ServerId;
+use infrarust_config::{LIMBO_SERVER_ID, ServerConfig};
+use infrarust_server_manager::ServerState;
+
+use super::validation;
+use crate::services::ProxyServices;
+
+/// A place to send a player when their server is not available.
+#[derive(Debug, Clone)]
+pub(crate) enum Fallback {
+    Server(ServerId, Arc<ServerConfig>),
+    Limbo,
+}
+
+/// Returns the usable fallbacks of `from`, healthiest first.
+pub(crate) fn fallback_chain(services: &ProxyServices, from: &ServerConfig) -> Vec<Fallback> {
+    order_fallbacks(
+        from,
+        |id| services.domain_router.find_by_server_id(id),
+        |id| {
+            services
+                .server_manager
+                .as_ref()
+                .and_then(|sm| sm.get_state(id))
+        },
+    )
+}
+
+fn order_fallbacks(
+    from: &ServerConfig,
+    lookup: impl Fn(&str) -> Option<Arc<ServerConfig>>,
+    state: impl Fn(&str) -> Option<ServerState>,
+) -> Vec<Fallback> {
+    let from_id = from.effective_id();
+    let mut candidates = Vec::with_capacity(from.fallbacks.len());
+
+    for id in &from.fallbacks {
+        if id == LIMBO_SERVER_ID {
+            candidates.push((0, Fallback::Limbo));
+            continue;
+        }
+        if *id == from_id {
+            continue;
+        }
+        let Some(config) = lookup(id) else {
+            tracing::debug!(server = %from_id, fallback = %id, "skipping unknown fallback server");
+            continue;
+        };
+        if config.proxy_mode.is_forwarding() {
+            tracing::debug!(server = %from_id, fallback = %id, "skipping forwarding-mode fallback server");
+            continue;
+        }
+        if let Err(e) = validation::validate_switch_allowed(from, &config) {
+            tracing::debug!(server = %from_id, fallback = %id, "skipping fallback server: {e}");
+            continue;
+        }
+        candidates.push((
+            health_rank(state(id)),
+            Fallback::Server(ServerId::new(id), config),
+        ));
+    }
+
+    // Stable sort: configured order is kept within a tier.
+    candidates.sort_by_key(|(rank, _)| *rank);
+    candidates
+        .into_iter()
+        .map(|(_, fallback)| fallback)
+        .collect()
+}
+
+/// Lower is better. Unmanaged servers are assumed to be up.
+fn health_rank(state: Option<ServerState>) -> u8 {
+    match state {
+        None | Some(ServerState::Online) => 0,
+        Some(ServerState::Starting) => 1,
+        Some(_) => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn config(name: &str, extra: &str) -> ServerConfig {
+        let mode = if extra.contains("proxy_mode") {
+            ""
+        } else {
+            "proxy_mode = \"client_only\"\n"
+        };
+        toml::from_str(&format!(
+            "name = \"{name}\"\naddresses = [\"127.0.0.1:25565\"]\n{mode}{extra}"
+        ))
+        .unwrap()
+    }
+
+    fn names(chain: &[Fallback]) -> Vec<String> {
+        chain
+            .iter()
+            .map(|f| match f {
+                Fallback::Server(id, _) => id.as_str().to_string(),
+                Fallback::Limbo => LIMBO_SERVER_ID.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fallbacks_ordered_by_health() {
+        let from = config(
+            "minigame",
+            "network = \"main\"\nfallbacks = [\"hub-1\", \"hub-2\", \"hub-3\", \"$limbo\"]",
+        );
+        let servers: HashMap<_, _> = ["hub-1", "hub-2", "hub-3"]
+            .into_iter()
+            .map(|n| (n, Arc::new(config(n, "network = \"main\""))))
+            .collect();
+        let states = HashMap::from([
+            ("hub-1", ServerState::Sleeping),
+            ("hub-2", ServerState::Starting),
+        ]);
+
+        let chain = order_fallbacks(
+            &from,
+            |id| servers.get(id).cloned(),
+            |id| states.get(id).copied(),
+        );
+        assert_eq!(names(&chain), ["hub-3", "$limbo", "hub-2", "hub-1"]);
+    }
+
+    #[test]
+    fn test_fallbacks_skip_unusable_servers() {
+        let from = config(
+            "minigame",
+            "network = \"main\"\nfallbacks = [\"minigame\", \"other-net\", \"missing\", \"relay\", \"hub\"]",
+        );
+        let servers = HashMap::from([
+            ("minigame", Arc::new(from.clone())),
+            (
+                "other-net",
+                Arc::new(config("other-net", "network = \"other\"")),
+            ),
+            (
+                "relay",
+                Arc::new(config(
+                    "relay",
+                    "proxy_mode = \"passthrough\"\ndomains = [\"relay.example.com\"]",
+                )),
+            ),
+            ("hub", Arc::new(config("hub", "network = \"main\""))),
+        ]);
+
+        let chain = order_fallbacks(&from, |id| servers.get(id).cloned(), |_| None);
+        assert_eq!(names(&chain), ["hub"]);
+    }
+}
@@ -5,6 +5,7 @@
 //! and `config_phase` submodules for details.
 
 mod config_phase;
+pub(crate) mod fallback;
 mod switch_packets;
 mod validation;
 
@@ -147,6 +147,9 @@ impl StatusHandler {
         {
             match sm.get_state(config_id) {
                 Some(ServerState::Online) | None => {}
+                Some(ServerState::Sleeping) if sm.wake_on_ping(config_id) => {
+                    return Self::build_state_motd(config, ServerState::Starting);
+                }
                 Some(state) => {
                     return Self::build_state_motd(config, state);
                 }
@@ -357,6 +357,9 @@ mod tests {
             ip_filter: None,
             disconnect_message: None,
             limbo_handlers: vec![],
+            fallbacks: Vec::new(),
+            reconnect: None,
+            resource_packs: Vec::new(),
         }
     }
 
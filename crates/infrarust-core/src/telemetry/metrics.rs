@@ -18,6 +18,7 @@ pub struct ProxyMetrics {
     connection_duration: Histogram<f64>,
     handshake_duration: Histogram<f64>,
     backend_connect_duration: Histogram<f64>,
+    player_latency: Histogram<f64>,
     packets_relayed: Counter<u64>,
 }
 
@@ -63,6 +64,11 @@ impl ProxyMetrics {
                 .with_description("Backend connection time")
                 .with_unit("s")
                 .build(),
+            player_latency: meter
+                .f64_histogram("infrarust.player.latency")
+                .with_description("Player round-trip time, measured from keepalives")
+                .with_unit("s")
+                .build(),
             packets_relayed: meter
                 .u64_counter("infrarust.packets.relayed")
                 .with_description("Total packets relayed")
@@ -120,6 +126,12 @@ impl ProxyMetrics {
         );
     }
 
+    /// Records a player latency sample.
+    pub fn record_player_latency(&self, latency_secs: f64, server: &str) {
+        self.player_latency
+            .record(latency_secs, &[KeyValue::new("server", server.to_string())]);
+    }
+
     /// Records handshake processing duration.
     pub fn record_handshake(&self, duration_secs: f64) {
         self.handshake_duration.record(duration_secs, &[]);
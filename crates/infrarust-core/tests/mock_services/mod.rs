@@ -8,7 +8,7 @@ use infrarust_api::event::BoxFuture;
 use infrarust_api::services::ban_service::{BanEntry, BanTarget};
 use infrarust_api::services::config_service::ServerConfig;
 use infrarust_api::services::player_registry::PlayerRegistry;
-use infrarust_api::types::{PlayerId, ServerId};
+use infrarust_api::types::{PlayerId, ServerId, TabListEntry};
 
 pub struct MockPlayerRegistry;
 
@@ -89,3 +89,21 @@ impl infrarust_api::services::config_service::ConfigService for MockConfigServic
         None
     }
 }
+
+pub struct MockTabList;
+
+impl infrarust_api::services::tab_list::private::Sealed for MockTabList {}
+
+impl infrarust_api::services::tab_list::TabList for MockTabList {
+    fn set_header_footer(&self, _player: PlayerId, _header: &str, _footer: &str) {}
+    fn clear_header_footer(&self, _player: PlayerId) {}
+    fn set_default_header_footer(&self, _header: &str, _footer: &str) {}
+    fn add_entry(&self, _entry: TabListEntry) {}
+    fn remove_entry(&self, _uuid: &uuid::Uuid) -> bool {
+        false
+    }
+    fn entries(&self) -> Vec<TabListEntry> {
+        vec![]
+    }
+    fn refresh(&self) {}
+}
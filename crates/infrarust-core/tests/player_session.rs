@@ -1,7 +1,13 @@
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 
+use std::time::Duration;
+
+use infrarust_api::boss_bar::{BossBar, BossBarColor, BossBarStyle};
 use infrarust_api::player::Player;
-use infrarust_api::types::{Component, ServerId};
+use infrarust_api::scoreboard::Sidebar;
+use infrarust_api::types::{
+    ChatMode, ClientSettings, Component, MainHand, ServerId, SkinParts, TabListEntry,
+};
 use infrarust_core::player::{PlayerCommand, PlayerSession};
 
 #[test]
@@ -82,3 +88,141 @@ fn test_current_server_update() {
     session.set_current_server(ServerId::new("survival"));
     assert_eq!(session.current_server().unwrap().as_str(), "survival");
 }
+
+#[test]
+fn test_tab_list_commands() {
+    let (session, mut rx) = PlayerSession::new_test(true);
+    let uuid = uuid::Uuid::new_v4();
+
+    session
+        .send_tab_list_header_footer(Component::text("header"), Component::text("footer"))
+        .expect("should succeed");
+    session
+        .add_tab_list_entries(vec![TabListEntry::new(uuid, "Fake")])
+        .expect("should succeed");
+    session
+        .remove_tab_list_entries(vec![uuid])
+        .expect("should succeed");
+
+    assert!(matches!(
+        rx.try_recv().unwrap(),
+        PlayerCommand::SetTabListHeaderFooter(..)
+    ));
+    assert!(matches!(
+        rx.try_recv().unwrap(),
+        PlayerCommand::AddTabListEntries(entries) if entries[0].uuid == uuid
+    ));
+    assert!(matches!(
+        rx.try_recv().unwrap(),
+        PlayerCommand::RemoveTabListEntries(uuids) if uuids == vec![uuid]
+    ));
+
+    let (passive, _rx) = PlayerSession::new_test(false);
+    assert!(matches!(
+        passive.remove_tab_list_entries(vec![uuid]),
+        Err(infrarust_api::error::PlayerError::NotActive)
+    ));
+}
+
+#[test]
+fn test_boss_bar_commands() {
+    let (session, mut rx) = PlayerSession::new_test(true);
+    let bar = BossBar::new(
+        Component::text("Starting"),
+        0.0,
+        BossBarColor::Yellow,
+        BossBarStyle::Notched10,
+    );
+
+    session.show_boss_bar(&bar).expect("should succeed");
+    assert!(matches!(rx.try_recv().unwrap(), PlayerCommand::ShowBossBar(b) if b == bar));
+
+    bar.set_progress(0.5);
+    assert!(matches!(
+        rx.try_recv().unwrap(),
+        PlayerCommand::UpdateBossBar(b, _) if b == bar
+    ));
+
+    // Shown bars are re-sent after a server switch
+    session.set_current_server(ServerId::new("lobby"));
+    assert!(matches!(
+        rx.try_recv().unwrap(),
+        PlayerCommand::ShowBossBar(_)
+    ));
+
+    session.hide_boss_bar(&bar).expect("should succeed");
+    assert!(matches!(
+        rx.try_recv().unwrap(),
+        PlayerCommand::HideBossBar(id) if id == bar.id()
+    ));
+    assert_eq!(bar.viewer_count(), 0);
+
+    bar.set_progress(1.0);
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn test_sidebar_commands() {
+    let (session, mut rx) = PlayerSession::new_test(true);
+    let sidebar = Sidebar::new(Component::text("Network"));
+    sidebar.set_lines(vec![Component::text("Online: 1")]);
+
+    session.show_sidebar(&sidebar).expect("should succeed");
+    assert!(matches!(
+        rx.try_recv().unwrap(),
+        PlayerCommand::ShowSidebar(s) if s.id == sidebar.id() && s.lines.len() == 1
+    ));
+
+    sidebar.set_line(0, Component::text("Online: 2"));
+    match rx.try_recv().unwrap() {
+        PlayerCommand::UpdateSidebar(old, new) => {
+            assert_eq!(old.lines[0].to_string(), "Online: 1");
+            assert_eq!(new.lines[0].to_string(), "Online: 2");
+        }
+        _ => panic!("expected UpdateSidebar"),
+    }
+
+    // Taken on server switch, leaving nothing to hide afterwards
+    let taken = session.take_sidebar().expect("sidebar was shown");
+    assert_eq!(taken.lines[0].to_string(), "Online: 2");
+    assert_eq!(sidebar.viewer_count(), 0);
+    session.hide_sidebar().expect("should succeed");
+    sidebar.set_title(Component::text("Ignored"));
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn test_client_settings_and_brand() {
+    let (session, _rx) = PlayerSession::new_test(true);
+    assert!(session.settings().is_none());
+    assert!(session.locale().is_none());
+
+    let settings = ClientSettings {
+        locale: "fr_fr".to_string(),
+        view_distance: 10,
+        chat_mode: ChatMode::Enabled,
+        chat_colors: true,
+        skin_parts: SkinParts::from_bits(0x7F),
+        main_hand: MainHand::Right,
+        text_filtering: false,
+        allow_server_listings: true,
+    };
+    assert!(session.set_settings(settings.clone()));
+    // Unchanged settings are not reported again
+    assert!(!session.set_settings(settings));
+    assert_eq!(session.locale().as_deref(), Some("fr_fr"));
+    assert_eq!(session.view_distance(), Some(10));
+
+    assert!(session.set_client_brand("fabric".to_string()));
+    assert!(!session.set_client_brand("fabric".to_string()));
+    assert_eq!(session.client_brand().as_deref(), Some("fabric"));
+}
+
+#[test]
+fn test_ping() {
+    let (session, _rx) = PlayerSession::new_test(true);
+    assert!(session.ping().is_none());
+
+    session.record_ping(Duration::from_millis(42));
+    assert_eq!(session.ping(), Some(Duration::from_millis(42)));
+}
@@ -62,7 +62,9 @@ async fn test_per_plugin_ids() {
     // Verify CommandManagerImpl register/unregister
     use infrarust_api::command::CommandManager;
     command_manager.register("test", &["t"], "A test command", Box::new(NoopHandler));
+    assert!(command_manager.is_plugin_command("t"));
     command_manager.unregister("test");
+    assert!(!command_manager.is_plugin_command("test"));
 
     let _ = event_bus;
     let _ = connection_registry;
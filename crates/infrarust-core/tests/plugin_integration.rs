@@ -13,6 +13,7 @@ use infrarust_api::events::lifecycle::PostLoginEvent;
 use infrarust_api::plugin::{Plugin, PluginContext, PluginMetadata};
 use infrarust_api::types::{GameProfile, PlayerId, ProtocolVersion};
 use infrarust_core::event_bus::EventBusImpl;
+use infrarust_core::player::data_store::SqlitePlayerDataStore;
 use infrarust_core::plugin::PluginContextFactoryImpl;
 use infrarust_core::plugin::manager::{PluginManager, PluginServices};
 use infrarust_core::plugin::static_loader::StaticPluginLoader;
@@ -21,7 +22,7 @@ use infrarust_core::services::scheduler::SchedulerImpl;
 use infrarust_core::services::server_manager_bridge::NoopServerManager;
 
 mod mock_services;
-use mock_services::{MockBanService, MockConfigService, MockPlayerRegistry};
+use mock_services::{MockBanService, MockConfigService, MockPlayerRegistry, MockTabList};
 
 /// A test plugin that sets a flag when a PostLoginEvent is received.
 struct TestPlugin {
@@ -61,6 +62,8 @@ async fn test_plugin_receives_events_end_to_end() {
         ban_service: Arc::new(MockBanService),
         command_manager: Arc::new(CommandManagerImpl::new()),
         scheduler: Arc::new(SchedulerImpl::new()),
+        tab_list: Arc::new(MockTabList),
+        player_data: Arc::new(SqlitePlayerDataStore::in_memory()),
         config_service: Arc::new(MockConfigService),
         plugin_registry: Arc::new(infrarust_core::plugin::PluginRegistryImpl::new()),
         codec_filter_registry: Arc::new(
@@ -150,6 +153,8 @@ async fn test_dependency_order_end_to_end() {
         ban_service: Arc::new(MockBanService),
         command_manager: Arc::new(CommandManagerImpl::new()),
         scheduler: Arc::new(SchedulerImpl::new()),
+        tab_list: Arc::new(MockTabList),
+        player_data: Arc::new(SqlitePlayerDataStore::in_memory()),
         config_service: Arc::new(MockConfigService),
         plugin_registry: Arc::new(infrarust_core::plugin::PluginRegistryImpl::new()),
         codec_filter_registry: Arc::new(
@@ -8,6 +8,7 @@ use infrarust_api::error::PluginError;
 use infrarust_api::event::BoxFuture;
 use infrarust_api::plugin::{Plugin, PluginContext, PluginMetadata};
 use infrarust_core::event_bus::EventBusImpl;
+use infrarust_core::player::data_store::SqlitePlayerDataStore;
 use infrarust_core::plugin::PluginState;
 use infrarust_core::plugin::context_factory::PluginContextFactory;
 use infrarust_core::plugin::manager::{PluginManager, PluginServices};
@@ -17,7 +18,7 @@ use infrarust_core::services::scheduler::SchedulerImpl;
 use infrarust_core::services::server_manager_bridge::NoopServerManager;
 
 mod mock_services;
-use mock_services::{MockBanService, MockConfigService, MockPlayerRegistry};
+use mock_services::{MockBanService, MockConfigService, MockPlayerRegistry, MockTabList};
 
 struct MockPluginContext {
     plugin_id: String,
@@ -55,6 +56,18 @@ impl PluginContext for MockPluginContext {
     fn scheduler(&self) -> &dyn infrarust_api::services::scheduler::Scheduler {
         unimplemented!("mock")
     }
+    fn tab_list(&self) -> &dyn infrarust_api::services::tab_list::TabList {
+        unimplemented!("mock")
+    }
+    fn tab_list_handle(&self) -> Arc<dyn infrarust_api::services::tab_list::TabList> {
+        unimplemented!("mock")
+    }
+    fn player_data(&self) -> &dyn infrarust_api::services::player_data::PlayerDataStore {
+        unimplemented!("mock")
+    }
+    fn player_data_handle(&self) -> Arc<dyn infrarust_api::services::player_data::PlayerDataStore> {
+        unimplemented!("mock")
+    }
     fn register_limbo_handler(&self, _handler: Box<dyn infrarust_api::limbo::LimboHandler>) {
         unimplemented!("mock")
     }
@@ -136,6 +149,8 @@ fn make_services() -> PluginServices {
         ban_service: Arc::new(MockBanService),
         command_manager: Arc::new(CommandManagerImpl::new()),
         scheduler: Arc::new(SchedulerImpl::new()),
+        tab_list: Arc::new(MockTabList),
+        player_data: Arc::new(SqlitePlayerDataStore::in_memory()),
         config_service: Arc::new(MockConfigService),
         plugin_registry: Arc::new(infrarust_core::plugin::PluginRegistryImpl::new()),
         codec_filter_registry: Arc::new(
@@ -417,6 +432,8 @@ async fn test_cleanup_on_disable() {
         ban_service: Arc::new(MockBanService),
         command_manager: Arc::new(CommandManagerImpl::new()),
         scheduler: Arc::new(SchedulerImpl::new()),
+        tab_list: Arc::new(MockTabList),
+        player_data: Arc::new(SqlitePlayerDataStore::in_memory()),
         config_service: Arc::new(MockConfigService),
         plugin_registry: Arc::new(infrarust_core::plugin::PluginRegistryImpl::new()),
         codec_filter_registry: Arc::new(
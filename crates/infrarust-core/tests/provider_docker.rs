@@ -61,6 +61,25 @@ fn test_labels_to_config_proxy_protocol() {
     assert!(config.send_proxy_protocol);
 }
 
+#[test]
+fn test_labels_to_config_values_are_not_toml() {
+    let labels = make_labels(&[
+        ("infrarust.enable", "true"),
+        ("infrarust.proxy_mode", "client_only"),
+        ("infrarust.network", "main"),
+        ("infrarust.motd.text", "Say \"hi\"\n[motd.offline]"),
+    ]);
+    let config = labels_to_server_config("mc-test", &labels, "172.17.0.2:25565");
+
+    assert_eq!(config.proxy_mode, infrarust_config::ProxyMode::ClientOnly);
+    assert_eq!(config.network.as_deref(), Some("main"));
+    assert_eq!(
+        config.motd.online.map(|m| m.text).as_deref(),
+        Some("Say \"hi\"\n[motd.offline]")
+    );
+    assert!(config.motd.offline.is_none());
+}
+
 #[test]
 fn test_address_resolution_hostname_fallback() {
     use bollard::models::ContainerInspectResponse;
@@ -0,0 +1,280 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+#![cfg(feature = "kubernetes")]
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use infrarust_config::KubernetesProviderConfig;
+use infrarust_core::provider::kubernetes::{
+    KubernetesProvider, Pod, Service, pod_to_server_config, resolve_pod_address,
+    resolve_service_address, service_to_server_config,
+};
+use infrarust_core::provider::{ConfigProvider, ProviderEvent};
+
+fn service(json: serde_json::Value) -> Service {
+    serde_json::from_value(json).unwrap()
+}
+
+fn pod(json: serde_json::Value) -> Pod {
+    serde_json::from_value(json).unwrap()
+}
+
+fn lobby_service() -> serde_json::Value {
+    serde_json::json!({
+        "metadata": {
+            "name": "lobby",
+            "namespace": "mc",
+            "resourceVersion": "10",
+            "annotations": {
+                "infrarust.enable": "true",
+                "infrarust.domains": "lobby.example.com",
+                "infrarust.proxy_mode": "client_only",
+            }
+        },
+        "spec": {
+            "clusterIP": "10.96.0.20",
+            "ports": [{ "name": "minecraft", "port": 25565 }]
+        }
+    })
+}
+
+fn ready_pod() -> serde_json::Value {
+    serde_json::json!({
+        "metadata": {
+            "name": "survival-0",
+            "namespace": "mc",
+            "resourceVersion": "11",
+            "annotations": { "infrarust.enable": "true" }
+        },
+        "spec": {
+            "containers": [{ "ports": [{ "name": "minecraft", "containerPort": 25566 }] }]
+        },
+        "status": {
+            "phase": "Running",
+            "podIP": "10.244.1.7",
+            "conditions": [{ "type": "Ready", "status": "True" }]
+        }
+    })
+}
+
+#[test]
+fn test_service_annotations_to_config() {
+    let config = service_to_server_config(&service(lobby_service())).unwrap();
+
+    assert_eq!(config.domains, vec!["lobby.example.com"]);
+    assert_eq!(config.addresses[0].host, "10.96.0.20");
+    assert_eq!(config.addresses[0].port, 25565);
+    assert_eq!(config.proxy_mode, infrarust_config::ProxyMode::ClientOnly);
+}
+
+#[test]
+fn test_service_without_enable_is_ignored() {
+    let svc = service(serde_json::json!({
+        "metadata": { "name": "web", "namespace": "default" },
+        "spec": { "clusterIP": "10.96.0.1", "ports": [{ "port": 80 }] }
+    }));
+
+    assert!(service_to_server_config(&svc).is_none());
+}
+
+#[test]
+fn test_headless_service_resolves_to_dns() {
+    let svc = service(serde_json::json!({
+        "metadata": {
+            "name": "creative",
+            "namespace": "mc",
+            "annotations": { "infrarust.enable": "true", "infrarust.port": "25570" }
+        },
+        "spec": { "clusterIP": "None", "ports": [{ "port": 25565 }] }
+    }));
+
+    assert_eq!(resolve_service_address(&svc), "creative.mc.svc:25570");
+}
+
+#[test]
+fn test_pod_auto_domain_and_named_port() {
+    let config = pod_to_server_config(&pod(ready_pod())).unwrap();
+
+    assert_eq!(config.domains, vec!["survival-0.mc.k8s.local"]);
+    assert_eq!(config.addresses[0].port, 25566);
+}
+
+#[test]
+fn test_unready_pod_is_ignored() {
+    let mut json = ready_pod();
+    json["status"]["conditions"][0]["status"] = "False".into();
+    let p = pod(json);
+
+    assert_eq!(resolve_pod_address(&p).as_deref(), Some("10.244.1.7:25566"));
+    assert!(pod_to_server_config(&p).is_none());
+}
+
+/// Spawns a mock API server. `watch` bodies are served once per path and
+/// later watch requests are held open until the test ends.
+async fn spawn_mock_api(
+    lists: Vec<(&'static str, String)>,
+    watches: Vec<(&'static str, String)>,
+) -> (SocketAddr, CancellationToken) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let shutdown = CancellationToken::new();
+    let token = shutdown.clone();
+    let served = std::sync::Arc::new(std::sync::Mutex::new(Vec::<&'static str>::new()));
+
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = tokio::select! {
+                result = listener.accept() => result.unwrap(),
+                () = token.cancelled() => break,
+            };
+            let lists = lists.clone();
+            let watches = watches.clone();
+            let served = std::sync::Arc::clone(&served);
+            let token = token.clone();
+
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 4096];
+                let n = stream.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]).to_string();
+                let first_line = request.lines().next().unwrap_or("").to_string();
+
+                let body = if first_line.contains("watch=true") {
+                    let entry = watches.iter().find(|(path, _)| first_line.contains(path));
+                    let fresh = entry.is_some_and(|(path, _)| {
+                        let mut served = served.lock().unwrap();
+                        !served.contains(path) && {
+                            served.push(path);
+                            true
+                        }
+                    });
+                    if !fresh {
+                        token.cancelled().await;
+                        return;
+                    }
+                    entry.map(|(_, body)| body.clone()).unwrap_or_default()
+                } else {
+                    lists
+                        .iter()
+                        .find(|(path, _)| first_line.contains(path))
+                        .map(|(_, body)| body.clone())
+                        .unwrap_or_else(|| r#"{"items":[]}"#.to_string())
+                };
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+                let _ = stream.flush().await;
+            });
+        }
+    });
+
+    (addr, shutdown)
+}
+
+fn make_config(addr: SocketAddr) -> KubernetesProviderConfig {
+    KubernetesProviderConfig {
+        api_server: Some(format!("http://{addr}")),
+        namespace: Some("mc".to_string()),
+        token_file: "/nonexistent/token".into(),
+        ca_file: "/nonexistent/ca.crt".into(),
+        reconnect_delay: Duration::from_millis(50),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_load_initial_from_mock_api() {
+    let services = serde_json::json!({
+        "metadata": { "resourceVersion": "100" },
+        "items": [lobby_service()]
+    });
+    let pods = serde_json::json!({
+        "metadata": { "resourceVersion": "100" },
+        "items": [ready_pod()]
+    });
+    let (addr, shutdown) = spawn_mock_api(
+        vec![
+            ("/api/v1/namespaces/mc/services", services.to_string()),
+            ("/api/v1/namespaces/mc/pods", pods.to_string()),
+        ],
+        Vec::new(),
+    )
+    .await;
+
+    let provider = KubernetesProvider::new(&make_config(addr)).unwrap();
+    let mut configs = provider.load_initial().await.unwrap();
+    configs.sort_by(|a, b| a.id.unique_id.cmp(&b.id.unique_id));
+
+    let ids: Vec<String> = configs.iter().map(|pc| pc.id.to_string()).collect();
+    assert_eq!(
+        ids,
+        vec![
+            "kubernetes@pod/mc/survival-0",
+            "kubernetes@service/mc/lobby"
+        ]
+    );
+    shutdown.cancel();
+}
+
+#[tokio::test]
+async fn test_watch_emits_added_and_removed() {
+    let empty = r#"{"metadata":{"resourceVersion":"100"},"items":[]}"#.to_string();
+    let events = [
+        serde_json::json!({ "type": "ADDED", "object": lobby_service() }),
+        serde_json::json!({ "type": "DELETED", "object": lobby_service() }),
+    ]
+    .iter()
+    .map(|e| format!("{e}\n"))
+    .collect::<String>();
+
+    let (addr, api_shutdown) = spawn_mock_api(
+        vec![("/api/v1/namespaces/mc/services", empty)],
+        vec![("/api/v1/namespaces/mc/services", events)],
+    )
+    .await;
+
+    let config = KubernetesProviderConfig {
+        watch_pods: false,
+        ..make_config(addr)
+    };
+    let provider = KubernetesProvider::new(&config).unwrap();
+    assert!(provider.load_initial().await.unwrap().is_empty());
+
+    let (tx, mut rx) = mpsc::channel(16);
+    let shutdown = CancellationToken::new();
+    let watch_shutdown = shutdown.clone();
+    let watcher = async move { provider.watch(tx, watch_shutdown).await };
+    let watcher = tokio::spawn(watcher);
+
+    let added = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    match added {
+        ProviderEvent::Added(pc) => {
+            assert_eq!(pc.id.to_string(), "kubernetes@service/mc/lobby");
+        }
+        other => panic!("expected Added, got {other:?}"),
+    }
+
+    let removed = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    match removed {
+        ProviderEvent::Removed(id) => assert_eq!(id.to_string(), "kubernetes@service/mc/lobby"),
+        other => panic!("expected Removed, got {other:?}"),
+    }
+
+    shutdown.cancel();
+    api_shutdown.cancel();
+    watcher.await.unwrap().unwrap();
+}
@@ -0,0 +1,193 @@
+use std::path::Path;
+use std::process::ExitCode;
+
+use console::style;
+
+use infrarust_config::ProxyConfig;
+use infrarust_config::check::{CheckReport, Checker, Diagnostic, Severity, line_col};
+
+/// Limbo handlers registered by the plugins compiled into this binary.
+const BUILTIN_LIMBO_HANDLERS: &[&str] = &[
+    #[cfg(feature = "plugin-auth")]
+    "auth",
+    #[cfg(feature = "plugin-server-wake")]
+    "server_wake",
+    #[cfg(feature = "plugin-hello")]
+    "test-gate",
+];
+
+pub fn run(config_path: &Path) -> ExitCode {
+    let mut checker = Checker::new();
+
+    if let Some(proxy) = checker.check_proxy_file(config_path) {
+        checker.check_servers_dir(&proxy.servers_dir);
+        load_dynamic_providers(&proxy, &mut checker);
+    }
+
+    let report = checker.finish(BUILTIN_LIMBO_HANDLERS);
+    for diagnostic in &report.diagnostics {
+        print!("{}", render(diagnostic, &report));
+    }
+    print_summary(&report);
+
+    if report.error_count() > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Queries the Docker and Kubernetes providers once, without watching.
+#[allow(unused_variables, unused_mut)]
+fn load_dynamic_providers(proxy: &ProxyConfig, checker: &mut Checker) {
+    let mut providers: Vec<Box<dyn infrarust_core::provider::ConfigProvider>> = Vec::new();
+
+    #[cfg(feature = "docker")]
+    if let Some(ref docker) = proxy.docker {
+        match infrarust_core::provider::docker::DockerProvider::new(docker) {
+            Ok(provider) => providers.push(Box::new(provider)),
+            Err(e) => checker.push_warning(format!("docker provider unavailable: {e}")),
+        }
+    }
+    #[cfg(not(feature = "docker"))]
+    if proxy.docker.is_some() {
+        checker.push_warning("[docker] is configured but this binary was built without docker");
+    }
+
+    #[cfg(feature = "kubernetes")]
+    if let Some(ref kube) = proxy.kubernetes {
+        match infrarust_core::provider::kubernetes::KubernetesProvider::new(kube) {
+            Ok(provider) => providers.push(Box::new(provider)),
+            Err(e) => checker.push_warning(format!("kubernetes provider unavailable: {e}")),
+        }
+    }
+    #[cfg(not(feature = "kubernetes"))]
+    if proxy.kubernetes.is_some() {
+        checker.push_warning(
+            "[kubernetes] is configured but this binary was built without kubernetes",
+        );
+    }
+
+    if providers.is_empty() {
+        return;
+    }
+
+    let runtime = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            checker.push_warning(format!("cannot query discovery providers: {e}"));
+            return;
+        }
+    };
+
+    for provider in providers {
+        match runtime.block_on(provider.load_initial()) {
+            Ok(configs) => {
+                for pc in configs {
+                    checker.add_provider_config(pc.id.to_string(), pc.config);
+                }
+            }
+            Err(e) => checker.push_warning(format!(
+                "{} provider could not be queried: {e}",
+                provider.provider_type()
+            )),
+        }
+    }
+}
+
+/// Renders a diagnostic the way rustc does.
+fn render(diagnostic: &Diagnostic, report: &CheckReport) -> String {
+    use std::fmt::Write;
+
+    let header = match diagnostic.severity {
+        Severity::Error => style("error").red().bold(),
+        Severity::Warning => style("warning").yellow().bold(),
+    };
+    let mut out = format!(
+        "{header}{} {}\n",
+        style(":").bold(),
+        style(&diagnostic.message).bold()
+    );
+
+    let source = diagnostic
+        .file
+        .as_ref()
+        .and_then(|file| report.sources.get(file).map(|content| (file, content)));
+    let arrow = style("-->").blue().bold();
+
+    match (source, &diagnostic.span) {
+        (Some((file, content)), Some(span)) => {
+            let (line, column) = line_col(content, span.start);
+            let text = content.lines().nth(line - 1).unwrap_or_default();
+            let gutter = " ".repeat(line.to_string().len());
+            let bar = style("|").blue().bold();
+
+            let width = content[span.clone()]
+                .lines()
+                .next()
+                .map_or(1, |l| l.chars().count().max(1));
+            let marker = "^".repeat(width);
+            let marker = match diagnostic.severity {
+                Severity::Error => style(marker).red().bold(),
+                Severity::Warning => style(marker).yellow().bold(),
+            };
+
+            let _ = writeln!(out, "{gutter}{arrow} {}:{line}:{column}", file.display());
+            let _ = writeln!(out, "{gutter} {bar}");
+            let _ = writeln!(out, "{} {bar} {text}", style(line).blue().bold());
+            let _ = write!(out, "{gutter} {bar} {}{marker}", " ".repeat(column - 1));
+            match &diagnostic.label {
+                Some(label) => {
+                    let _ = writeln!(out, " {label}");
+                }
+                None => out.push('\n'),
+            }
+            if diagnostic.help.is_some() {
+                let _ = writeln!(out, "{gutter} {bar}");
+            }
+        }
+        _ => {
+            if let Some(file) = &diagnostic.file {
+                let _ = writeln!(out, " {arrow} {}", file.display());
+            }
+        }
+    }
+
+    if let Some(help) = &diagnostic.help {
+        let _ = writeln!(
+            out,
+            "  {} {}: {help}",
+            style("=").blue().bold(),
+            style("help").bold()
+        );
+    }
+    out.push('\n');
+    out
+}
+
+fn print_summary(report: &CheckReport) {
+    let errors = report.error_count();
+    let warnings = report.warning_count();
+
+    if errors == 0 && warnings == 0 {
+        println!(
+            "{} {} server config(s) checked, no problems found",
+            style("ok:").green().bold(),
+            report.servers_checked
+        );
+        return;
+    }
+
+    let status = if errors > 0 {
+        style("failed:").red().bold()
+    } else {
+        style("ok:").green().bold()
+    };
+    println!(
+        "{status} {} server config(s) checked, {errors} error(s), {warnings} warning(s)",
+        report.servers_checked
+    );
+}
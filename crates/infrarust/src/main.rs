@@ -23,8 +23,10 @@ use infrarust_core::services::scheduler::SchedulerImpl;
 use infrarust_core::services::server_manager_bridge::{NoopServerManager, ServerManagerBridge};
 use infrarust_core::telemetry::formatter::InfrarustFormatter;
 
+mod check;
 mod migrate;
 mod plugins;
+mod schema;
 mod wizard;
 
 /// Infrarust — A Minecraft reverse proxy
@@ -53,13 +55,32 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Command {
-    /// Migrate V1 proxy configs (YAML) to V2 server configs (TOML)
+    /// Validate the proxy config and every server config without starting the proxy
+    Check,
+    /// Print the JSON Schema of a configuration file
+    Schema {
+        /// Which configuration file to describe
+        #[arg(value_enum, default_value_t = schema::SchemaTarget::Proxy)]
+        target: schema::SchemaTarget,
+        /// Write the schema to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
+    /// Migrate V1, Velocity or BungeeCord configs to V2 configs (TOML)
     Migrate {
+        /// V1 server config directory, `velocity.toml`, or BungeeCord `config.yml`
         input: std::path::PathBuf,
         #[arg(short, long, default_value = "./servers")]
         output: std::path::PathBuf,
+        /// V1 global config to migrate alongside the server configs
         #[arg(long)]
         config: Option<std::path::PathBuf>,
+        /// Configuration format of `input`
+        #[arg(long, value_enum, default_value_t = migrate::MigrateSource::V1)]
+        from: migrate::MigrateSource,
+        /// Where to write the proxy config when migrating from Velocity or BungeeCord
+        #[arg(long, default_value = "infrarust.toml")]
+        proxy_output: std::path::PathBuf,
     },
 }
 
@@ -67,13 +88,26 @@ enum Command {
 fn main() -> ExitCode {
     let cli = Cli::parse();
 
+    if let Some(Command::Check) = &cli.command {
+        return check::run(&cli.config);
+    }
+
+    if let Some(Command::Schema { target, output }) = &cli.command {
+        return schema::run(*target, output.as_deref());
+    }
+
     if let Some(Command::Migrate {
         input,
         output,
         config,
+        from,
+        proxy_output,
     }) = &cli.command
     {
-        return migrate::run(input, output, config.as_deref());
+        return match from {
+            migrate::MigrateSource::V1 => migrate::run(input, output, config.as_deref()),
+            source => migrate::run_external(*source, input, output, proxy_output),
+        };
     }
 
     let config = if !cli.config.exists()
@@ -264,6 +298,7 @@ fn build_proxy_info(config: &ProxyConfig) -> infrarust_api::services::proxy_info
         },
         telemetry_enabled: config.telemetry.as_ref().is_some_and(|t| t.enabled),
         docker_enabled: config.docker.is_some(),
+        kubernetes_enabled: config.kubernetes.is_some(),
         web_api_enabled: config.web.as_ref().is_some_and(|w| w.enable_api),
         web_ui_enabled: config.web.as_ref().is_some_and(|w| w.enable_webui),
         unknown_domain_behavior: match config.unknown_domain_behavior {
@@ -337,6 +372,10 @@ async fn run(config: ProxyConfig) -> anyhow::Result<()> {
         command_manager: Arc::clone(&services.command_manager)
             as Arc<dyn infrarust_api::command::CommandManager>,
         scheduler: Arc::new(SchedulerImpl::new()),
+        tab_list: Arc::clone(&services.tab_list)
+            as Arc<dyn infrarust_api::services::tab_list::TabList>,
+        player_data: Arc::clone(&services.player_data)
+            as Arc<dyn infrarust_api::services::player_data::PlayerDataStore>,
         config_service: Arc::new(ConfigServiceImpl::new(Arc::clone(&services.domain_router))),
         plugin_registry: Arc::clone(&plugin_registry)
             as Arc<dyn infrarust_api::services::plugin_registry::PluginRegistry>,
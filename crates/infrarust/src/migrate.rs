@@ -3,6 +3,17 @@ use std::process::ExitCode;
 
 use infrarust_config::migrate::{MigrationSeverity, MigrationWarning};
 
+/// Configuration format accepted by `infrarust migrate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum MigrateSource {
+    /// Infrarust V1 YAML configs
+    V1,
+    /// Velocity `velocity.toml`
+    Velocity,
+    /// BungeeCord / Waterfall `config.yml`
+    Bungeecord,
+}
+
 pub fn run(input: &Path, output: &Path, config: Option<&Path>) -> ExitCode {
     let mut has_errors = false;
 
@@ -49,6 +60,50 @@ pub fn run(input: &Path, output: &Path, config: Option<&Path>) -> ExitCode {
     }
 }
 
+pub fn run_external(
+    source: MigrateSource,
+    input: &Path,
+    servers_dir: &Path,
+    proxy_output: &Path,
+) -> ExitCode {
+    let (label, result) = match source {
+        MigrateSource::Velocity => (
+            "Velocity",
+            infrarust_config::migrate::migrate_velocity_config(input, proxy_output, servers_dir),
+        ),
+        MigrateSource::Bungeecord => (
+            "BungeeCord",
+            infrarust_config::migrate::migrate_bungeecord_config(input, proxy_output, servers_dir),
+        ),
+        MigrateSource::V1 => unreachable!("V1 migration is handled by `run`"),
+    };
+
+    println!("Migrating {label} config: {}", input.display());
+    println!("Proxy config: {}", proxy_output.display());
+    println!("Servers directory: {}", servers_dir.display());
+    println!();
+
+    let mut has_errors = false;
+    match result {
+        Ok(warnings) => {
+            print_warnings(&warnings, &mut has_errors);
+            if let Some(summary) = warnings.iter().find(|w| w.file == "summary") {
+                println!("\n{}", summary.message);
+            }
+        }
+        Err(e) => {
+            eprintln!("{label} migration failed: {e}");
+            has_errors = true;
+        }
+    }
+
+    if has_errors {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
 fn print_warnings(warnings: &[MigrationWarning], has_errors: &mut bool) {
     for w in warnings {
         if w.file == "summary" {
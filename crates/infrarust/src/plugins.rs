@@ -35,6 +35,14 @@ pub fn build_static_loader(
         });
     }
 
+    #[cfg(feature = "plugin-chat")]
+    {
+        use infrarust_api::plugin::Plugin;
+        loader.register(infrarust_plugin_chat::ChatPlugin.metadata(), || {
+            Box::new(infrarust_plugin_chat::ChatPlugin)
+        });
+    }
+
     // Admin API: always compiled, conditionally registered based on [web] config
     if let Some(web) = web_config {
         use infrarust_api::plugin::Plugin;
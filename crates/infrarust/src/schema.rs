@@ -0,0 +1,39 @@
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Configuration file a schema is generated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum SchemaTarget {
+    /// `infrarust.toml`
+    Proxy,
+    /// Server config files in `servers_dir`
+    Server,
+}
+
+pub fn run(target: SchemaTarget, output: Option<&Path>) -> ExitCode {
+    let schema = match target {
+        SchemaTarget::Proxy => infrarust_config::schema::proxy_config_schema(),
+        SchemaTarget::Server => infrarust_config::schema::server_config_schema(),
+    };
+
+    let json = match serde_json::to_string_pretty(&schema) {
+        Ok(json) => json,
+        Err(e) => {
+            eprintln!("failed to serialize schema: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match output {
+        Some(path) => {
+            if let Err(e) = std::fs::write(path, format!("{json}\n")) {
+                eprintln!("cannot write {}: {e}", path.display());
+                return ExitCode::FAILURE;
+            }
+            println!("Schema written to {}", path.display());
+        }
+        None => println!("{json}"),
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,754 @@
+//! Offline configuration checks (`infrarust check`).
+//!
+//! Loads the proxy config and server configs without starting anything,
+//! runs the per-file validation from [`crate::validation`] and the
+//! cross-file checks that only make sense once every server is known.
+//! Each problem becomes a [`Diagnostic`] pointing into its source file.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use wildmatch::WildMatch;
+
+use crate::error::ConfigError;
+use crate::proxy::ProxyConfig;
+use crate::server::{LIMBO_SERVER_ID, ServerConfig};
+use crate::types::{ForwardingMode, MotdConfig};
+
+/// How serious a diagnostic is. Only errors make `infrarust check` fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// A single problem found in the configuration.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    /// File the problem was found in, `None` for configs from dynamic providers.
+    pub file: Option<PathBuf>,
+    /// Byte range in the file content.
+    pub span: Option<Range<usize>>,
+    /// Short note printed under the span.
+    pub label: Option<String>,
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            file: None,
+            span: None,
+            label: None,
+            help: None,
+        }
+    }
+
+    fn at(mut self, source: &Source, span: Option<Range<usize>>) -> Self {
+        self.file = source.path.clone();
+        self.span = span;
+        self
+    }
+
+    fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+}
+
+/// Where a server config came from.
+#[derive(Debug, Clone)]
+struct Source {
+    path: Option<PathBuf>,
+    content: String,
+    /// Display name for configs without a file (e.g. `docker@mc-lobby`).
+    origin: String,
+}
+
+struct CheckedServer {
+    source: Source,
+    config: ServerConfig,
+}
+
+/// Result of a check run.
+#[derive(Debug, Default)]
+pub struct CheckReport {
+    pub diagnostics: Vec<Diagnostic>,
+    /// Content of every file read, keyed by path, for rendering snippets.
+    pub sources: HashMap<PathBuf, String>,
+    /// Number of server configs that parsed successfully.
+    pub servers_checked: usize,
+}
+
+impl CheckReport {
+    pub fn error_count(&self) -> usize {
+        self.count(Severity::Error)
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.count(Severity::Warning)
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == severity)
+            .count()
+    }
+}
+
+/// Accumulates configs and diagnostics, then runs the cross-file checks.
+#[derive(Default)]
+pub struct Checker {
+    proxy: Option<ProxyConfig>,
+    proxy_source: Option<Source>,
+    servers: Vec<CheckedServer>,
+    diagnostics: Vec<Diagnostic>,
+    sources: HashMap<PathBuf, String>,
+}
+
+impl Checker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses and validates the proxy config. Returns it when it parsed.
+    pub fn check_proxy_file(&mut self, path: &Path) -> Option<ProxyConfig> {
+        let source = self.read(path)?;
+        let proxy: ProxyConfig = match toml::from_str(&source.content) {
+            Ok(proxy) => proxy,
+            Err(e) => {
+                self.parse_error(&source, &e);
+                return None;
+            }
+        };
+
+        // Checked separately so a missing directory doesn't hide bad packs.
+        let results = [
+            crate::validation::validate_servers_dir(&proxy),
+            crate::validation::validate_proxy_resource_packs(&proxy),
+        ];
+        for error in results.into_iter().filter_map(Result::err) {
+            self.proxy_error(&source, error);
+        }
+
+        self.proxy = Some(proxy.clone());
+        self.proxy_source = Some(source);
+        Some(proxy)
+    }
+
+    /// Loads every `.toml` file in `dir` like the file provider does.
+    pub fn check_servers_dir(&mut self, dir: &Path) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(Result::ok)
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == "toml"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            self.check_server_file(&path);
+        }
+    }
+
+    /// Parses and validates a single server config file.
+    pub fn check_server_file(&mut self, path: &Path) {
+        let Some(source) = self.read(path) else {
+            return;
+        };
+        let mut config: ServerConfig = match toml::from_str(&source.content) {
+            Ok(config) => config,
+            Err(e) => {
+                self.parse_error(&source, &e);
+                return;
+            }
+        };
+        if config.id.is_none() {
+            config.id = path.file_stem().and_then(|s| s.to_str()).map(String::from);
+        }
+        self.add(source, config);
+    }
+
+    /// Adds a config produced by a dynamic provider (Docker, Kubernetes...).
+    pub fn add_provider_config(&mut self, origin: impl Into<String>, config: ServerConfig) {
+        let source = Source {
+            path: None,
+            content: String::new(),
+            origin: origin.into(),
+        };
+        self.add(source, config);
+    }
+
+    /// Records a problem found outside of this module (e.g. a provider
+    /// that could not be queried).
+    pub fn push_warning(&mut self, message: impl Into<String>) {
+        self.diagnostics
+            .push(Diagnostic::new(Severity::Warning, message));
+    }
+
+    /// Runs the cross-file checks and returns the report.
+    ///
+    /// `limbo_handlers` lists the handler names known to be registered by
+    /// the plugins compiled into the binary.
+    pub fn finish(mut self, limbo_handlers: &[&str]) -> CheckReport {
+        self.check_duplicate_ids();
+        self.check_domains();
+        self.check_networks();
+        self.check_limbo_handlers(limbo_handlers);
+        self.check_fallbacks();
+        self.check_forwarding_secret();
+        self.check_favicons();
+
+        CheckReport {
+            servers_checked: self.servers.len(),
+            diagnostics: self.diagnostics,
+            sources: self.sources,
+        }
+    }
+
+    fn read(&mut self, path: &Path) -> Option<Source> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => {
+                self.sources.insert(path.to_path_buf(), content.clone());
+                Some(Source {
+                    path: Some(path.to_path_buf()),
+                    content,
+                    origin: path.display().to_string(),
+                })
+            }
+            Err(e) => {
+                let mut diagnostic = Diagnostic::new(
+                    Severity::Error,
+                    format!("cannot read `{}`: {e}", path.display()),
+                );
+                diagnostic.file = Some(path.to_path_buf());
+                self.diagnostics.push(diagnostic);
+                None
+            }
+        }
+    }
+
+    fn parse_error(&mut self, source: &Source, error: &toml::de::Error) {
+        self.diagnostics.push(
+            Diagnostic::new(Severity::Error, error.message().trim().to_string())
+                .at(source, error.span()),
+        );
+    }
+
+    fn proxy_error(&mut self, source: &Source, error: ConfigError) {
+        let diagnostic = match error {
+            ConfigError::DirectoryNotFound(dir) => Diagnostic::new(
+                Severity::Error,
+                format!("servers directory `{}` does not exist", dir.display()),
+            )
+            .at(source, key_span(&source.content, "servers_dir"))
+            .label("configured here")
+            .help("relative paths are resolved from the working directory"),
+            error => {
+                let span = validation_key(&error).and_then(|key| key_span(&source.content, key));
+                Diagnostic::new(Severity::Error, error.to_string()).at(source, span)
+            }
+        };
+        self.diagnostics.push(diagnostic);
+    }
+
+    fn add(&mut self, source: Source, config: ServerConfig) {
+        if let Err(e) = crate::validate_server_config(&config) {
+            let span = validation_key(&e).and_then(|key| key_span(&source.content, key));
+            self.diagnostics
+                .push(Diagnostic::new(Severity::Error, e.to_string()).at(&source, span));
+        }
+        self.servers.push(CheckedServer { source, config });
+    }
+
+    fn check_duplicate_ids(&mut self) {
+        let mut seen: HashMap<String, &CheckedServer> = HashMap::new();
+        for server in &self.servers {
+            let id = server.config.effective_id();
+            if let Some(first) = seen.get(&id) {
+                let key = if server.config.name.is_some() {
+                    "name"
+                } else {
+                    "id"
+                };
+                self.diagnostics.push(
+                    Diagnostic::new(Severity::Error, format!("duplicate server id `{id}`"))
+                        .at(&server.source, key_span(&server.source.content, key))
+                        .label(format!("also used by {}", first.source.origin)),
+                );
+            } else {
+                seen.insert(id, server);
+            }
+        }
+    }
+
+    fn check_domains(&mut self) {
+        // (normalized domain, server index)
+        let mut exact: BTreeMap<String, usize> = BTreeMap::new();
+        let mut wildcards: Vec<(String, usize)> = Vec::new();
+        let mut diagnostics = Vec::new();
+
+        for (index, server) in self.servers.iter().enumerate() {
+            for domain in &server.config.domains {
+                let normalized = domain.to_lowercase();
+                let is_wildcard = normalized.contains('*') || normalized.contains('?');
+                let previous = if is_wildcard {
+                    wildcards
+                        .iter()
+                        .find(|(d, _)| *d == normalized)
+                        .map(|(_, i)| *i)
+                } else {
+                    exact.get(&normalized).copied()
+                };
+
+                if let Some(other) = previous.filter(|other| *other != index) {
+                    diagnostics.push(
+                        Diagnostic::new(
+                            Severity::Error,
+                            format!("domain `{domain}` is routed to more than one server"),
+                        )
+                        .at(&server.source, value_span(&server.source.content, domain))
+                        .label(format!(
+                            "also used by {}",
+                            self.servers[other].source.origin
+                        ))
+                        .help("only one of the servers would ever receive these players"),
+                    );
+                    continue;
+                }
+
+                if is_wildcard {
+                    let matcher = WildMatch::new(&normalized);
+                    for (pattern, other) in &wildcards {
+                        if *other == index {
+                            continue;
+                        }
+                        if matcher.matches(pattern) || WildMatch::new(pattern).matches(&normalized)
+                        {
+                            diagnostics.push(
+                                Diagnostic::new(
+                                    Severity::Warning,
+                                    format!(
+                                        "wildcard `{domain}` overlaps with `{pattern}` from {}",
+                                        self.servers[*other].source.origin
+                                    ),
+                                )
+                                .at(&server.source, value_span(&server.source.content, domain))
+                                .label("overlapping pattern")
+                                .help(
+                                    "wildcards are tried in load order: the server that \
+                                     receives overlapping hostnames may change on reload",
+                                ),
+                            );
+                        }
+                    }
+                    wildcards.push((normalized, index));
+                } else {
+                    exact.insert(normalized, index);
+                }
+            }
+        }
+
+        self.diagnostics.extend(diagnostics);
+    }
+
+    fn check_networks(&mut self) {
+        let mut members: BTreeMap<&str, Vec<&CheckedServer>> = BTreeMap::new();
+        for server in &self.servers {
+            if let Some(network) = &server.config.network {
+                members.entry(network).or_default().push(server);
+            }
+        }
+
+        for (network, servers) in members {
+            if let [server] = servers.as_slice() {
+                self.diagnostics.push(
+                    Diagnostic::new(
+                        Severity::Warning,
+                        format!(
+                            "network `{network}` only contains server `{}`",
+                            server.config.effective_id()
+                        ),
+                    )
+                    .at(&server.source, key_span(&server.source.content, "network"))
+                    .label("no other server references this network")
+                    .help(
+                        "servers can only switch to servers of the same network; check for a typo",
+                    ),
+                );
+            }
+        }
+    }
+
+    fn check_limbo_handlers(&mut self, known: &[&str]) {
+        for server in &self.servers {
+            for handler in &server.config.limbo_handlers {
+                if known.contains(&handler.as_str()) {
+                    continue;
+                }
+                self.diagnostics.push(
+                    Diagnostic::new(
+                        Severity::Warning,
+                        format!("unknown limbo handler `{handler}`"),
+                    )
+                    .at(&server.source, value_span(&server.source.content, handler))
+                    .label("not registered by any built-in plugin")
+                    .help(format!(
+                        "built-in handlers: {}; external plugins may register others",
+                        known.join(", ")
+                    )),
+                );
+            }
+        }
+    }
+
+    fn check_fallbacks(&mut self) {
+        let ids: HashSet<String> = self
+            .servers
+            .iter()
+            .map(|s| s.config.effective_id())
+            .collect();
+        for server in &self.servers {
+            for fallback in &server.config.fallbacks {
+                if fallback == LIMBO_SERVER_ID || ids.contains(fallback) {
+                    continue;
+                }
+                self.diagnostics.push(
+                    Diagnostic::new(
+                        Severity::Warning,
+                        format!("unknown fallback server `{fallback}`"),
+                    )
+                    .at(&server.source, value_span(&server.source.content, fallback))
+                    .label("no server has this id")
+                    .help(format!(
+                        "use a server id or `{LIMBO_SERVER_ID}`; unknown fallbacks are skipped"
+                    )),
+                );
+            }
+        }
+    }
+
+    fn check_forwarding_secret(&mut self) {
+        let Some(proxy) = &self.proxy else {
+            return;
+        };
+        let global = proxy.forwarding.as_ref();
+        let needs_secret = |mode: &ForwardingMode| {
+            matches!(mode, ForwardingMode::Velocity | ForwardingMode::BungeeGuard)
+        };
+        let used = global.is_some_and(|f| needs_secret(&f.mode))
+            || self
+                .servers
+                .iter()
+                .any(|s| s.config.forwarding_mode.as_ref().is_some_and(needs_secret));
+        if !used {
+            return;
+        }
+
+        let secret_file = global.map_or_else(
+            || PathBuf::from("forwarding.secret"),
+            |f| f.secret_file.clone(),
+        );
+        let source = self.proxy_source.as_ref();
+        let span = source.and_then(|s| key_span(&s.content, "secret_file"));
+        let diagnostic = match std::fs::read_to_string(&secret_file) {
+            Ok(content) if content.trim().is_empty() => Diagnostic::new(
+                Severity::Error,
+                format!(
+                    "forwarding secret file `{}` is empty",
+                    secret_file.display()
+                ),
+            ),
+            Ok(_) => return,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Diagnostic::new(
+                Severity::Warning,
+                format!(
+                    "forwarding secret file `{}` does not exist",
+                    secret_file.display()
+                ),
+            )
+            .help("a random secret is generated on startup; backends must be given the same one"),
+            Err(e) => Diagnostic::new(
+                Severity::Error,
+                format!(
+                    "cannot read forwarding secret file `{}`: {e}",
+                    secret_file.display()
+                ),
+            ),
+        };
+
+        let diagnostic = match source {
+            Some(source) => diagnostic.at(source, span),
+            None => diagnostic,
+        };
+        self.diagnostics.push(diagnostic);
+    }
+
+    fn check_favicons(&mut self) {
+        let mut diagnostics = Vec::new();
+        if let (Some(proxy), Some(source)) = (&self.proxy, &self.proxy_source)
+            && let Some(motd) = &proxy.default_motd
+        {
+            missing_favicons(motd, source, &mut diagnostics);
+        }
+        for server in &self.servers {
+            missing_favicons(&server.config.motd, &server.source, &mut diagnostics);
+        }
+        self.diagnostics.extend(diagnostics);
+    }
+}
+
+fn missing_favicons(motd: &MotdConfig, source: &Source, out: &mut Vec<Diagnostic>) {
+    let entries = [
+        &motd.online,
+        &motd.offline,
+        &motd.sleeping,
+        &motd.starting,
+        &motd.crashed,
+        &motd.stopping,
+        &motd.unreachable,
+    ];
+    for favicon in entries
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.favicon.as_deref())
+    {
+        if is_favicon_path(favicon) && !Path::new(favicon).is_file() {
+            out.push(
+                Diagnostic::new(
+                    Severity::Error,
+                    format!("favicon file `{favicon}` does not exist"),
+                )
+                .at(source, value_span(&source.content, favicon))
+                .label("file not found")
+                .help("relative paths are resolved from the working directory"),
+            );
+        }
+    }
+}
+
+/// Same heuristic the status cache uses to tell a path from inline base64.
+fn is_favicon_path(value: &str) -> bool {
+    if value.starts_with("data:") {
+        return false;
+    }
+    value.contains('/')
+        || value.contains('\\')
+        || Path::new(value).extension().is_some_and(|ext| {
+            ["png", "jpg", "jpeg", "gif", "ico"]
+                .iter()
+                .any(|e| ext.eq_ignore_ascii_case(e))
+        })
+}
+
+/// Key most likely responsible for a [`validate_server_config`] error.
+///
+/// [`validate_server_config`]: crate::validate_server_config
+fn validation_key(error: &ConfigError) -> Option<&'static str> {
+    match error {
+        ConfigError::NoDomains { .. } => Some("proxy_mode"),
+        ConfigError::NoAddresses { .. } => Some("addresses"),
+        ConfigError::Validation(message) => {
+            if message.contains("resource pack") {
+                Some("resource_packs")
+            } else if message.contains("empty domain") {
+                Some("domains")
+            } else if message.contains("network") {
+                Some("network")
+            } else if message.contains("name") {
+                Some("name")
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Span of the first `key = ...` assignment or `[key]` / `[[key]]` table
+/// header, covering the key.
+fn key_span(content: &str, key: &str) -> Option<Range<usize>> {
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        let brackets = trimmed.len() - trimmed.trim_start_matches('[').len();
+        if let Some(rest) = trimmed[brackets..].strip_prefix(key)
+            && (brackets == 0 && rest.trim_start().starts_with('=')
+                || brackets > 0 && rest.starts_with(']'))
+        {
+            let start = offset + (line.len() - trimmed.len()) + brackets;
+            return Some(start..start + key.len());
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Span of the first quoted string equal to `value`, quotes included.
+fn value_span(content: &str, value: &str) -> Option<Range<usize>> {
+    ['"', '\''].iter().find_map(|quote| {
+        let needle = format!("{quote}{value}{quote}");
+        content
+            .find(&needle)
+            .map(|start| start..start + needle.len())
+    })
+}
+
+/// Converts a byte offset to a 1-based `(line, column)` pair.
+pub fn line_col(content: &str, offset: usize) -> (usize, usize) {
+    let offset = offset.min(content.len());
+    let before = &content[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rfind('\n').map_or(before.chars().count(), |nl| {
+        before[nl + 1..].chars().count()
+    }) + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, name: &str, content: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn messages(report: &CheckReport) -> Vec<&str> {
+        report
+            .diagnostics
+            .iter()
+            .map(|d| d.message.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_error_has_span() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "lobby.toml",
+            "addresses = [\"127.0.0.1:25565\"]\nprxy_mode = \"passthrough\"\n",
+        );
+
+        let mut checker = Checker::new();
+        checker.check_servers_dir(dir.path());
+        let report = checker.finish(&[]);
+
+        assert_eq!(report.error_count(), 1);
+        let diagnostic = &report.diagnostics[0];
+        let content = &report.sources[diagnostic.file.as_ref().unwrap()];
+        let span = diagnostic.span.clone().unwrap();
+        assert_eq!(line_col(content, span.start), (2, 1));
+        assert!(diagnostic.message.contains("prxy_mode"));
+    }
+
+    #[test]
+    fn test_duplicate_domain_and_overlapping_wildcards() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "a.toml",
+            "domains = [\"play.example.com\", \"*.example.com\"]\naddresses = [\"10.0.0.1:25565\"]\n",
+        );
+        write(
+            dir.path(),
+            "b.toml",
+            "domains = [\"PLAY.example.com\", \"*.mc.example.com\"]\naddresses = [\"10.0.0.2:25565\"]\n",
+        );
+
+        let mut checker = Checker::new();
+        checker.check_servers_dir(dir.path());
+        let report = checker.finish(&[]);
+
+        assert_eq!(report.error_count(), 1);
+        assert_eq!(report.warning_count(), 1);
+        let messages = messages(&report);
+        assert!(messages[0].starts_with("domain `PLAY.example.com`"));
+        assert!(messages[1].starts_with("wildcard `*.mc.example.com` overlaps"));
+    }
+
+    #[test]
+    fn test_validation_error_points_at_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let content = "addresses = [\"10.0.0.1:25565\"]\nproxy_mode = \"passthrough\"\n";
+        write(dir.path(), "fwd.toml", content);
+
+        let mut checker = Checker::new();
+        checker.check_servers_dir(dir.path());
+        let report = checker.finish(&[]);
+
+        let span = report.diagnostics[0].span.clone().unwrap();
+        assert_eq!(&content[span], "proxy_mode");
+    }
+
+    #[test]
+    fn test_invalid_proxy_resource_pack() {
+        let dir = tempfile::tempdir().unwrap();
+        let content = "servers_dir = \"missing\"\n\n[[resource_packs]]\nurl = \"ftp://packs.example.com/pack.zip\"\n";
+        let path = write(dir.path(), "infrarust.toml", content);
+
+        let mut checker = Checker::new();
+        checker.check_proxy_file(&path);
+        let report = checker.finish(&[]);
+
+        assert_eq!(report.error_count(), 2);
+        let messages = messages(&report);
+        assert!(messages[0].starts_with("servers directory `missing`"));
+        assert!(messages[1].contains("must start with http:// or https://"));
+        let span = report.diagnostics[1].span.clone().unwrap();
+        assert_eq!(&content[span], "resource_packs");
+    }
+
+    #[test]
+    fn test_cross_file_warnings() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "lobby.toml",
+            "network = \"mian\"\nproxy_mode = \"client_only\"\naddresses = [\"10.0.0.1:25565\"]\nlimbo_handlers = [\"auth\", \"captcha\"]\nfallbacks = [\"survival\", \"hub\", \"$limbo\"]\n",
+        );
+        write(
+            dir.path(),
+            "survival.toml",
+            "network = \"main\"\nproxy_mode = \"client_only\"\naddresses = [\"10.0.0.2:25565\"]\n\n[motd.online]\ntext = \"hi\"\nfavicon = \"icons/missing.png\"\n",
+        );
+
+        let mut checker = Checker::new();
+        checker.check_servers_dir(dir.path());
+        let report = checker.finish(&["auth"]);
+
+        let messages = messages(&report);
+        assert!(messages.contains(&"network `main` only contains server `survival`"));
+        assert!(messages.contains(&"network `mian` only contains server `lobby`"));
+        assert!(messages.contains(&"unknown limbo handler `captcha`"));
+        assert!(messages.contains(&"unknown fallback server `hub`"));
+        assert!(
+            !messages
+                .iter()
+                .any(|m| m.contains("`survival`") && m.contains("fallback"))
+        );
+        assert!(messages.contains(&"favicon file `icons/missing.png` does not exist"));
+        assert_eq!(report.error_count(), 1);
+    }
+
+    #[test]
+    fn test_line_col() {
+        assert_eq!(line_col("ab\ncd", 4), (2, 2));
+        assert_eq!(line_col("ab", 0), (1, 1));
+    }
+}
@@ -20,6 +20,10 @@ pub fn plugins_dir() -> PathBuf {
     PathBuf::from("./plugins")
 }
 
+pub fn data_dir() -> PathBuf {
+    PathBuf::from("./data")
+}
+
 pub const fn rate_limit_max() -> u32 {
     3
 }
@@ -63,6 +67,51 @@ pub const fn poll_interval() -> Duration {
     Duration::from_secs(5)
 }
 
+pub const fn restart_max_retries() -> u32 {
+    5
+}
+
+pub const fn restart_window() -> Duration {
+    Duration::from_secs(600)
+}
+
+pub const fn restart_backoff() -> Duration {
+    Duration::from_secs(5)
+}
+
+pub const fn restart_max_backoff() -> Duration {
+    Duration::from_secs(300)
+}
+
+pub fn schedule_closed_message() -> String {
+    "This server is closed right now, come back during its opening hours.".to_string()
+}
+
+pub fn schedule_warnings() -> Vec<Duration> {
+    vec![
+        Duration::from_secs(300),
+        Duration::from_secs(60),
+        Duration::from_secs(30),
+        Duration::from_secs(10),
+    ]
+}
+
+pub fn schedule_warning_message() -> String {
+    "&eScheduled server {action} in {time}.".to_string()
+}
+
+pub const fn prewarm_lead() -> Duration {
+    Duration::from_secs(5 * 60)
+}
+
+pub const fn prewarm_history() -> Duration {
+    Duration::from_secs(7 * 24 * 3600)
+}
+
+pub const fn prewarm_min_days() -> u32 {
+    3
+}
+
 pub fn otlp_endpoint() -> String {
     "http://localhost:4317".to_string()
 }
@@ -119,6 +168,34 @@ pub fn docker_endpoint() -> String {
     "unix:///var/run/docker.sock".to_string()
 }
 
+pub const fn http_request_timeout() -> Duration {
+    Duration::from_secs(10)
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| (*v).to_string()).collect()
+}
+
+pub fn http_status_running() -> Vec<String> {
+    strings(&["running", "online", "started", "true"])
+}
+
+pub fn http_status_starting() -> Vec<String> {
+    strings(&["starting"])
+}
+
+pub fn http_status_stopping() -> Vec<String> {
+    strings(&["stopping"])
+}
+
+pub fn http_status_stopped() -> Vec<String> {
+    strings(&["stopped", "offline", "false"])
+}
+
+pub fn http_status_crashed() -> Vec<String> {
+    strings(&["crashed"])
+}
+
 pub const fn docker_poll_interval() -> std::time::Duration {
     std::time::Duration::from_secs(30)
 }
@@ -130,3 +207,51 @@ pub const fn docker_reconnect_delay() -> std::time::Duration {
 pub const fn announce_proxy_commands() -> bool {
     true
 }
+
+pub fn kubernetes_token_file() -> PathBuf {
+    PathBuf::from("/var/run/secrets/kubernetes.io/serviceaccount/token")
+}
+
+pub fn kubernetes_ca_file() -> PathBuf {
+    PathBuf::from("/var/run/secrets/kubernetes.io/serviceaccount/ca.crt")
+}
+
+pub const fn kubernetes_reconnect_delay() -> Duration {
+    Duration::from_secs(5)
+}
+
+pub const fn reconnect_timeout() -> Duration {
+    Duration::from_secs(120)
+}
+
+pub const fn reconnect_poll_interval() -> Duration {
+    Duration::from_secs(2)
+}
+
+pub fn reconnect_kick_reasons() -> Vec<String> {
+    strings(&["Server closed", "server_shutdown", "restarting"])
+}
+
+pub fn reconnect_title() -> String {
+    "&eServer restarting".to_string()
+}
+
+pub fn reconnect_subtitle() -> String {
+    "&7Reconnecting to {server}... ({elapsed}s)".to_string()
+}
+
+pub fn reconnect_timeout_message() -> String {
+    "&cThe server did not come back in time.".to_string()
+}
+
+pub const fn last_server_expiry() -> Duration {
+    Duration::from_secs(30 * 24 * 3600)
+}
+
+pub const fn tab_list_refresh_interval() -> Duration {
+    Duration::from_secs(5)
+}
+
+pub fn resource_pack_kick_message() -> String {
+    "&cYou must accept the resource pack to play here.".to_string()
+}
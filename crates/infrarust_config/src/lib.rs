@@ -1,11 +1,13 @@
 //! Configuration types for the Infrarust Minecraft proxy.
 
+pub mod check;
 pub mod defaults;
 pub mod domain;
 pub mod error;
 pub mod migrate;
 pub mod provider;
 pub mod proxy;
+pub mod schema;
 pub mod server;
 pub mod types;
 pub mod validation;
@@ -15,6 +17,6 @@ pub use domain::DomainIndex;
 pub use error::ConfigError;
 pub use provider::{ConfigChange, ConfigProvider};
 pub use proxy::{ProxyConfig, UnknownDomainBehavior};
-pub use server::ServerConfig;
+pub use server::{LIMBO_SERVER_ID, ServerConfig};
 pub use types::*;
 pub use validation::{validate_proxy_config, validate_server_config, validate_server_configs};
@@ -0,0 +1,420 @@
+//! BungeeCord / Waterfall (`config.yml`) → V2 conversion.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::proxy::{ProxyConfig, UnknownDomainBehavior};
+use crate::types::{
+    ForwardingConfig, ForwardingMode, MotdConfig, MotdEntry, PermissionsConfig, ProxyMode,
+    RateLimitConfig, TimeoutConfig,
+};
+
+use super::convert::MigrationSeverity;
+use super::external::{
+    ExternalMigrationResult, Report, SourceServer, ampersand_to_section, build_servers,
+};
+
+const FILE: &str = "config.yml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct BungeeConfig {
+    pub listeners: Vec<BungeeListener>,
+    pub servers: BTreeMap<String, BungeeServer>,
+    pub ip_forward: Option<bool>,
+    pub online_mode: Option<bool>,
+    /// Milliseconds between connections from the same IP.
+    pub connection_throttle: Option<u64>,
+    pub connection_throttle_limit: Option<u32>,
+    /// Read timeout in milliseconds.
+    pub timeout: Option<u64>,
+    pub server_connect_timeout: Option<u64>,
+    pub player_limit: Option<i64>,
+    pub prevent_proxy_connections: Option<bool>,
+    pub forge_support: Option<bool>,
+    pub network_compression_threshold: Option<i64>,
+    pub disabled_commands: Vec<String>,
+    /// Player name → groups.
+    pub groups: BTreeMap<String, Vec<String>>,
+    /// Group → permission nodes.
+    pub permissions: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct BungeeListener {
+    pub host: Option<String>,
+    pub motd: Option<String>,
+    pub max_players: Option<u32>,
+    pub forced_hosts: BTreeMap<String, String>,
+    pub priorities: Vec<String>,
+    pub proxy_protocol: Option<bool>,
+    pub ping_passthrough: Option<bool>,
+    pub query_enabled: Option<bool>,
+    pub force_default_server: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct BungeeServer {
+    pub address: String,
+    pub motd: Option<String>,
+    pub restricted: Option<bool>,
+}
+
+pub fn convert_bungeecord_config(bungee: &BungeeConfig) -> ExternalMigrationResult {
+    let mut report = Report::new(FILE);
+
+    let default_listener = BungeeListener::default();
+    let listener = bungee.listeners.first().unwrap_or(&default_listener);
+    if bungee.listeners.len() > 1 {
+        report.push(
+            MigrationSeverity::Warning,
+            format!(
+                "{} listeners found; only the first one ({}) was migrated",
+                bungee.listeners.len(),
+                listener.host.as_deref().unwrap_or("default")
+            ),
+        );
+    }
+
+    let bind = match listener.host.as_deref().map(str::parse) {
+        Some(Ok(addr)) => addr,
+        Some(Err(_)) => {
+            report.push(
+                MigrationSeverity::Warning,
+                format!(
+                    "Cannot parse listener host '{}', using the default",
+                    listener.host.as_deref().unwrap_or_default()
+                ),
+            );
+            crate::defaults::bind()
+        }
+        None => crate::defaults::bind(),
+    };
+
+    let default_motd = listener.motd.as_deref().map(|motd| MotdConfig {
+        online: Some(MotdEntry {
+            text: ampersand_to_section(motd),
+            favicon: None,
+            version_name: None,
+            max_players: listener.max_players,
+        }),
+        ..MotdConfig::default()
+    });
+
+    let forwarding_mode = if bungee.ip_forward.unwrap_or(false) {
+        ForwardingMode::BungeeCord
+    } else {
+        ForwardingMode::None
+    };
+
+    let proxy_mode = if bungee.online_mode.unwrap_or(true) {
+        ProxyMode::ClientOnly
+    } else {
+        ProxyMode::Offline
+    };
+
+    // Without ping passthrough, BungeeCord answers pings with the server MOTD
+    let use_server_motd = !listener.ping_passthrough.unwrap_or(false);
+    let servers: Vec<SourceServer<'_>> = bungee
+        .servers
+        .iter()
+        .map(|(name, server)| SourceServer {
+            name,
+            address: &server.address,
+            motd: match &server.motd {
+                Some(motd) if use_server_motd => MotdConfig {
+                    online: Some(MotdEntry {
+                        text: ampersand_to_section(motd),
+                        favicon: None,
+                        version_name: None,
+                        max_players: None,
+                    }),
+                    ..MotdConfig::default()
+                },
+                _ => MotdConfig::default(),
+            },
+        })
+        .collect();
+
+    for (name, server) in &bungee.servers {
+        if server.restricted == Some(true) {
+            report.push(
+                MigrationSeverity::Warning,
+                format!("Server '{name}' is restricted; V2 has no per-server join permission"),
+            );
+        }
+    }
+
+    let forced_hosts: BTreeMap<String, Vec<String>> = listener
+        .forced_hosts
+        .iter()
+        .map(|(domain, server)| (domain.clone(), vec![server.clone()]))
+        .collect();
+
+    if !listener.priorities.is_empty() {
+        report.push(
+            MigrationSeverity::Info,
+            format!(
+                "priorities = {:?} converted to the fallbacks of every server; players still connect through a domain",
+                listener.priorities
+            ),
+        );
+    }
+
+    let connect_timeout = bungee
+        .server_connect_timeout
+        .map(Duration::from_millis)
+        .unwrap_or_else(crate::defaults::connect_timeout);
+
+    let mut servers = build_servers(
+        &servers,
+        &forced_hosts,
+        &listener.priorities,
+        proxy_mode,
+        &mut report,
+    );
+    if let Some(read) = bungee.timeout.map(Duration::from_millis) {
+        for (_, server) in &mut servers {
+            server.timeouts = Some(TimeoutConfig {
+                connect: connect_timeout,
+                read,
+                write: crate::defaults::write_timeout(),
+            });
+        }
+    }
+    for (name, server) in &servers {
+        if server.domains.is_empty() {
+            report.push(
+                MigrationSeverity::Info,
+                format!("Server '{name}' has no forced host, reachable only by server switch"),
+            );
+        }
+    }
+
+    let rate_limit = match bungee.connection_throttle {
+        Some(ms) if ms > 0 => RateLimitConfig {
+            max_connections: bungee.connection_throttle_limit.unwrap_or(3),
+            window: Duration::from_millis(ms),
+            ..RateLimitConfig::default()
+        },
+        _ => RateLimitConfig::default(),
+    };
+
+    // Members of the `admin` group become proxy admins
+    let admins: Vec<String> = bungee
+        .groups
+        .iter()
+        .filter(|(_, groups)| groups.iter().any(|g| g == "admin"))
+        .map(|(player, _)| player.clone())
+        .collect();
+    if !admins.is_empty() {
+        report.push(
+            MigrationSeverity::Info,
+            format!("Members of the 'admin' group {admins:?} added to [permissions] admins"),
+        );
+    }
+    if !bungee.permissions.is_empty() {
+        report.push(
+            MigrationSeverity::Info,
+            "Permission nodes are not migrated; V2 only distinguishes admins and players",
+        );
+    }
+
+    if let Some(limit) = bungee.player_limit
+        && limit > 0
+    {
+        report.push(
+            MigrationSeverity::Warning,
+            format!(
+                "player_limit = {limit} has no global V2 equivalent; use per-server max_players"
+            ),
+        );
+    }
+    if !bungee.disabled_commands.is_empty() {
+        report.push(
+            MigrationSeverity::Warning,
+            format!("disabled_commands {:?} dropped", bungee.disabled_commands),
+        );
+    }
+    if bungee.prevent_proxy_connections == Some(true) {
+        report.push(
+            MigrationSeverity::Warning,
+            "prevent_proxy_connections has no V2 equivalent and was dropped",
+        );
+    }
+    if bungee.network_compression_threshold.is_some() {
+        report.push(
+            MigrationSeverity::Info,
+            "network_compression_threshold is not configurable in V2",
+        );
+    }
+    if listener.query_enabled == Some(true) {
+        report.push(
+            MigrationSeverity::Warning,
+            "GameSpy query is not supported in V2",
+        );
+    }
+    if listener.force_default_server == Some(true) {
+        report.push(
+            MigrationSeverity::Info,
+            "force_default_server has no V2 equivalent",
+        );
+    }
+
+    let config = ProxyConfig {
+        bind,
+        max_connections: 0,
+        connect_timeout,
+        receive_proxy_protocol: listener.proxy_protocol.unwrap_or(false),
+        servers_dir: crate::defaults::servers_dir(),
+        plugins_dir: crate::defaults::plugins_dir(),
+        data_dir: crate::defaults::data_dir(),
+        worker_threads: 0,
+        rate_limit,
+        status_cache: Default::default(),
+        default_motd,
+        telemetry: None,
+        keepalive: Default::default(),
+        so_reuseport: false,
+        ban: Default::default(),
+        docker: None,
+        kubernetes: None,
+        ip_filter: None,
+        unknown_domain_behavior: UnknownDomainBehavior::default(),
+        announce_proxy_commands: crate::defaults::announce_proxy_commands(),
+        forwarding: Some(ForwardingConfig {
+            mode: forwarding_mode,
+            ..ForwardingConfig::default()
+        }),
+        web: None,
+        last_server: None,
+        tab_list: None,
+        resource_packs: Vec::new(),
+        permissions: PermissionsConfig {
+            admins,
+            ..PermissionsConfig::default()
+        },
+        plugins: std::collections::HashMap::new(),
+    };
+
+    ExternalMigrationResult {
+        config,
+        servers,
+        forwarding_secret: None,
+        forwarding_secret_source: None,
+        warnings: report.warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG_YML: &str = r#"
+listeners:
+- query_port: 25577
+  motd: '&1Another Bungee server'
+  tab_list: GLOBAL_PING
+  query_enabled: false
+  proxy_protocol: true
+  forced_hosts:
+    pvp.md-5.net: pvp
+  ping_passthrough: false
+  priorities:
+  - lobby
+  bind_local_address: true
+  host: 0.0.0.0:25577
+  max_players: 1
+  tab_size: 60
+  force_default_server: false
+servers:
+  lobby:
+    motd: '&1Just another BungeeCord - Forced Host'
+    address: localhost:25565
+    restricted: false
+  pvp:
+    address: 10.0.0.2:25566
+    restricted: true
+ip_forward: true
+online_mode: true
+connection_throttle: 4000
+connection_throttle_limit: 3
+timeout: 30000
+server_connect_timeout: 5000
+player_limit: -1
+groups:
+  md_5:
+  - admin
+permissions:
+  default:
+  - bungeecord.command.server
+"#;
+
+    fn convert() -> ExternalMigrationResult {
+        let bungee: BungeeConfig = serde_yml::from_str(CONFIG_YML).unwrap();
+        convert_bungeecord_config(&bungee)
+    }
+
+    #[test]
+    fn test_servers_and_forced_hosts() {
+        let result = convert();
+        let names: Vec<&str> = result.servers.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["lobby", "pvp"]);
+
+        let lobby = &result.servers[0].1;
+        assert!(lobby.domains.is_empty());
+        assert_eq!(
+            lobby.motd.online.as_ref().unwrap().text,
+            "§1Just another BungeeCord - Forced Host"
+        );
+
+        let pvp = &result.servers[1].1;
+        assert_eq!(pvp.domains, vec!["pvp.md-5.net"]);
+        assert_eq!(pvp.proxy_mode, ProxyMode::ClientOnly);
+        // priorities = [lobby]
+        assert_eq!(pvp.fallbacks, vec!["lobby"]);
+        assert!(lobby.fallbacks.is_empty());
+
+        for (_, server) in &result.servers {
+            crate::validation::validate_server_config(server).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_proxy_settings() {
+        let result = convert();
+        let config = &result.config;
+
+        assert_eq!(config.bind, "0.0.0.0:25577".parse().unwrap());
+        assert!(config.receive_proxy_protocol);
+        assert_eq!(
+            config.forwarding.as_ref().unwrap().mode,
+            ForwardingMode::BungeeCord
+        );
+        assert_eq!(config.rate_limit.window, Duration::from_secs(4));
+        assert_eq!(config.permissions.admins, vec!["md_5"]);
+        assert_eq!(
+            config
+                .default_motd
+                .as_ref()
+                .unwrap()
+                .online
+                .as_ref()
+                .unwrap()
+                .text,
+            "§1Another Bungee server"
+        );
+    }
+
+    #[test]
+    fn test_reports_unmapped_settings() {
+        let result = convert();
+        let messages: Vec<&str> = result.warnings.iter().map(|w| w.message.as_str()).collect();
+        assert!(messages.iter().any(|m| m.starts_with("priorities = ")));
+        assert!(messages.iter().any(|m| m.contains("'pvp' is restricted")));
+    }
+}
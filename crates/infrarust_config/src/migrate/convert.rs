@@ -194,6 +194,9 @@ pub fn convert_v1_to_v2(v1: &V1ServerConfig, filename: &str) -> MigrationResult
         ip_filter,
         disconnect_message: None,
         limbo_handlers: Vec::new(),
+        fallbacks: Vec::new(),
+        reconnect: None,
+        resource_packs: Vec::new(),
     };
 
     MigrationResult { config, warnings }
@@ -355,6 +358,10 @@ fn convert_server_manager(
                 shutdown_timeout: crate::defaults::shutdown_timeout(),
                 shutdown_after,
                 start_timeout: crate::defaults::start_timeout(),
+                restart: crate::types::RestartConfig::default(),
+                schedule: crate::types::ScheduleConfig::default(),
+                evacuate_to: None,
+                wake: crate::types::WakeConfig::default(),
             }))
         }
         "pterodactyl" => {
@@ -380,6 +387,10 @@ fn convert_server_manager(
                     shutdown_after,
                     start_timeout: crate::defaults::start_timeout(),
                     poll_interval: crate::defaults::poll_interval(),
+                    restart: crate::types::RestartConfig::default(),
+                    schedule: crate::types::ScheduleConfig::default(),
+                    evacuate_to: None,
+                    wake: crate::types::WakeConfig::default(),
                 },
             ))
         }
@@ -407,6 +418,10 @@ fn convert_server_manager(
                     shutdown_after,
                     start_timeout: crate::defaults::start_timeout(),
                     poll_interval: crate::defaults::poll_interval(),
+                    restart: crate::types::RestartConfig::default(),
+                    schedule: crate::types::ScheduleConfig::default(),
+                    evacuate_to: None,
+                    wake: crate::types::WakeConfig::default(),
                 },
             ))
         }
@@ -710,6 +725,7 @@ pub fn convert_v1_proxy_config(v1: &V1InfrarustConfig) -> ProxyMigrationResult {
         receive_proxy_protocol,
         servers_dir,
         plugins_dir: crate::defaults::plugins_dir(),
+        data_dir: crate::defaults::data_dir(),
         worker_threads: 0,
         rate_limit,
         status_cache,
@@ -719,11 +735,15 @@ pub fn convert_v1_proxy_config(v1: &V1InfrarustConfig) -> ProxyMigrationResult {
         so_reuseport: false,
         ban,
         docker,
+        kubernetes: None,
         unknown_domain_behavior: Default::default(),
         announce_proxy_commands: crate::defaults::announce_proxy_commands(),
         forwarding: None,
         ip_filter: None,
         web: None,
+        last_server: None,
+        tab_list: None,
+        resource_packs: Vec::new(),
         permissions: Default::default(),
         plugins: std::collections::HashMap::new(),
     };
@@ -0,0 +1,395 @@
+//! Shared helpers for migrating from other proxies (Velocity, BungeeCord).
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+
+use crate::proxy::ProxyConfig;
+use crate::server::ServerConfig;
+use crate::types::{MotdConfig, ProxyMode, ServerAddress};
+
+use super::convert::{MigrationSeverity, MigrationWarning};
+
+/// Network assigned to every migrated server so `/server` switching keeps
+/// working like it did on the source proxy.
+pub const MIGRATED_NETWORK: &str = "main";
+
+/// Result of converting another proxy's configuration.
+pub struct ExternalMigrationResult {
+    pub config: ProxyConfig,
+    /// Server configs keyed by output file stem (without `.toml`).
+    pub servers: Vec<(String, ServerConfig)>,
+    /// Inline forwarding secret to write to `config.forwarding.secret_file`.
+    pub forwarding_secret: Option<String>,
+    /// Forwarding secret file referenced by the source config, relative to it.
+    pub forwarding_secret_source: Option<PathBuf>,
+    pub warnings: Vec<MigrationWarning>,
+}
+
+/// Collects warnings for a single source file.
+pub(crate) struct Report {
+    file: String,
+    pub(crate) warnings: Vec<MigrationWarning>,
+}
+
+impl Report {
+    pub(crate) fn new(file: &str) -> Self {
+        Self {
+            file: file.to_string(),
+            warnings: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, severity: MigrationSeverity, message: impl Into<String>) {
+        self.warnings.push(MigrationWarning {
+            severity,
+            file: self.file.clone(),
+            message: message.into(),
+        });
+    }
+}
+
+/// A backend server as declared by the source proxy.
+pub(crate) struct SourceServer<'a> {
+    pub name: &'a str,
+    pub address: &'a str,
+    pub motd: MotdConfig,
+}
+
+/// Builds one `ServerConfig` per source server.
+///
+/// `forced_hosts` maps a domain to the ordered list of servers it may
+/// route to. Only the first one receives the domain: the rest are reported.
+///
+/// `fallback_order` is the source proxy's connection order (Velocity `try`,
+/// BungeeCord `priorities`). It becomes the `fallbacks` of every server,
+/// minus the server itself, since both proxies send kicked players down it.
+pub(crate) fn build_servers(
+    servers: &[SourceServer<'_>],
+    forced_hosts: &BTreeMap<String, Vec<String>>,
+    fallback_order: &[String],
+    proxy_mode: ProxyMode,
+    report: &mut Report,
+) -> Vec<(String, ServerConfig)> {
+    let mut domains_by_server: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for (domain, targets) in forced_hosts {
+        let Some(first) = targets.first() else {
+            continue;
+        };
+        if !servers.iter().any(|s| s.name == first) {
+            report.push(
+                MigrationSeverity::Error,
+                format!("Forced host '{domain}' points to unknown server '{first}', skipped"),
+            );
+            continue;
+        }
+        domains_by_server
+            .entry(first.as_str())
+            .or_default()
+            .push(domain.clone());
+        if targets.len() > 1 {
+            report.push(
+                MigrationSeverity::Warning,
+                format!(
+                    "Forced host '{domain}' lists several servers {targets:?}; only '{first}' receives the domain"
+                ),
+            );
+        }
+    }
+
+    let mut used_names = HashSet::new();
+    let mut renamed: HashMap<&str, String> = HashMap::new();
+    let mut result = Vec::new();
+
+    for server in servers {
+        let address: ServerAddress = match server.address.parse() {
+            Ok(a) => a,
+            Err(e) => {
+                report.push(
+                    MigrationSeverity::Error,
+                    format!(
+                        "Server '{}': cannot parse address '{}': {e}",
+                        server.name, server.address
+                    ),
+                );
+                continue;
+            }
+        };
+
+        let mut name = sanitize_name(server.name);
+        if !used_names.insert(name.clone()) {
+            let mut i = 2;
+            while !used_names.insert(format!("{name}-{i}")) {
+                i += 1;
+            }
+            name = format!("{name}-{i}");
+        }
+        if name != server.name {
+            report.push(
+                MigrationSeverity::Info,
+                format!("Server '{}' renamed to '{name}'", server.name),
+            );
+        }
+        renamed.insert(server.name, name.clone());
+
+        let config = ServerConfig {
+            id: None,
+            name: Some(name.clone()),
+            network: Some(MIGRATED_NETWORK.to_string()),
+            domains: domains_by_server
+                .get(server.name)
+                .cloned()
+                .unwrap_or_default(),
+            addresses: vec![address],
+            proxy_mode,
+            forwarding_mode: None,
+            send_proxy_protocol: false,
+            domain_rewrite: Default::default(),
+            motd: server.motd.clone(),
+            server_manager: None,
+            timeouts: None,
+            max_players: 0,
+            ip_filter: None,
+            disconnect_message: None,
+            limbo_handlers: Vec::new(),
+            fallbacks: Vec::new(),
+            reconnect: None,
+            resource_packs: Vec::new(),
+        };
+
+        result.push((name, config));
+    }
+
+    let fallbacks: Vec<String> = fallback_order
+        .iter()
+        .filter_map(|source| {
+            let name = renamed.get(source.as_str()).cloned();
+            if name.is_none() {
+                report.push(
+                    MigrationSeverity::Warning,
+                    format!("Fallback '{source}' is not a known server, skipped"),
+                );
+            }
+            name
+        })
+        .collect();
+    for (name, config) in &mut result {
+        config.fallbacks = fallbacks.iter().filter(|f| *f != name).cloned().collect();
+    }
+
+    result
+}
+
+/// Lowercases and replaces anything outside `[a-z0-9_-]` with `-`.
+pub(crate) fn sanitize_name(name: &str) -> String {
+    let sanitized: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    if sanitized.is_empty() {
+        "server".to_string()
+    } else {
+        sanitized
+    }
+}
+
+/// Converts `&`-style color codes to `§` codes.
+pub(crate) fn ampersand_to_section(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '&'
+            && let Some(&next) = chars.peek()
+            && "0123456789abcdefklmnorABCDEFKLMNOR".contains(next)
+        {
+            out.push('§');
+            out.push(next.to_ascii_lowercase());
+            chars.next();
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Legacy palette: code and RGB value.
+const LEGACY_COLORS: [(&str, char, u32); 16] = [
+    ("black", '0', 0x00_00_00),
+    ("dark_blue", '1', 0x00_00_AA),
+    ("dark_green", '2', 0x00_AA_00),
+    ("dark_aqua", '3', 0x00_AA_AA),
+    ("dark_red", '4', 0xAA_00_00),
+    ("dark_purple", '5', 0xAA_00_AA),
+    ("gold", '6', 0xFF_AA_00),
+    ("gray", '7', 0xAA_AA_AA),
+    ("dark_gray", '8', 0x55_55_55),
+    ("blue", '9', 0x55_55_FF),
+    ("green", 'a', 0x55_FF_55),
+    ("aqua", 'b', 0x55_FF_FF),
+    ("red", 'c', 0xFF_55_55),
+    ("light_purple", 'd', 0xFF_55_FF),
+    ("yellow", 'e', 0xFF_FF_55),
+    ("white", 'f', 0xFF_FF_FF),
+];
+
+fn named_color(name: &str) -> Option<char> {
+    let name = name.replace("grey", "gray");
+    LEGACY_COLORS
+        .iter()
+        .find(|(n, _, _)| *n == name)
+        .map(|(_, code, _)| *code)
+}
+
+/// Maps `#rrggbb` to the closest legacy color.
+fn nearest_color(hex: &str) -> Option<char> {
+    let rgb = u32::from_str_radix(hex.strip_prefix('#')?, 16).ok()?;
+    let channels = |c: u32| [(c >> 16) & 0xFF, (c >> 8) & 0xFF, c & 0xFF].map(i64::from);
+    let [r, g, b] = channels(rgb);
+    LEGACY_COLORS
+        .iter()
+        .min_by_key(|(_, _, c)| {
+            let [cr, cg, cb] = channels(*c);
+            (r - cr).pow(2) + (g - cg).pow(2) + (b - cb).pow(2)
+        })
+        .map(|(_, code, _)| *code)
+}
+
+fn decoration(name: &str) -> Option<char> {
+    match name {
+        "bold" | "b" => Some('l'),
+        "italic" | "i" | "em" => Some('o'),
+        "underlined" | "u" => Some('n'),
+        "strikethrough" | "st" => Some('m'),
+        "obfuscated" | "obf" => Some('k'),
+        "reset" => Some('r'),
+        _ => None,
+    }
+}
+
+/// Converts a MiniMessage string (Velocity's text format) to `§` codes.
+///
+/// Hex colors are approximated to the nearest legacy color. Tags without a
+/// legacy equivalent (gradients, hover, click...) are dropped and reported.
+pub(crate) fn minimessage_to_legacy(text: &str, report: &mut Report, what: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut approximated = false;
+    let mut dropped: Vec<String> = Vec::new();
+    let mut rest = ampersand_to_section(text);
+
+    while let Some(start) = rest.find('<') {
+        // `\<` is an escaped literal
+        if rest[..start].ends_with('\\') {
+            out.push_str(&rest[..start - 1]);
+            out.push('<');
+            rest = rest[start + 1..].to_string();
+            continue;
+        }
+        out.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('>') else {
+            out.push_str(&rest[start..]);
+            rest.clear();
+            break;
+        };
+        let tag = rest[start + 1..start + len].to_lowercase();
+        rest = rest[start + len + 1..].to_string();
+
+        if tag.starts_with('/') {
+            out.push_str("§r");
+            continue;
+        }
+
+        let value = tag
+            .strip_prefix("color:")
+            .or_else(|| tag.strip_prefix("c:"))
+            .unwrap_or(&tag);
+        if let Some(code) = named_color(value) {
+            out.push('§');
+            out.push(code);
+        } else if value.starts_with('#') {
+            match nearest_color(value) {
+                Some(code) => {
+                    approximated = true;
+                    out.push('§');
+                    out.push(code);
+                }
+                None => dropped.push(tag.clone()),
+            }
+        } else if let Some(code) = decoration(value) {
+            out.push('§');
+            out.push(code);
+        } else if value == "newline" || value == "br" {
+            out.push('\n');
+        } else {
+            let name = value.split(':').next().unwrap_or(value).to_string();
+            if !dropped.contains(&name) {
+                dropped.push(name);
+            }
+        }
+    }
+    out.push_str(&rest);
+
+    if approximated {
+        report.push(
+            MigrationSeverity::Info,
+            format!("{what}: hex colors approximated to the nearest legacy color"),
+        );
+    }
+    if !dropped.is_empty() {
+        report.push(
+            MigrationSeverity::Warning,
+            format!("{what}: MiniMessage tags without legacy equivalent dropped: {dropped:?}"),
+        );
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ampersand_codes() {
+        assert_eq!(
+            ampersand_to_section("&1Hello &lWorld & co"),
+            "§1Hello §lWorld & co"
+        );
+    }
+
+    #[test]
+    fn test_minimessage_named_and_hex() {
+        let mut report = Report::new("velocity.toml");
+        let out = minimessage_to_legacy(
+            "<#09add3>A <bold>Velocity</bold> Server",
+            &mut report,
+            "motd",
+        );
+        assert_eq!(out, "§3A §lVelocity§r Server");
+        assert_eq!(report.warnings.len(), 1);
+        assert_eq!(report.warnings[0].severity, MigrationSeverity::Info);
+    }
+
+    #[test]
+    fn test_minimessage_drops_unknown_tags() {
+        let mut report = Report::new("velocity.toml");
+        let out = minimessage_to_legacy(
+            "<gradient:red:blue>Hi</gradient><newline>\\<3",
+            &mut report,
+            "motd",
+        );
+        assert_eq!(out, "Hi§r\n<3");
+        assert!(report.warnings[0].message.contains("gradient"));
+    }
+
+    #[test]
+    fn test_sanitize_name() {
+        assert_eq!(sanitize_name("Lobby 1"), "lobby-1");
+        assert_eq!(sanitize_name(""), "server");
+    }
+}
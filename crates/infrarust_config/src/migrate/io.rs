@@ -1,11 +1,14 @@
-//! V1 → V2 directory migration.
+//! V1 → V2 directory migration, and Velocity / BungeeCord imports.
 
 use std::path::Path;
 
+use super::bungeecord::{BungeeConfig, convert_bungeecord_config};
 use super::convert::{
     MigrationSeverity, MigrationWarning, convert_v1_proxy_config, convert_v1_to_v2,
 };
+use super::external::ExternalMigrationResult;
 use super::v1_types::{V1InfrarustConfig, V1ServerConfig};
+use super::velocity::{VelocityConfig, convert_velocity_config};
 use crate::error::ConfigError;
 
 pub fn migrate_directory(
@@ -180,6 +183,156 @@ pub fn migrate_proxy_config(
     Ok(result.warnings)
 }
 
+/// Migrates a Velocity `velocity.toml` into `proxy_output` plus one
+/// server file per `[servers]` entry in `servers_dir`.
+pub fn migrate_velocity_config(
+    input_file: &Path,
+    proxy_output: &Path,
+    servers_dir: &Path,
+) -> Result<Vec<MigrationWarning>, ConfigError> {
+    let content = read_input(input_file)?;
+    let velocity: VelocityConfig = toml::from_str(&content).map_err(|e| {
+        ConfigError::Validation(format!("TOML parse error in {}: {e}", input_file.display()))
+    })?;
+
+    write_external(
+        convert_velocity_config(&velocity),
+        input_file,
+        proxy_output,
+        servers_dir,
+    )
+}
+
+/// Migrates a BungeeCord / Waterfall `config.yml` into `proxy_output`
+/// plus one server file per `servers` entry in `servers_dir`.
+pub fn migrate_bungeecord_config(
+    input_file: &Path,
+    proxy_output: &Path,
+    servers_dir: &Path,
+) -> Result<Vec<MigrationWarning>, ConfigError> {
+    let content = read_input(input_file)?;
+    let bungee: BungeeConfig = serde_yml::from_str(&content).map_err(|e| {
+        ConfigError::Validation(format!("YAML parse error in {}: {e}", input_file.display()))
+    })?;
+
+    write_external(
+        convert_bungeecord_config(&bungee),
+        input_file,
+        proxy_output,
+        servers_dir,
+    )
+}
+
+fn read_input(input_file: &Path) -> Result<String, ConfigError> {
+    std::fs::read_to_string(input_file).map_err(|e| {
+        ConfigError::Validation(format!(
+            "Cannot read config file {}: {e}",
+            input_file.display()
+        ))
+    })
+}
+
+fn write_external(
+    mut result: ExternalMigrationResult,
+    input_file: &Path,
+    proxy_output: &Path,
+    servers_dir: &Path,
+) -> Result<Vec<MigrationWarning>, ConfigError> {
+    let create_dir = |dir: &Path| {
+        std::fs::create_dir_all(dir).map_err(|e| {
+            ConfigError::Validation(format!(
+                "Cannot create output directory {}: {e}",
+                dir.display()
+            ))
+        })
+    };
+    let proxy_dir = proxy_output.parent().unwrap_or(Path::new("."));
+    create_dir(proxy_dir)?;
+    create_dir(servers_dir)?;
+
+    result.config.servers_dir = servers_dir.to_path_buf();
+    let mut warnings = result.warnings;
+
+    // Carry the forwarding secret over next to the new infrarust.toml
+    let secret = match (&result.forwarding_secret, &result.forwarding_secret_source) {
+        (Some(secret), _) => Some(secret.clone()),
+        (None, Some(source)) => {
+            let path = input_file.parent().unwrap_or(Path::new(".")).join(source);
+            match std::fs::read_to_string(&path) {
+                Ok(secret) => Some(secret.trim().to_string()),
+                Err(e) => {
+                    warnings.push(MigrationWarning {
+                        severity: MigrationSeverity::Warning,
+                        file: "forwarding.secret".to_string(),
+                        message: format!(
+                            "Cannot read {}: {e}; a new secret will be generated on first start",
+                            path.display()
+                        ),
+                    });
+                    None
+                }
+            }
+        }
+        (None, None) => None,
+    };
+    if let (Some(secret), Some(forwarding)) = (secret, &result.config.forwarding) {
+        let secret_path = proxy_dir.join(&forwarding.secret_file);
+        if secret_path.exists() {
+            warnings.push(MigrationWarning {
+                severity: MigrationSeverity::Warning,
+                file: "forwarding.secret".to_string(),
+                message: format!("{} already exists, not overwritten", secret_path.display()),
+            });
+        } else {
+            std::fs::write(&secret_path, secret).map_err(|e| {
+                ConfigError::Validation(format!("Cannot write {}: {e}", secret_path.display()))
+            })?;
+        }
+    }
+
+    let toml_content = toml::to_string_pretty(&result.config)
+        .map_err(|e| ConfigError::Validation(format!("TOML serialization error: {e}")))?;
+    std::fs::write(proxy_output, toml_content).map_err(|e| {
+        ConfigError::Validation(format!(
+            "Cannot write output file {}: {e}",
+            proxy_output.display()
+        ))
+    })?;
+
+    let mut converted = 0u32;
+    for (name, config) in &result.servers {
+        let file = format!("{name}.toml");
+        let content = match toml::to_string_pretty(config) {
+            Ok(t) => t,
+            Err(e) => {
+                warnings.push(MigrationWarning {
+                    severity: MigrationSeverity::Error,
+                    file,
+                    message: format!("TOML serialization error: {e}"),
+                });
+                continue;
+            }
+        };
+        if let Err(e) = std::fs::write(servers_dir.join(&file), content) {
+            warnings.push(MigrationWarning {
+                severity: MigrationSeverity::Error,
+                file,
+                message: format!("Cannot write output file: {e}"),
+            });
+            continue;
+        }
+        converted += 1;
+    }
+
+    warnings.push(MigrationWarning {
+        severity: MigrationSeverity::Info,
+        file: "summary".to_string(),
+        message: format!("{converted} server(s) converted"),
+    });
+
+    Ok(warnings)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,4 +387,53 @@ motds:
         let result = migrate_directory(Path::new("/nonexistent"), Path::new("/tmp/out"));
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_migrate_velocity_writes_proxy_servers_and_secret() {
+        let tmp = tempfile::tempdir().unwrap();
+        let input = tmp.path().join("velocity");
+        let output = tmp.path().join("infrarust");
+        fs::create_dir_all(&input).unwrap();
+
+        fs::write(
+            input.join("velocity.toml"),
+            r#"
+bind = "0.0.0.0:25577"
+player-info-forwarding-mode = "modern"
+
+[servers]
+lobby = "127.0.0.1:30066"
+try = ["lobby"]
+
+[forced-hosts]
+"mc.example.com" = ["lobby"]
+"#,
+        )
+        .unwrap();
+        fs::write(input.join("forwarding.secret"), "abc123\n").unwrap();
+
+        let warnings = migrate_velocity_config(
+            &input.join("velocity.toml"),
+            &output.join("infrarust.toml"),
+            &output.join("servers"),
+        )
+        .unwrap();
+
+        let proxy: crate::proxy::ProxyConfig =
+            toml::from_str(&fs::read_to_string(output.join("infrarust.toml")).unwrap()).unwrap();
+        assert_eq!(proxy.servers_dir, output.join("servers"));
+
+        let lobby: crate::server::ServerConfig =
+            toml::from_str(&fs::read_to_string(output.join("servers/lobby.toml")).unwrap())
+                .unwrap();
+        assert_eq!(lobby.domains, vec!["mc.example.com"]);
+
+        assert_eq!(
+            fs::read_to_string(output.join("forwarding.secret")).unwrap(),
+            "abc123"
+        );
+
+        let summary = warnings.iter().find(|w| w.file == "summary").unwrap();
+        assert_eq!(summary.message, "1 server(s) converted");
+    }
 }
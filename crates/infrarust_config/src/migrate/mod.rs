@@ -1,6 +1,12 @@
+pub mod bungeecord;
 pub mod convert;
+pub mod external;
 pub mod io;
 pub mod v1_types;
+pub mod velocity;
 
 pub use convert::{MigrationResult, MigrationSeverity, MigrationWarning};
-pub use io::{migrate_directory, migrate_proxy_config};
+pub use external::ExternalMigrationResult;
+pub use io::{
+    migrate_bungeecord_config, migrate_directory, migrate_proxy_config, migrate_velocity_config,
+};
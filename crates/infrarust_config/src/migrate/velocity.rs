@@ -0,0 +1,464 @@
+//! Velocity (`velocity.toml`) → V2 conversion.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::proxy::{ProxyConfig, UnknownDomainBehavior};
+use crate::types::{
+    ForwardingConfig, ForwardingMode, MotdConfig, MotdEntry, ProxyMode, RateLimitConfig,
+    TimeoutConfig,
+};
+
+use super::convert::MigrationSeverity;
+use super::external::{
+    ExternalMigrationResult, Report, SourceServer, build_servers, minimessage_to_legacy,
+};
+
+const FILE: &str = "velocity.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct VelocityConfig {
+    pub config_version: Option<String>,
+    pub bind: Option<String>,
+    pub motd: Option<String>,
+    pub show_max_players: Option<u32>,
+    pub online_mode: Option<bool>,
+    pub force_key_authentication: Option<bool>,
+    pub prevent_client_proxy_connections: Option<bool>,
+    pub player_info_forwarding_mode: Option<String>,
+    pub forwarding_secret_file: Option<String>,
+    /// Inline secret used by pre-2.0 configs.
+    pub forwarding_secret: Option<String>,
+    pub announce_forge: Option<bool>,
+    pub kick_existing_players: Option<bool>,
+    pub ping_passthrough: Option<String>,
+    pub enable_player_address_logging: Option<bool>,
+    /// Server name → address, plus the `try` list.
+    pub servers: BTreeMap<String, toml::Value>,
+    pub forced_hosts: BTreeMap<String, Vec<String>>,
+    pub advanced: Option<VelocityAdvanced>,
+    pub query: Option<VelocityQuery>,
+    pub metrics: Option<toml::Value>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct VelocityAdvanced {
+    pub compression_threshold: Option<i64>,
+    pub compression_level: Option<i64>,
+    pub login_ratelimit: Option<u64>,
+    pub connection_timeout: Option<u64>,
+    pub read_timeout: Option<u64>,
+    pub haproxy_protocol: Option<bool>,
+    pub tcp_fast_open: Option<bool>,
+    pub bungee_plugin_message_channel: Option<bool>,
+    pub show_ping_requests: Option<bool>,
+    pub failover_on_unexpected_server_disconnect: Option<bool>,
+    pub announce_proxy_commands: Option<bool>,
+    pub log_command_executions: Option<bool>,
+    pub log_player_connections: Option<bool>,
+    pub accepts_transfers: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct VelocityQuery {
+    pub enabled: bool,
+    pub port: Option<u16>,
+}
+
+pub fn convert_velocity_config(velocity: &VelocityConfig) -> ExternalMigrationResult {
+    let mut report = Report::new(FILE);
+
+    let bind = match velocity.bind.as_deref().map(str::parse) {
+        Some(Ok(addr)) => addr,
+        Some(Err(_)) => {
+            report.push(
+                MigrationSeverity::Warning,
+                format!(
+                    "Cannot parse bind '{}', using the default",
+                    velocity.bind.as_deref().unwrap_or_default()
+                ),
+            );
+            crate::defaults::bind()
+        }
+        None => crate::defaults::bind(),
+    };
+
+    let default_motd = velocity.motd.as_deref().map(|motd| MotdConfig {
+        online: Some(MotdEntry {
+            text: minimessage_to_legacy(motd, &mut report, "motd"),
+            favicon: None,
+            version_name: None,
+            max_players: velocity.show_max_players,
+        }),
+        ..MotdConfig::default()
+    });
+    if default_motd.is_some() {
+        report.push(
+            MigrationSeverity::Info,
+            "motd is now the default MOTD, shown for unknown domains; each server relays its own status",
+        );
+    }
+
+    let forwarding_mode = match velocity
+        .player_info_forwarding_mode
+        .as_deref()
+        .map(str::to_lowercase)
+        .as_deref()
+    {
+        None | Some("none") => ForwardingMode::None,
+        Some("legacy") => ForwardingMode::BungeeCord,
+        Some("bungeeguard") => ForwardingMode::BungeeGuard,
+        Some("modern") => ForwardingMode::Velocity,
+        Some(other) => {
+            report.push(
+                MigrationSeverity::Warning,
+                format!("Unknown player-info-forwarding-mode '{other}', forwarding disabled"),
+            );
+            ForwardingMode::None
+        }
+    };
+
+    let uses_secret = matches!(
+        forwarding_mode,
+        ForwardingMode::Velocity | ForwardingMode::BungeeGuard
+    );
+    let (forwarding_secret, forwarding_secret_source) = if !uses_secret {
+        (None, None)
+    } else if let Some(secret) = velocity.forwarding_secret.clone() {
+        (Some(secret), None)
+    } else {
+        let file = velocity
+            .forwarding_secret_file
+            .clone()
+            .unwrap_or_else(|| "forwarding.secret".to_string());
+        (None, Some(PathBuf::from(file)))
+    };
+
+    let proxy_mode = if velocity.online_mode.unwrap_or(true) {
+        ProxyMode::ClientOnly
+    } else {
+        ProxyMode::Offline
+    };
+
+    let advanced = velocity.advanced.as_ref();
+    let read_timeout = advanced
+        .and_then(|a| a.read_timeout)
+        .map(Duration::from_millis);
+    let connect_timeout = advanced
+        .and_then(|a| a.connection_timeout)
+        .map(Duration::from_millis)
+        .unwrap_or_else(crate::defaults::connect_timeout);
+
+    let rate_limit = match advanced.and_then(|a| a.login_ratelimit) {
+        Some(0) => {
+            report.push(
+                MigrationSeverity::Info,
+                "login-ratelimit = 0 has no V2 equivalent, the default [rate_limit] applies",
+            );
+            RateLimitConfig::default()
+        }
+        Some(ms) => {
+            report.push(
+                MigrationSeverity::Info,
+                format!("login-ratelimit approximated as 1 login per {ms}ms per IP"),
+            );
+            RateLimitConfig {
+                max_connections: 1,
+                window: Duration::from_millis(ms),
+                ..RateLimitConfig::default()
+            }
+        }
+        None => RateLimitConfig::default(),
+    };
+
+    if let Some(a) = advanced {
+        if a.compression_threshold.is_some() || a.compression_level.is_some() {
+            report.push(
+                MigrationSeverity::Info,
+                "compression-threshold / compression-level are not configurable in V2",
+            );
+        }
+        if a.failover_on_unexpected_server_disconnect == Some(true) {
+            report.push(
+                MigrationSeverity::Warning,
+                "failover-on-unexpected-server-disconnect has no V2 equivalent",
+            );
+        }
+        if a.tcp_fast_open == Some(true) {
+            report.push(
+                MigrationSeverity::Info,
+                "tcp-fast-open is not supported in V2",
+            );
+        }
+    }
+
+    let unsupported_flags = [
+        (
+            "force-key-authentication",
+            velocity.force_key_authentication,
+        ),
+        (
+            "prevent-client-proxy-connections",
+            velocity.prevent_client_proxy_connections,
+        ),
+        ("kick-existing-players", velocity.kick_existing_players),
+        ("announce-forge", velocity.announce_forge),
+    ];
+    for (key, value) in unsupported_flags {
+        if value == Some(true) {
+            report.push(
+                MigrationSeverity::Warning,
+                format!("{key} has no V2 equivalent and was dropped"),
+            );
+        }
+    }
+    if let Some(mode) = &velocity.ping_passthrough
+        && mode != "disabled"
+    {
+        report.push(
+            MigrationSeverity::Info,
+            format!("ping-passthrough = \"{mode}\": V2 always relays the backend status for routed domains"),
+        );
+    }
+    if velocity.query.as_ref().is_some_and(|q| q.enabled) {
+        report.push(
+            MigrationSeverity::Warning,
+            "GameSpy [query] is not supported in V2",
+        );
+    }
+    if velocity.metrics.is_some() {
+        report.push(
+            MigrationSeverity::Info,
+            "bStats [metrics] dropped; see [telemetry] for OpenTelemetry export",
+        );
+    }
+
+    // [servers]: string values are servers, `try` is the connection order
+    let mut servers = Vec::new();
+    let mut try_order: Vec<String> = Vec::new();
+    for (key, value) in &velocity.servers {
+        match (key.as_str(), value) {
+            ("try", toml::Value::Array(list)) => {
+                try_order = list
+                    .iter()
+                    .filter_map(|v| v.as_str().map(str::to_string))
+                    .collect();
+            }
+            (_, toml::Value::String(address)) => servers.push(SourceServer {
+                name: key,
+                address,
+                motd: MotdConfig::default(),
+            }),
+            _ => report.push(
+                MigrationSeverity::Warning,
+                format!("Ignoring unexpected [servers] entry '{key}'"),
+            ),
+        }
+    }
+    if !try_order.is_empty() {
+        report.push(
+            MigrationSeverity::Info,
+            format!(
+                "try = {try_order:?} converted to the fallbacks of every server; players still connect through a domain"
+            ),
+        );
+    }
+
+    let mut servers = build_servers(
+        &servers,
+        &velocity.forced_hosts,
+        &try_order,
+        proxy_mode,
+        &mut report,
+    );
+    if let Some(read) = read_timeout {
+        for (_, server) in &mut servers {
+            server.timeouts = Some(TimeoutConfig {
+                connect: connect_timeout,
+                read,
+                write: crate::defaults::write_timeout(),
+            });
+        }
+    }
+    for (name, server) in &servers {
+        if server.domains.is_empty() {
+            report.push(
+                MigrationSeverity::Info,
+                format!("Server '{name}' has no forced host, reachable only by server switch"),
+            );
+        }
+    }
+
+    let config = ProxyConfig {
+        bind,
+        max_connections: 0,
+        connect_timeout,
+        receive_proxy_protocol: advanced.and_then(|a| a.haproxy_protocol).unwrap_or(false),
+        servers_dir: crate::defaults::servers_dir(),
+        plugins_dir: crate::defaults::plugins_dir(),
+        data_dir: crate::defaults::data_dir(),
+        worker_threads: 0,
+        rate_limit,
+        status_cache: Default::default(),
+        default_motd,
+        telemetry: None,
+        keepalive: Default::default(),
+        so_reuseport: false,
+        ban: Default::default(),
+        docker: None,
+        kubernetes: None,
+        ip_filter: None,
+        unknown_domain_behavior: UnknownDomainBehavior::default(),
+        announce_proxy_commands: advanced
+            .and_then(|a| a.announce_proxy_commands)
+            .unwrap_or_else(crate::defaults::announce_proxy_commands),
+        forwarding: Some(ForwardingConfig {
+            mode: forwarding_mode,
+            bungeecord_channel: advanced
+                .and_then(|a| a.bungee_plugin_message_channel)
+                .unwrap_or(true),
+            ..ForwardingConfig::default()
+        }),
+        web: None,
+        last_server: None,
+        tab_list: None,
+        resource_packs: Vec::new(),
+        permissions: Default::default(),
+        plugins: std::collections::HashMap::new(),
+    };
+
+    ExternalMigrationResult {
+        config,
+        servers,
+        forwarding_secret,
+        forwarding_secret_source,
+        warnings: report.warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VELOCITY_TOML: &str = r#"
+config-version = "2.7"
+bind = "0.0.0.0:25577"
+motd = "<#09add3>A Velocity Server"
+show-max-players = 500
+online-mode = true
+player-info-forwarding-mode = "modern"
+forwarding-secret-file = "forwarding.secret"
+
+[servers]
+lobby = "127.0.0.1:30066"
+factions = "127.0.0.1:30067"
+"Mini Games" = "127.0.0.1:30068"
+try = ["lobby"]
+
+[forced-hosts]
+"lobby.example.com" = ["lobby"]
+"factions.example.com" = ["factions", "lobby"]
+
+[advanced]
+connection-timeout = 5000
+read-timeout = 30000
+haproxy-protocol = false
+login-ratelimit = 3000
+
+[query]
+enabled = false
+"#;
+
+    fn convert() -> ExternalMigrationResult {
+        let velocity: VelocityConfig = toml::from_str(VELOCITY_TOML).unwrap();
+        convert_velocity_config(&velocity)
+    }
+
+    #[test]
+    fn test_servers_and_forced_hosts() {
+        let result = convert();
+        let names: Vec<&str> = result.servers.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["mini-games", "factions", "lobby"]);
+
+        let factions = &result.servers[1].1;
+        assert_eq!(factions.domains, vec!["factions.example.com"]);
+        assert_eq!(factions.proxy_mode, ProxyMode::ClientOnly);
+        assert_eq!(factions.network.as_deref(), Some("main"));
+        assert_eq!(
+            factions.timeouts.as_ref().unwrap().read,
+            Duration::from_secs(30)
+        );
+
+        for (_, server) in &result.servers {
+            crate::validation::validate_server_config(server).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_try_becomes_fallbacks() {
+        let result = convert();
+        let fallbacks = |name: &str| {
+            result
+                .servers
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, s)| s.fallbacks.clone())
+                .unwrap()
+        };
+        assert_eq!(fallbacks("factions"), vec!["lobby"]);
+        assert_eq!(fallbacks("mini-games"), vec!["lobby"]);
+        assert!(fallbacks("lobby").is_empty());
+    }
+
+    #[test]
+    fn test_forwarding_and_motd() {
+        let result = convert();
+        let forwarding = result.config.forwarding.as_ref().unwrap();
+        assert_eq!(forwarding.mode, ForwardingMode::Velocity);
+        assert_eq!(
+            result.forwarding_secret_source,
+            Some(PathBuf::from("forwarding.secret"))
+        );
+
+        let motd = result.config.default_motd.as_ref().unwrap();
+        let online = motd.online.as_ref().unwrap();
+        assert_eq!(online.text, "§3A Velocity Server");
+        assert_eq!(online.max_players, Some(500));
+        assert_eq!(result.config.bind, "0.0.0.0:25577".parse().unwrap());
+    }
+
+    #[test]
+    fn test_reports_unmapped_settings() {
+        let result = convert();
+        let messages: Vec<&str> = result.warnings.iter().map(|w| w.message.as_str()).collect();
+        assert!(messages.iter().any(|m| m.starts_with("try = ")));
+        assert!(messages.iter().any(|m| m.contains("only 'factions'")));
+        assert!(messages.iter().any(|m| m.contains("'Mini Games' renamed")));
+    }
+
+    #[test]
+    fn test_offline_mode_and_inline_secret() {
+        let velocity: VelocityConfig = toml::from_str(
+            r#"
+online-mode = false
+player-info-forwarding-mode = "bungeeguard"
+forwarding-secret = "s3cr3t"
+
+[servers]
+hub = "10.0.0.1:25565"
+"#,
+        )
+        .unwrap();
+        let result = convert_velocity_config(&velocity);
+
+        assert_eq!(result.servers[0].1.proxy_mode, ProxyMode::Offline);
+        assert_eq!(result.forwarding_secret.as_deref(), Some("s3cr3t"));
+        assert!(result.forwarding_secret_source.is_none());
+    }
+}
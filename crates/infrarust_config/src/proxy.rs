@@ -5,15 +5,17 @@ use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::time::Duration;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::defaults;
 use crate::types::{
-    BanConfig, DockerProviderConfig, ForwardingConfig, IpFilterConfig, KeepaliveConfig, MotdConfig,
-    PermissionsConfig, RateLimitConfig, StatusCacheConfig, TelemetryConfig, WebConfig,
+    BanConfig, DockerProviderConfig, ForwardingConfig, IpFilterConfig, KeepaliveConfig,
+    KubernetesProviderConfig, LastServerConfig, MotdConfig, PermissionsConfig, RateLimitConfig,
+    ResourcePackConfig, StatusCacheConfig, TabListConfig, TelemetryConfig, WebConfig,
 };
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum UnknownDomainBehavior {
     #[default]
@@ -22,7 +24,7 @@ pub enum UnknownDomainBehavior {
 }
 
 /// Corresponds to the `infrarust.toml` file.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ProxyConfig {
     /// Listen address, e.g., "0.0.0.0:25565"
@@ -36,6 +38,7 @@ pub struct ProxyConfig {
     /// Backend connection timeout
     #[serde(default = "defaults::connect_timeout")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub connect_timeout: Duration,
 
     /// Enables receiving proxy protocol (`HAProxy` v1/v2)
@@ -49,6 +52,10 @@ pub struct ProxyConfig {
     #[serde(default = "defaults::plugins_dir")]
     pub plugins_dir: PathBuf,
 
+    /// Directory for state persisted across restarts (e.g. managed servers)
+    #[serde(default = "defaults::data_dir")]
+    pub data_dir: PathBuf,
+
     /// Number of tokio worker threads (0 = auto)
     #[serde(default)]
     pub worker_threads: usize,
@@ -86,6 +93,11 @@ pub struct ProxyConfig {
     #[serde(default)]
     pub docker: Option<DockerProviderConfig>,
 
+    /// Kubernetes provider configuration (optional).
+    /// Present in the TOML even without the `kubernetes` feature compiled.
+    #[serde(default)]
+    pub kubernetes: Option<KubernetesProviderConfig>,
+
     #[serde(default)]
     pub ip_filter: Option<IpFilterConfig>,
 
@@ -105,12 +117,24 @@ pub struct ProxyConfig {
     #[serde(default)]
     pub permissions: PermissionsConfig,
 
+    /// Sends returning players back to their last server (absent = disabled).
+    #[serde(default)]
+    pub last_server: Option<LastServerConfig>,
+
+    /// Proxy-managed tab list (absent = left entirely to the backends).
+    #[serde(default)]
+    pub tab_list: Option<TabListConfig>,
+
+    /// Resource packs pushed to players on every server. Intercepted modes only.
+    #[serde(default)]
+    pub resource_packs: Vec<ResourcePackConfig>,
+
     /// Plugin configurations keyed by plugin ID.
     #[serde(default)]
     pub plugins: HashMap<String, PluginConfig>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PluginConfig {
     /// Path to the plugin binary/library.
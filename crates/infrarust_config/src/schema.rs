@@ -0,0 +1,117 @@
+//! JSON Schema generation for the configuration files.
+//!
+//! Editors (Taplo, VS Code Even Better TOML) use these schemas for
+//! completion and validation. Field doc comments become descriptions.
+
+use std::borrow::Cow;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+use crate::proxy::ProxyConfig;
+use crate::server::ServerConfig;
+
+/// Schema for `infrarust.toml`.
+pub fn proxy_config_schema() -> Schema {
+    schemars::schema_for!(ProxyConfig)
+}
+
+/// Schema for a server config file in `servers_dir`.
+pub fn server_config_schema() -> Schema {
+    schemars::schema_for!(ServerConfig)
+}
+
+/// Schema stand-in for durations parsed with `humantime_serde`.
+pub(crate) struct HumanDuration;
+
+impl JsonSchema for HumanDuration {
+    fn schema_name() -> Cow<'static, str> {
+        "Duration".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "Human-readable duration, e.g. `500ms`, `30s`, `5m` or `1h 30m`.",
+            "pattern": r"^\s*(\d+\s*[a-zµ]+\s*)+$",
+            "examples": ["30s", "5m", "1h 30m"],
+        })
+    }
+}
+
+/// Schema stand-in for `ipnet::IpNet`.
+pub(crate) struct IpNetwork;
+
+impl JsonSchema for IpNetwork {
+    fn schema_name() -> Cow<'static, str> {
+        "IpNetwork".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "IPv4 or IPv6 network in CIDR notation.",
+            "examples": ["10.0.0.0/8", "2001:db8::/32"],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn property<'a>(schema: &'a serde_json::Value, path: &[&str]) -> &'a serde_json::Value {
+        path.iter().fold(schema, |value, key| &value[*key])
+    }
+
+    #[test]
+    fn test_server_schema_describes_fields() {
+        let schema = serde_json::to_value(server_config_schema()).unwrap();
+
+        assert_eq!(schema["additionalProperties"], false);
+        assert_eq!(schema["required"], serde_json::json!(["addresses"]));
+        let domains = property(&schema, &["properties", "domains", "description"]);
+        assert!(domains.as_str().unwrap().starts_with("Domains that route"));
+    }
+
+    #[test]
+    fn test_durations_are_strings() {
+        let schema = serde_json::to_value(proxy_config_schema()).unwrap();
+
+        assert_eq!(
+            property(&schema, &["properties", "connect_timeout", "$ref"]),
+            "#/$defs/Duration"
+        );
+        assert_eq!(property(&schema, &["$defs", "Duration", "type"]), "string");
+    }
+
+    #[test]
+    fn test_server_manager_is_tagged() {
+        let schema = serde_json::to_value(server_config_schema()).unwrap();
+        let variants = property(&schema, &["$defs", "ServerManagerConfig", "oneOf"])
+            .as_array()
+            .unwrap();
+
+        let tags: Vec<&str> = variants
+            .iter()
+            .map(|v| v["properties"]["type"]["const"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            tags,
+            vec!["local", "pterodactyl", "crafty", "docker", "http"]
+        );
+    }
+
+    #[test]
+    fn test_defaults_use_config_syntax() {
+        let schema = serde_json::to_value(proxy_config_schema()).unwrap();
+
+        assert_eq!(
+            property(&schema, &["properties", "connect_timeout", "default"]),
+            "5s"
+        );
+        assert_eq!(
+            property(&schema, &["properties", "bind", "default"]),
+            "0.0.0.0:25565"
+        );
+    }
+}
@@ -1,14 +1,19 @@
 //! Backend server configuration (one per `.toml` file in `servers_dir`).
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::types::{
-    DomainRewrite, ForwardingMode, IpFilterConfig, MotdConfig, ProxyMode, ServerAddress,
-    ServerManagerConfig, TimeoutConfig,
+    DomainRewrite, ForwardingMode, IpFilterConfig, MotdConfig, ProxyMode, ReconnectConfig,
+    ResourcePackConfig, ServerAddress, ServerManagerConfig, TimeoutConfig,
 };
 
+/// Server ID that stands for the current server's limbo handlers, in
+/// `fallbacks` and in server switch requests.
+pub const LIMBO_SERVER_ID: &str = "$limbo";
+
 /// Each file in `servers_dir/` deserializes into this type.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ServerConfig {
     /// Unique identifier. Derived from the filename if absent.
@@ -76,6 +81,20 @@ pub struct ServerConfig {
     /// Limbo handler chain for this server (plugin IDs, executed in order).
     #[serde(default)]
     pub limbo_handlers: Vec<String>,
+
+    /// Servers tried in order when this one is unreachable or kicks a player
+    /// (`"$limbo"`: this server's limbo handlers). Intercepted modes only.
+    #[serde(default)]
+    pub fallbacks: Vec<String>,
+
+    /// Holds players in limbo while the backend restarts. Intercepted modes only.
+    #[serde(default)]
+    pub reconnect: Option<ReconnectConfig>,
+
+    /// Resource packs pushed to players on this server, after the global ones.
+    /// Intercepted modes only.
+    #[serde(default)]
+    pub resource_packs: Vec<ResourcePackConfig>,
 }
 
 impl ServerConfig {
@@ -4,6 +4,7 @@ use std::fmt;
 use std::net::SocketAddr;
 use std::str::FromStr;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::DEFAULT_MC_PORT;
@@ -67,6 +68,20 @@ impl Serialize for ServerAddress {
     }
 }
 
+impl JsonSchema for ServerAddress {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "ServerAddress".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "Backend address as `host:port` or `host` (port defaults to 25565).",
+            "examples": ["127.0.0.1:25565", "mc.example.com"],
+        })
+    }
+}
+
 /// Serde deserialization from a string.
 impl<'de> Deserialize<'de> for ServerAddress {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -80,7 +95,7 @@ impl<'de> Deserialize<'de> for ServerAddress {
 
 /// How to rewrite the domain in the Minecraft handshake
 /// before forwarding it to the backend.
-#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum DomainRewrite {
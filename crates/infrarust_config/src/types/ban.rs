@@ -2,11 +2,12 @@
 
 use std::time::Duration;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::defaults;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BanConfig {
     /// Path to the JSON bans file.
@@ -16,6 +17,7 @@ pub struct BanConfig {
     /// Automatic purge interval for expired bans.
     #[serde(default = "defaults::ban_purge_interval")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub purge_interval: Duration,
 
     /// Enables the audit log (tracks ban/unban operations).
@@ -2,6 +2,7 @@
 
 use std::time::Duration;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::defaults;
@@ -10,7 +11,7 @@ use crate::defaults;
 /// `ProxyConfig` can parse a `[docker]` section regardless of
 /// the build configuration. The `DockerProvider` itself
 /// is feature-gated in `infrarust-core`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct DockerProviderConfig {
     /// Endpoint Docker (ex: "<unix:///var/run/docker.sock>").
@@ -24,11 +25,13 @@ pub struct DockerProviderConfig {
     /// Fallback polling interval.
     #[serde(default = "defaults::docker_poll_interval")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub poll_interval: Duration,
 
     /// Reconnection delay after Docker daemon disconnection.
     #[serde(default = "defaults::docker_reconnect_delay")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub reconnect_delay: Duration,
 }
 
@@ -1,6 +1,7 @@
 //! IP filtering configuration.
 
 use ipnet::IpNet;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// IP filtering by CIDR.
@@ -8,12 +9,14 @@ use serde::{Deserialize, Serialize};
 /// If `whitelist` is non-empty, only IPs in the whitelist are allowed.
 /// If `blacklist` is non-empty, IPs in the blacklist are rejected.
 /// The whitelist is evaluated first.
-#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct IpFilterConfig {
     #[serde(default)]
+    #[schemars(with = "Vec<crate::schema::IpNetwork>")]
     pub whitelist: Vec<IpNet>,
     #[serde(default)]
+    #[schemars(with = "Vec<crate::schema::IpNetwork>")]
     pub blacklist: Vec<IpNet>,
 }
 
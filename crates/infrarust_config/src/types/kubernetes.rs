@@ -0,0 +1,69 @@
+//! Kubernetes provider configuration.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::defaults;
+
+/// Like [`DockerProviderConfig`](super::DockerProviderConfig), this type is
+/// always compiled so that `ProxyConfig` can parse a `[kubernetes]` section
+/// regardless of the build configuration. The `KubernetesProvider` itself
+/// is feature-gated in `infrarust-core`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct KubernetesProviderConfig {
+    /// API server URL (ex: "<https://10.0.0.1:6443>").
+    /// Defaults to the in-cluster endpoint from `KUBERNETES_SERVICE_HOST`
+    /// and `KUBERNETES_SERVICE_PORT`.
+    #[serde(default)]
+    pub api_server: Option<String>,
+
+    /// Namespace to watch. `None` = all namespaces.
+    #[serde(default)]
+    pub namespace: Option<String>,
+
+    /// Optional label selector applied server-side (ex: "app=minecraft").
+    #[serde(default)]
+    pub label_selector: Option<String>,
+
+    /// Bearer token file. Defaults to the in-cluster service account token.
+    #[serde(default = "defaults::kubernetes_token_file")]
+    pub token_file: PathBuf,
+
+    /// CA bundle used to verify the API server certificate.
+    /// Defaults to the in-cluster service account CA.
+    #[serde(default = "defaults::kubernetes_ca_file")]
+    pub ca_file: PathBuf,
+
+    /// Discover annotated Services (routed through their ClusterIP).
+    #[serde(default = "defaults::true_val")]
+    pub watch_services: bool,
+
+    /// Discover annotated Pods (routed through their pod IP).
+    #[serde(default = "defaults::true_val")]
+    pub watch_pods: bool,
+
+    /// Reconnection delay after the watch stream drops.
+    #[serde(default = "defaults::kubernetes_reconnect_delay")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub reconnect_delay: Duration,
+}
+
+impl Default for KubernetesProviderConfig {
+    fn default() -> Self {
+        Self {
+            api_server: None,
+            namespace: None,
+            label_selector: None,
+            token_file: defaults::kubernetes_token_file(),
+            ca_file: defaults::kubernetes_ca_file(),
+            watch_services: true,
+            watch_pods: true,
+            reconnect_delay: defaults::kubernetes_reconnect_delay(),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Last server memory configuration.
+
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::defaults;
+
+/// Sends returning players back to the server they logged out from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LastServerConfig {
+    /// Networks where the last server is remembered.
+    pub networks: Vec<String>,
+
+    /// How long a player's last server is remembered after they log out.
+    #[serde(default = "defaults::last_server_expiry")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub expiry: Duration,
+
+    /// Domains that always send players to the server they route to (forced hosts).
+    #[serde(default)]
+    pub forced_hosts: Vec<String>,
+}
@@ -5,12 +5,17 @@ mod ban;
 mod docker;
 mod forwarding;
 mod ip_filter;
+mod kubernetes;
+mod last_server;
 mod network;
 mod permissions;
 mod proxy_mode;
 mod rate_limit;
+mod reconnect;
+mod resource_pack;
 mod server_manager;
 mod status;
+mod tab_list;
 mod telemetry;
 mod web;
 
@@ -19,14 +24,21 @@ pub use ban::BanConfig;
 pub use docker::DockerProviderConfig;
 pub use forwarding::{BungeeCordChannelPermissions, ForwardingConfig, ForwardingMode};
 pub use ip_filter::IpFilterConfig;
+pub use kubernetes::KubernetesProviderConfig;
+pub use last_server::LastServerConfig;
 pub use network::{KeepaliveConfig, TimeoutConfig};
 pub use permissions::PermissionsConfig;
 pub use proxy_mode::ProxyMode;
 pub use rate_limit::RateLimitConfig;
+pub use reconnect::ReconnectConfig;
+pub use resource_pack::ResourcePackConfig;
 pub use server_manager::{
-    CraftyManagerConfig, LocalManagerConfig, PterodactylManagerConfig, ServerManagerConfig,
+    CraftyManagerConfig, DockerManagerConfig, HttpManagerConfig, HttpRequestConfig,
+    HttpStatusMapping, LocalManagerConfig, PterodactylManagerConfig, RestartConfig, RestartPolicy,
+    ScheduleAction, ScheduleConfig, ScheduleEntry, ServerManagerConfig, WakeConfig,
 };
 pub use status::{MotdConfig, MotdEntry, StatusCacheConfig};
+pub use tab_list::TabListConfig;
 pub use telemetry::{MetricsConfig, ResourceConfig, TelemetryConfig, TracesConfig};
 pub use web::WebConfig;
 
@@ -2,40 +2,46 @@
 
 use std::time::Duration;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::defaults;
 
 /// Server-specific timeouts (overrides global settings).
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TimeoutConfig {
     #[serde(default = "defaults::connect_timeout")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub connect: Duration,
 
     #[serde(default = "defaults::read_timeout")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub read: Duration,
 
     #[serde(default = "defaults::write_timeout")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub write: Duration,
 }
 
 /// Controls the keepalive probes sent on TCP connections
 /// to detect dead connections.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct KeepaliveConfig {
     /// Idle duration before the first probe.
     #[serde(default = "defaults::keepalive_time")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub time: Duration,
 
     /// Interval between probes.
     #[serde(default = "defaults::keepalive_interval")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub interval: Duration,
 
     /// Number of failed probes before closing the connection.
@@ -2,11 +2,12 @@
 
 use std::time::Duration;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::defaults;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct RateLimitConfig {
     /// Maximum login connections per IP per window.
@@ -16,6 +17,7 @@ pub struct RateLimitConfig {
     /// Window duration for logins.
     #[serde(default = "defaults::rate_limit_window")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub window: Duration,
 
     /// Separate limit for status pings (more permissive).
@@ -25,6 +27,7 @@ pub struct RateLimitConfig {
     /// Window duration for status pings.
     #[serde(default = "defaults::rate_limit_status_window")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub status_window: Duration,
 }
 
@@ -0,0 +1,60 @@
+//! Reconnect limbo configuration.
+
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::defaults;
+
+/// Holds players in limbo while their backend restarts, then sends them back.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReconnectConfig {
+    /// How long players wait for the server before giving up.
+    #[serde(default = "defaults::reconnect_timeout")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub timeout: Duration,
+
+    /// Delay between two checks of the server.
+    #[serde(default = "defaults::reconnect_poll_interval")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub poll_interval: Duration,
+
+    /// Server players are sent to after `timeout` (unset: they are disconnected).
+    #[serde(default)]
+    pub fallback: Option<String>,
+
+    /// Kick messages that mean the server is restarting (case-insensitive substrings).
+    /// A lost connection always counts.
+    #[serde(default = "defaults::reconnect_kick_reasons")]
+    pub kick_reasons: Vec<String>,
+
+    /// Title shown while waiting. Supports `{server}` and legacy `&` colour codes.
+    #[serde(default = "defaults::reconnect_title")]
+    pub title: String,
+
+    /// Subtitle shown while waiting. Supports `{server}` and `{elapsed}` (seconds).
+    #[serde(default = "defaults::reconnect_subtitle")]
+    pub subtitle: String,
+
+    /// Disconnect message once `timeout` elapses without a `fallback`.
+    #[serde(default = "defaults::reconnect_timeout_message")]
+    pub timeout_message: String,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            timeout: defaults::reconnect_timeout(),
+            poll_interval: defaults::reconnect_poll_interval(),
+            fallback: None,
+            kick_reasons: defaults::reconnect_kick_reasons(),
+            title: defaults::reconnect_title(),
+            subtitle: defaults::reconnect_subtitle(),
+            timeout_message: defaults::reconnect_timeout_message(),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Resource pack configuration.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::defaults;
+
+/// A resource pack pushed to players by the proxy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ResourcePackConfig {
+    /// Download URL of the pack (`http://` or `https://`).
+    pub url: String,
+
+    /// SHA-1 of the pack as 40 hex characters. Lets clients reuse a cached download.
+    #[serde(default)]
+    pub hash: Option<String>,
+
+    /// Message shown in the download prompt (1.17+). Supports legacy `&` colour codes.
+    #[serde(default)]
+    pub prompt: Option<String>,
+
+    /// Disconnects players who decline the pack or fail to download it.
+    #[serde(default)]
+    pub required: bool,
+
+    /// Disconnect message for players who refuse a required pack.
+    #[serde(default = "defaults::resource_pack_kick_message")]
+    pub kick_message: String,
+}
@@ -2,21 +2,70 @@
 
 use std::time::Duration;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::defaults;
 
 /// Server manager configuration (auto start/stop).
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ServerManagerConfig {
     Local(LocalManagerConfig),
     Pterodactyl(PterodactylManagerConfig),
     Crafty(CraftyManagerConfig),
+    Docker(DockerManagerConfig),
+    Http(Box<HttpManagerConfig>),
+}
+
+impl ServerManagerConfig {
+    /// Restart policy of the configured provider.
+    pub const fn restart(&self) -> &RestartConfig {
+        match self {
+            Self::Local(cfg) => &cfg.restart,
+            Self::Pterodactyl(cfg) => &cfg.restart,
+            Self::Crafty(cfg) => &cfg.restart,
+            Self::Docker(cfg) => &cfg.restart,
+            Self::Http(cfg) => &cfg.restart,
+        }
+    }
+
+    /// Start/stop schedule of the configured provider.
+    pub const fn schedule(&self) -> &ScheduleConfig {
+        match self {
+            Self::Local(cfg) => &cfg.schedule,
+            Self::Pterodactyl(cfg) => &cfg.schedule,
+            Self::Crafty(cfg) => &cfg.schedule,
+            Self::Docker(cfg) => &cfg.schedule,
+            Self::Http(cfg) => &cfg.schedule,
+        }
+    }
+
+    /// Wake triggers of the configured provider.
+    pub const fn wake(&self) -> &WakeConfig {
+        match self {
+            Self::Local(cfg) => &cfg.wake,
+            Self::Pterodactyl(cfg) => &cfg.wake,
+            Self::Crafty(cfg) => &cfg.wake,
+            Self::Docker(cfg) => &cfg.wake,
+            Self::Http(cfg) => &cfg.wake,
+        }
+    }
+
+    /// Evacuation target used by graceful restarts.
+    pub fn evacuate_to(&self) -> Option<&str> {
+        match self {
+            Self::Local(cfg) => cfg.evacuate_to.as_deref(),
+            Self::Pterodactyl(cfg) => cfg.evacuate_to.as_deref(),
+            Self::Crafty(cfg) => cfg.evacuate_to.as_deref(),
+            Self::Docker(cfg) => cfg.evacuate_to.as_deref(),
+            Self::Http(cfg) => cfg.evacuate_to.as_deref(),
+        }
+    }
 }
 
 /// Local provider: launches a local Java process.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct LocalManagerConfig {
     /// Command to execute (e.g., "java")
@@ -32,19 +81,35 @@ pub struct LocalManagerConfig {
     /// Timeout for graceful shutdown
     #[serde(default = "defaults::shutdown_timeout")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub shutdown_timeout: Duration,
     /// Idle duration before automatic shutdown (None = disabled)
     #[serde(default)]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "Option<crate::schema::HumanDuration>")]
     pub shutdown_after: Option<Duration>,
     /// Timeout for server startup
     #[serde(default = "defaults::start_timeout")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub start_timeout: Duration,
+    /// Automatic restart after an unexpected exit
+    #[serde(default)]
+    pub restart: RestartConfig,
+    /// Scheduled starts, stops and restarts
+    #[serde(default)]
+    pub schedule: ScheduleConfig,
+    /// Server players are moved to during a graceful restart
+    /// (`"$limbo"`: this server's limbo handlers, unset: players are disconnected)
+    #[serde(default)]
+    pub evacuate_to: Option<String>,
+    /// Wake on status ping and predictive pre-warming
+    #[serde(default)]
+    pub wake: WakeConfig,
 }
 
 /// Pterodactyl provider: REST API.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PterodactylManagerConfig {
     pub api_url: String,
@@ -53,19 +118,35 @@ pub struct PterodactylManagerConfig {
     /// Idle duration before automatic shutdown (None = disabled)
     #[serde(default)]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "Option<crate::schema::HumanDuration>")]
     pub shutdown_after: Option<Duration>,
     /// Timeout for server startup
     #[serde(default = "defaults::start_timeout")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub start_timeout: Duration,
     /// Polling interval to check server state
     #[serde(default = "defaults::poll_interval")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub poll_interval: Duration,
+    /// Automatic restart after an unexpected exit
+    #[serde(default)]
+    pub restart: RestartConfig,
+    /// Scheduled starts, stops and restarts
+    #[serde(default)]
+    pub schedule: ScheduleConfig,
+    /// Server players are moved to during a graceful restart
+    /// (`"$limbo"`: this server's limbo handlers, unset: players are disconnected)
+    #[serde(default)]
+    pub evacuate_to: Option<String>,
+    /// Wake on status ping and predictive pre-warming
+    #[serde(default)]
+    pub wake: WakeConfig,
 }
 
 /// Crafty Controller provider: REST API.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct CraftyManagerConfig {
     pub api_url: String,
@@ -74,13 +155,385 @@ pub struct CraftyManagerConfig {
     /// Idle duration before automatic shutdown (None = disabled)
     #[serde(default)]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "Option<crate::schema::HumanDuration>")]
+    pub shutdown_after: Option<Duration>,
+    /// Timeout for server startup
+    #[serde(default = "defaults::start_timeout")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub start_timeout: Duration,
+    /// Polling interval to check server state
+    #[serde(default = "defaults::poll_interval")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub poll_interval: Duration,
+    /// Automatic restart after an unexpected exit
+    #[serde(default)]
+    pub restart: RestartConfig,
+    /// Scheduled starts, stops and restarts
+    #[serde(default)]
+    pub schedule: ScheduleConfig,
+    /// Server players are moved to during a graceful restart
+    /// (`"$limbo"`: this server's limbo handlers, unset: players are disconnected)
+    #[serde(default)]
+    pub evacuate_to: Option<String>,
+    /// Wake on status ping and predictive pre-warming
+    #[serde(default)]
+    pub wake: WakeConfig,
+}
+
+/// Docker provider: starts and stops an existing container.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DockerManagerConfig {
+    /// Container name or ID
+    pub container: String,
+    /// Docker endpoint (`unix:///var/run/docker.sock` or `tcp://host:2375`)
+    #[serde(default = "defaults::docker_endpoint")]
+    pub endpoint: String,
+    /// Pause the container when idle instead of stopping it
+    #[serde(default)]
+    pub pause: bool,
+    /// Time given to the container to stop before it is killed
+    #[serde(default = "defaults::shutdown_timeout")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub stop_timeout: Duration,
+    /// Idle duration before automatic shutdown (None = disabled)
+    #[serde(default)]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "Option<crate::schema::HumanDuration>")]
+    pub shutdown_after: Option<Duration>,
+    /// Timeout for server startup
+    #[serde(default = "defaults::start_timeout")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub start_timeout: Duration,
+    /// Polling interval to check container state
+    #[serde(default = "defaults::poll_interval")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub poll_interval: Duration,
+    /// Automatic restart after an unexpected exit
+    #[serde(default)]
+    pub restart: RestartConfig,
+    /// Scheduled starts, stops and restarts
+    #[serde(default)]
+    pub schedule: ScheduleConfig,
+    /// Server players are moved to during a graceful restart
+    /// (`"$limbo"`: this server's limbo handlers, unset: players are disconnected)
+    #[serde(default)]
+    pub evacuate_to: Option<String>,
+    /// Wake on status ping and predictive pre-warming
+    #[serde(default)]
+    pub wake: WakeConfig,
+}
+
+/// Generic HTTP provider: any panel reachable through simple REST calls.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct HttpManagerConfig {
+    /// Request that starts the server
+    pub start: HttpRequestConfig,
+    /// Request that stops the server
+    pub stop: HttpRequestConfig,
+    /// Request whose response tells the server state
+    pub status: HttpRequestConfig,
+    /// How the status response maps to a server state
+    #[serde(default)]
+    pub status_mapping: HttpStatusMapping,
+    /// Timeout of each request
+    #[serde(default = "defaults::http_request_timeout")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub request_timeout: Duration,
+    /// Idle duration before automatic shutdown (None = disabled)
+    #[serde(default)]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "Option<crate::schema::HumanDuration>")]
     pub shutdown_after: Option<Duration>,
     /// Timeout for server startup
     #[serde(default = "defaults::start_timeout")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub start_timeout: Duration,
     /// Polling interval to check server state
     #[serde(default = "defaults::poll_interval")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub poll_interval: Duration,
+    /// Automatic restart after an unexpected exit
+    #[serde(default)]
+    pub restart: RestartConfig,
+    /// Scheduled starts, stops and restarts
+    #[serde(default)]
+    pub schedule: ScheduleConfig,
+    /// Server players are moved to during a graceful restart
+    /// (`"$limbo"`: this server's limbo handlers, unset: players are disconnected)
+    #[serde(default)]
+    pub evacuate_to: Option<String>,
+    /// Wake on status ping and predictive pre-warming
+    #[serde(default)]
+    pub wake: WakeConfig,
+}
+
+/// A templated HTTP request. `{server_id}` in the URL, header values and
+/// body is replaced by the managed server's ID.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct HttpRequestConfig {
+    /// HTTP method (e.g. "GET", "POST")
+    pub method: String,
+    /// Request URL
+    pub url: String,
+    /// Extra request headers (e.g. an `Authorization` header)
+    #[serde(default)]
+    pub headers: std::collections::BTreeMap<String, String>,
+    /// Raw request body
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+/// Extracts the server state from a status response.
+///
+/// The value found by `json_path` or `regex` (or the whole body when
+/// neither is set) is compared case-insensitively to the state lists.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct HttpStatusMapping {
+    /// Dotted path into a JSON body, e.g. `data.state` or `$.servers[0].running`
+    #[serde(default)]
+    pub json_path: Option<String>,
+    /// Regex applied to the body; the first capture group (or the whole match) is the state
+    #[serde(default)]
+    pub regex: Option<String>,
+    /// Values meaning the server is running
+    #[serde(default = "defaults::http_status_running")]
+    pub running: Vec<String>,
+    /// Values meaning the server is starting
+    #[serde(default = "defaults::http_status_starting")]
+    pub starting: Vec<String>,
+    /// Values meaning the server is stopping
+    #[serde(default = "defaults::http_status_stopping")]
+    pub stopping: Vec<String>,
+    /// Values meaning the server is stopped
+    #[serde(default = "defaults::http_status_stopped")]
+    pub stopped: Vec<String>,
+    /// Values meaning the server has crashed
+    #[serde(default = "defaults::http_status_crashed")]
+    pub crashed: Vec<String>,
+}
+
+impl Default for HttpStatusMapping {
+    fn default() -> Self {
+        Self {
+            json_path: None,
+            regex: None,
+            running: defaults::http_status_running(),
+            starting: defaults::http_status_starting(),
+            stopping: defaults::http_status_stopping(),
+            stopped: defaults::http_status_stopped(),
+            crashed: defaults::http_status_crashed(),
+        }
+    }
+}
+
+/// When a managed server is restarted after it exits on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RestartPolicy {
+    /// Never restart; the server stays `Crashed` or `Sleeping`.
+    #[default]
+    Never,
+    /// Restart only when the process exits with an error.
+    OnFailure,
+    /// Restart on every exit not requested by the proxy.
+    Always,
+}
+
+/// Crash auto-restart with exponential backoff.
+///
+/// More than `max_retries` restarts within `window` is treated as a
+/// crash loop: restarts stop until the server is started again manually.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RestartConfig {
+    #[serde(default)]
+    pub policy: RestartPolicy,
+    /// Restarts allowed within `window` before giving up
+    #[serde(default = "defaults::restart_max_retries")]
+    pub max_retries: u32,
+    /// Sliding window used to count restarts
+    #[serde(default = "defaults::restart_window")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub window: Duration,
+    /// Delay before the first restart, doubled on each retry
+    #[serde(default = "defaults::restart_backoff")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub backoff: Duration,
+    /// Upper bound for the backoff delay
+    #[serde(default = "defaults::restart_max_backoff")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub max_backoff: Duration,
+}
+
+impl Default for RestartConfig {
+    fn default() -> Self {
+        Self {
+            policy: RestartPolicy::default(),
+            max_retries: defaults::restart_max_retries(),
+            window: defaults::restart_window(),
+            backoff: defaults::restart_backoff(),
+            max_backoff: defaults::restart_max_backoff(),
+        }
+    }
+}
+
+/// Action performed by a schedule entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleAction {
+    Start,
+    Stop,
+    /// Stop then start again; skipped if the server is not running.
+    Restart,
+}
+
+impl std::fmt::Display for ScheduleAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Start => write!(f, "start"),
+            Self::Stop => write!(f, "stop"),
+            Self::Restart => write!(f, "restart"),
+        }
+    }
+}
+
+/// A cron expression and the action to run when it fires.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ScheduleEntry {
+    /// Cron expression in local time: `min hour day month weekday`
+    /// (a leading seconds field is also accepted)
+    pub cron: String,
+    pub action: ScheduleAction,
+}
+
+impl ScheduleEntry {
+    /// Parses the cron expression.
+    ///
+    /// Five-field crontab expressions get an implicit `0` seconds field.
+    ///
+    /// # Errors
+    /// Returns the parser message if the expression is invalid.
+    pub fn parse_cron(&self) -> Result<cron::Schedule, String> {
+        let expression = if self.cron.split_whitespace().count() == 5 {
+            format!("0 {}", self.cron)
+        } else {
+            self.cron.clone()
+        };
+        expression
+            .parse::<cron::Schedule>()
+            .map_err(|e| format!("invalid cron expression '{}': {e}", self.cron))
+    }
+}
+
+/// Scheduled start/stop windows and restarts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ScheduleConfig {
+    #[serde(default)]
+    pub entries: Vec<ScheduleEntry>,
+    /// Refuse wake-on-join between a scheduled stop and the next scheduled start
+    #[serde(default)]
+    pub block_outside_window: bool,
+    /// Kick message for players joining outside the window
+    #[serde(default = "defaults::schedule_closed_message")]
+    pub closed_message: String,
+    /// Countdown before a scheduled stop or restart, sent to players on the server
+    #[serde(default = "defaults::schedule_warnings")]
+    #[serde(with = "duration_list")]
+    #[schemars(with = "Vec<crate::schema::HumanDuration>")]
+    pub warnings: Vec<Duration>,
+    /// Countdown message; `{action}` and `{time}` are replaced
+    #[serde(default = "defaults::schedule_warning_message")]
+    pub warning_message: String,
+}
+
+impl Default for ScheduleConfig {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            block_outside_window: false,
+            closed_message: defaults::schedule_closed_message(),
+            warnings: defaults::schedule_warnings(),
+            warning_message: defaults::schedule_warning_message(),
+        }
+    }
+}
+
+/// Extra triggers that start a sleeping server before a player joins.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WakeConfig {
+    /// Start the server when a client pings it in the server list
+    #[serde(default)]
+    pub on_ping: bool,
+    /// Start the server ahead of the hours players usually join
+    #[serde(default)]
+    pub prewarm: bool,
+    /// How long before a usual peak hour the server is started
+    #[serde(default = "defaults::prewarm_lead")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub prewarm_lead: Duration,
+    /// Join history taken into account
+    #[serde(default = "defaults::prewarm_history")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub prewarm_history: Duration,
+    /// Days of the history with a join during an hour for it to count as a peak
+    #[serde(default = "defaults::prewarm_min_days")]
+    pub prewarm_min_days: u32,
+}
+
+impl Default for WakeConfig {
+    fn default() -> Self {
+        Self {
+            on_ping: false,
+            prewarm: false,
+            prewarm_lead: defaults::prewarm_lead(),
+            prewarm_history: defaults::prewarm_history(),
+            prewarm_min_days: defaults::prewarm_min_days(),
+        }
+    }
+}
+
+/// `humantime_serde` for a list of durations.
+mod duration_list {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &[Duration], serializer: S) -> Result<S::Ok, S::Error> {
+        value
+            .iter()
+            .map(|d| humantime_serde::Serde::from(*d))
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Duration>, D::Error> {
+        Ok(
+            Vec::<humantime_serde::Serde<Duration>>::deserialize(deserializer)?
+                .into_iter()
+                .map(humantime_serde::Serde::into_inner)
+                .collect(),
+        )
+    }
 }
@@ -2,16 +2,18 @@
 
 use std::time::Duration;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::defaults;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct StatusCacheConfig {
     /// Time-to-live for a cache entry.
     #[serde(default = "defaults::status_cache_ttl")]
     #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
     pub ttl: Duration,
 
     /// Maximum number of entries.
@@ -29,7 +31,7 @@ impl Default for StatusCacheConfig {
 }
 
 /// MOTD per server state.
-#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct MotdConfig {
     pub online: Option<MotdEntry>,
@@ -41,7 +43,7 @@ pub struct MotdConfig {
     pub unreachable: Option<MotdEntry>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct MotdEntry {
     /// MOTD text (supports Minecraft formatting codes).
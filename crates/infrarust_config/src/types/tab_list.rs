@@ -0,0 +1,45 @@
+//! Tab list configuration.
+
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::defaults;
+
+/// Proxy-managed tab list header, footer and network-wide player list.
+///
+/// Header and footer use `&` colour codes and the placeholders `{player}`,
+/// `{server}`, `{network}`, `{online}`, `{network_online}` and `{server_online}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TabListConfig {
+    /// Text above the player list (empty = left to the backend).
+    #[serde(default)]
+    pub header: String,
+
+    /// Text below the player list (empty = left to the backend).
+    #[serde(default)]
+    pub footer: String,
+
+    /// Lists players from every server in the viewer's network, not just their own server.
+    #[serde(default)]
+    pub global: bool,
+
+    /// How often the header, footer and global player list are refreshed.
+    #[serde(default = "defaults::tab_list_refresh_interval")]
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "crate::schema::HumanDuration")]
+    pub refresh_interval: Duration,
+}
+
+impl Default for TabListConfig {
+    fn default() -> Self {
+        Self {
+            header: String::new(),
+            footer: String::new(),
+            global: false,
+            refresh_interval: defaults::tab_list_refresh_interval(),
+        }
+    }
+}
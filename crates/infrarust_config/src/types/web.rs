@@ -1,5 +1,6 @@
 //! Web admin API / UI configuration.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 fn default_true() -> bool {
@@ -14,7 +15,7 @@ fn default_requests_per_minute() -> u64 {
     60
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct WebConfig {
     #[serde(default = "default_true")]
@@ -35,7 +36,7 @@ pub struct WebConfig {
     pub rate_limit: WebRateLimitConfig,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct WebRateLimitConfig {
     #[serde(default = "default_requests_per_minute")]
@@ -5,22 +5,31 @@ use std::collections::HashSet;
 use crate::error::ConfigError;
 use crate::proxy::ProxyConfig;
 use crate::server::ServerConfig;
+use crate::types::{HttpManagerConfig, ResourcePackConfig, ServerManagerConfig};
 
 /// Validates a single server configuration.
 ///
 /// Checks:
 /// - Forwarding modes (Passthrough, ZeroCopy, ServerOnly) have at least one domain
 /// - Forwarding modes cannot belong to a network (no server switching support)
+/// - Forwarding modes cannot have fallbacks, and a server is not its own fallback
+/// - Forwarding modes cannot use the reconnect limbo, whose poll interval must be non-zero
+/// - Forwarding modes cannot push resource packs, which need an HTTP(S) URL and a valid SHA-1
 /// - At least one address is defined
 /// - No empty domain strings
 /// - `name` (if set) matches `[a-z0-9_-]+`
 /// - `network` (if set) matches `[a-z0-9_-]+`
+/// - Server manager schedule entries are valid cron expressions
+/// - Server manager `evacuate_to` does not point at the server itself
+/// - HTTP server manager requests and status mapping are well-formed
+/// - Server manager pre-warming needs at least one day of joins
 ///
 /// # Errors
 ///
 /// Returns [`ConfigError::NoDomains`] if a forwarding-mode server has no domains,
 /// [`ConfigError::NoAddresses`] if no addresses are defined, or
-/// [`ConfigError::Validation`] if any domain string is empty or name/network are invalid.
+/// [`ConfigError::Validation`] if any domain string is empty, name/network are invalid
+/// or a schedule entry does not parse.
 pub fn validate_server_config(config: &ServerConfig) -> Result<(), ConfigError> {
     let id = config.effective_id();
 
@@ -38,8 +47,50 @@ pub fn validate_server_config(config: &ServerConfig) -> Result<(), ConfigError>
                 config.proxy_mode
             )));
         }
+        if !config.fallbacks.is_empty() {
+            return Err(ConfigError::Validation(format!(
+                "server '{id}' uses {:?} mode which cannot have fallbacks \
+                 (forwarding modes don't support server switching)",
+                config.proxy_mode
+            )));
+        }
+        if config.reconnect.is_some() {
+            return Err(ConfigError::Validation(format!(
+                "server '{id}' uses {:?} mode which cannot use the reconnect limbo \
+                 (forwarding modes don't support server switching)",
+                config.proxy_mode
+            )));
+        }
+        if !config.resource_packs.is_empty() {
+            return Err(ConfigError::Validation(format!(
+                "server '{id}' uses {:?} mode which cannot push resource packs \
+                 (forwarding modes don't inspect packets)",
+                config.proxy_mode
+            )));
+        }
+    }
+
+    if config.fallbacks.contains(&id) {
+        return Err(ConfigError::Validation(format!(
+            "server '{id}': fallbacks cannot include the server itself"
+        )));
+    }
+
+    if let Some(reconnect) = &config.reconnect {
+        if reconnect.poll_interval.is_zero() {
+            return Err(ConfigError::Validation(format!(
+                "server '{id}': reconnect.poll_interval must be greater than zero"
+            )));
+        }
+        if reconnect.fallback.as_deref() == Some(id.as_str()) {
+            return Err(ConfigError::Validation(format!(
+                "server '{id}': reconnect.fallback cannot be the server itself"
+            )));
+        }
     }
 
+    validate_resource_packs(&config.resource_packs, &format!("server '{id}'"))?;
+
     if config.addresses.is_empty() {
         return Err(ConfigError::NoAddresses { id });
     }
@@ -60,6 +111,27 @@ pub fn validate_server_config(config: &ServerConfig) -> Result<(), ConfigError>
         validate_identifier(network, "network", &id)?;
     }
 
+    if let Some(manager) = &config.server_manager {
+        for entry in &manager.schedule().entries {
+            entry
+                .parse_cron()
+                .map_err(|e| ConfigError::Validation(format!("server '{id}': schedule: {e}")))?;
+        }
+        if manager.evacuate_to() == Some(id.as_str()) {
+            return Err(ConfigError::Validation(format!(
+                "server '{id}': evacuate_to cannot be the server itself"
+            )));
+        }
+        if manager.wake().prewarm && manager.wake().prewarm_min_days == 0 {
+            return Err(ConfigError::Validation(format!(
+                "server '{id}': wake.prewarm_min_days must be at least 1"
+            )));
+        }
+        if let ServerManagerConfig::Http(http) = manager {
+            validate_http_manager(http, &id)?;
+        }
+    }
+
     #[cfg(not(target_os = "linux"))]
     if config.proxy_mode == crate::types::ProxyMode::ZeroCopy {
         tracing::warn!(
@@ -71,6 +143,41 @@ pub fn validate_server_config(config: &ServerConfig) -> Result<(), ConfigError>
     Ok(())
 }
 
+fn validate_http_manager(config: &HttpManagerConfig, server_id: &str) -> Result<(), ConfigError> {
+    for (name, request) in [
+        ("start", &config.start),
+        ("stop", &config.stop),
+        ("status", &config.status),
+    ] {
+        if request.method.is_empty() || !request.method.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return Err(ConfigError::Validation(format!(
+                "server '{server_id}': server_manager.{name}.method '{}' is not an HTTP method",
+                request.method
+            )));
+        }
+        if !request.url.starts_with("http://") && !request.url.starts_with("https://") {
+            return Err(ConfigError::Validation(format!(
+                "server '{server_id}': server_manager.{name}.url must start with http:// or https://"
+            )));
+        }
+    }
+
+    let mapping = &config.status_mapping;
+    if mapping.json_path.is_some() && mapping.regex.is_some() {
+        return Err(ConfigError::Validation(format!(
+            "server '{server_id}': status_mapping accepts json_path or regex, not both"
+        )));
+    }
+    if let Some(pattern) = &mapping.regex {
+        regex::Regex::new(pattern).map_err(|e| {
+            ConfigError::Validation(format!(
+                "server '{server_id}': status_mapping.regex is invalid: {e}"
+            ))
+        })?;
+    }
+    Ok(())
+}
+
 fn validate_identifier(value: &str, field: &str, server_id: &str) -> Result<(), ConfigError> {
     if value.is_empty() {
         return Err(ConfigError::Validation(format!(
@@ -114,15 +221,46 @@ pub fn validate_server_configs(configs: &[ServerConfig]) -> Result<(), ConfigErr
 ///
 /// Checks:
 /// - `servers_dir` exists on disk
+/// - Resource packs have an HTTP(S) URL and a valid SHA-1
 ///
 /// # Errors
 ///
 /// Returns [`ConfigError::DirectoryNotFound`] if `servers_dir` does not
-/// exist or is not a directory.
+/// exist or is not a directory, or [`ConfigError::Validation`] for an
+/// invalid resource pack.
 pub fn validate_proxy_config(config: &ProxyConfig) -> Result<(), ConfigError> {
+    validate_servers_dir(config)?;
+    validate_proxy_resource_packs(config)?;
+
+    Ok(())
+}
+
+pub(crate) fn validate_servers_dir(config: &ProxyConfig) -> Result<(), ConfigError> {
     if !config.servers_dir.is_dir() {
         return Err(ConfigError::DirectoryNotFound(config.servers_dir.clone()));
     }
+    Ok(())
+}
+
+pub(crate) fn validate_proxy_resource_packs(config: &ProxyConfig) -> Result<(), ConfigError> {
+    validate_resource_packs(&config.resource_packs, "proxy")
+}
 
+fn validate_resource_packs(packs: &[ResourcePackConfig], owner: &str) -> Result<(), ConfigError> {
+    for pack in packs {
+        if !pack.url.starts_with("http://") && !pack.url.starts_with("https://") {
+            return Err(ConfigError::Validation(format!(
+                "{owner}: resource pack URL '{}' must start with http:// or https://",
+                pack.url
+            )));
+        }
+        if let Some(hash) = &pack.hash
+            && (hash.len() != 40 || !hash.chars().all(|c| c.is_ascii_hexdigit()))
+        {
+            return Err(ConfigError::Validation(format!(
+                "{owner}: resource pack hash '{hash}' must be 40 hex characters (SHA-1)"
+            )));
+        }
+    }
     Ok(())
 }
@@ -19,6 +19,9 @@ fn make_config(id: &str, domains: &[&str], addr: &str) -> ServerConfig {
         ip_filter: None,
         disconnect_message: None,
         limbo_handlers: vec![],
+        fallbacks: Vec::new(),
+        reconnect: None,
+        resource_packs: Vec::new(),
     }
 }
 
@@ -82,3 +82,41 @@ fn test_proxy_defaults_minimal_config() {
     assert_eq!(config.status_cache.ttl, Duration::from_secs(5));
     assert!(config.default_motd.is_none());
 }
+
+#[test]
+fn test_parse_proxy_last_server() {
+    let config: ProxyConfig = toml::from_str(
+        r#"
+[last_server]
+networks = ["main"]
+forced_hosts = ["survival.mc.example.com"]
+"#,
+    )
+    .unwrap();
+
+    let last_server = config.last_server.unwrap();
+    assert_eq!(last_server.networks, ["main"]);
+    assert_eq!(last_server.expiry, Duration::from_secs(30 * 24 * 60 * 60));
+    assert_eq!(last_server.forced_hosts, ["survival.mc.example.com"]);
+}
+
+#[test]
+fn test_parse_proxy_tab_list() {
+    let config: ProxyConfig = toml::from_str(
+        r#"
+[tab_list]
+header = "&6My Network"
+global = true
+"#,
+    )
+    .unwrap();
+
+    let tab_list = config.tab_list.unwrap();
+    assert_eq!(tab_list.header, "&6My Network");
+    assert!(tab_list.footer.is_empty());
+    assert!(tab_list.global);
+    assert_eq!(tab_list.refresh_interval, Duration::from_secs(5));
+
+    let config: ProxyConfig = toml::from_str("").unwrap();
+    assert!(config.tab_list.is_none());
+}
@@ -1,5 +1,7 @@
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
-use infrarust_config::{ProxyMode, ServerConfig, ServerManagerConfig};
+use infrarust_config::{
+    ProxyMode, RestartPolicy, ScheduleAction, ServerConfig, ServerManagerConfig,
+};
 
 fn load_survival() -> ServerConfig {
     let toml_str = include_str!("fixtures/survival.toml");
@@ -113,3 +115,228 @@ fn test_deny_unknown_fields() {
     let result: Result<ServerConfig, _> = toml::from_str(toml_str);
     assert!(result.is_err(), "unknown field should cause an error");
 }
+
+#[test]
+fn test_parse_docker_server_manager() {
+    let toml_str = r#"
+        addresses = ["mc-survival:25565"]
+
+        [server_manager]
+        type = "docker"
+        container = "mc-survival"
+        pause = true
+        shutdown_after = "10m"
+    "#;
+
+    let config: ServerConfig = toml::from_str(toml_str).unwrap();
+    match config.server_manager {
+        Some(ServerManagerConfig::Docker(ref docker)) => {
+            assert_eq!(docker.container, "mc-survival");
+            assert_eq!(docker.endpoint, "unix:///var/run/docker.sock");
+            assert!(docker.pause);
+            assert_eq!(
+                docker.shutdown_after,
+                Some(std::time::Duration::from_secs(600))
+            );
+            assert_eq!(docker.stop_timeout, std::time::Duration::from_secs(30));
+        }
+        other => panic!("expected Docker, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_parse_server_manager_restart_policy() {
+    let toml_str = r#"
+        addresses = ["localhost:25565"]
+
+        [server_manager]
+        type = "local"
+        command = "java"
+        working_dir = "/srv/survival"
+
+        [server_manager.restart]
+        policy = "on_failure"
+        max_retries = 3
+        backoff = "10s"
+    "#;
+
+    let config: ServerConfig = toml::from_str(toml_str).unwrap();
+    let restart = config.server_manager.as_ref().unwrap().restart();
+    assert_eq!(restart.policy, RestartPolicy::OnFailure);
+    assert_eq!(restart.max_retries, 3);
+    assert_eq!(restart.backoff, std::time::Duration::from_secs(10));
+    assert_eq!(restart.window, std::time::Duration::from_secs(600));
+}
+
+#[test]
+fn test_parse_server_manager_schedule() {
+    let toml_str = r#"
+        addresses = ["localhost:25565"]
+
+        [server_manager]
+        type = "local"
+        command = "java"
+        working_dir = "/srv/events"
+
+        [server_manager.schedule]
+        block_outside_window = true
+        warnings = ["1m", "10s"]
+
+        [[server_manager.schedule.entries]]
+        cron = "0 18 * * Sat,Sun"
+        action = "start"
+
+        [[server_manager.schedule.entries]]
+        cron = "0 23 * * Sat,Sun"
+        action = "stop"
+    "#;
+
+    let config: ServerConfig = toml::from_str(toml_str).unwrap();
+    let schedule = config.server_manager.as_ref().unwrap().schedule();
+    assert!(schedule.block_outside_window);
+    assert_eq!(
+        schedule.warnings,
+        vec![
+            std::time::Duration::from_secs(60),
+            std::time::Duration::from_secs(10)
+        ]
+    );
+    assert_eq!(schedule.entries.len(), 2);
+    assert_eq!(schedule.entries[0].action, ScheduleAction::Start);
+    assert!(schedule.entries.iter().all(|e| e.parse_cron().is_ok()));
+}
+
+#[test]
+fn test_parse_server_manager_wake() {
+    let toml_str = r#"
+        addresses = ["localhost:25565"]
+
+        [server_manager]
+        type = "local"
+        command = "java"
+        working_dir = "/srv/lobby"
+
+        [server_manager.wake]
+        on_ping = true
+        prewarm = true
+        prewarm_lead = "10m"
+    "#;
+
+    let config: ServerConfig = toml::from_str(toml_str).unwrap();
+    let wake = config.server_manager.as_ref().unwrap().wake();
+    assert!(wake.on_ping);
+    assert!(wake.prewarm);
+    assert_eq!(wake.prewarm_lead, std::time::Duration::from_secs(600));
+    assert_eq!(
+        wake.prewarm_history,
+        std::time::Duration::from_secs(7 * 24 * 3600)
+    );
+    assert_eq!(wake.prewarm_min_days, 3);
+}
+
+#[test]
+fn test_parse_fallbacks() {
+    let toml_str = r#"
+        addresses = ["localhost:25565"]
+        network = "main"
+        fallbacks = ["lobby-2", "$limbo"]
+    "#;
+
+    let config: ServerConfig = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.fallbacks, ["lobby-2", "$limbo"]);
+
+    let config: ServerConfig = toml::from_str(r#"addresses = ["localhost:25565"]"#).unwrap();
+    assert!(config.fallbacks.is_empty());
+}
+
+#[test]
+fn test_parse_reconnect() {
+    let toml_str = r#"
+        addresses = ["localhost:25565"]
+        proxy_mode = "client_only"
+
+        [reconnect]
+        timeout = "5m"
+        fallback = "lobby"
+    "#;
+
+    let config: ServerConfig = toml::from_str(toml_str).unwrap();
+    let reconnect = config.reconnect.unwrap();
+    assert_eq!(reconnect.timeout, std::time::Duration::from_secs(300));
+    assert_eq!(reconnect.poll_interval, std::time::Duration::from_secs(2));
+    assert_eq!(reconnect.fallback.as_deref(), Some("lobby"));
+    assert!(reconnect.kick_reasons.iter().any(|r| r == "Server closed"));
+}
+
+#[test]
+fn test_parse_resource_packs() {
+    let toml_str = r#"
+        addresses = ["localhost:25565"]
+        proxy_mode = "client_only"
+
+        [[resource_packs]]
+        url = "https://cdn.example.com/survival.zip"
+        hash = "2ef7bde608ce5404e97d5f042f95f89f1c232871"
+        prompt = "&eThis server uses custom textures"
+        required = true
+    "#;
+
+    let config: ServerConfig = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.resource_packs.len(), 1);
+    let pack = &config.resource_packs[0];
+    assert_eq!(pack.url, "https://cdn.example.com/survival.zip");
+    assert!(pack.required);
+    assert!(pack.kick_message.contains("resource pack"));
+}
+
+#[test]
+fn test_parse_server_manager_http() {
+    let toml_str = r#"
+        addresses = ["localhost:25565"]
+
+        [server_manager]
+        type = "http"
+        shutdown_after = "10m"
+
+        [server_manager.start]
+        method = "POST"
+        url = "https://panel.example.com/api/servers/{server_id}/start"
+        headers = { Authorization = "Bearer token" }
+        body = '{"id":"{server_id}"}'
+
+        [server_manager.stop]
+        method = "POST"
+        url = "https://panel.example.com/api/servers/{server_id}/stop"
+
+        [server_manager.status]
+        method = "GET"
+        url = "https://panel.example.com/api/servers/{server_id}"
+
+        [server_manager.status_mapping]
+        json_path = "data.state"
+        running = ["up"]
+    "#;
+
+    let config: ServerConfig = toml::from_str(toml_str).unwrap();
+    let Some(ServerManagerConfig::Http(http)) = config.server_manager else {
+        panic!("expected an http server manager");
+    };
+    assert_eq!(http.start.method, "POST");
+    assert_eq!(
+        http.start.headers.get("Authorization").map(String::as_str),
+        Some("Bearer token")
+    );
+    assert_eq!(http.start.body.as_deref(), Some(r#"{"id":"{server_id}"}"#));
+    assert!(http.stop.body.is_none());
+    assert_eq!(http.status_mapping.json_path.as_deref(), Some("data.state"));
+    assert_eq!(http.status_mapping.running, vec!["up".to_string()]);
+    assert_eq!(
+        http.status_mapping.stopped,
+        vec!["stopped", "offline", "false"]
+    );
+    assert_eq!(http.request_timeout, std::time::Duration::from_secs(10));
+    assert_eq!(
+        http.shutdown_after,
+        Some(std::time::Duration::from_secs(600))
+    );
+}
@@ -172,3 +172,153 @@ fn test_client_only_with_network_is_valid() {
     );
     assert!(validate_server_config(&config).is_ok());
 }
+
+#[test]
+fn test_invalid_schedule_cron_is_invalid() {
+    let config = from_toml(
+        r#"
+        addresses = ["127.0.0.1:25565"]
+
+        [server_manager]
+        type = "local"
+        command = "java"
+        working_dir = "/srv/events"
+
+        [[server_manager.schedule.entries]]
+        cron = "0 25 * * *"
+        action = "start"
+    "#,
+    );
+    assert!(validate_server_config(&config).is_err());
+}
+
+#[test]
+fn test_evacuate_to_self_is_invalid() {
+    let config = from_toml(
+        r#"
+        name = "survival"
+        addresses = ["127.0.0.1:25565"]
+
+        [server_manager]
+        type = "local"
+        command = "java"
+        working_dir = "/srv/survival"
+        evacuate_to = "survival"
+    "#,
+    );
+    assert!(validate_server_config(&config).is_err());
+}
+
+#[test]
+fn test_http_manager_with_both_mappings_is_invalid() {
+    let config = from_toml(
+        r#"
+        addresses = ["127.0.0.1:25565"]
+
+        [server_manager]
+        type = "http"
+        start = { method = "POST", url = "http://panel/start" }
+        stop = { method = "POST", url = "http://panel/stop" }
+        status = { method = "GET", url = "http://panel/status" }
+
+        [server_manager.status_mapping]
+        json_path = "state"
+        regex = "state=(\\w+)"
+    "#,
+    );
+    assert!(validate_server_config(&config).is_err());
+}
+
+#[test]
+fn test_fallbacks_on_forwarding_mode_is_invalid() {
+    let config = from_toml(
+        r#"
+        domains = ["mc.example.com"]
+        addresses = ["127.0.0.1:25565"]
+        proxy_mode = "passthrough"
+        fallbacks = ["lobby"]
+    "#,
+    );
+    assert!(validate_server_config(&config).is_err());
+}
+
+#[test]
+fn test_fallbacks_cannot_include_self() {
+    let config = from_toml(
+        r#"
+        name = "minigame"
+        addresses = ["127.0.0.1:25565"]
+        fallbacks = ["lobby", "minigame"]
+    "#,
+    );
+    assert!(validate_server_config(&config).is_err());
+}
+
+#[test]
+fn test_reconnect_on_forwarding_mode_is_invalid() {
+    let config = from_toml(
+        r#"
+        domains = ["mc.example.com"]
+        addresses = ["127.0.0.1:25565"]
+        proxy_mode = "passthrough"
+
+        [reconnect]
+    "#,
+    );
+    assert!(validate_server_config(&config).is_err());
+}
+
+#[test]
+fn test_resource_pack_hash_must_be_sha1() {
+    let config = from_toml(
+        r#"
+        addresses = ["127.0.0.1:25565"]
+        proxy_mode = "client_only"
+
+        [[resource_packs]]
+        url = "https://cdn.example.com/pack.zip"
+        hash = "not-a-hash"
+    "#,
+    );
+    assert!(validate_server_config(&config).is_err());
+
+    let config = from_toml(
+        r#"
+        addresses = ["127.0.0.1:25565"]
+        proxy_mode = "client_only"
+
+        [[resource_packs]]
+        url = "https://cdn.example.com/pack.zip"
+        hash = "2ef7bde608ce5404e97d5f042f95f89f1c232871"
+    "#,
+    );
+    assert!(validate_server_config(&config).is_ok());
+}
+
+#[test]
+fn test_resource_pack_url_must_be_http() {
+    let config = from_toml(
+        r#"
+        addresses = ["127.0.0.1:25565"]
+        proxy_mode = "client_only"
+
+        [[resource_packs]]
+        url = "file:///etc/pack.zip"
+    "#,
+    );
+    assert!(validate_server_config(&config).is_err());
+}
+
+#[test]
+fn test_reconnect_zero_poll_interval_is_invalid() {
+    let config = from_toml(
+        r#"
+        addresses = ["127.0.0.1:25565"]
+        proxy_mode = "client_only"
+
+        [reconnect]
+        poll_interval = "0s"
+    "#,
+    );
+    assert!(validate_server_config(&config).is_err());
+}
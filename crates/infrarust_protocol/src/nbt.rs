@@ -65,6 +65,19 @@ pub fn _skip_nbt_compound_nameless(r: &mut &[u8]) -> ProtocolResult<()> {
     skip_compound_payload(r, 0)
 }
 
+/// Reads one tag in 1.20.2+ network format (type byte + payload, no name)
+/// and returns its raw bytes.
+///
+/// Text components are sent this way since 1.20.3, usually as a compound but
+/// sometimes as a bare string tag.
+pub fn read_network_nbt(r: &mut &[u8]) -> ProtocolResult<Vec<u8>> {
+    let start = *r;
+    let tag_type = r.read_u8()?;
+    skip_tag_payload(r, tag_type, 0)?;
+    let len = start.len() - r.len();
+    Ok(start[..len].to_vec())
+}
+
 /// Skips the payload of a compound tag (children until TAG_End).
 fn skip_compound_payload(r: &mut &[u8], depth: u32) -> ProtocolResult<()> {
     if depth > MAX_DEPTH {
@@ -327,4 +340,22 @@ mod tests {
         let mut r: &[u8] = &data;
         assert!(skip_nbt_compound(&mut r).is_err());
     }
+
+    #[test]
+    fn test_read_network_nbt_returns_tag_bytes() {
+        // Nameless compound with a string child, followed by trailing data
+        let mut data = vec![TAG_COMPOUND, TAG_STRING];
+        data.extend_from_slice(&4u16.to_be_bytes());
+        data.extend_from_slice(b"text");
+        data.extend_from_slice(&2u16.to_be_bytes());
+        data.extend_from_slice(b"hi");
+        data.push(TAG_END);
+        let tag_len = data.len();
+        data.push(0x01);
+
+        let mut r: &[u8] = &data;
+        let tag = read_network_nbt(&mut r).unwrap();
+        assert_eq!(tag, &data[..tag_len]);
+        assert_eq!(r, &[0x01]);
+    }
 }
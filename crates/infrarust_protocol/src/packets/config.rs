@@ -3,6 +3,8 @@ use crate::error::ProtocolResult;
 use crate::version::{ConnectionState, Direction, ProtocolVersion};
 
 use super::Packet;
+use super::play::client_information::ClientInformation;
+use super::play::resource_pack::{ResourcePackRequest, ResourcePackResponse, read_pop, write_pop};
 
 /// A known data pack entry used in `CKnownPacks` / `SKnownPacks`.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -201,6 +203,138 @@ impl Packet for CConfigDisconnect {
     }
 }
 
+/// Offers a resource pack during configuration (Clientbound).
+///
+/// Same payload as the play-state
+/// [`CResourcePackPush`](crate::packets::play::resource_pack::CResourcePackPush).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CConfigResourcePackPush {
+    pub pack: ResourcePackRequest,
+}
+
+impl Packet for CConfigResourcePackPush {
+    const NAME: &'static str = "CConfigResourcePackPush";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Config
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        Ok(Self {
+            pack: ResourcePackRequest::read(r, version)?,
+        })
+    }
+
+    fn encode(
+        &self,
+        w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        self.pack.write(w, version)
+    }
+}
+
+/// Removes one pack, or all of them, during configuration (Clientbound, 1.20.3+).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CConfigResourcePackPop {
+    pub id: Option<uuid::Uuid>,
+}
+
+impl Packet for CConfigResourcePackPop {
+    const NAME: &'static str = "CConfigResourcePackPop";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Config
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], _version: ProtocolVersion) -> ProtocolResult<Self> {
+        Ok(Self { id: read_pop(r)? })
+    }
+
+    fn encode(
+        &self,
+        w: &mut (impl std::io::Write + ?Sized),
+        _version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        write_pop(w, self.id)
+    }
+}
+
+/// Client status update for a pack pushed during configuration (Serverbound).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SConfigResourcePackStatus {
+    pub response: ResourcePackResponse,
+}
+
+impl Packet for SConfigResourcePackStatus {
+    const NAME: &'static str = "SConfigResourcePackStatus";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Config
+    }
+
+    fn direction() -> Direction {
+        Direction::Serverbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        Ok(Self {
+            response: ResourcePackResponse::read(r, version)?,
+        })
+    }
+
+    fn encode(
+        &self,
+        w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        self.response.write(w, version)
+    }
+}
+
+/// Client settings sent at the start of configuration (Serverbound, 1.20.2+).
+///
+/// Same payload as the play-state
+/// [`SClientInformation`](crate::packets::play::client_information::SClientInformation).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SConfigClientInformation {
+    pub info: ClientInformation,
+}
+
+impl Packet for SConfigClientInformation {
+    const NAME: &'static str = "SConfigClientInformation";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Config
+    }
+
+    fn direction() -> Direction {
+        Direction::Serverbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        Ok(Self {
+            info: ClientInformation::read(r, version)?,
+        })
+    }
+
+    fn encode(
+        &self,
+        w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        self.info.write(w, version)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used)]
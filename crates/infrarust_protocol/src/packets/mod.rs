@@ -97,8 +97,9 @@ pub mod play;
 pub mod status;
 
 pub use config::{
-    CConfigDisconnect, CConfigPluginMessage, CFinishConfig, CKnownPacks, CRegistryData, KnownPack,
-    SAcknowledgeFinishConfig, SConfigPluginMessage, SKnownPacks,
+    CConfigDisconnect, CConfigPluginMessage, CConfigResourcePackPop, CConfigResourcePackPush,
+    CFinishConfig, CKnownPacks, CRegistryData, KnownPack, SAcknowledgeFinishConfig,
+    SConfigClientInformation, SConfigPluginMessage, SConfigResourcePackStatus, SKnownPacks,
 };
 pub use handshake::SHandshake;
 pub use login::{
@@ -107,12 +108,15 @@ pub use login::{
 };
 pub use opaque::OpaquePacket;
 pub use play::{
-    CChatMessageLegacy, CChunkBatchFinished, CChunkBatchStart, CCommands, CDisconnect, CGameEvent,
-    CJoinGame, CKeepAlive, CPluginMessage, CRespawn, CSetCenterChunk, CSetDefaultSpawnPosition,
-    CSetSubtitle, CSetTitle, CSetTitleTimes, CStartConfiguration, CSynchronizePlayerPosition,
-    CSystemChatMessage, CTabCompleteResponse, CTitleLegacy, CTransfer, DimensionInfo,
-    SAcknowledgeConfiguration, SChatCommand, SChatMessage, SChatSessionUpdate, SKeepAlive,
-    SPluginMessage, STabCompleteRequest,
+    BossBarAction, CBossBar, CChatMessageLegacy, CChunkBatchFinished, CChunkBatchStart, CCommands,
+    CDisconnect, CDisplayObjective, CGameEvent, CJoinGame, CKeepAlive, CPlayerInfoRemove,
+    CPlayerInfoUpdate, CPlayerListHeaderFooter, CPlayerListItemLegacy, CPluginMessage, CResetScore,
+    CResourcePackPop, CResourcePackPush, CRespawn, CScoreboardObjective, CSetCenterChunk,
+    CSetDefaultSpawnPosition, CSetSubtitle, CSetTitle, CSetTitleTimes, CStartConfiguration,
+    CSynchronizePlayerPosition, CSystemChatMessage, CTabCompleteResponse, CTeams, CTitleLegacy,
+    CTransfer, CUpdateScore, ClientInformation, DimensionInfo, SAcknowledgeConfiguration,
+    SChatAcknowledgement, SChatCommand, SChatMessage, SChatSessionUpdate, SClientInformation,
+    SKeepAlive, SPluginMessage, SResourcePackStatus, SSignedChatCommand, STabCompleteRequest,
 };
 pub use status::{CPingResponse, CStatusResponse, SPingRequest, SStatusRequest};
 
@@ -0,0 +1,205 @@
+//! Boss bar packet (Clientbound, 1.9+).
+//!
+//! A single packet carries every boss bar operation, selected by an action
+//! discriminator. Bars are identified by a UUID chosen by the sender.
+
+use crate::codec::{McBufReadExt, McBufWriteExt, VarInt};
+use crate::error::{ProtocolError, ProtocolResult};
+use crate::packets::Packet;
+use crate::packets::play::common::{read_component, write_component};
+use crate::version::{ConnectionState, Direction, ProtocolVersion};
+
+/// Darkens the sky while the bar is shown.
+pub const BOSS_BAR_FLAG_DARKEN_SKY: u8 = 0x01;
+/// Plays the end boss music while the bar is shown.
+pub const BOSS_BAR_FLAG_PLAY_MUSIC: u8 = 0x02;
+/// Creates fog while the bar is shown (also set by 0x04 before 1.10).
+pub const BOSS_BAR_FLAG_CREATE_FOG: u8 = 0x04;
+
+/// Operation carried by a [`CBossBar`] packet.
+///
+/// Titles are opaque bytes: UTF-8 JSON before 1.20.3, network NBT from 1.20.3.
+/// `color` is 0–6 (pink, blue, red, green, yellow, purple, white) and
+/// `division` is 0–4 (none, 6, 10, 12 or 20 notches).
+#[derive(Debug, Clone, PartialEq)]
+pub enum BossBarAction {
+    Add {
+        title: Vec<u8>,
+        health: f32,
+        color: i32,
+        division: i32,
+        flags: u8,
+    },
+    Remove,
+    UpdateHealth(f32),
+    UpdateTitle(Vec<u8>),
+    UpdateStyle {
+        color: i32,
+        division: i32,
+    },
+    UpdateFlags(u8),
+}
+
+impl BossBarAction {
+    const fn id(&self) -> i32 {
+        match self {
+            Self::Add { .. } => 0,
+            Self::Remove => 1,
+            Self::UpdateHealth(_) => 2,
+            Self::UpdateTitle(_) => 3,
+            Self::UpdateStyle { .. } => 4,
+            Self::UpdateFlags(_) => 5,
+        }
+    }
+}
+
+/// Adds, updates or removes a boss bar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CBossBar {
+    pub uuid: uuid::Uuid,
+    pub action: BossBarAction,
+}
+
+impl Packet for CBossBar {
+    const NAME: &'static str = "CBossBar";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        let uuid = r.read_uuid()?;
+        let action = match r.read_var_int()?.0 {
+            0 => BossBarAction::Add {
+                title: read_component(r, version)?,
+                health: r.read_f32_be()?,
+                color: r.read_var_int()?.0,
+                division: r.read_var_int()?.0,
+                flags: r.read_u8()?,
+            },
+            1 => BossBarAction::Remove,
+            2 => BossBarAction::UpdateHealth(r.read_f32_be()?),
+            3 => BossBarAction::UpdateTitle(read_component(r, version)?),
+            4 => BossBarAction::UpdateStyle {
+                color: r.read_var_int()?.0,
+                division: r.read_var_int()?.0,
+            },
+            5 => BossBarAction::UpdateFlags(r.read_u8()?),
+            other => {
+                return Err(ProtocolError::invalid(format!(
+                    "CBossBar: unknown action {other}"
+                )));
+            }
+        };
+        Ok(Self { uuid, action })
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        w.write_uuid(&self.uuid)?;
+        w.write_var_int(&VarInt(self.action.id()))?;
+        match &self.action {
+            BossBarAction::Add {
+                title,
+                health,
+                color,
+                division,
+                flags,
+            } => {
+                write_component(w, title, version)?;
+                w.write_f32_be(*health)?;
+                w.write_var_int(&VarInt(*color))?;
+                w.write_var_int(&VarInt(*division))?;
+                w.write_u8(*flags)?;
+            }
+            BossBarAction::Remove => {}
+            BossBarAction::UpdateHealth(health) => w.write_f32_be(*health)?,
+            BossBarAction::UpdateTitle(title) => write_component(w, title, version)?,
+            BossBarAction::UpdateStyle { color, division } => {
+                w.write_var_int(&VarInt(*color))?;
+                w.write_var_int(&VarInt(*division))?;
+            }
+            BossBarAction::UpdateFlags(flags) => w.write_u8(*flags)?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+
+    fn round_trip(packet: &CBossBar, version: ProtocolVersion) -> CBossBar {
+        let mut buf = Vec::new();
+        packet.encode(&mut buf, version).unwrap();
+        let mut r = buf.as_slice();
+        let decoded = CBossBar::decode(&mut r, version).unwrap();
+        assert!(r.is_empty(), "trailing bytes after CBossBar");
+        decoded
+    }
+
+    #[test]
+    fn test_add_round_trip_json() {
+        let pkt = CBossBar {
+            uuid: uuid::Uuid::from_u128(7),
+            action: BossBarAction::Add {
+                title: br#"{"text":"Starting"}"#.to_vec(),
+                health: 0.5,
+                color: 1,
+                division: 2,
+                flags: BOSS_BAR_FLAG_DARKEN_SKY | BOSS_BAR_FLAG_CREATE_FOG,
+            },
+        };
+        assert_eq!(round_trip(&pkt, ProtocolVersion::V1_20_2), pkt);
+    }
+
+    #[test]
+    fn test_update_title_round_trip_nbt() {
+        // Nameless compound with a single "text" string tag
+        let mut nbt = vec![0x0A, 0x08, 0x00, 0x04];
+        nbt.extend_from_slice(b"text");
+        nbt.extend_from_slice(&[0x00, 0x02]);
+        nbt.extend_from_slice(b"Hi");
+        nbt.push(0x00);
+        let pkt = CBossBar {
+            uuid: uuid::Uuid::from_u128(7),
+            action: BossBarAction::UpdateTitle(nbt),
+        };
+        assert_eq!(round_trip(&pkt, ProtocolVersion::V1_21), pkt);
+    }
+
+    #[test]
+    fn test_simple_actions_round_trip() {
+        for action in [
+            BossBarAction::Remove,
+            BossBarAction::UpdateHealth(1.0),
+            BossBarAction::UpdateStyle {
+                color: 6,
+                division: 4,
+            },
+            BossBarAction::UpdateFlags(BOSS_BAR_FLAG_PLAY_MUSIC),
+        ] {
+            let pkt = CBossBar {
+                uuid: uuid::Uuid::from_u128(1),
+                action,
+            };
+            assert_eq!(round_trip(&pkt, ProtocolVersion::V1_12_2), pkt);
+        }
+    }
+
+    #[test]
+    fn test_unknown_action_rejected() {
+        let mut buf = Vec::new();
+        buf.write_uuid(&uuid::Uuid::nil()).unwrap();
+        buf.write_var_int(&VarInt(9)).unwrap();
+        assert!(CBossBar::decode(&mut buf.as_slice(), ProtocolVersion::V1_21).is_err());
+    }
+}
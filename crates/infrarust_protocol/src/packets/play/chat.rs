@@ -1,4 +1,4 @@
-use crate::codec::{McBufReadExt, McBufWriteExt};
+use crate::codec::{McBufReadExt, McBufWriteExt, VarInt};
 use crate::error::ProtocolResult;
 use crate::packets::Packet;
 use crate::version::{ConnectionState, Direction, ProtocolVersion};
@@ -189,6 +189,124 @@ impl Packet for SChatMessage {
     }
 }
 
+impl SChatMessage {
+    /// Returns the count of newly seen messages the client acknowledges
+    /// with this message (1.19.3+), `None` for older versions.
+    ///
+    /// A backend that does not receive the message must still be told
+    /// about these acknowledgements, see [`SChatAcknowledgement`].
+    pub fn last_seen_offset(&self, version: ProtocolVersion) -> Option<i32> {
+        let (_, tail) = split_message_signature(&self.remaining, version)?;
+        last_seen_offset(tail, version)
+    }
+
+    /// Returns `true` if the message carries a signature (1.19+).
+    pub fn is_signed(&self, version: ProtocolVersion) -> bool {
+        // An unsigned message has a single zero byte in place of the signature
+        split_message_signature(&self.remaining, version)
+            .is_some_and(|(head, tail)| self.remaining.len() - head.len() - tail.len() > 1)
+    }
+
+    /// Returns this message with `message` as text and without its signature,
+    /// keeping the acknowledgements. `None` if the trailing bytes are malformed.
+    pub fn rewrite(&self, message: String, version: ProtocolVersion) -> Option<Self> {
+        let remaining = if version.less_than(ProtocolVersion::V1_19) {
+            self.remaining.clone()
+        } else {
+            let (head, tail) = split_message_signature(&self.remaining, version)?;
+            unsigned_remaining(head, tail)
+        };
+        Some(Self { message, remaining })
+    }
+}
+
+/// Splits the trailing bytes of a 1.19+ chat message into the timestamp and
+/// salt before its signature and the bytes after it.
+fn split_message_signature(remaining: &[u8], version: ProtocolVersion) -> Option<(&[u8], &[u8])> {
+    if version.less_than(ProtocolVersion::V1_19) {
+        return None;
+    }
+    let (head, mut r) = remaining.split_at_checked(16)?;
+    if version.no_less_than(ProtocolVersion::V1_19_3) {
+        if r.read_bool().ok()? {
+            r.read_byte_array_bounded(256).ok()?;
+        }
+    } else {
+        r.read_byte_array(256).ok()?;
+    }
+    Some((head, r))
+}
+
+/// Splits the trailing bytes of a signed 1.19+ command into the timestamp
+/// and salt before its argument signatures and the bytes after them.
+fn split_argument_signatures(remaining: &[u8], version: ProtocolVersion) -> Option<(&[u8], &[u8])> {
+    let (head, mut r) = remaining.split_at_checked(16)?;
+    let count = r.read_var_int().ok()?.0;
+    for _ in 0..count {
+        r.read_string_bounded(16).ok()?;
+        if version.no_less_than(ProtocolVersion::V1_19_3) {
+            r.read_byte_array_bounded(256).ok()?;
+        } else {
+            r.read_byte_array(256).ok()?;
+        }
+    }
+    Some((head, r))
+}
+
+fn last_seen_offset(mut tail: &[u8], version: ProtocolVersion) -> Option<i32> {
+    if version.less_than(ProtocolVersion::V1_19_3) {
+        return None;
+    }
+    tail.read_var_int().ok().map(|offset| offset.0)
+}
+
+/// Joins split trailing bytes back with an empty signature section: a
+/// `false` "has signature" flag, or an empty array / argument list, which
+/// are all a single zero byte.
+fn unsigned_remaining(head: &[u8], tail: &[u8]) -> Vec<u8> {
+    let mut remaining = Vec::with_capacity(head.len() + 1 + tail.len());
+    remaining.extend_from_slice(head);
+    remaining.push(0);
+    remaining.extend_from_slice(tail);
+    remaining
+}
+
+/// Serverbound message acknowledgement packet (1.19.3+).
+///
+/// Sent on behalf of the client when the proxy consumes a chat message,
+/// so the backend's count of acknowledged messages stays in sync.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SChatAcknowledgement {
+    pub offset: i32,
+}
+
+impl Packet for SChatAcknowledgement {
+    const NAME: &'static str = "SChatAcknowledgement";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Serverbound
+    }
+
+    fn decode(r: &mut &[u8], _version: ProtocolVersion) -> ProtocolResult<Self> {
+        Ok(Self {
+            offset: r.read_var_int()?.0,
+        })
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        _version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        w.write_var_int(&VarInt(self.offset))?;
+        Ok(())
+    }
+}
+
 /// Serverbound chat command packet (1.19+).
 ///
 /// Sent by the client when typing a slash command. The command string
@@ -232,6 +350,107 @@ impl Packet for SChatCommand {
     }
 }
 
+impl SChatCommand {
+    /// Returns the count of newly seen messages the client acknowledges
+    /// with this command (1.19.3 to 1.20.4), `None` otherwise.
+    ///
+    /// From 1.20.5 this packet is unsigned and carries no acknowledgements;
+    /// signed commands use [`SSignedChatCommand`].
+    pub fn last_seen_offset(&self, version: ProtocolVersion) -> Option<i32> {
+        if version.no_less_than(ProtocolVersion::V1_20_5) {
+            return None;
+        }
+        let (_, tail) = split_argument_signatures(&self.remaining, version)?;
+        last_seen_offset(tail, version)
+    }
+
+    /// Returns `true` if the command carries argument signatures
+    /// (1.19 to 1.20.4).
+    pub fn is_signed(&self, version: ProtocolVersion) -> bool {
+        if version.less_than(ProtocolVersion::V1_19)
+            || version.no_less_than(ProtocolVersion::V1_20_5)
+        {
+            return false;
+        }
+        let Some(mut r) = self.remaining.get(16..) else {
+            return false;
+        };
+        r.read_var_int().is_ok_and(|count| count.0 > 0)
+    }
+
+    /// Returns this command with `command` as text and without argument
+    /// signatures. `None` if the trailing bytes are malformed.
+    pub fn rewrite(&self, command: String, version: ProtocolVersion) -> Option<Self> {
+        let remaining = if version.no_less_than(ProtocolVersion::V1_20_5) {
+            self.remaining.clone()
+        } else {
+            let (head, tail) = split_argument_signatures(&self.remaining, version)?;
+            unsigned_remaining(head, tail)
+        };
+        Some(Self { command, remaining })
+    }
+}
+
+/// Serverbound signed chat command packet (1.20.5+).
+///
+/// Sent instead of [`SChatCommand`] for commands with signable arguments,
+/// such as `/msg` or `/me`. Same layout as [`SChatCommand`]: the command
+/// string, then the opaque timestamp, salt, signatures and acknowledgements.
+#[derive(Debug, Clone)]
+pub struct SSignedChatCommand {
+    /// The command text without the leading `/`.
+    pub command: String,
+    /// Remaining bytes after the command (signatures, etc.).
+    pub remaining: Vec<u8>,
+}
+
+impl Packet for SSignedChatCommand {
+    const NAME: &'static str = "SSignedChatCommand";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Serverbound
+    }
+
+    fn decode(r: &mut &[u8], _version: ProtocolVersion) -> ProtocolResult<Self> {
+        let command = r.read_string()?;
+        let remaining = r.read_remaining()?;
+        Ok(Self { command, remaining })
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        _version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        w.write_string(&self.command)?;
+        w.write_all(&self.remaining)?;
+        Ok(())
+    }
+}
+
+impl SSignedChatCommand {
+    /// Returns the count of newly seen messages the client acknowledges
+    /// with this command.
+    pub fn last_seen_offset(&self, version: ProtocolVersion) -> Option<i32> {
+        let (_, tail) = split_argument_signatures(&self.remaining, version)?;
+        last_seen_offset(tail, version)
+    }
+
+    /// Returns this command with `command` as text and without argument
+    /// signatures. `None` if the trailing bytes are malformed.
+    pub fn rewrite(&self, command: String, version: ProtocolVersion) -> Option<Self> {
+        let (head, tail) = split_argument_signatures(&self.remaining, version)?;
+        Some(Self {
+            command,
+            remaining: unsigned_remaining(head, tail),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used)]
@@ -243,6 +462,146 @@ mod tests {
         P::decode(&mut buf.as_slice(), version).unwrap()
     }
 
+    #[test]
+    fn test_chat_message_last_seen_offset() {
+        let mut remaining = Vec::new();
+        remaining.write_i64_be(1_700_000_000_000).unwrap();
+        remaining.write_i64_be(42).unwrap();
+        remaining.write_bool(true).unwrap();
+        remaining.extend_from_slice(&[0xAB; 256]);
+        remaining.write_var_int(&VarInt(3)).unwrap();
+        remaining.extend_from_slice(&[0, 0, 0]);
+        let pkt = SChatMessage {
+            message: "hi".to_string(),
+            remaining,
+        };
+        assert_eq!(pkt.last_seen_offset(ProtocolVersion::V1_21), Some(3));
+        assert_eq!(pkt.last_seen_offset(ProtocolVersion::V1_19_1), None);
+
+        let ack = round_trip(&SChatAcknowledgement { offset: 3 }, ProtocolVersion::V1_21);
+        assert_eq!(ack.offset, 3);
+    }
+
+    #[test]
+    fn test_chat_message_rewrite_drops_signature() {
+        let mut remaining = vec![7; 16];
+        remaining.write_bool(true).unwrap();
+        remaining.extend_from_slice(&[0xAB; 256]);
+        remaining.write_var_int(&VarInt(2)).unwrap();
+        remaining.extend_from_slice(&[1, 0, 0]);
+        let pkt = SChatMessage {
+            message: "darn".to_string(),
+            remaining,
+        };
+
+        let rewritten = pkt
+            .rewrite("d**n".to_string(), ProtocolVersion::V1_21)
+            .unwrap();
+        assert_eq!(rewritten.message, "d**n");
+        let mut expected = vec![7; 16];
+        expected.extend_from_slice(&[0, 2, 1, 0, 0]);
+        assert_eq!(rewritten.remaining, expected);
+        assert_eq!(rewritten.last_seen_offset(ProtocolVersion::V1_21), Some(2));
+        assert!(pkt.is_signed(ProtocolVersion::V1_21));
+        assert!(!rewritten.is_signed(ProtocolVersion::V1_21));
+
+        let legacy = SChatMessage {
+            message: "darn".to_string(),
+            remaining: Vec::new(),
+        };
+        let rewritten = legacy
+            .rewrite("d**n".to_string(), ProtocolVersion::V1_18_2)
+            .unwrap();
+        assert!(rewritten.remaining.is_empty());
+        assert!(
+            pkt.rewrite(String::new(), ProtocolVersion::V1_19_3)
+                .is_some()
+        );
+        assert!(
+            SChatMessage {
+                message: String::new(),
+                remaining: vec![0; 4],
+            }
+            .rewrite(String::new(), ProtocolVersion::V1_21)
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_chat_message_is_signed_1_19_1() {
+        let mut remaining = vec![7; 16];
+        remaining.write_var_int(&VarInt(256)).unwrap();
+        remaining.extend_from_slice(&[0xAB; 256]);
+        // Signed preview, no last seen messages, no last received
+        remaining.extend_from_slice(&[0, 0, 0]);
+        let pkt = SChatMessage {
+            message: "hi".to_string(),
+            remaining,
+        };
+        assert!(pkt.is_signed(ProtocolVersion::V1_19_1));
+        let rewritten = pkt
+            .rewrite("hi".to_string(), ProtocolVersion::V1_19_1)
+            .unwrap();
+        assert!(!rewritten.is_signed(ProtocolVersion::V1_19_1));
+        assert!(!pkt.is_signed(ProtocolVersion::V1_18_2));
+    }
+
+    #[test]
+    fn test_chat_command_is_signed_1_19_1() {
+        let mut remaining = vec![7; 16];
+        remaining.write_var_int(&VarInt(1)).unwrap();
+        remaining.write_string("message").unwrap();
+        remaining.write_var_int(&VarInt(256)).unwrap();
+        remaining.extend_from_slice(&[0xAB; 256]);
+        // Signed preview, no last seen messages, no last received
+        remaining.extend_from_slice(&[0, 0, 0]);
+        let pkt = SChatCommand {
+            command: "msg bob hi".to_string(),
+            remaining,
+        };
+        assert!(pkt.is_signed(ProtocolVersion::V1_19_1));
+        let rewritten = pkt
+            .rewrite("msg bob hi".to_string(), ProtocolVersion::V1_19_1)
+            .unwrap();
+        assert!(!rewritten.is_signed(ProtocolVersion::V1_19_1));
+        assert!(!pkt.is_signed(ProtocolVersion::V1_20_5));
+    }
+
+    #[test]
+    fn test_signed_command_rewrite_drops_argument_signatures() {
+        let mut remaining = vec![7; 16];
+        remaining.write_var_int(&VarInt(1)).unwrap();
+        remaining.write_string("message").unwrap();
+        remaining.extend_from_slice(&[0xAB; 256]);
+        remaining.write_var_int(&VarInt(4)).unwrap();
+        remaining.extend_from_slice(&[0, 0, 0]);
+        let pkt = SSignedChatCommand {
+            command: "msg bob darn".to_string(),
+            remaining: remaining.clone(),
+        };
+        assert_eq!(pkt.last_seen_offset(ProtocolVersion::V1_21), Some(4));
+
+        let rewritten = pkt
+            .rewrite("msg bob d**n".to_string(), ProtocolVersion::V1_21)
+            .unwrap();
+        let mut expected = vec![7; 16];
+        expected.extend_from_slice(&[0, 4, 0, 0, 0]);
+        assert_eq!(rewritten.remaining, expected);
+
+        // Before 1.20.5 the same layout is sent in the plain command packet
+        let pkt = SChatCommand {
+            command: "me waves".to_string(),
+            remaining,
+        };
+        assert_eq!(pkt.last_seen_offset(ProtocolVersion::V1_20_3), Some(4));
+        assert_eq!(
+            pkt.rewrite("me hi".to_string(), ProtocolVersion::V1_20_3)
+                .unwrap()
+                .remaining,
+            expected
+        );
+    }
+
     #[test]
     fn test_system_chat_round_trip_json() {
         let pkt = CSystemChatMessage::from_json(r#"{"text":"Hello!"}"#, false);
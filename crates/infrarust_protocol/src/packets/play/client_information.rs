@@ -0,0 +1,200 @@
+//! Client settings packet ("Client Information" from 1.20.2, "Client Settings" before).
+//!
+//! Sent by the client when it joins and whenever the player changes an
+//! option. From 1.20.2 it is sent first in the configuration state, see
+//! [`SConfigClientInformation`](crate::packets::config::SConfigClientInformation).
+
+use crate::codec::{McBufReadExt, McBufWriteExt, VarInt};
+use crate::error::ProtocolResult;
+use crate::packets::Packet;
+use crate::version::{ConnectionState, Direction, ProtocolVersion};
+
+/// Options reported by the client.
+///
+/// Fields missing from older versions decode to the vanilla default:
+/// `main_hand` is sent from 1.9, `text_filtering` from 1.17,
+/// `allow_server_listings` from 1.18 and `particle_status` from 1.21.2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientInformation {
+    pub locale: String,
+    pub view_distance: i8,
+    /// 0 = enabled, 1 = commands only, 2 = hidden.
+    pub chat_mode: i32,
+    pub chat_colors: bool,
+    /// Bit mask of the displayed skin parts (cape, jacket, sleeves, pants, hat).
+    pub skin_parts: u8,
+    /// 0 = left, 1 = right.
+    pub main_hand: i32,
+    pub text_filtering: bool,
+    pub allow_server_listings: bool,
+    /// 0 = all, 1 = decreased, 2 = minimal.
+    pub particle_status: i32,
+}
+
+impl ClientInformation {
+    pub(crate) fn read(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        let locale = r.read_string_bounded(16)?;
+        let view_distance = r.read_i8()?;
+        // 1.8 sends the chat mode as a byte
+        let chat_mode = if version.no_less_than(ProtocolVersion::V1_9) {
+            r.read_var_int()?.0
+        } else {
+            i32::from(r.read_i8()?)
+        };
+        let chat_colors = r.read_bool()?;
+        let skin_parts = r.read_u8()?;
+        let main_hand = if version.no_less_than(ProtocolVersion::V1_9) {
+            r.read_var_int()?.0
+        } else {
+            1
+        };
+        let text_filtering = if version.no_less_than(ProtocolVersion::V1_17) {
+            r.read_bool()?
+        } else {
+            false
+        };
+        let allow_server_listings = if version.no_less_than(ProtocolVersion::V1_18) {
+            r.read_bool()?
+        } else {
+            true
+        };
+        let particle_status = if version.no_less_than(ProtocolVersion::V1_21_2) {
+            r.read_var_int()?.0
+        } else {
+            0
+        };
+        Ok(Self {
+            locale,
+            view_distance,
+            chat_mode,
+            chat_colors,
+            skin_parts,
+            main_hand,
+            text_filtering,
+            allow_server_listings,
+            particle_status,
+        })
+    }
+
+    pub(crate) fn write(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        w.write_string(&self.locale)?;
+        w.write_i8(self.view_distance)?;
+        if version.no_less_than(ProtocolVersion::V1_9) {
+            w.write_var_int(&VarInt(self.chat_mode))?;
+        } else {
+            w.write_i8(self.chat_mode as i8)?;
+        }
+        w.write_bool(self.chat_colors)?;
+        w.write_u8(self.skin_parts)?;
+        if version.no_less_than(ProtocolVersion::V1_9) {
+            w.write_var_int(&VarInt(self.main_hand))?;
+        }
+        if version.no_less_than(ProtocolVersion::V1_17) {
+            w.write_bool(self.text_filtering)?;
+        }
+        if version.no_less_than(ProtocolVersion::V1_18) {
+            w.write_bool(self.allow_server_listings)?;
+        }
+        if version.no_less_than(ProtocolVersion::V1_21_2) {
+            w.write_var_int(&VarInt(self.particle_status))?;
+        }
+        Ok(())
+    }
+}
+
+/// Client settings update (Serverbound, 1.8+).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SClientInformation {
+    pub info: ClientInformation,
+}
+
+impl Packet for SClientInformation {
+    const NAME: &'static str = "SClientInformation";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Serverbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        Ok(Self {
+            info: ClientInformation::read(r, version)?,
+        })
+    }
+
+    fn encode(
+        &self,
+        w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        self.info.write(w, version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+
+    fn round_trip(packet: &SClientInformation, version: ProtocolVersion) -> SClientInformation {
+        let mut buf = Vec::new();
+        packet.encode(&mut buf, version).unwrap();
+        let mut r = buf.as_slice();
+        let decoded = SClientInformation::decode(&mut r, version).unwrap();
+        assert!(
+            r.is_empty(),
+            "trailing bytes after {}",
+            SClientInformation::NAME
+        );
+        decoded
+    }
+
+    fn settings() -> SClientInformation {
+        SClientInformation {
+            info: ClientInformation {
+                locale: "fr_fr".to_string(),
+                view_distance: 12,
+                chat_mode: 1,
+                chat_colors: true,
+                skin_parts: 0x7F,
+                main_hand: 0,
+                text_filtering: true,
+                allow_server_listings: false,
+                particle_status: 2,
+            },
+        }
+    }
+
+    #[test]
+    fn test_modern_round_trip() {
+        let pkt = settings();
+        assert_eq!(round_trip(&pkt, ProtocolVersion::V1_21_4), pkt);
+    }
+
+    #[test]
+    fn test_old_versions_use_defaults() {
+        let pkt = settings();
+
+        let v1_20 = round_trip(&pkt, ProtocolVersion::V1_20);
+        assert!(v1_20.info.text_filtering);
+        assert_eq!(v1_20.info.particle_status, 0);
+
+        // 1.8: byte chat mode, no main hand nor later fields
+        let mut buf = Vec::new();
+        pkt.encode(&mut buf, ProtocolVersion::V1_8).unwrap();
+        assert_eq!(buf.len(), 6 + 1 + 1 + 1 + 1);
+        let v1_8 = SClientInformation::decode(&mut buf.as_slice(), ProtocolVersion::V1_8).unwrap();
+        assert_eq!(v1_8.info.locale, "fr_fr");
+        assert_eq!(v1_8.info.chat_mode, 1);
+        assert_eq!(v1_8.info.main_hand, 1);
+        assert!(!v1_8.info.text_filtering);
+        assert!(v1_8.info.allow_server_listings);
+    }
+}
@@ -1,11 +1,38 @@
-//! Shared helpers for play packets (`CJoinGame`, `CRespawn`).
+//! Shared helpers for play packets (`CJoinGame`, `CRespawn`, text components).
 
 use std::io::Write;
 
 use crate::codec::{McBufReadExt, McBufWriteExt, VarInt};
-use crate::error::ProtocolResult;
+use crate::error::{ProtocolError, ProtocolResult};
+use crate::nbt::read_network_nbt;
 use crate::version::ProtocolVersion;
 
+/// Decodes a text component: UTF-8 JSON before 1.20.3, network NBT from 1.20.3.
+pub fn read_component(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Vec<u8>> {
+    if version.less_than(ProtocolVersion::V1_20_3) {
+        Ok(r.read_string()?.into_bytes())
+    } else {
+        read_network_nbt(r)
+    }
+}
+
+/// Encodes a text component read by [`read_component`].
+pub fn write_component(
+    mut w: &mut (impl Write + ?Sized),
+    text: &[u8],
+    version: ProtocolVersion,
+) -> ProtocolResult<()> {
+    if version.less_than(ProtocolVersion::V1_20_3) {
+        let json = std::str::from_utf8(text).map_err(|_| {
+            ProtocolError::invalid("text component is not valid UTF-8 for JSON version")
+        })?;
+        w.write_string(json)?;
+    } else {
+        w.write_all(text)?;
+    }
+    Ok(())
+}
+
 /// Decodes an optional death location (dimension identifier + packed position).
 pub fn decode_death_location(r: &mut &[u8]) -> ProtocolResult<(Option<String>, Option<i64>)> {
     if r.read_bool()? {
@@ -1,7 +1,9 @@
+pub mod boss_bar;
 pub mod center_chunk;
 pub mod chat;
 pub mod chat_session;
 pub mod chunk_batch;
+pub mod client_information;
 pub mod commands;
 pub(crate) mod common;
 pub mod dimension;
@@ -9,29 +11,49 @@ pub mod disconnect;
 pub mod game_event;
 pub mod join_game;
 pub mod keepalive;
+pub mod player_list;
 pub mod player_position;
 pub mod plugin_message;
+pub mod resource_pack;
 pub mod respawn;
 pub mod respawn_switch;
+pub mod scoreboard;
 pub mod spawn_position;
 pub mod start_configuration;
 pub mod tab_complete;
 pub mod title;
 pub mod transfer;
 
+pub use boss_bar::{BossBarAction, CBossBar};
 pub use center_chunk::CSetCenterChunk;
-pub use chat::{CChatMessageLegacy, CSystemChatMessage, SChatCommand, SChatMessage};
+pub use chat::{
+    CChatMessageLegacy, CSystemChatMessage, SChatAcknowledgement, SChatCommand, SChatMessage,
+    SSignedChatCommand,
+};
 pub use chat_session::SChatSessionUpdate;
 pub use chunk_batch::{CChunkBatchFinished, CChunkBatchStart};
+pub use client_information::{ClientInformation, SClientInformation};
 pub use commands::CCommands;
 pub use dimension::{DimensionInfo, extract_dimension_from_join_game};
 pub use disconnect::CDisconnect;
 pub use game_event::CGameEvent;
 pub use join_game::CJoinGame;
 pub use keepalive::{CKeepAlive, SKeepAlive};
+pub use player_list::{
+    CPlayerInfoRemove, CPlayerInfoUpdate, CPlayerListHeaderFooter, CPlayerListItemLegacy,
+    LegacyPlayerListAction, PlayerChatSession, PlayerInfoActions, PlayerInfoEntry,
+};
 pub use player_position::CSynchronizePlayerPosition;
 pub use plugin_message::{CPluginMessage, SPluginMessage};
+pub use resource_pack::{
+    CResourcePackPop, CResourcePackPush, ResourcePackRequest, ResourcePackResponse,
+    ResourcePackResult, SResourcePackStatus,
+};
 pub use respawn::CRespawn;
+pub use scoreboard::{
+    CDisplayObjective, CResetScore, CScoreboardObjective, CTeams, CUpdateScore, NumberFormat,
+    ObjectiveAction, ObjectiveInfo, ScoreAction, TeamAction, TeamInfo,
+};
 pub use spawn_position::CSetDefaultSpawnPosition;
 pub use start_configuration::{CStartConfiguration, SAcknowledgeConfiguration};
 pub use tab_complete::{CTabCompleteResponse, STabCompleteRequest};
@@ -0,0 +1,614 @@
+//! Tab list packets (Clientbound).
+//!
+//! - [`CPlayerListHeaderFooter`] — text above and below the player list (1.8+)
+//! - [`CPlayerInfoUpdate`] / [`CPlayerInfoRemove`] — player list entries (1.19.3+)
+//! - [`CPlayerListItemLegacy`] — single entry packet with an action
+//!   discriminator (1.8–1.19.1)
+//!
+//! Text components are stored as opaque bytes: UTF-8 JSON before 1.20.3,
+//! network NBT from 1.20.3.
+
+use std::ops::BitOr;
+
+use crate::codec::{McBufReadExt, McBufWriteExt, VarInt};
+use crate::error::{ProtocolError, ProtocolResult};
+use crate::packets::Packet;
+use crate::packets::login::Property;
+use crate::packets::play::common::{read_component, write_component};
+use crate::version::{ConnectionState, Direction, ProtocolVersion};
+
+/// Upper bound for public keys and signatures in chat session data.
+const MAX_KEY_LEN: usize = 4096;
+
+fn read_properties(r: &mut &[u8]) -> ProtocolResult<Vec<Property>> {
+    let count = r.read_var_int()?.0.max(0) as usize;
+    let mut properties = Vec::with_capacity(count.min(16));
+    for _ in 0..count {
+        let name = r.read_string()?;
+        let value = r.read_string()?;
+        let signature = if r.read_bool()? {
+            Some(r.read_string()?)
+        } else {
+            None
+        };
+        properties.push(Property {
+            name,
+            value,
+            signature,
+        });
+    }
+    Ok(properties)
+}
+
+fn write_properties(
+    mut w: &mut (impl std::io::Write + ?Sized),
+    properties: &[Property],
+) -> ProtocolResult<()> {
+    w.write_var_int(&VarInt(properties.len() as i32))?;
+    for property in properties {
+        w.write_string(&property.name)?;
+        w.write_string(&property.value)?;
+        match property.signature {
+            Some(ref signature) => {
+                w.write_bool(true)?;
+                w.write_string(signature)?;
+            }
+            None => w.write_bool(false)?,
+        }
+    }
+    Ok(())
+}
+
+fn read_optional_component(
+    r: &mut &[u8],
+    version: ProtocolVersion,
+) -> ProtocolResult<Option<Vec<u8>>> {
+    if r.read_bool()? {
+        Ok(Some(read_component(r, version)?))
+    } else {
+        Ok(None)
+    }
+}
+
+fn write_optional_component(
+    mut w: &mut (impl std::io::Write + ?Sized),
+    text: Option<&[u8]>,
+    version: ProtocolVersion,
+) -> ProtocolResult<()> {
+    match text {
+        Some(text) => {
+            w.write_bool(true)?;
+            write_component(w, text, version)
+        }
+        None => w.write_bool(false),
+    }
+}
+
+/// Sets the text shown above and below the tab list.
+///
+/// An empty component clears that line.
+#[derive(Debug, Clone)]
+pub struct CPlayerListHeaderFooter {
+    pub header: Vec<u8>,
+    pub footer: Vec<u8>,
+}
+
+impl CPlayerListHeaderFooter {
+    /// Creates the packet from JSON text component strings.
+    pub fn from_json(header: &str, footer: &str) -> Self {
+        Self {
+            header: header.as_bytes().to_vec(),
+            footer: footer.as_bytes().to_vec(),
+        }
+    }
+
+    /// Creates the packet from pre-encoded NBT bytes (1.20.3+).
+    pub fn from_nbt(header: Vec<u8>, footer: Vec<u8>) -> Self {
+        Self { header, footer }
+    }
+}
+
+impl Packet for CPlayerListHeaderFooter {
+    const NAME: &'static str = "CPlayerListHeaderFooter";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        let header = read_component(r, version)?;
+        let footer = read_component(r, version)?;
+        Ok(Self { header, footer })
+    }
+
+    fn encode(
+        &self,
+        w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        write_component(w, &self.header, version)?;
+        write_component(w, &self.footer, version)
+    }
+}
+
+/// Signed chat session of a player list entry (1.19.3+).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerChatSession {
+    pub session_id: uuid::Uuid,
+    /// Key expiry, in milliseconds since the Unix epoch.
+    pub expires_at: i64,
+    pub public_key: Vec<u8>,
+    pub key_signature: Vec<u8>,
+}
+
+/// A player list entry.
+///
+/// Which fields are sent depends on the packet's actions; the rest are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerInfoEntry {
+    pub uuid: uuid::Uuid,
+    pub name: String,
+    pub properties: Vec<Property>,
+    pub chat_session: Option<PlayerChatSession>,
+    pub game_mode: i32,
+    pub listed: bool,
+    /// Latency in milliseconds.
+    pub latency: i32,
+    pub display_name: Option<Vec<u8>>,
+    /// Sort order in the list (1.21.2+). Higher values are listed first.
+    pub list_order: i32,
+    /// Whether the skin's hat layer is shown (1.21.4+).
+    pub show_hat: bool,
+}
+
+impl PlayerInfoEntry {
+    /// Creates a listed survival-mode entry with no properties.
+    pub fn new(uuid: uuid::Uuid, name: impl Into<String>) -> Self {
+        Self {
+            uuid,
+            name: name.into(),
+            properties: Vec::new(),
+            chat_session: None,
+            game_mode: 0,
+            listed: true,
+            latency: 0,
+            display_name: None,
+            list_order: 0,
+            show_hat: true,
+        }
+    }
+}
+
+/// The fields a [`CPlayerInfoUpdate`] carries, as a bit set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PlayerInfoActions(pub u8);
+
+impl PlayerInfoActions {
+    pub const ADD_PLAYER: Self = Self(0x01);
+    pub const INITIALIZE_CHAT: Self = Self(0x02);
+    pub const UPDATE_GAME_MODE: Self = Self(0x04);
+    pub const UPDATE_LISTED: Self = Self(0x08);
+    pub const UPDATE_LATENCY: Self = Self(0x10);
+    pub const UPDATE_DISPLAY_NAME: Self = Self(0x20);
+    /// 1.21.2+.
+    pub const UPDATE_LIST_ORDER: Self = Self(0x40);
+    /// 1.21.4+.
+    pub const UPDATE_HAT: Self = Self(0x80);
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Drops the actions the given version does not know about.
+    fn supported_by(self, version: ProtocolVersion) -> Self {
+        let mut mask = 0x3F;
+        if version.no_less_than(ProtocolVersion::V1_21_2) {
+            mask |= Self::UPDATE_LIST_ORDER.0;
+        }
+        if version.no_less_than(ProtocolVersion::V1_21_4) {
+            mask |= Self::UPDATE_HAT.0;
+        }
+        Self(self.0 & mask)
+    }
+}
+
+impl BitOr for PlayerInfoActions {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Adds or updates player list entries (1.19.3+).
+#[derive(Debug, Clone)]
+pub struct CPlayerInfoUpdate {
+    pub actions: PlayerInfoActions,
+    pub entries: Vec<PlayerInfoEntry>,
+}
+
+impl Packet for CPlayerInfoUpdate {
+    const NAME: &'static str = "CPlayerInfoUpdate";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        let actions = PlayerInfoActions(r.read_u8()?);
+        let count = r.read_var_int()?.0.max(0) as usize;
+        let mut entries = Vec::with_capacity(count.min(256));
+        for _ in 0..count {
+            let mut entry = PlayerInfoEntry::new(r.read_uuid()?, String::new());
+            if actions.contains(PlayerInfoActions::ADD_PLAYER) {
+                entry.name = r.read_string_bounded(16)?;
+                entry.properties = read_properties(r)?;
+            }
+            if actions.contains(PlayerInfoActions::INITIALIZE_CHAT) && r.read_bool()? {
+                entry.chat_session = Some(PlayerChatSession {
+                    session_id: r.read_uuid()?,
+                    expires_at: r.read_i64_be()?,
+                    public_key: r.read_byte_array(MAX_KEY_LEN)?,
+                    key_signature: r.read_byte_array(MAX_KEY_LEN)?,
+                });
+            }
+            if actions.contains(PlayerInfoActions::UPDATE_GAME_MODE) {
+                entry.game_mode = r.read_var_int()?.0;
+            }
+            if actions.contains(PlayerInfoActions::UPDATE_LISTED) {
+                entry.listed = r.read_bool()?;
+            }
+            if actions.contains(PlayerInfoActions::UPDATE_LATENCY) {
+                entry.latency = r.read_var_int()?.0;
+            }
+            if actions.contains(PlayerInfoActions::UPDATE_DISPLAY_NAME) {
+                entry.display_name = read_optional_component(r, version)?;
+            }
+            if actions.contains(PlayerInfoActions::UPDATE_LIST_ORDER) {
+                entry.list_order = r.read_var_int()?.0;
+            }
+            if actions.contains(PlayerInfoActions::UPDATE_HAT) {
+                entry.show_hat = r.read_bool()?;
+            }
+            entries.push(entry);
+        }
+        Ok(Self { actions, entries })
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        let actions = self.actions.supported_by(version);
+        w.write_u8(actions.0)?;
+        w.write_var_int(&VarInt(self.entries.len() as i32))?;
+        for entry in &self.entries {
+            w.write_uuid(&entry.uuid)?;
+            if actions.contains(PlayerInfoActions::ADD_PLAYER) {
+                w.write_string(&entry.name)?;
+                write_properties(w, &entry.properties)?;
+            }
+            if actions.contains(PlayerInfoActions::INITIALIZE_CHAT) {
+                match entry.chat_session {
+                    Some(ref session) => {
+                        w.write_bool(true)?;
+                        w.write_uuid(&session.session_id)?;
+                        w.write_i64_be(session.expires_at)?;
+                        w.write_byte_array(&session.public_key)?;
+                        w.write_byte_array(&session.key_signature)?;
+                    }
+                    None => w.write_bool(false)?,
+                }
+            }
+            if actions.contains(PlayerInfoActions::UPDATE_GAME_MODE) {
+                w.write_var_int(&VarInt(entry.game_mode))?;
+            }
+            if actions.contains(PlayerInfoActions::UPDATE_LISTED) {
+                w.write_bool(entry.listed)?;
+            }
+            if actions.contains(PlayerInfoActions::UPDATE_LATENCY) {
+                w.write_var_int(&VarInt(entry.latency))?;
+            }
+            if actions.contains(PlayerInfoActions::UPDATE_DISPLAY_NAME) {
+                write_optional_component(w, entry.display_name.as_deref(), version)?;
+            }
+            if actions.contains(PlayerInfoActions::UPDATE_LIST_ORDER) {
+                w.write_var_int(&VarInt(entry.list_order))?;
+            }
+            if actions.contains(PlayerInfoActions::UPDATE_HAT) {
+                w.write_bool(entry.show_hat)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Removes player list entries (1.19.3+).
+#[derive(Debug, Clone)]
+pub struct CPlayerInfoRemove {
+    pub uuids: Vec<uuid::Uuid>,
+}
+
+impl Packet for CPlayerInfoRemove {
+    const NAME: &'static str = "CPlayerInfoRemove";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], _version: ProtocolVersion) -> ProtocolResult<Self> {
+        let count = r.read_var_int()?.0.max(0) as usize;
+        let mut uuids = Vec::with_capacity(count.min(256));
+        for _ in 0..count {
+            uuids.push(r.read_uuid()?);
+        }
+        Ok(Self { uuids })
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        _version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        w.write_var_int(&VarInt(self.uuids.len() as i32))?;
+        for uuid in &self.uuids {
+            w.write_uuid(uuid)?;
+        }
+        Ok(())
+    }
+}
+
+/// Action of a [`CPlayerListItemLegacy`] packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegacyPlayerListAction {
+    AddPlayer,
+    UpdateGameMode,
+    UpdateLatency,
+    UpdateDisplayName,
+    RemovePlayer,
+}
+
+impl LegacyPlayerListAction {
+    const fn id(self) -> i32 {
+        match self {
+            Self::AddPlayer => 0,
+            Self::UpdateGameMode => 1,
+            Self::UpdateLatency => 2,
+            Self::UpdateDisplayName => 3,
+            Self::RemovePlayer => 4,
+        }
+    }
+}
+
+/// Player list entries packet before 1.19.3 (1.8–1.19.1).
+///
+/// One action per packet; display names are always JSON.
+#[derive(Debug, Clone)]
+pub struct CPlayerListItemLegacy {
+    pub action: LegacyPlayerListAction,
+    pub entries: Vec<PlayerInfoEntry>,
+}
+
+impl Packet for CPlayerListItemLegacy {
+    const NAME: &'static str = "CPlayerListItemLegacy";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        let action = match r.read_var_int()?.0 {
+            0 => LegacyPlayerListAction::AddPlayer,
+            1 => LegacyPlayerListAction::UpdateGameMode,
+            2 => LegacyPlayerListAction::UpdateLatency,
+            3 => LegacyPlayerListAction::UpdateDisplayName,
+            4 => LegacyPlayerListAction::RemovePlayer,
+            other => {
+                return Err(ProtocolError::invalid(format!(
+                    "CPlayerListItemLegacy: unknown action {other}"
+                )));
+            }
+        };
+        let count = r.read_var_int()?.0.max(0) as usize;
+        let mut entries = Vec::with_capacity(count.min(256));
+        for _ in 0..count {
+            let mut entry = PlayerInfoEntry::new(r.read_uuid()?, String::new());
+            match action {
+                LegacyPlayerListAction::AddPlayer => {
+                    entry.name = r.read_string_bounded(16)?;
+                    entry.properties = read_properties(r)?;
+                    entry.game_mode = r.read_var_int()?.0;
+                    entry.latency = r.read_var_int()?.0;
+                    entry.display_name = read_optional_component(r, version)?;
+                    if version.no_less_than(ProtocolVersion::V1_19) && r.read_bool()? {
+                        // Player public key: not kept.
+                        r.read_i64_be()?;
+                        r.read_byte_array(MAX_KEY_LEN)?;
+                        r.read_byte_array(MAX_KEY_LEN)?;
+                    }
+                }
+                LegacyPlayerListAction::UpdateGameMode => {
+                    entry.game_mode = r.read_var_int()?.0;
+                }
+                LegacyPlayerListAction::UpdateLatency => {
+                    entry.latency = r.read_var_int()?.0;
+                }
+                LegacyPlayerListAction::UpdateDisplayName => {
+                    entry.display_name = read_optional_component(r, version)?;
+                }
+                LegacyPlayerListAction::RemovePlayer => {}
+            }
+            entries.push(entry);
+        }
+        Ok(Self { action, entries })
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        w.write_var_int(&VarInt(self.action.id()))?;
+        w.write_var_int(&VarInt(self.entries.len() as i32))?;
+        for entry in &self.entries {
+            w.write_uuid(&entry.uuid)?;
+            match self.action {
+                LegacyPlayerListAction::AddPlayer => {
+                    w.write_string(&entry.name)?;
+                    write_properties(w, &entry.properties)?;
+                    w.write_var_int(&VarInt(entry.game_mode))?;
+                    w.write_var_int(&VarInt(entry.latency))?;
+                    write_optional_component(w, entry.display_name.as_deref(), version)?;
+                    if version.no_less_than(ProtocolVersion::V1_19) {
+                        w.write_bool(false)?; // no player public key
+                    }
+                }
+                LegacyPlayerListAction::UpdateGameMode => {
+                    w.write_var_int(&VarInt(entry.game_mode))?;
+                }
+                LegacyPlayerListAction::UpdateLatency => {
+                    w.write_var_int(&VarInt(entry.latency))?;
+                }
+                LegacyPlayerListAction::UpdateDisplayName => {
+                    write_optional_component(w, entry.display_name.as_deref(), version)?;
+                }
+                LegacyPlayerListAction::RemovePlayer => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+
+    fn round_trip<P: Packet>(packet: &P, version: ProtocolVersion) -> P {
+        let mut buf = Vec::new();
+        packet.encode(&mut buf, version).unwrap();
+        let mut r = buf.as_slice();
+        let decoded = P::decode(&mut r, version).unwrap();
+        assert!(r.is_empty(), "trailing bytes after {}", P::NAME);
+        decoded
+    }
+
+    fn entry() -> PlayerInfoEntry {
+        let mut entry = PlayerInfoEntry::new(uuid::Uuid::from_u128(42), "Steve");
+        entry.properties = vec![Property {
+            name: "textures".into(),
+            value: "base64".into(),
+            signature: Some("sig".into()),
+        }];
+        entry.game_mode = 1;
+        entry.latency = 35;
+        entry.display_name = Some(br#"{"text":"Steve"}"#.to_vec());
+        entry
+    }
+
+    #[test]
+    fn test_header_footer_round_trip_json() {
+        let pkt = CPlayerListHeaderFooter::from_json(r#"{"text":"Top"}"#, r#"{"text":"Bottom"}"#);
+        let decoded = round_trip(&pkt, ProtocolVersion::V1_20_2);
+        assert_eq!(decoded.header, br#"{"text":"Top"}"#);
+        assert_eq!(decoded.footer, br#"{"text":"Bottom"}"#);
+    }
+
+    #[test]
+    fn test_header_footer_round_trip_nbt() {
+        // Two nameless compounds containing a single string tag each
+        let nbt = |text: &str| {
+            let mut buf = vec![0x0A, 0x08, 0x00, 0x04];
+            buf.extend_from_slice(b"text");
+            buf.extend_from_slice(&(text.len() as u16).to_be_bytes());
+            buf.extend_from_slice(text.as_bytes());
+            buf.push(0x00);
+            buf
+        };
+        let pkt = CPlayerListHeaderFooter::from_nbt(nbt("Top"), nbt("Bottom"));
+        let decoded = round_trip(&pkt, ProtocolVersion::V1_21);
+        assert_eq!(decoded.header, nbt("Top"));
+        assert_eq!(decoded.footer, nbt("Bottom"));
+    }
+
+    #[test]
+    fn test_info_update_round_trip() {
+        let actions = PlayerInfoActions::ADD_PLAYER
+            | PlayerInfoActions::UPDATE_GAME_MODE
+            | PlayerInfoActions::UPDATE_LISTED
+            | PlayerInfoActions::UPDATE_LATENCY
+            | PlayerInfoActions::UPDATE_DISPLAY_NAME;
+        let pkt = CPlayerInfoUpdate {
+            actions,
+            entries: vec![entry()],
+        };
+        let decoded = round_trip(&pkt, ProtocolVersion::V1_19_4);
+        assert_eq!(decoded.actions, actions);
+        assert_eq!(decoded.entries, vec![entry()]);
+    }
+
+    #[test]
+    fn test_info_update_drops_unsupported_actions() {
+        let pkt = CPlayerInfoUpdate {
+            actions: PlayerInfoActions::UPDATE_LATENCY
+                | PlayerInfoActions::UPDATE_LIST_ORDER
+                | PlayerInfoActions::UPDATE_HAT,
+            entries: vec![entry()],
+        };
+        let decoded = round_trip(&pkt, ProtocolVersion::V1_21);
+        assert_eq!(decoded.actions, PlayerInfoActions::UPDATE_LATENCY);
+
+        let decoded = round_trip(&pkt, ProtocolVersion::V1_21_4);
+        assert_eq!(decoded.actions, pkt.actions);
+    }
+
+    #[test]
+    fn test_info_remove_round_trip() {
+        let pkt = CPlayerInfoRemove {
+            uuids: vec![uuid::Uuid::from_u128(1), uuid::Uuid::from_u128(2)],
+        };
+        let decoded = round_trip(&pkt, ProtocolVersion::V1_21);
+        assert_eq!(decoded.uuids, pkt.uuids);
+    }
+
+    #[test]
+    fn test_legacy_add_round_trip() {
+        for version in [ProtocolVersion::V1_8, ProtocolVersion::V1_19_1] {
+            let pkt = CPlayerListItemLegacy {
+                action: LegacyPlayerListAction::AddPlayer,
+                entries: vec![entry()],
+            };
+            let decoded = round_trip(&pkt, version);
+            assert_eq!(decoded.action, LegacyPlayerListAction::AddPlayer);
+            assert_eq!(decoded.entries, vec![entry()]);
+        }
+    }
+
+    #[test]
+    fn test_legacy_remove_round_trip() {
+        let pkt = CPlayerListItemLegacy {
+            action: LegacyPlayerListAction::RemovePlayer,
+            entries: vec![PlayerInfoEntry::new(uuid::Uuid::from_u128(7), "")],
+        };
+        let decoded = round_trip(&pkt, ProtocolVersion::V1_12_2);
+        assert_eq!(decoded.action, LegacyPlayerListAction::RemovePlayer);
+        assert_eq!(decoded.entries[0].uuid, uuid::Uuid::from_u128(7));
+    }
+}
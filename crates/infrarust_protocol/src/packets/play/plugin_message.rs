@@ -39,6 +39,22 @@ define_twin_packets! {
     },
 }
 
+/// Channel of the client brand (`MC|Brand` before 1.13).
+pub const BRAND_CHANNEL: &str = "minecraft:brand";
+/// Pre-1.13 name of [`BRAND_CHANNEL`].
+pub const LEGACY_BRAND_CHANNEL: &str = "MC|Brand";
+
+/// Reads the client brand if `channel` is the brand channel.
+///
+/// The payload is a single string (e.g. `vanilla`, `fabric`).
+pub fn read_brand(channel: &str, data: &[u8]) -> Option<String> {
+    if channel != BRAND_CHANNEL && channel != LEGACY_BRAND_CHANNEL {
+        return None;
+    }
+    let mut r = data;
+    r.read_string_bounded(256).ok()
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used)]
@@ -63,6 +79,18 @@ mod tests {
         assert_eq!(decoded.data, pkt.data);
     }
 
+    #[test]
+    fn test_read_brand() {
+        let data = [0x06, b'f', b'a', b'b', b'r', b'i', b'c'];
+        assert_eq!(read_brand(BRAND_CHANNEL, &data).as_deref(), Some("fabric"));
+        assert_eq!(
+            read_brand(LEGACY_BRAND_CHANNEL, &data).as_deref(),
+            Some("fabric")
+        );
+        assert_eq!(read_brand("velocity:player_info", &data), None);
+        assert_eq!(read_brand(BRAND_CHANNEL, &[0x09, b'x']), None);
+    }
+
     #[test]
     fn test_plugin_message_channel_preserved() {
         let pkt = SPluginMessage {
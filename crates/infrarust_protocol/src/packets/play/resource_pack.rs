@@ -0,0 +1,371 @@
+//! Resource pack packets (Play state).
+//!
+//! Before 1.20.3 a client holds a single server pack, replaced by every push.
+//! From 1.20.3 packs are stacked and identified by a UUID chosen by the
+//! sender, which is echoed back in status responses and used to pop them.
+//!
+//! The same payloads are sent in the configuration state, see
+//! [`CConfigResourcePackPush`](crate::packets::config::CConfigResourcePackPush).
+
+use crate::codec::{McBufReadExt, McBufWriteExt, VarInt};
+use crate::error::{ProtocolError, ProtocolResult};
+use crate::packets::Packet;
+use crate::packets::play::common::{read_component, write_component};
+use crate::version::{ConnectionState, Direction, ProtocolVersion};
+
+/// A resource pack offered to the client.
+///
+/// `id` is only sent from 1.20.3 (nil when decoded from older versions),
+/// `forced` and `prompt` from 1.17. The prompt is an opaque text component:
+/// UTF-8 JSON before 1.20.3, network NBT from 1.20.3.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourcePackRequest {
+    pub id: uuid::Uuid,
+    pub url: String,
+    /// Lowercase hex SHA-1 of the pack, or empty.
+    pub hash: String,
+    pub forced: bool,
+    pub prompt: Option<Vec<u8>>,
+}
+
+impl ResourcePackRequest {
+    pub(crate) fn read(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        let id = if version.no_less_than(ProtocolVersion::V1_20_3) {
+            r.read_uuid()?
+        } else {
+            uuid::Uuid::nil()
+        };
+        let url = r.read_string()?;
+        let hash = r.read_string()?;
+        let (forced, prompt) = if version.no_less_than(ProtocolVersion::V1_17) {
+            let forced = r.read_bool()?;
+            let prompt = if r.read_bool()? {
+                Some(read_component(r, version)?)
+            } else {
+                None
+            };
+            (forced, prompt)
+        } else {
+            (false, None)
+        };
+        Ok(Self {
+            id,
+            url,
+            hash,
+            forced,
+            prompt,
+        })
+    }
+
+    pub(crate) fn write(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        if version.no_less_than(ProtocolVersion::V1_20_3) {
+            w.write_uuid(&self.id)?;
+        }
+        w.write_string(&self.url)?;
+        w.write_string(&self.hash)?;
+        if version.no_less_than(ProtocolVersion::V1_17) {
+            w.write_bool(self.forced)?;
+            w.write_bool(self.prompt.is_some())?;
+            if let Some(prompt) = &self.prompt {
+                write_component(w, prompt, version)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Outcome reported by the client for a pushed pack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourcePackResult {
+    SuccessfullyLoaded,
+    Declined,
+    FailedDownload,
+    Accepted,
+    /// 1.20.3+.
+    Downloaded,
+    /// 1.20.3+.
+    InvalidUrl,
+    /// 1.20.3+.
+    FailedReload,
+    /// 1.20.3+.
+    Discarded,
+}
+
+impl ResourcePackResult {
+    pub const fn id(self) -> i32 {
+        match self {
+            Self::SuccessfullyLoaded => 0,
+            Self::Declined => 1,
+            Self::FailedDownload => 2,
+            Self::Accepted => 3,
+            Self::Downloaded => 4,
+            Self::InvalidUrl => 5,
+            Self::FailedReload => 6,
+            Self::Discarded => 7,
+        }
+    }
+
+    pub fn from_id(id: i32) -> ProtocolResult<Self> {
+        Ok(match id {
+            0 => Self::SuccessfullyLoaded,
+            1 => Self::Declined,
+            2 => Self::FailedDownload,
+            3 => Self::Accepted,
+            4 => Self::Downloaded,
+            5 => Self::InvalidUrl,
+            6 => Self::FailedReload,
+            7 => Self::Discarded,
+            other => {
+                return Err(ProtocolError::invalid(format!(
+                    "unknown resource pack result {other}"
+                )));
+            }
+        })
+    }
+}
+
+/// A client's status update for a pushed pack.
+///
+/// `id` is sent from 1.20.3. `hash` is only sent by 1.8–1.9.4 clients, which
+/// echo the hash of the pack instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourcePackResponse {
+    pub id: Option<uuid::Uuid>,
+    pub hash: Option<String>,
+    pub result: ResourcePackResult,
+}
+
+impl ResourcePackResponse {
+    pub(crate) fn read(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        let id = if version.no_less_than(ProtocolVersion::V1_20_3) {
+            Some(r.read_uuid()?)
+        } else {
+            None
+        };
+        let hash = if version.less_than(ProtocolVersion::V1_12) {
+            Some(r.read_string()?)
+        } else {
+            None
+        };
+        let result = ResourcePackResult::from_id(r.read_var_int()?.0)?;
+        Ok(Self { id, hash, result })
+    }
+
+    pub(crate) fn write(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        if version.no_less_than(ProtocolVersion::V1_20_3) {
+            w.write_uuid(&self.id.unwrap_or_default())?;
+        }
+        if version.less_than(ProtocolVersion::V1_12) {
+            w.write_string(self.hash.as_deref().unwrap_or_default())?;
+        }
+        w.write_var_int(&VarInt(self.result.id()))?;
+        Ok(())
+    }
+}
+
+pub(crate) fn read_pop(r: &mut &[u8]) -> ProtocolResult<Option<uuid::Uuid>> {
+    if r.read_bool()? {
+        Ok(Some(r.read_uuid()?))
+    } else {
+        Ok(None)
+    }
+}
+
+pub(crate) fn write_pop(
+    mut w: &mut (impl std::io::Write + ?Sized),
+    id: Option<uuid::Uuid>,
+) -> ProtocolResult<()> {
+    w.write_bool(id.is_some())?;
+    if let Some(id) = id {
+        w.write_uuid(&id)?;
+    }
+    Ok(())
+}
+
+/// Offers a resource pack (Clientbound). "Add Resource Pack" from 1.20.3,
+/// "Resource Pack Send" before.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CResourcePackPush {
+    pub pack: ResourcePackRequest,
+}
+
+impl Packet for CResourcePackPush {
+    const NAME: &'static str = "CResourcePackPush";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        Ok(Self {
+            pack: ResourcePackRequest::read(r, version)?,
+        })
+    }
+
+    fn encode(
+        &self,
+        w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        self.pack.write(w, version)
+    }
+}
+
+/// Removes one pack by id, or every pack when `id` is `None` (Clientbound, 1.20.3+).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CResourcePackPop {
+    pub id: Option<uuid::Uuid>,
+}
+
+impl Packet for CResourcePackPop {
+    const NAME: &'static str = "CResourcePackPop";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], _version: ProtocolVersion) -> ProtocolResult<Self> {
+        Ok(Self { id: read_pop(r)? })
+    }
+
+    fn encode(
+        &self,
+        w: &mut (impl std::io::Write + ?Sized),
+        _version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        write_pop(w, self.id)
+    }
+}
+
+/// Client status update for a pushed pack (Serverbound).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SResourcePackStatus {
+    pub response: ResourcePackResponse,
+}
+
+impl Packet for SResourcePackStatus {
+    const NAME: &'static str = "SResourcePackStatus";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Serverbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        Ok(Self {
+            response: ResourcePackResponse::read(r, version)?,
+        })
+    }
+
+    fn encode(
+        &self,
+        w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        self.response.write(w, version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+
+    fn round_trip<P: Packet>(packet: &P, version: ProtocolVersion) -> P {
+        let mut buf = Vec::new();
+        packet.encode(&mut buf, version).unwrap();
+        let mut r = buf.as_slice();
+        let decoded = P::decode(&mut r, version).unwrap();
+        assert!(r.is_empty(), "trailing bytes after {}", P::NAME);
+        decoded
+    }
+
+    fn pack(prompt: Option<Vec<u8>>) -> CResourcePackPush {
+        CResourcePackPush {
+            pack: ResourcePackRequest {
+                id: uuid::Uuid::from_u128(9),
+                url: "https://example.com/pack.zip".to_string(),
+                hash: "a".repeat(40),
+                forced: true,
+                prompt,
+            },
+        }
+    }
+
+    #[test]
+    fn test_push_fields_follow_version() {
+        let pkt = pack(Some(br#"{"text":"Please"}"#.to_vec()));
+        assert_eq!(round_trip(&pkt, ProtocolVersion::V1_20_2), {
+            let mut legacy = pkt.clone();
+            legacy.pack.id = uuid::Uuid::nil();
+            legacy
+        });
+
+        // 1.8–1.16 only know the URL and hash
+        let old = round_trip(&pkt, ProtocolVersion::V1_16_4);
+        assert_eq!(old.pack.url, pkt.pack.url);
+        assert!(!old.pack.forced);
+        assert_eq!(old.pack.prompt, None);
+
+        let modern = pack(None);
+        assert_eq!(round_trip(&modern, ProtocolVersion::V1_21), modern);
+    }
+
+    #[test]
+    fn test_pop_round_trip() {
+        for id in [None, Some(uuid::Uuid::from_u128(3))] {
+            let pkt = CResourcePackPop { id };
+            assert_eq!(round_trip(&pkt, ProtocolVersion::V1_20_3), pkt);
+        }
+    }
+
+    #[test]
+    fn test_status_fields_follow_version() {
+        let legacy = SResourcePackStatus {
+            response: ResourcePackResponse {
+                id: None,
+                hash: Some("abc".to_string()),
+                result: ResourcePackResult::Declined,
+            },
+        };
+        assert_eq!(round_trip(&legacy, ProtocolVersion::V1_8), legacy);
+
+        let mut buf = Vec::new();
+        legacy.encode(&mut buf, ProtocolVersion::V1_19_4).unwrap();
+        assert_eq!(buf, vec![0x01]);
+
+        let modern = SResourcePackStatus {
+            response: ResourcePackResponse {
+                id: Some(uuid::Uuid::from_u128(5)),
+                hash: None,
+                result: ResourcePackResult::Discarded,
+            },
+        };
+        assert_eq!(round_trip(&modern, ProtocolVersion::V1_21_5), modern);
+    }
+
+    #[test]
+    fn test_unknown_result_rejected() {
+        let buf = [0x08];
+        assert!(SResourcePackStatus::decode(&mut buf.as_slice(), ProtocolVersion::V1_19).is_err());
+    }
+}
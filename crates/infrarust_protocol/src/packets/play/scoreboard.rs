@@ -0,0 +1,782 @@
+//! Scoreboard packets (Clientbound, 1.8+): objectives, display slots, scores
+//! and teams.
+//!
+//! Text fields are opaque bytes: legacy `§` text before 1.13, UTF-8 JSON
+//! before 1.20.3 and network NBT from 1.20.3.
+
+use crate::codec::{McBufReadExt, McBufWriteExt, VarInt};
+use crate::error::{ProtocolError, ProtocolResult};
+use crate::packets::Packet;
+use crate::packets::play::common::{read_component, write_component};
+use crate::version::{ConnectionState, Direction, ProtocolVersion};
+
+/// Display slot of the sidebar in [`CDisplayObjective`].
+pub const DISPLAY_SLOT_SIDEBAR: i32 = 1;
+
+/// Name tag visibility and collision rule values, in their 1.21.5+ id order.
+const NAME_TAG_VISIBILITY: [&str; 4] = ["always", "never", "hideForOtherTeams", "hideForOwnTeam"];
+const COLLISION_RULE: [&str; 4] = ["always", "never", "pushOtherTeams", "pushOwnTeam"];
+
+/// How score numbers are rendered (1.20.3+).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumberFormat {
+    /// No number shown.
+    Blank,
+    /// The number, styled with an NBT style compound.
+    Styled(Vec<u8>),
+    /// A fixed text component instead of the number.
+    Fixed(Vec<u8>),
+}
+
+impl NumberFormat {
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        match r.read_var_int()?.0 {
+            0 => Ok(Self::Blank),
+            1 => Ok(Self::Styled(read_component(r, version)?)),
+            2 => Ok(Self::Fixed(read_component(r, version)?)),
+            other => Err(ProtocolError::invalid(format!(
+                "unknown number format {other}"
+            ))),
+        }
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        match self {
+            Self::Blank => w.write_var_int(&VarInt(0))?,
+            Self::Styled(style) => {
+                w.write_var_int(&VarInt(1))?;
+                write_component(w, style, version)?;
+            }
+            Self::Fixed(text) => {
+                w.write_var_int(&VarInt(2))?;
+                write_component(w, text, version)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn read_optional_number_format(
+    r: &mut &[u8],
+    version: ProtocolVersion,
+) -> ProtocolResult<Option<NumberFormat>> {
+    if r.read_bool()? {
+        Ok(Some(NumberFormat::decode(r, version)?))
+    } else {
+        Ok(None)
+    }
+}
+
+fn write_optional_number_format(
+    mut w: &mut (impl std::io::Write + ?Sized),
+    format: Option<&NumberFormat>,
+    version: ProtocolVersion,
+) -> ProtocolResult<()> {
+    w.write_bool(format.is_some())?;
+    if let Some(format) = format {
+        format.encode(w, version)?;
+    }
+    Ok(())
+}
+
+/// Display settings of an objective.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectiveInfo {
+    pub display_name: Vec<u8>,
+    /// Render scores as hearts instead of integers.
+    pub hearts: bool,
+    /// Default number format of the objective's scores (1.20.3+).
+    pub number_format: Option<NumberFormat>,
+}
+
+/// Operation carried by a [`CScoreboardObjective`] packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectiveAction {
+    Create(ObjectiveInfo),
+    Remove,
+    Update(ObjectiveInfo),
+}
+
+/// Creates, removes or updates a scoreboard objective.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CScoreboardObjective {
+    pub name: String,
+    pub action: ObjectiveAction,
+}
+
+impl ObjectiveInfo {
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        let display_name = read_component(r, version)?;
+        let hearts = if version.less_than(ProtocolVersion::V1_13) {
+            r.read_string()? == "hearts"
+        } else {
+            r.read_var_int()?.0 == 1
+        };
+        let number_format = if version.less_than(ProtocolVersion::V1_20_3) {
+            None
+        } else {
+            read_optional_number_format(r, version)?
+        };
+        Ok(Self {
+            display_name,
+            hearts,
+            number_format,
+        })
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        write_component(w, &self.display_name, version)?;
+        if version.less_than(ProtocolVersion::V1_13) {
+            w.write_string(if self.hearts { "hearts" } else { "integer" })?;
+        } else {
+            w.write_var_int(&VarInt(i32::from(self.hearts)))?;
+        }
+        if version.no_less_than(ProtocolVersion::V1_20_3) {
+            write_optional_number_format(w, self.number_format.as_ref(), version)?;
+        }
+        Ok(())
+    }
+}
+
+impl Packet for CScoreboardObjective {
+    const NAME: &'static str = "CScoreboardObjective";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        let name = r.read_string()?;
+        let action = match r.read_u8()? {
+            0 => ObjectiveAction::Create(ObjectiveInfo::decode(r, version)?),
+            1 => ObjectiveAction::Remove,
+            2 => ObjectiveAction::Update(ObjectiveInfo::decode(r, version)?),
+            other => {
+                return Err(ProtocolError::invalid(format!(
+                    "CScoreboardObjective: unknown mode {other}"
+                )));
+            }
+        };
+        Ok(Self { name, action })
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        w.write_string(&self.name)?;
+        match &self.action {
+            ObjectiveAction::Create(info) => {
+                w.write_u8(0)?;
+                info.encode(w, version)?;
+            }
+            ObjectiveAction::Remove => w.write_u8(1)?,
+            ObjectiveAction::Update(info) => {
+                w.write_u8(2)?;
+                info.encode(w, version)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Shows an objective in a display slot, or clears the slot with an empty name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CDisplayObjective {
+    pub position: i32,
+    pub objective_name: String,
+}
+
+impl Packet for CDisplayObjective {
+    const NAME: &'static str = "CDisplayObjective";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        let position = if version.less_than(ProtocolVersion::V1_20_2) {
+            i32::from(r.read_i8()?)
+        } else {
+            r.read_var_int()?.0
+        };
+        Ok(Self {
+            position,
+            objective_name: r.read_string()?,
+        })
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        if version.less_than(ProtocolVersion::V1_20_2) {
+            let position = i8::try_from(self.position)
+                .map_err(|_| ProtocolError::invalid("CDisplayObjective: position out of range"))?;
+            w.write_i8(position)?;
+        } else {
+            w.write_var_int(&VarInt(self.position))?;
+        }
+        w.write_string(&self.objective_name)?;
+        Ok(())
+    }
+}
+
+/// Operation carried by a [`CUpdateScore`] packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScoreAction {
+    /// Sets the score. `display_name` and `number_format` are 1.20.3+ only.
+    Set {
+        value: i32,
+        display_name: Option<Vec<u8>>,
+        number_format: Option<NumberFormat>,
+    },
+    /// Removes the score (before 1.20.3; later versions use [`CResetScore`]).
+    Remove,
+}
+
+/// Sets or removes the score of an entry in an objective.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CUpdateScore {
+    pub entity_name: String,
+    pub objective_name: String,
+    pub action: ScoreAction,
+}
+
+impl Packet for CUpdateScore {
+    const NAME: &'static str = "CUpdateScore";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        let entity_name = r.read_string()?;
+        if version.less_than(ProtocolVersion::V1_20_3) {
+            let remove = r.read_var_int()?.0 == 1;
+            let objective_name = r.read_string()?;
+            let action = if remove {
+                ScoreAction::Remove
+            } else {
+                ScoreAction::Set {
+                    value: r.read_var_int()?.0,
+                    display_name: None,
+                    number_format: None,
+                }
+            };
+            return Ok(Self {
+                entity_name,
+                objective_name,
+                action,
+            });
+        }
+
+        let objective_name = r.read_string()?;
+        let value = r.read_var_int()?.0;
+        let display_name = if r.read_bool()? {
+            Some(read_component(r, version)?)
+        } else {
+            None
+        };
+        let number_format = read_optional_number_format(r, version)?;
+        Ok(Self {
+            entity_name,
+            objective_name,
+            action: ScoreAction::Set {
+                value,
+                display_name,
+                number_format,
+            },
+        })
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        w.write_string(&self.entity_name)?;
+        if version.less_than(ProtocolVersion::V1_20_3) {
+            match &self.action {
+                ScoreAction::Set { value, .. } => {
+                    w.write_var_int(&VarInt(0))?;
+                    w.write_string(&self.objective_name)?;
+                    w.write_var_int(&VarInt(*value))?;
+                }
+                ScoreAction::Remove => {
+                    w.write_var_int(&VarInt(1))?;
+                    w.write_string(&self.objective_name)?;
+                }
+            }
+            return Ok(());
+        }
+
+        let ScoreAction::Set {
+            value,
+            display_name,
+            number_format,
+        } = &self.action
+        else {
+            return Err(ProtocolError::invalid(
+                "CUpdateScore: removal needs CResetScore from 1.20.3",
+            ));
+        };
+        w.write_string(&self.objective_name)?;
+        w.write_var_int(&VarInt(*value))?;
+        w.write_bool(display_name.is_some())?;
+        if let Some(display_name) = display_name {
+            write_component(w, display_name, version)?;
+        }
+        write_optional_number_format(w, number_format.as_ref(), version)?;
+        Ok(())
+    }
+}
+
+/// Removes the score of an entry (1.20.3+), in one objective or all of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CResetScore {
+    pub entity_name: String,
+    pub objective_name: Option<String>,
+}
+
+impl Packet for CResetScore {
+    const NAME: &'static str = "CResetScore";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], _version: ProtocolVersion) -> ProtocolResult<Self> {
+        let entity_name = r.read_string()?;
+        let objective_name = if r.read_bool()? {
+            Some(r.read_string()?)
+        } else {
+            None
+        };
+        Ok(Self {
+            entity_name,
+            objective_name,
+        })
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        _version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        w.write_string(&self.entity_name)?;
+        w.write_bool(self.objective_name.is_some())?;
+        if let Some(name) = &self.objective_name {
+            w.write_string(name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Display settings of a team.
+///
+/// `name_tag_visibility` and `collision_rule` use their string names
+/// (`"always"`, `"never"`, ...), sent as ids from 1.21.5. `color` is a
+/// legacy color index 0–15, or -1 (21 from 1.13) for none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeamInfo {
+    pub display_name: Vec<u8>,
+    pub prefix: Vec<u8>,
+    pub suffix: Vec<u8>,
+    pub friendly_flags: u8,
+    pub name_tag_visibility: String,
+    pub collision_rule: String,
+    pub color: i32,
+}
+
+impl TeamInfo {
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        if version.less_than(ProtocolVersion::V1_13) {
+            let display_name = r.read_string()?.into_bytes();
+            let prefix = r.read_string()?.into_bytes();
+            let suffix = r.read_string()?.into_bytes();
+            let friendly_flags = r.read_u8()?;
+            let name_tag_visibility = r.read_string()?;
+            let collision_rule = if version.less_than(ProtocolVersion::V1_9) {
+                "always".to_string()
+            } else {
+                r.read_string()?
+            };
+            let color = i32::from(r.read_i8()?);
+            return Ok(Self {
+                display_name,
+                prefix,
+                suffix,
+                friendly_flags,
+                name_tag_visibility,
+                collision_rule,
+                color,
+            });
+        }
+
+        let display_name = read_component(r, version)?;
+        let friendly_flags = r.read_u8()?;
+        let (name_tag_visibility, collision_rule) = if version.less_than(ProtocolVersion::V1_21_5) {
+            (r.read_string()?, r.read_string()?)
+        } else {
+            (
+                enum_name(&NAME_TAG_VISIBILITY, r.read_var_int()?.0)?,
+                enum_name(&COLLISION_RULE, r.read_var_int()?.0)?,
+            )
+        };
+        let color = r.read_var_int()?.0;
+        let prefix = read_component(r, version)?;
+        let suffix = read_component(r, version)?;
+        Ok(Self {
+            display_name,
+            prefix,
+            suffix,
+            friendly_flags,
+            name_tag_visibility,
+            collision_rule,
+            color,
+        })
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        if version.less_than(ProtocolVersion::V1_13) {
+            write_component(w, &self.display_name, version)?;
+            write_component(w, &self.prefix, version)?;
+            write_component(w, &self.suffix, version)?;
+            w.write_u8(self.friendly_flags)?;
+            w.write_string(&self.name_tag_visibility)?;
+            if version.no_less_than(ProtocolVersion::V1_9) {
+                w.write_string(&self.collision_rule)?;
+            }
+            let color = i8::try_from(self.color)
+                .map_err(|_| ProtocolError::invalid("CTeams: color out of range"))?;
+            w.write_i8(color)?;
+            return Ok(());
+        }
+
+        write_component(w, &self.display_name, version)?;
+        w.write_u8(self.friendly_flags)?;
+        if version.less_than(ProtocolVersion::V1_21_5) {
+            w.write_string(&self.name_tag_visibility)?;
+            w.write_string(&self.collision_rule)?;
+        } else {
+            w.write_var_int(&VarInt(enum_id(
+                &NAME_TAG_VISIBILITY,
+                &self.name_tag_visibility,
+            )?))?;
+            w.write_var_int(&VarInt(enum_id(&COLLISION_RULE, &self.collision_rule)?))?;
+        }
+        w.write_var_int(&VarInt(self.color))?;
+        write_component(w, &self.prefix, version)?;
+        write_component(w, &self.suffix, version)?;
+        Ok(())
+    }
+}
+
+fn enum_name(names: &[&str], id: i32) -> ProtocolResult<String> {
+    usize::try_from(id)
+        .ok()
+        .and_then(|i| names.get(i))
+        .map(|name| (*name).to_string())
+        .ok_or_else(|| ProtocolError::invalid(format!("CTeams: unknown rule id {id}")))
+}
+
+fn enum_id(names: &[&str], name: &str) -> ProtocolResult<i32> {
+    names
+        .iter()
+        .position(|n| *n == name)
+        .map(|i| i as i32)
+        .ok_or_else(|| ProtocolError::invalid(format!("CTeams: unknown rule {name}")))
+}
+
+/// Operation carried by a [`CTeams`] packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TeamAction {
+    Create(TeamInfo, Vec<String>),
+    Remove,
+    UpdateInfo(TeamInfo),
+    AddEntities(Vec<String>),
+    RemoveEntities(Vec<String>),
+}
+
+/// Creates, removes or updates a team and its members.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CTeams {
+    pub name: String,
+    pub action: TeamAction,
+}
+
+fn read_entities(r: &mut &[u8]) -> ProtocolResult<Vec<String>> {
+    let count = r.read_var_int()?.0;
+    let count = usize::try_from(count)
+        .map_err(|_| ProtocolError::invalid("CTeams: negative entity count"))?;
+    (0..count).map(|_| r.read_string()).collect()
+}
+
+fn write_entities(
+    mut w: &mut (impl std::io::Write + ?Sized),
+    entities: &[String],
+) -> ProtocolResult<()> {
+    w.write_var_int(&VarInt(entities.len() as i32))?;
+    for entity in entities {
+        w.write_string(entity)?;
+    }
+    Ok(())
+}
+
+impl Packet for CTeams {
+    const NAME: &'static str = "CTeams";
+
+    fn state() -> ConnectionState {
+        ConnectionState::Play
+    }
+
+    fn direction() -> Direction {
+        Direction::Clientbound
+    }
+
+    fn decode(r: &mut &[u8], version: ProtocolVersion) -> ProtocolResult<Self> {
+        let name = r.read_string()?;
+        let action = match r.read_u8()? {
+            0 => {
+                let info = TeamInfo::decode(r, version)?;
+                TeamAction::Create(info, read_entities(r)?)
+            }
+            1 => TeamAction::Remove,
+            2 => TeamAction::UpdateInfo(TeamInfo::decode(r, version)?),
+            3 => TeamAction::AddEntities(read_entities(r)?),
+            4 => TeamAction::RemoveEntities(read_entities(r)?),
+            other => {
+                return Err(ProtocolError::invalid(format!(
+                    "CTeams: unknown mode {other}"
+                )));
+            }
+        };
+        Ok(Self { name, action })
+    }
+
+    fn encode(
+        &self,
+        mut w: &mut (impl std::io::Write + ?Sized),
+        version: ProtocolVersion,
+    ) -> ProtocolResult<()> {
+        w.write_string(&self.name)?;
+        match &self.action {
+            TeamAction::Create(info, entities) => {
+                w.write_u8(0)?;
+                info.encode(w, version)?;
+                write_entities(w, entities)?;
+            }
+            TeamAction::Remove => w.write_u8(1)?,
+            TeamAction::UpdateInfo(info) => {
+                w.write_u8(2)?;
+                info.encode(w, version)?;
+            }
+            TeamAction::AddEntities(entities) => {
+                w.write_u8(3)?;
+                write_entities(w, entities)?;
+            }
+            TeamAction::RemoveEntities(entities) => {
+                w.write_u8(4)?;
+                write_entities(w, entities)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+
+    fn round_trip<P: Packet + PartialEq + std::fmt::Debug>(packet: &P, version: ProtocolVersion) {
+        let mut buf = Vec::new();
+        packet.encode(&mut buf, version).unwrap();
+        let mut r = buf.as_slice();
+        let decoded = P::decode(&mut r, version).unwrap();
+        assert!(r.is_empty(), "trailing bytes after {}", P::NAME);
+        assert_eq!(&decoded, packet);
+    }
+
+    fn nbt_text(text: &str) -> Vec<u8> {
+        let mut nbt = vec![0x0A, 0x08, 0x00, 0x04];
+        nbt.extend_from_slice(b"text");
+        nbt.extend_from_slice(&(text.len() as u16).to_be_bytes());
+        nbt.extend_from_slice(text.as_bytes());
+        nbt.push(0x00);
+        nbt
+    }
+
+    #[test]
+    fn test_objective_round_trip_across_versions() {
+        let legacy = CScoreboardObjective {
+            name: "ir_sidebar".into(),
+            action: ObjectiveAction::Create(ObjectiveInfo {
+                display_name: "§6Network".as_bytes().to_vec(),
+                hearts: false,
+                number_format: None,
+            }),
+        };
+        round_trip(&legacy, ProtocolVersion::V1_8);
+        round_trip(&legacy, ProtocolVersion::V1_16);
+
+        let modern = CScoreboardObjective {
+            name: "ir_sidebar".into(),
+            action: ObjectiveAction::Update(ObjectiveInfo {
+                display_name: nbt_text("Network"),
+                hearts: true,
+                number_format: Some(NumberFormat::Blank),
+            }),
+        };
+        round_trip(&modern, ProtocolVersion::V1_21);
+    }
+
+    #[test]
+    fn test_display_objective_position_encoding() {
+        let pkt = CDisplayObjective {
+            position: DISPLAY_SLOT_SIDEBAR,
+            objective_name: "ir_sidebar".into(),
+        };
+        round_trip(&pkt, ProtocolVersion::V1_12_2);
+        round_trip(&pkt, ProtocolVersion::V1_21);
+    }
+
+    #[test]
+    fn test_score_round_trip_and_modern_remove_rejected() {
+        let remove = CUpdateScore {
+            entity_name: "§0".into(),
+            objective_name: "ir_sidebar".into(),
+            action: ScoreAction::Remove,
+        };
+        round_trip(&remove, ProtocolVersion::V1_19_4);
+        assert!(
+            remove
+                .encode(&mut Vec::new(), ProtocolVersion::V1_21)
+                .is_err()
+        );
+
+        let set = CUpdateScore {
+            entity_name: "§0".into(),
+            objective_name: "ir_sidebar".into(),
+            action: ScoreAction::Set {
+                value: 3,
+                display_name: Some(nbt_text("Online: 5")),
+                number_format: Some(NumberFormat::Fixed(nbt_text("!"))),
+            },
+        };
+        round_trip(&set, ProtocolVersion::V1_21);
+
+        round_trip(
+            &CResetScore {
+                entity_name: "§0".into(),
+                objective_name: Some("ir_sidebar".into()),
+            },
+            ProtocolVersion::V1_21,
+        );
+    }
+
+    #[test]
+    fn test_teams_round_trip_across_versions() {
+        let legacy_info = TeamInfo {
+            display_name: b"line".to_vec(),
+            prefix: "§aOnline: ".as_bytes().to_vec(),
+            suffix: b"5".to_vec(),
+            friendly_flags: 0,
+            name_tag_visibility: "always".into(),
+            collision_rule: "always".into(),
+            color: -1,
+        };
+        let create = CTeams {
+            name: "ir_line_0".into(),
+            action: TeamAction::Create(legacy_info, vec!["§0".into()]),
+        };
+        round_trip(&create, ProtocolVersion::V1_8);
+        round_trip(&create, ProtocolVersion::V1_12_2);
+
+        let modern_info = TeamInfo {
+            display_name: br#"{"text":"line"}"#.to_vec(),
+            prefix: br#"{"text":"Online: 5"}"#.to_vec(),
+            suffix: br#"{"text":""}"#.to_vec(),
+            friendly_flags: 0,
+            name_tag_visibility: "never".into(),
+            collision_rule: "pushOwnTeam".into(),
+            color: 21,
+        };
+        let update = CTeams {
+            name: "ir_line_0".into(),
+            action: TeamAction::UpdateInfo(modern_info.clone()),
+        };
+        round_trip(&update, ProtocolVersion::V1_16);
+
+        let nbt_info = TeamInfo {
+            display_name: nbt_text("line"),
+            prefix: nbt_text("Online: 5"),
+            suffix: nbt_text(""),
+            ..modern_info
+        };
+        let update = CTeams {
+            name: "ir_line_0".into(),
+            action: TeamAction::UpdateInfo(nbt_info),
+        };
+        round_trip(&update, ProtocolVersion::V1_21_5);
+
+        round_trip(
+            &CTeams {
+                name: "ir_line_0".into(),
+                action: TeamAction::RemoveEntities(vec!["§0".into()]),
+            },
+            ProtocolVersion::V1_21,
+        );
+    }
+
+    #[test]
+    fn test_unknown_team_rule_rejected() {
+        let pkt = CTeams {
+            name: "t".into(),
+            action: TeamAction::UpdateInfo(TeamInfo {
+                display_name: nbt_text(""),
+                prefix: nbt_text(""),
+                suffix: nbt_text(""),
+                friendly_flags: 0,
+                name_tag_visibility: "sometimes".into(),
+                collision_rule: "always".into(),
+                color: 21,
+            }),
+        };
+        assert!(
+            pkt.encode(&mut Vec::new(), ProtocolVersion::V1_21_5)
+                .is_err()
+        );
+    }
+}
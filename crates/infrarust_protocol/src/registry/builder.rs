@@ -313,6 +313,38 @@ pub fn build_default_registry() -> PacketRegistry {
     .map(0x0E, ProtocolVersion::V1_20_5, false)
     .register(&mut registry);
 
+    PacketRegistration::<crate::packets::SConfigClientInformation>::new(
+        ConnectionState::Config,
+        Direction::Serverbound,
+    )
+    .map(0x00, ProtocolVersion::V1_20_2, false)
+    .register(&mut registry);
+
+    PacketRegistration::<crate::packets::SConfigResourcePackStatus>::new(
+        ConnectionState::Config,
+        Direction::Serverbound,
+    )
+    .map(0x05, ProtocolVersion::V1_20_2, false)
+    .map(0x06, ProtocolVersion::V1_20_5, false)
+    .register(&mut registry);
+
+    PacketRegistration::<crate::packets::CConfigResourcePackPop>::new(
+        ConnectionState::Config,
+        Direction::Clientbound,
+    )
+    .map(0x06, ProtocolVersion::V1_20_3, false)
+    .map(0x08, ProtocolVersion::V1_20_5, false)
+    .register(&mut registry);
+
+    PacketRegistration::<crate::packets::CConfigResourcePackPush>::new(
+        ConnectionState::Config,
+        Direction::Clientbound,
+    )
+    .map(0x06, ProtocolVersion::V1_20_2, false)
+    .map(0x07, ProtocolVersion::V1_20_3, false)
+    .map(0x09, ProtocolVersion::V1_20_5, false)
+    .register(&mut registry);
+
     PacketRegistration::<crate::packets::CCommands>::new(
         ConnectionState::Play,
         Direction::Clientbound,
@@ -579,6 +611,288 @@ pub fn build_default_registry() -> PacketRegistry {
     .map(0x71, ProtocolVersion::V1_21_9, true)
     .register(&mut registry);
 
+    // PlayerListHeaderFooter Clientbound (1.8+, encode-only: injected by plugin system)
+    PacketRegistration::<crate::packets::CPlayerListHeaderFooter>::new(
+        ConnectionState::Play,
+        Direction::Clientbound,
+    )
+    .map(0x47, ProtocolVersion::V1_8, true)
+    .map(0x48, ProtocolVersion::V1_9, true)
+    .map(0x47, ProtocolVersion::V1_9_4, true)
+    .map(0x49, ProtocolVersion::V1_12, true)
+    .map(0x4A, ProtocolVersion::V1_12_1, true)
+    .map(0x4E, ProtocolVersion::V1_13, true)
+    .map(0x53, ProtocolVersion::V1_14, true)
+    .map(0x54, ProtocolVersion::V1_15, true)
+    .map(0x53, ProtocolVersion::V1_16, true)
+    .map(0x5E, ProtocolVersion::V1_17, true)
+    .map(0x5F, ProtocolVersion::V1_18, true)
+    .map(0x60, ProtocolVersion::V1_19, true)
+    .map(0x63, ProtocolVersion::V1_19_1, true)
+    .map(0x61, ProtocolVersion::V1_19_3, true)
+    .map(0x65, ProtocolVersion::V1_19_4, true)
+    .map(0x68, ProtocolVersion::V1_20_2, true)
+    .map(0x6A, ProtocolVersion::V1_20_3, true)
+    .map(0x6D, ProtocolVersion::V1_20_5, true)
+    .map(0x74, ProtocolVersion::V1_21_2, true)
+    .map(0x73, ProtocolVersion::V1_21_5, true)
+    .map(0x78, ProtocolVersion::V1_21_9, true)
+    .register(&mut registry);
+
+    // Legacy PlayerListItem Clientbound (1.8–1.19.1, encode-only)
+    PacketRegistration::<crate::packets::CPlayerListItemLegacy>::new(
+        ConnectionState::Play,
+        Direction::Clientbound,
+    )
+    .map(0x38, ProtocolVersion::V1_8, true)
+    .map(0x2D, ProtocolVersion::V1_9, true)
+    .map(0x2E, ProtocolVersion::V1_12_1, true)
+    .map(0x30, ProtocolVersion::V1_13, true)
+    .map(0x33, ProtocolVersion::V1_14, true)
+    .map(0x34, ProtocolVersion::V1_15, true)
+    .map(0x33, ProtocolVersion::V1_16, true)
+    .map(0x32, ProtocolVersion::V1_16_2, true)
+    .map(0x36, ProtocolVersion::V1_17, true)
+    .map(0x34, ProtocolVersion::V1_19, true)
+    .map_range(
+        0x37,
+        ProtocolVersion::V1_19_1,
+        ProtocolVersion::V1_19_1,
+        true,
+    )
+    .register(&mut registry);
+
+    // PlayerInfoRemove Clientbound (1.19.3+, encode-only)
+    PacketRegistration::<crate::packets::CPlayerInfoRemove>::new(
+        ConnectionState::Play,
+        Direction::Clientbound,
+    )
+    .map(0x35, ProtocolVersion::V1_19_3, true)
+    .map(0x39, ProtocolVersion::V1_19_4, true)
+    .map(0x3B, ProtocolVersion::V1_20_2, true)
+    .map(0x3D, ProtocolVersion::V1_20_5, true)
+    .map(0x3F, ProtocolVersion::V1_21_2, true)
+    .map(0x3E, ProtocolVersion::V1_21_5, true)
+    .map(0x43, ProtocolVersion::V1_21_9, true)
+    .register(&mut registry);
+
+    // PlayerInfoUpdate Clientbound (1.19.3+, encode-only)
+    PacketRegistration::<crate::packets::CPlayerInfoUpdate>::new(
+        ConnectionState::Play,
+        Direction::Clientbound,
+    )
+    .map(0x36, ProtocolVersion::V1_19_3, true)
+    .map(0x3A, ProtocolVersion::V1_19_4, true)
+    .map(0x3C, ProtocolVersion::V1_20_2, true)
+    .map(0x3E, ProtocolVersion::V1_20_5, true)
+    .map(0x40, ProtocolVersion::V1_21_2, true)
+    .map(0x3F, ProtocolVersion::V1_21_5, true)
+    .map(0x44, ProtocolVersion::V1_21_9, true)
+    .register(&mut registry);
+
+    // BossBar Clientbound (encode-only: proxy-owned boss bars, 1.9+)
+    PacketRegistration::<crate::packets::CBossBar>::new(
+        ConnectionState::Play,
+        Direction::Clientbound,
+    )
+    .map(0x0C, ProtocolVersion::V1_9, true)
+    .map(0x0D, ProtocolVersion::V1_15, true)
+    .map(0x0C, ProtocolVersion::V1_16, true)
+    .map(0x0D, ProtocolVersion::V1_17, true)
+    .map(0x0A, ProtocolVersion::V1_19, true)
+    .map(0x0B, ProtocolVersion::V1_19_4, true)
+    .map(0x0A, ProtocolVersion::V1_20_2, true)
+    .map(0x09, ProtocolVersion::V1_21_5, true)
+    .register(&mut registry);
+
+    // Scoreboard Objective Clientbound (encode-only)
+    PacketRegistration::<crate::packets::CScoreboardObjective>::new(
+        ConnectionState::Play,
+        Direction::Clientbound,
+    )
+    .map(0x3B, ProtocolVersion::V1_8, true)
+    .map(0x3F, ProtocolVersion::V1_9, true)
+    .map(0x41, ProtocolVersion::V1_12, true)
+    .map(0x42, ProtocolVersion::V1_12_1, true)
+    .map(0x45, ProtocolVersion::V1_13, true)
+    .map(0x49, ProtocolVersion::V1_14, true)
+    .map(0x4A, ProtocolVersion::V1_15, true)
+    .map(0x53, ProtocolVersion::V1_17, true)
+    .map(0x56, ProtocolVersion::V1_19_1, true)
+    .map(0x54, ProtocolVersion::V1_19_3, true)
+    .map(0x58, ProtocolVersion::V1_19_4, true)
+    .map(0x5A, ProtocolVersion::V1_20_2, true)
+    .map(0x5C, ProtocolVersion::V1_20_3, true)
+    .map(0x5E, ProtocolVersion::V1_20_5, true)
+    .map(0x64, ProtocolVersion::V1_21_2, true)
+    .map(0x63, ProtocolVersion::V1_21_5, true)
+    .map(0x68, ProtocolVersion::V1_21_9, true)
+    .register(&mut registry);
+
+    // Display Objective Clientbound (encode-only)
+    PacketRegistration::<crate::packets::CDisplayObjective>::new(
+        ConnectionState::Play,
+        Direction::Clientbound,
+    )
+    .map(0x3D, ProtocolVersion::V1_8, true)
+    .map(0x38, ProtocolVersion::V1_9, true)
+    .map(0x3A, ProtocolVersion::V1_12, true)
+    .map(0x3B, ProtocolVersion::V1_12_1, true)
+    .map(0x3E, ProtocolVersion::V1_13, true)
+    .map(0x42, ProtocolVersion::V1_14, true)
+    .map(0x43, ProtocolVersion::V1_15, true)
+    .map(0x4C, ProtocolVersion::V1_17, true)
+    .map(0x4F, ProtocolVersion::V1_19_1, true)
+    .map(0x4D, ProtocolVersion::V1_19_3, true)
+    .map(0x51, ProtocolVersion::V1_19_4, true)
+    .map(0x53, ProtocolVersion::V1_20_2, true)
+    .map(0x55, ProtocolVersion::V1_20_3, true)
+    .map(0x57, ProtocolVersion::V1_20_5, true)
+    .map(0x5C, ProtocolVersion::V1_21_2, true)
+    .map(0x5B, ProtocolVersion::V1_21_5, true)
+    .map(0x60, ProtocolVersion::V1_21_9, true)
+    .register(&mut registry);
+
+    // Update Score Clientbound (encode-only)
+    PacketRegistration::<crate::packets::CUpdateScore>::new(
+        ConnectionState::Play,
+        Direction::Clientbound,
+    )
+    .map(0x3C, ProtocolVersion::V1_8, true)
+    .map(0x42, ProtocolVersion::V1_9, true)
+    .map(0x44, ProtocolVersion::V1_12, true)
+    .map(0x45, ProtocolVersion::V1_12_1, true)
+    .map(0x48, ProtocolVersion::V1_13, true)
+    .map(0x4C, ProtocolVersion::V1_14, true)
+    .map(0x4D, ProtocolVersion::V1_15, true)
+    .map(0x56, ProtocolVersion::V1_17, true)
+    .map(0x59, ProtocolVersion::V1_19_1, true)
+    .map(0x57, ProtocolVersion::V1_19_3, true)
+    .map(0x5B, ProtocolVersion::V1_19_4, true)
+    .map(0x5D, ProtocolVersion::V1_20_2, true)
+    .map(0x5F, ProtocolVersion::V1_20_3, true)
+    .map(0x61, ProtocolVersion::V1_20_5, true)
+    .map(0x68, ProtocolVersion::V1_21_2, true)
+    .map(0x67, ProtocolVersion::V1_21_5, true)
+    .map(0x6C, ProtocolVersion::V1_21_9, true)
+    .register(&mut registry);
+
+    // Reset Score Clientbound (1.20.3+, encode-only)
+    PacketRegistration::<crate::packets::CResetScore>::new(
+        ConnectionState::Play,
+        Direction::Clientbound,
+    )
+    .map(0x42, ProtocolVersion::V1_20_3, true)
+    .map(0x44, ProtocolVersion::V1_20_5, true)
+    .map(0x49, ProtocolVersion::V1_21_2, true)
+    .map(0x48, ProtocolVersion::V1_21_5, true)
+    .map(0x4D, ProtocolVersion::V1_21_9, true)
+    .register(&mut registry);
+
+    // Teams Clientbound (encode-only)
+    PacketRegistration::<crate::packets::CTeams>::new(
+        ConnectionState::Play,
+        Direction::Clientbound,
+    )
+    .map(0x3E, ProtocolVersion::V1_8, true)
+    .map(0x41, ProtocolVersion::V1_9, true)
+    .map(0x43, ProtocolVersion::V1_12, true)
+    .map(0x44, ProtocolVersion::V1_12_1, true)
+    .map(0x47, ProtocolVersion::V1_13, true)
+    .map(0x4B, ProtocolVersion::V1_14, true)
+    .map(0x4C, ProtocolVersion::V1_15, true)
+    .map(0x55, ProtocolVersion::V1_17, true)
+    .map(0x58, ProtocolVersion::V1_19_1, true)
+    .map(0x56, ProtocolVersion::V1_19_3, true)
+    .map(0x5A, ProtocolVersion::V1_19_4, true)
+    .map(0x5C, ProtocolVersion::V1_20_2, true)
+    .map(0x5E, ProtocolVersion::V1_20_3, true)
+    .map(0x60, ProtocolVersion::V1_20_5, true)
+    .map(0x67, ProtocolVersion::V1_21_2, true)
+    .map(0x66, ProtocolVersion::V1_21_5, true)
+    .map(0x6B, ProtocolVersion::V1_21_9, true)
+    .register(&mut registry);
+
+    // Resource pack push/pop Clientbound (decoded: the proxy tracks backend packs)
+    PacketRegistration::<crate::packets::CResourcePackPush>::new(
+        ConnectionState::Play,
+        Direction::Clientbound,
+    )
+    .map(0x48, ProtocolVersion::V1_8, false)
+    .map(0x32, ProtocolVersion::V1_9, false)
+    .map(0x33, ProtocolVersion::V1_12, false)
+    .map(0x34, ProtocolVersion::V1_12_1, false)
+    .map(0x37, ProtocolVersion::V1_13, false)
+    .map(0x39, ProtocolVersion::V1_14, false)
+    .map(0x3A, ProtocolVersion::V1_15, false)
+    .map(0x39, ProtocolVersion::V1_16, false)
+    .map(0x38, ProtocolVersion::V1_16_2, false)
+    .map(0x3C, ProtocolVersion::V1_17, false)
+    .map(0x3A, ProtocolVersion::V1_19, false)
+    .map(0x3D, ProtocolVersion::V1_19_1, false)
+    .map(0x3C, ProtocolVersion::V1_19_3, false)
+    .map(0x40, ProtocolVersion::V1_19_4, false)
+    .map(0x42, ProtocolVersion::V1_20_2, false)
+    .map(0x44, ProtocolVersion::V1_20_3, false)
+    .map(0x46, ProtocolVersion::V1_20_5, false)
+    .map(0x4B, ProtocolVersion::V1_21_2, false)
+    .map(0x4A, ProtocolVersion::V1_21_5, false)
+    .map(0x4F, ProtocolVersion::V1_21_9, false)
+    .register(&mut registry);
+
+    PacketRegistration::<crate::packets::CResourcePackPop>::new(
+        ConnectionState::Play,
+        Direction::Clientbound,
+    )
+    .map(0x43, ProtocolVersion::V1_20_3, false)
+    .map(0x45, ProtocolVersion::V1_20_5, false)
+    .map(0x4A, ProtocolVersion::V1_21_2, false)
+    .map(0x49, ProtocolVersion::V1_21_5, false)
+    .map(0x4E, ProtocolVersion::V1_21_9, false)
+    .register(&mut registry);
+
+    // Client settings Serverbound (recorded on the player, then forwarded)
+    PacketRegistration::<crate::packets::SClientInformation>::new(
+        ConnectionState::Play,
+        Direction::Serverbound,
+    )
+    .map(0x15, ProtocolVersion::V1_8, false)
+    .map(0x04, ProtocolVersion::V1_9, false)
+    .map(0x05, ProtocolVersion::V1_12, false)
+    .map(0x04, ProtocolVersion::V1_12_1, false)
+    .map(0x05, ProtocolVersion::V1_14, false)
+    .map(0x07, ProtocolVersion::V1_19, false)
+    .map(0x08, ProtocolVersion::V1_19_1, false)
+    .map(0x07, ProtocolVersion::V1_19_3, false)
+    .map(0x08, ProtocolVersion::V1_19_4, false)
+    .map(0x09, ProtocolVersion::V1_20_2, false)
+    .map(0x0A, ProtocolVersion::V1_20_5, false)
+    .map(0x0C, ProtocolVersion::V1_21_2, false)
+    .map(0x0D, ProtocolVersion::V1_21_6, false)
+    .register(&mut registry);
+
+    // Resource pack status Serverbound (answers to proxy packs are not forwarded)
+    PacketRegistration::<crate::packets::SResourcePackStatus>::new(
+        ConnectionState::Play,
+        Direction::Serverbound,
+    )
+    .map(0x19, ProtocolVersion::V1_8, false)
+    .map(0x16, ProtocolVersion::V1_9, false)
+    .map(0x18, ProtocolVersion::V1_12, false)
+    .map(0x1D, ProtocolVersion::V1_13, false)
+    .map(0x1F, ProtocolVersion::V1_14, false)
+    .map(0x20, ProtocolVersion::V1_16, false)
+    .map(0x21, ProtocolVersion::V1_16_2, false)
+    .map(0x23, ProtocolVersion::V1_19, false)
+    .map(0x24, ProtocolVersion::V1_19_1, false)
+    .map(0x27, ProtocolVersion::V1_20_2, false)
+    .map(0x28, ProtocolVersion::V1_20_3, false)
+    .map(0x2B, ProtocolVersion::V1_20_5, false)
+    .map(0x2D, ProtocolVersion::V1_21_2, false)
+    .map(0x2F, ProtocolVersion::V1_21_4, false)
+    .map(0x30, ProtocolVersion::V1_21_6, false)
+    .register(&mut registry);
+
     // Transfer Clientbound (encode-only: proxy doesn't intercept)
     PacketRegistration::<crate::packets::CTransfer>::new(
         ConnectionState::Play,
@@ -799,6 +1113,28 @@ pub fn build_default_registry() -> PacketRegistry {
     .map(0x06, ProtocolVersion::V1_21_6, true)
     .register(&mut registry);
 
+    // Signed ChatCommand Serverbound (1.20.5+) — commands with signable
+    // arguments; decoded manually like ChatCommand.
+    PacketRegistration::<crate::packets::SSignedChatCommand>::new(
+        ConnectionState::Play,
+        Direction::Serverbound,
+    )
+    .map(0x05, ProtocolVersion::V1_20_5, true)
+    .map(0x06, ProtocolVersion::V1_21_2, true)
+    .map(0x07, ProtocolVersion::V1_21_6, true)
+    .register(&mut registry);
+
+    // Message Acknowledgement Serverbound (1.19.3+, encode-only: sent when
+    // the proxy consumes a chat message)
+    PacketRegistration::<crate::packets::SChatAcknowledgement>::new(
+        ConnectionState::Play,
+        Direction::Serverbound,
+    )
+    .map(0x03, ProtocolVersion::V1_19_3, true)
+    .map(0x04, ProtocolVersion::V1_21_2, true)
+    .map(0x05, ProtocolVersion::V1_21_6, true)
+    .register(&mut registry);
+
     // Chat Session Update Serverbound (encode-only: proxy uses ID for filtering)
     PacketRegistration::<crate::packets::SChatSessionUpdate>::new(
         ConnectionState::Play,
@@ -616,6 +616,206 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_registry_tab_list_packets_by_version() {
+        use crate::packets::{CPlayerInfoUpdate, CPlayerListHeaderFooter, CPlayerListItemLegacy};
+
+        let registry = build_default_registry();
+        let id = |version| {
+            (
+                registry.get_packet_id::<CPlayerListItemLegacy>(
+                    ConnectionState::Play,
+                    Direction::Clientbound,
+                    version,
+                ),
+                registry.get_packet_id::<CPlayerInfoUpdate>(
+                    ConnectionState::Play,
+                    Direction::Clientbound,
+                    version,
+                ),
+            )
+        };
+
+        // The legacy list item packet was replaced in 1.19.3
+        assert_eq!(id(ProtocolVersion::V1_19_1), (Some(0x37), None));
+        assert_eq!(id(ProtocolVersion::V1_19_3), (None, Some(0x36)));
+        assert_eq!(
+            registry.get_packet_id::<CPlayerListHeaderFooter>(
+                ConnectionState::Play,
+                Direction::Clientbound,
+                ProtocolVersion::V1_21,
+            ),
+            Some(0x6D)
+        );
+    }
+
+    #[test]
+    fn test_registry_boss_bar_by_version() {
+        use crate::packets::CBossBar;
+
+        let registry = build_default_registry();
+        let id = |version| {
+            registry.get_packet_id::<CBossBar>(
+                ConnectionState::Play,
+                Direction::Clientbound,
+                version,
+            )
+        };
+
+        assert_eq!(id(ProtocolVersion::V1_8), None);
+        assert_eq!(id(ProtocolVersion::V1_12_2), Some(0x0C));
+        assert_eq!(id(ProtocolVersion::V1_19_4), Some(0x0B));
+        assert_eq!(id(ProtocolVersion::V1_21), Some(0x0A));
+        assert_eq!(id(ProtocolVersion::V1_21_9), Some(0x09));
+    }
+
+    #[test]
+    fn test_registry_scoreboard_by_version() {
+        use crate::packets::{CResetScore, CScoreboardObjective, CTeams, CUpdateScore};
+
+        fn id<P: crate::packets::Packet + 'static>(
+            registry: &PacketRegistry,
+            version: ProtocolVersion,
+        ) -> Option<i32> {
+            registry.get_packet_id::<P>(ConnectionState::Play, Direction::Clientbound, version)
+        }
+
+        let registry = build_default_registry();
+        assert_eq!(
+            id::<CScoreboardObjective>(&registry, ProtocolVersion::V1_7_2),
+            None
+        );
+        assert_eq!(
+            id::<CScoreboardObjective>(&registry, ProtocolVersion::V1_8),
+            Some(0x3B)
+        );
+        assert_eq!(
+            id::<CScoreboardObjective>(&registry, ProtocolVersion::V1_16),
+            Some(0x4A)
+        );
+        assert_eq!(
+            id::<CTeams>(&registry, ProtocolVersion::V1_18_2),
+            Some(0x55)
+        );
+        assert_eq!(
+            id::<CUpdateScore>(&registry, ProtocolVersion::V1_21),
+            Some(0x61)
+        );
+        assert_eq!(id::<CResetScore>(&registry, ProtocolVersion::V1_20_2), None);
+        assert_eq!(
+            id::<CResetScore>(&registry, ProtocolVersion::V1_21),
+            Some(0x44)
+        );
+    }
+
+    #[test]
+    fn test_registry_resource_pack_by_version() {
+        use crate::packets::{
+            CConfigResourcePackPush, CResourcePackPop, CResourcePackPush, SResourcePackStatus,
+        };
+
+        let registry = build_default_registry();
+        let push = |version| {
+            registry.get_packet_id::<CResourcePackPush>(
+                ConnectionState::Play,
+                Direction::Clientbound,
+                version,
+            )
+        };
+        assert_eq!(push(ProtocolVersion::V1_7_6), None);
+        assert_eq!(push(ProtocolVersion::V1_8), Some(0x48));
+        assert_eq!(push(ProtocolVersion::V1_16_4), Some(0x38));
+        assert_eq!(push(ProtocolVersion::V1_21), Some(0x46));
+        assert_eq!(
+            registry.get_packet_id::<CResourcePackPop>(
+                ConnectionState::Play,
+                Direction::Clientbound,
+                ProtocolVersion::V1_20_2,
+            ),
+            None
+        );
+        assert_eq!(
+            registry.get_packet_id::<SResourcePackStatus>(
+                ConnectionState::Play,
+                Direction::Serverbound,
+                ProtocolVersion::V1_21_4,
+            ),
+            Some(0x2F)
+        );
+        assert_eq!(
+            registry.get_packet_id::<CConfigResourcePackPush>(
+                ConnectionState::Config,
+                Direction::Clientbound,
+                ProtocolVersion::V1_21,
+            ),
+            Some(0x09)
+        );
+    }
+
+    #[test]
+    fn test_registry_client_information_by_version() {
+        use crate::packets::{SClientInformation, SConfigClientInformation};
+
+        let registry = build_default_registry();
+        let play = |version| {
+            registry.get_packet_id::<SClientInformation>(
+                ConnectionState::Play,
+                Direction::Serverbound,
+                version,
+            )
+        };
+        assert_eq!(play(ProtocolVersion::V1_8), Some(0x15));
+        assert_eq!(play(ProtocolVersion::V1_12_2), Some(0x04));
+        assert_eq!(play(ProtocolVersion::V1_19_4), Some(0x08));
+        assert_eq!(play(ProtocolVersion::V1_21), Some(0x0A));
+        assert_eq!(play(ProtocolVersion::V1_21_6), Some(0x0D));
+        assert_eq!(
+            registry.get_packet_id::<SConfigClientInformation>(
+                ConnectionState::Config,
+                Direction::Serverbound,
+                ProtocolVersion::V1_21,
+            ),
+            Some(0x00)
+        );
+    }
+
+    #[test]
+    fn test_registry_chat_acknowledgement_by_version() {
+        use crate::packets::SChatAcknowledgement;
+
+        let registry = build_default_registry();
+        let id = |version| {
+            registry.get_packet_id::<SChatAcknowledgement>(
+                ConnectionState::Play,
+                Direction::Serverbound,
+                version,
+            )
+        };
+        assert_eq!(id(ProtocolVersion::V1_19), None);
+        assert_eq!(id(ProtocolVersion::V1_19_3), Some(0x03));
+        assert_eq!(id(ProtocolVersion::V1_21), Some(0x03));
+        assert_eq!(id(ProtocolVersion::V1_21_2), Some(0x04));
+        assert_eq!(id(ProtocolVersion::V1_21_6), Some(0x05));
+    }
+
+    #[test]
+    fn test_registry_signed_chat_command_by_version() {
+        use crate::packets::SSignedChatCommand;
+
+        let registry = build_default_registry();
+        let id = |version| {
+            registry.get_packet_id::<SSignedChatCommand>(
+                ConnectionState::Play,
+                Direction::Serverbound,
+                version,
+            )
+        };
+        assert_eq!(id(ProtocolVersion::V1_20_3), None);
+        assert_eq!(id(ProtocolVersion::V1_20_5), Some(0x05));
+        assert_eq!(id(ProtocolVersion::V1_21_2), Some(0x06));
+        assert_eq!(id(ProtocolVersion::V1_21_6), Some(0x07));
+    }
+
     #[test]
     fn test_registry_keepalive_different_ids_by_version() {
         use crate::packets::CKeepAlive;
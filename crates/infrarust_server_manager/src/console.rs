@@ -0,0 +1,104 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use tokio::sync::broadcast;
+
+/// Number of console lines kept for late subscribers.
+pub const DEFAULT_CONSOLE_HISTORY: usize = 500;
+
+/// Output stream a console line was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsoleStream {
+    Stdout,
+    Stderr,
+}
+
+/// A single line of server console output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsoleLine {
+    pub stream: ConsoleStream,
+    pub line: String,
+}
+
+/// Fan-out of a server's console output with a bounded backlog.
+///
+/// Lives as long as the provider, so subscribers keep receiving output
+/// across server restarts.
+pub struct ConsoleOutput {
+    sender: broadcast::Sender<ConsoleLine>,
+    history: Mutex<VecDeque<ConsoleLine>>,
+    capacity: usize,
+}
+
+impl ConsoleOutput {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self {
+            sender,
+            history: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+        }
+    }
+
+    /// Records a line and forwards it to every subscriber.
+    pub fn push(&self, line: ConsoleLine) {
+        let mut history = self.history.lock().expect("lock poisoned");
+        if self.capacity > 0 {
+            if history.len() == self.capacity {
+                history.pop_front();
+            }
+            history.push_back(line.clone());
+        }
+        // Sent under the lock so `subscribe` never sees a line twice or misses one.
+        let _ = self.sender.send(line);
+    }
+
+    /// Returns the buffered lines and a receiver for everything after them.
+    pub fn subscribe(&self) -> (Vec<ConsoleLine>, broadcast::Receiver<ConsoleLine>) {
+        let history = self.history.lock().expect("lock poisoned");
+        (history.iter().cloned().collect(), self.sender.subscribe())
+    }
+}
+
+impl Default for ConsoleOutput {
+    fn default() -> Self {
+        Self::new(DEFAULT_CONSOLE_HISTORY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+
+    fn line(text: &str) -> ConsoleLine {
+        ConsoleLine {
+            stream: ConsoleStream::Stdout,
+            line: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let output = ConsoleOutput::new(2);
+        output.push(line("a"));
+        output.push(line("b"));
+        output.push(line("c"));
+
+        let (history, _) = output.subscribe();
+        assert_eq!(history, vec![line("b"), line("c")]);
+    }
+
+    #[test]
+    fn test_subscriber_receives_new_lines_only() {
+        let output = ConsoleOutput::new(10);
+        output.push(line("old"));
+
+        let (history, mut rx) = output.subscribe();
+        output.push(line("new"));
+
+        assert_eq!(history, vec![line("old")]);
+        assert_eq!(rx.try_recv().unwrap(), line("new"));
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use bollard::container::{InspectContainerOptions, StopContainerOptions};
+use bollard::models::{ContainerState, ContainerStateStatusEnum, HealthStatusEnum};
+use bollard::{API_DEFAULT_VERSION, Docker};
+
+use infrarust_config::DockerManagerConfig;
+
+use crate::error::ServerManagerError;
+use crate::provider::{ProviderStatus, ServerProvider};
+
+/// Timeout in seconds for Docker API requests.
+const DOCKER_API_TIMEOUT: u64 = 30;
+
+/// Provider for an existing Docker container, started and stopped on demand.
+///
+/// The container is not created by the proxy: it must already exist
+/// (e.g. declared in `docker-compose.yml` and stopped).
+pub struct DockerProvider {
+    docker: Docker,
+    container: String,
+    pause: bool,
+    stop_timeout: std::time::Duration,
+}
+
+impl DockerProvider {
+    /// Builds the Docker client for `config.endpoint`.
+    ///
+    /// # Errors
+    /// Returns `ServerManagerError::Provider` if the endpoint is invalid
+    /// (e.g. the unix socket does not exist).
+    pub fn new(config: &DockerManagerConfig) -> Result<Self, ServerManagerError> {
+        let endpoint = config.endpoint.as_str();
+        let docker = if endpoint.starts_with("tcp://") || endpoint.starts_with("http://") {
+            Docker::connect_with_http(endpoint, DOCKER_API_TIMEOUT, API_DEFAULT_VERSION)
+        } else if endpoint.starts_with("unix://") {
+            Docker::connect_with_socket(endpoint, DOCKER_API_TIMEOUT, API_DEFAULT_VERSION)
+        } else {
+            Docker::connect_with_local_defaults()
+        }
+        .map_err(|e| ServerManagerError::Provider {
+            server_id: config.container.clone(),
+            message: format!("cannot connect to Docker at {endpoint}: {e}"),
+        })?;
+
+        Ok(Self {
+            docker,
+            container: config.container.clone(),
+            pause: config.pause,
+            stop_timeout: config.stop_timeout,
+        })
+    }
+
+    fn api_error(&self, action: &str, e: &bollard::errors::Error) -> ServerManagerError {
+        ServerManagerError::ApiResponse(format!(
+            "Docker {action} on container {} failed: {e}",
+            self.container
+        ))
+    }
+
+    async fn state(&self) -> Result<ContainerState, ServerManagerError> {
+        let info = self
+            .docker
+            .inspect_container(&self.container, None::<InspectContainerOptions>)
+            .await
+            .map_err(|e| self.api_error("inspect", &e))?;
+        Ok(info.state.unwrap_or_default())
+    }
+}
+
+impl ServerProvider for DockerProvider {
+    fn start(
+        &self,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<(), ServerManagerError>> + Send + '_>,
+    > {
+        Box::pin(async move {
+            let state = self.state().await?;
+
+            if state.paused == Some(true) {
+                tracing::info!(container = %self.container, "unpausing Docker container");
+                return self
+                    .docker
+                    .unpause_container(&self.container)
+                    .await
+                    .map_err(|e| self.api_error("unpause", &e));
+            }
+            if state.running == Some(true) {
+                return Ok(());
+            }
+
+            tracing::info!(container = %self.container, "starting Docker container");
+            self.docker
+                .start_container::<String>(&self.container, None)
+                .await
+                .map_err(|e| self.api_error("start", &e))
+        })
+    }
+
+    fn stop(
+        &self,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<(), ServerManagerError>> + Send + '_>,
+    > {
+        Box::pin(async move {
+            if self.pause {
+                tracing::info!(container = %self.container, "pausing Docker container");
+                return self
+                    .docker
+                    .pause_container(&self.container)
+                    .await
+                    .map_err(|e| self.api_error("pause", &e));
+            }
+
+            tracing::info!(container = %self.container, "stopping Docker container");
+            let t = i64::try_from(self.stop_timeout.as_secs()).unwrap_or(i64::MAX);
+            self.docker
+                .stop_container(&self.container, Some(StopContainerOptions { t }))
+                .await
+                .map_err(|e| self.api_error("stop", &e))
+        })
+    }
+
+    fn check_status(
+        &self,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<ProviderStatus, ServerManagerError>>
+                + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move { Ok(map_container_state(&self.state().await?)) })
+    }
+
+    fn provider_type(&self) -> &'static str {
+        "docker"
+    }
+}
+
+/// Maps the container state (and its health check, when defined) to a status.
+///
+/// A running container with a health check is only `Running` once healthy,
+/// so players are not sent to a server that is still loading its world.
+pub fn map_container_state(state: &ContainerState) -> ProviderStatus {
+    let health = state
+        .health
+        .as_ref()
+        .and_then(|h| h.status)
+        .unwrap_or(HealthStatusEnum::NONE);
+
+    match state.status {
+        Some(ContainerStateStatusEnum::RUNNING) => match health {
+            HealthStatusEnum::STARTING => ProviderStatus::Starting,
+            HealthStatusEnum::UNHEALTHY => ProviderStatus::Unknown,
+            _ => ProviderStatus::Running,
+        },
+        Some(ContainerStateStatusEnum::RESTARTING) => ProviderStatus::Starting,
+        Some(ContainerStateStatusEnum::REMOVING) => ProviderStatus::Stopping,
+        Some(
+            ContainerStateStatusEnum::PAUSED
+            | ContainerStateStatusEnum::CREATED
+            | ContainerStateStatusEnum::EXITED
+            | ContainerStateStatusEnum::DEAD,
+        ) => ProviderStatus::Stopped,
+        Some(ContainerStateStatusEnum::EMPTY) | None => ProviderStatus::Unknown,
+    }
+}
@@ -38,6 +38,15 @@ pub enum ServerManagerError {
         exit_code: Option<i32>,
     },
 
+    #[error("{provider} provider does not support {action}")]
+    Unsupported {
+        provider: &'static str,
+        action: &'static str,
+    },
+
+    #[error("server {server_id} is closed by its schedule")]
+    OutsideSchedule { server_id: String, message: String },
+
     #[error("shutdown in progress")]
     Shutdown,
 }
@@ -0,0 +1,231 @@
+use infrarust_config::{HttpManagerConfig, HttpRequestConfig, HttpStatusMapping};
+use regex::Regex;
+
+use crate::error::ServerManagerError;
+use crate::provider::{ProviderStatus, ServerProvider};
+
+/// Provider for any panel with a REST API, driven by templated requests.
+pub struct HttpProvider {
+    http_client: reqwest::Client,
+    server_id: String,
+    config: HttpManagerConfig,
+    regex: Option<Regex>,
+}
+
+impl HttpProvider {
+    /// Creates the provider for the managed server `server_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ServerManagerError::Provider`] if the status regex does not compile.
+    pub fn new(
+        server_id: &str,
+        config: &HttpManagerConfig,
+        http_client: reqwest::Client,
+    ) -> Result<Self, ServerManagerError> {
+        let regex = config
+            .status_mapping
+            .regex
+            .as_deref()
+            .map(Regex::new)
+            .transpose()
+            .map_err(|e| ServerManagerError::Provider {
+                server_id: server_id.to_string(),
+                message: format!("invalid status regex: {e}"),
+            })?;
+
+        Ok(Self {
+            http_client,
+            server_id: server_id.to_string(),
+            config: config.clone(),
+            regex,
+        })
+    }
+
+    fn render(&self, template: &str) -> String {
+        template.replace("{server_id}", &self.server_id)
+    }
+
+    /// Sends one of the configured requests and returns the response body.
+    async fn send(
+        &self,
+        name: &str,
+        request: &HttpRequestConfig,
+    ) -> Result<String, ServerManagerError> {
+        let method = reqwest::Method::from_bytes(request.method.to_ascii_uppercase().as_bytes())
+            .map_err(|_| ServerManagerError::Provider {
+                server_id: self.server_id.clone(),
+                message: format!("invalid HTTP method '{}'", request.method),
+            })?;
+
+        let mut builder = self
+            .http_client
+            .request(method, self.render(&request.url))
+            .timeout(self.config.request_timeout);
+        for (header, value) in &request.headers {
+            builder = builder.header(header, self.render(value));
+        }
+        if let Some(body) = &request.body {
+            builder = builder.body(self.render(body));
+        }
+
+        let resp = builder.send().await?;
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        if !status.is_success() {
+            return Err(ServerManagerError::ApiResponse(format!(
+                "HTTP {name} request returned {status}: {body}"
+            )));
+        }
+        Ok(body)
+    }
+}
+
+/// Extracts the raw state value from a status response body.
+fn extract_state(body: &str, mapping: &HttpStatusMapping, regex: Option<&Regex>) -> Option<String> {
+    if let Some(path) = &mapping.json_path {
+        let json: serde_json::Value = serde_json::from_str(body).ok()?;
+        return match json_path(&json, path)? {
+            serde_json::Value::String(s) => Some(s.clone()),
+            serde_json::Value::Bool(b) => Some(b.to_string()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        };
+    }
+    if let Some(regex) = regex {
+        let captures = regex.captures(body)?;
+        let value = captures.get(1).or_else(|| captures.get(0))?;
+        return Some(value.as_str().to_string());
+    }
+    Some(body.trim().to_string())
+}
+
+/// Resolves a dotted path such as `$.data.servers[0].state`.
+fn json_path<'a>(value: &'a serde_json::Value, path: &str) -> Option<&'a serde_json::Value> {
+    let path = path.trim_start_matches('$').trim_start_matches('.');
+    let mut current = value;
+    for segment in path.split('.').filter(|s| !s.is_empty()) {
+        let (key, indices) = segment.split_once('[').unwrap_or((segment, ""));
+        if !key.is_empty() {
+            current = current.get(key)?;
+        }
+        for index in indices.split('[').filter(|s| !s.is_empty()) {
+            let index: usize = index.trim_end_matches(']').parse().ok()?;
+            current = current.get(index)?;
+        }
+    }
+    Some(current)
+}
+
+/// Maps a raw state value to a provider status.
+fn map_state(value: &str, mapping: &HttpStatusMapping) -> ProviderStatus {
+    let matches = |list: &[String]| list.iter().any(|v| v.eq_ignore_ascii_case(value));
+    if matches(&mapping.running) {
+        ProviderStatus::Running
+    } else if matches(&mapping.starting) {
+        ProviderStatus::Starting
+    } else if matches(&mapping.stopping) {
+        ProviderStatus::Stopping
+    } else if matches(&mapping.stopped) {
+        ProviderStatus::Stopped
+    } else if matches(&mapping.crashed) {
+        ProviderStatus::Crashed
+    } else {
+        ProviderStatus::Unknown
+    }
+}
+
+impl ServerProvider for HttpProvider {
+    fn start(
+        &self,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<(), ServerManagerError>> + Send + '_>,
+    > {
+        Box::pin(async move {
+            tracing::info!(server_id = %self.server_id, "sending HTTP start request");
+            self.send("start", &self.config.start).await.map(|_| ())
+        })
+    }
+
+    fn stop(
+        &self,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<(), ServerManagerError>> + Send + '_>,
+    > {
+        Box::pin(async move {
+            tracing::info!(server_id = %self.server_id, "sending HTTP stop request");
+            self.send("stop", &self.config.stop).await.map(|_| ())
+        })
+    }
+
+    fn check_status(
+        &self,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<ProviderStatus, ServerManagerError>>
+                + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move {
+            let body = self.send("status", &self.config.status).await?;
+            let mapping = &self.config.status_mapping;
+
+            let Some(value) = extract_state(&body, mapping, self.regex.as_ref()) else {
+                tracing::warn!(
+                    server_id = %self.server_id,
+                    "failed to extract state from HTTP status response, defaulting to Unknown"
+                );
+                return Ok(ProviderStatus::Unknown);
+            };
+
+            let status = map_state(&value, mapping);
+            if status == ProviderStatus::Unknown {
+                tracing::warn!(
+                    server_id = %self.server_id,
+                    value = %value,
+                    "HTTP status value matches no configured state"
+                );
+            }
+            Ok(status)
+        })
+    }
+
+    fn provider_type(&self) -> &'static str {
+        "http"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_json_path() {
+        let json = serde_json::json!({"data": {"servers": [{"state": "running"}], "up": true}});
+        assert_eq!(
+            json_path(&json, "$.data.servers[0].state"),
+            Some(&serde_json::json!("running"))
+        );
+        assert_eq!(json_path(&json, "data.up"), Some(&serde_json::json!(true)));
+        assert_eq!(json_path(&json, "data.servers[3].state"), None);
+    }
+
+    #[test]
+    fn test_extract_and_map_state() {
+        let mut mapping = HttpStatusMapping {
+            json_path: Some("status".to_string()),
+            ..HttpStatusMapping::default()
+        };
+        let value = extract_state(r#"{"status": "ONLINE"}"#, &mapping, None).unwrap();
+        assert_eq!(map_state(&value, &mapping), ProviderStatus::Running);
+
+        mapping.json_path = None;
+        let regex = Regex::new(r"state=(\w+)").unwrap();
+        let value = extract_state("pid=4 state=stopped", &mapping, Some(&regex)).unwrap();
+        assert_eq!(map_state(&value, &mapping), ProviderStatus::Stopped);
+
+        assert_eq!(map_state("rebooting", &mapping), ProviderStatus::Unknown);
+    }
+}
@@ -1,22 +1,35 @@
 //! Server manager for Infrarust.
 //!
 //! Provides automatic server start/stop with support for Local (Java process),
-//! Pterodactyl (panel API), and Crafty Controller (panel API).
+//! Pterodactyl (panel API), Crafty Controller (panel API), Docker
+//! (existing container, behind the `docker` feature) and generic HTTP APIs.
 //!
 //! The `ServerProvider` trait is non-sealed and can be implemented by third parties
 //! for custom panel integrations.
 
+pub mod console;
 pub mod crafty;
+#[cfg(feature = "docker")]
+pub mod docker;
 pub mod error;
+pub mod http;
 pub mod local;
 mod monitor;
+mod persist;
 pub mod provider;
 pub mod pterodactyl;
+mod restart;
+mod schedule;
 pub mod service;
 pub mod state;
+mod wake;
 
+pub use console::{ConsoleLine, ConsoleOutput, ConsoleStream};
 pub use crafty::CraftyProvider;
+#[cfg(feature = "docker")]
+pub use docker::DockerProvider;
 pub use error::ServerManagerError;
+pub use http::HttpProvider;
 pub use local::LocalProvider;
 pub use provider::{ProviderStatus, ServerProvider};
 pub use pterodactyl::PterodactylProvider;
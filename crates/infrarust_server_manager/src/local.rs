@@ -6,6 +6,7 @@ use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::process::{Child, ChildStdin};
 use tokio::task::JoinHandle;
 
+use crate::console::{ConsoleLine, ConsoleOutput, ConsoleStream};
 use crate::error::ServerManagerError;
 use crate::provider::{ProviderStatus, ServerProvider};
 use crate::state::ServerState;
@@ -15,6 +16,10 @@ pub struct LocalProvider {
     config: LocalManagerConfig,
     /// Handle to the running process. None if not started.
     process: tokio::sync::Mutex<Option<LocalProcess>>,
+    /// Console output, kept across restarts of the process.
+    console: Arc<ConsoleOutput>,
+    /// PID of the running process, spawned or adopted from a previous proxy instance.
+    pid: std::sync::Mutex<Option<u32>>,
 }
 
 struct LocalProcess {
@@ -33,9 +38,40 @@ impl LocalProvider {
         Self {
             config,
             process: tokio::sync::Mutex::new(None),
+            console: Arc::new(ConsoleOutput::default()),
+            pid: std::sync::Mutex::new(None),
         }
     }
 
+    fn set_pid(&self, pid: Option<u32>) {
+        *self.pid.lock().expect("lock poisoned") = pid;
+    }
+
+    /// PID of an adopted process, only meaningful while no process is spawned.
+    fn adopted_pid(&self) -> Option<u32> {
+        *self.pid.lock().expect("lock poisoned")
+    }
+
+    /// Stops an adopted process: no stdin to type `stop` into, so `SIGTERM`
+    /// triggers the server's shutdown hook, then `SIGKILL` after the timeout.
+    #[cfg(unix)]
+    async fn stop_adopted(&self, pid: u32) {
+        adopted::signal(pid, nix::sys::signal::Signal::SIGTERM);
+        let deadline = tokio::time::Instant::now() + self.config.shutdown_timeout;
+        while adopted::is_alive(pid) {
+            if tokio::time::Instant::now() >= deadline {
+                tracing::warn!(server = %self.server_label(), pid, "shutdown timeout, killing adopted process");
+                adopted::signal(pid, nix::sys::signal::Signal::SIGKILL);
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        tracing::info!(server = %self.server_label(), pid, "adopted process stopped");
+    }
+
+    #[cfg(not(unix))]
+    async fn stop_adopted(&self, _pid: u32) {}
+
     /// Returns a descriptive string for logging.
     fn server_label(&self) -> String {
         format!(
@@ -54,7 +90,7 @@ impl ServerProvider for LocalProvider {
     > {
         Box::pin(async move {
             let mut process_lock = self.process.lock().await;
-            if process_lock.is_some() {
+            if process_lock.is_some() || self.adopted_pid().is_some_and(adopted::is_alive) {
                 return Err(ServerManagerError::InvalidState {
                     server_id: self.server_label(),
                     state: ServerState::Starting,
@@ -70,6 +106,7 @@ impl ServerProvider for LocalProvider {
                 .stderr(std::process::Stdio::piped());
 
             let mut child = cmd.spawn().map_err(ServerManagerError::Process)?;
+            self.set_pid(child.id());
 
             let stdin = child.stdin.take().ok_or_else(|| {
                 ServerManagerError::Process(std::io::Error::other("stdin not available"))
@@ -85,6 +122,7 @@ impl ServerProvider for LocalProvider {
             let ready_clone = Arc::clone(&ready);
             let pattern = self.config.ready_pattern.clone();
             let label = self.server_label();
+            let console = Arc::clone(&self.console);
 
             // Task that reads stdout line by line and detects the ready_pattern
             let stdout_task = tokio::spawn(async move {
@@ -96,16 +134,25 @@ impl ServerProvider for LocalProvider {
                         tracing::info!(server = %label, "ready pattern detected");
                         ready_clone.store(true, Ordering::Release);
                     }
+                    console.push(ConsoleLine {
+                        stream: ConsoleStream::Stdout,
+                        line,
+                    });
                 }
             });
 
-            // Separate task for stderr (logging only)
+            // Separate task for stderr (no ready detection)
             let label2 = self.server_label();
+            let console = Arc::clone(&self.console);
             let stderr_task = tokio::spawn(async move {
                 let reader = BufReader::new(stderr);
                 let mut lines = reader.lines();
                 while let Ok(Some(line)) = lines.next_line().await {
                     tracing::warn!(server = %label2, "[stderr] {line}");
+                    console.push(ConsoleLine {
+                        stream: ConsoleStream::Stderr,
+                        line,
+                    });
                 }
             });
 
@@ -130,6 +177,13 @@ impl ServerProvider for LocalProvider {
     > {
         Box::pin(async move {
             let mut process_lock = self.process.lock().await;
+            if process_lock.is_none()
+                && let Some(pid) = self.adopted_pid()
+            {
+                self.stop_adopted(pid).await;
+                self.set_pid(None);
+                return Ok(());
+            }
             let process =
                 process_lock
                     .as_mut()
@@ -165,6 +219,7 @@ impl ServerProvider for LocalProvider {
             process.stderr_task.abort();
             *process_lock = None;
             drop(process_lock);
+            self.set_pid(None);
 
             Ok(())
         })
@@ -182,16 +237,33 @@ impl ServerProvider for LocalProvider {
         Box::pin(async move {
             let mut process_lock = self.process.lock().await;
             let result = match process_lock.as_mut() {
-                None => Ok(ProviderStatus::Stopped),
+                None => match self.adopted_pid() {
+                    Some(pid) if adopted::is_alive(pid) => Ok(ProviderStatus::Running),
+                    Some(pid) => {
+                        // Not our child, so its exit status can't be read. We
+                        // didn't stop it (that clears the PID), so count it as
+                        // a crash like a spawned process exiting on its own.
+                        tracing::warn!(server = %self.server_label(), pid, "adopted process exited unexpectedly");
+                        self.set_pid(None);
+                        Ok(ProviderStatus::Crashed)
+                    }
+                    None => Ok(ProviderStatus::Stopped),
+                },
                 Some(process) => {
                     // Check if the process has exited by trying try_wait
                     match process.child.try_wait() {
-                        Ok(Some(_status)) => {
+                        Ok(Some(status)) => {
                             // Process has exited — clean up
                             process.stdout_task.abort();
                             process.stderr_task.abort();
                             *process_lock = None;
-                            Ok(ProviderStatus::Stopped)
+                            self.set_pid(None);
+                            if status.success() {
+                                Ok(ProviderStatus::Stopped)
+                            } else {
+                                tracing::warn!(server = %self.server_label(), "process exited with {status}");
+                                Ok(ProviderStatus::Crashed)
+                            }
                         }
                         Ok(None) => {
                             // Process still running
@@ -220,4 +292,114 @@ impl ServerProvider for LocalProvider {
     fn provider_type(&self) -> &'static str {
         "local"
     }
+
+    fn send_command(
+        &self,
+        command: &str,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<(), ServerManagerError>> + Send + '_>,
+    > {
+        let mut line = command.trim_end_matches(['\r', '\n']).to_string();
+        line.push('\n');
+        Box::pin(async move {
+            let mut process_lock = self.process.lock().await;
+            if process_lock.is_none() && self.adopted_pid().is_some() {
+                return Err(ServerManagerError::Unsupported {
+                    provider: "local",
+                    action: "console commands on a process adopted after a proxy restart",
+                });
+            }
+            let process =
+                process_lock
+                    .as_mut()
+                    .ok_or_else(|| ServerManagerError::InvalidState {
+                        server_id: self.server_label(),
+                        state: ServerState::Sleeping,
+                        action: "send a console command".to_string(),
+                    })?;
+
+            process
+                .stdin
+                .write_all(line.as_bytes())
+                .await
+                .map_err(ServerManagerError::Process)?;
+            process
+                .stdin
+                .flush()
+                .await
+                .map_err(ServerManagerError::Process)
+        })
+    }
+
+    fn console_output(&self) -> Option<&ConsoleOutput> {
+        Some(&self.console)
+    }
+
+    fn process_id(&self) -> Option<u32> {
+        self.adopted_pid()
+    }
+
+    fn adopt_process(
+        &self,
+        pid: u32,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = bool> + Send + '_>> {
+        Box::pin(async move {
+            let process_lock = self.process.lock().await;
+            if process_lock.is_some()
+                || !adopted::is_alive(pid)
+                || !adopted::runs_in(pid, &self.config.working_dir)
+            {
+                return false;
+            }
+            self.set_pid(Some(pid));
+            drop(process_lock);
+
+            tracing::info!(server = %self.server_label(), pid, "adopted running process");
+            true
+        })
+    }
+}
+
+/// Process checks for processes adopted from a previous proxy instance.
+#[cfg(unix)]
+mod adopted {
+    use nix::sys::signal::{Signal, kill};
+    use nix::unistd::Pid;
+
+    pub(super) fn is_alive(pid: u32) -> bool {
+        i32::try_from(pid).is_ok_and(|pid| kill(Pid::from_raw(pid), None).is_ok())
+    }
+
+    pub(super) fn signal(pid: u32, signal: Signal) {
+        if let Ok(raw) = i32::try_from(pid)
+            && let Err(e) = kill(Pid::from_raw(raw), signal)
+        {
+            tracing::warn!(pid, "failed to send {signal}: {e}");
+        }
+    }
+
+    /// Guards against PID reuse: the process must run in the server's working directory.
+    #[cfg(target_os = "linux")]
+    pub(super) fn runs_in(pid: u32, working_dir: &std::path::Path) -> bool {
+        let Ok(cwd) = std::fs::read_link(format!("/proc/{pid}/cwd")) else {
+            return false;
+        };
+        working_dir.canonicalize().is_ok_and(|dir| dir == cwd)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub(super) fn runs_in(_pid: u32, _working_dir: &std::path::Path) -> bool {
+        true
+    }
+}
+
+#[cfg(not(unix))]
+mod adopted {
+    pub(super) const fn is_alive(_pid: u32) -> bool {
+        false
+    }
+
+    pub(super) const fn runs_in(_pid: u32, _working_dir: &std::path::Path) -> bool {
+        false
+    }
 }
@@ -1,4 +1,4 @@
-//! Per-server monitoring: adaptive polling and auto-shutdown.
+//! Per-server monitoring: adaptive polling, auto-shutdown and crash restarts.
 
 use std::sync::Arc;
 use std::time::Instant;
@@ -13,7 +13,8 @@ use crate::state::ServerState;
 /// Per-server monitoring task.
 ///
 /// Polls the provider for status changes, notifies waiters on transitions,
-/// and triggers auto-shutdown when no players are connected.
+/// restarts crashed servers according to their policy, and triggers
+/// auto-shutdown when no players are connected.
 pub async fn monitor_server(
     service: Arc<ServerManagerService>,
     server_id: String,
@@ -57,7 +58,19 @@ pub async fn monitor_server(
         };
 
         // 2. Update state and detect transitions
-        if let Some((_old, new_state)) = service.update_state(&server_id, new_status) {
+        if let Some((old_state, new_state)) = service.update_state(&server_id, new_status) {
+            // Requested stops go through Stopping, so this exit was not asked for
+            if matches!(old_state, ServerState::Online | ServerState::Starting)
+                && matches!(new_state, ServerState::Crashed | ServerState::Sleeping)
+            {
+                schedule_restart(
+                    &service,
+                    &server_id,
+                    new_state == ServerState::Crashed,
+                    &shutdown,
+                );
+            }
+
             match new_state {
                 ServerState::Online => {
                     service.notify_waiters(&server_id, &Ok(()));
@@ -88,6 +101,30 @@ pub async fn monitor_server(
     }
 }
 
+/// Restarts the server after its backoff if the restart policy allows it.
+fn schedule_restart(
+    service: &Arc<ServerManagerService>,
+    server_id: &str,
+    failed: bool,
+    shutdown: &CancellationToken,
+) {
+    let Some(backoff) = service.on_unexpected_exit(server_id, failed) else {
+        return;
+    };
+
+    let service = Arc::clone(service);
+    let server_id = server_id.to_string();
+    let shutdown = shutdown.clone();
+    tokio::spawn(async move {
+        tokio::select! {
+            () = shutdown.cancelled() => {}
+            () = tokio::time::sleep(backoff) => {
+                service.restart_after_crash(&server_id).await;
+            }
+        }
+    });
+}
+
 /// Checks if a server should be auto-shutdown due to inactivity.
 async fn check_auto_shutdown(service: &ServerManagerService, server_id: &str, player_count: usize) {
     let should_stop = {
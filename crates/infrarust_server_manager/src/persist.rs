@@ -0,0 +1,123 @@
+//! Managed server state persisted across proxy restarts.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::state::ServerState;
+
+/// Contents of the state file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct PersistedState {
+    #[serde(default)]
+    pub(crate) servers: HashMap<String, PersistedServer>,
+}
+
+/// What the proxy knows about one managed server.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct PersistedServer {
+    #[serde(default)]
+    pub(crate) state: Option<ServerState>,
+    /// PID of the process started by the proxy, for local servers.
+    #[serde(default)]
+    pub(crate) pid: Option<u32>,
+    /// Unix time in seconds when a player was last seen on the server.
+    #[serde(default)]
+    pub(crate) last_player_seen: Option<u64>,
+    /// Pre-warming join history, as `(date, hour)` slots.
+    #[serde(default)]
+    pub(crate) joins: Vec<(NaiveDate, u32)>,
+}
+
+impl PersistedState {
+    /// Reads the state file, returning an empty state if it is missing or invalid.
+    pub(crate) async fn load(path: &Path) -> Self {
+        match tokio::fs::read_to_string(path).await {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                tracing::warn!(path = %path.display(), "ignoring invalid server manager state: {e}");
+                Self::default()
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => {
+                tracing::warn!(path = %path.display(), "failed to read server manager state: {e}");
+                Self::default()
+            }
+        }
+    }
+
+    /// Writes the state file (crash-safe: write tmp then rename).
+    pub(crate) async fn save(&self, path: &Path) -> std::io::Result<()> {
+        let data = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        if let Some(dir) = path.parent()
+            && !dir.as_os_str().is_empty()
+        {
+            tokio::fs::create_dir_all(dir).await?;
+        }
+        let tmp_path = path.with_extension("json.tmp");
+        tokio::fs::write(&tmp_path, data).await?;
+        tokio::fs::rename(&tmp_path, path).await
+    }
+}
+
+/// Converts an `Instant` to Unix time, for persistence.
+pub(crate) fn to_unix(instant: Instant) -> u64 {
+    let at = SystemTime::now()
+        .checked_sub(instant.elapsed())
+        .unwrap_or(UNIX_EPOCH);
+    at.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+/// Converts Unix time back to an `Instant`, so idle countdowns resume where they were.
+pub(crate) fn from_unix(secs: u64) -> Instant {
+    let at = UNIX_EPOCH + Duration::from_secs(secs);
+    let elapsed = SystemTime::now().duration_since(at).unwrap_or_default();
+    let now = Instant::now();
+    now.checked_sub(elapsed).unwrap_or(now)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_unix_round_trip() {
+        let seen = Instant::now()
+            .checked_sub(Duration::from_secs(120))
+            .unwrap();
+        let restored = from_unix(to_unix(seen));
+        let drift = restored.elapsed().abs_diff(seen.elapsed());
+        assert!(drift <= Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data").join("server_manager.json");
+
+        let mut state = PersistedState::default();
+        state.servers.insert(
+            "survival".to_string(),
+            PersistedServer {
+                state: Some(ServerState::Online),
+                pid: Some(4242),
+                last_player_seen: Some(1_700_000_000),
+                joins: vec![(NaiveDate::from_ymd_opt(2026, 10, 17).unwrap(), 20)],
+            },
+        );
+        state.save(&path).await.unwrap();
+
+        let loaded = PersistedState::load(&path).await;
+        let server = &loaded.servers["survival"];
+        assert_eq!(server.state, Some(ServerState::Online));
+        assert_eq!(server.pid, Some(4242));
+        assert_eq!(server.last_player_seen, Some(1_700_000_000));
+        assert_eq!(server.joins.len(), 1);
+
+        let missing = PersistedState::load(&dir.path().join("missing.json")).await;
+        assert!(missing.servers.is_empty());
+    }
+}
@@ -1,6 +1,7 @@
 use std::future::Future;
 use std::pin::Pin;
 
+use crate::console::ConsoleOutput;
 use crate::error::ServerManagerError;
 use crate::state::ServerState;
 
@@ -32,6 +33,42 @@ pub trait ServerProvider: Send + Sync {
 
     /// Name of the provider type (for logging).
     fn provider_type(&self) -> &'static str;
+
+    /// Writes a command to the server console.
+    ///
+    /// Providers without console access return [`ServerManagerError::Unsupported`].
+    fn send_command(
+        &self,
+        _command: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<(), ServerManagerError>> + Send + '_>> {
+        let provider = self.provider_type();
+        Box::pin(async move {
+            Err(ServerManagerError::Unsupported {
+                provider,
+                action: "console commands",
+            })
+        })
+    }
+
+    /// Console output of the server, if the provider can read it.
+    fn console_output(&self) -> Option<&ConsoleOutput> {
+        None
+    }
+
+    /// OS process ID of the server, for providers that run it as a child process.
+    ///
+    /// Persisted so a restarted proxy can re-adopt the process.
+    fn process_id(&self) -> Option<u32> {
+        None
+    }
+
+    /// Takes over a process left running by a previous proxy instance.
+    ///
+    /// Returns `false` if the process is gone or does not belong to this
+    /// server, or if the provider does not run local processes.
+    fn adopt_process(&self, _pid: u32) -> Pin<Box<dyn Future<Output = bool> + Send + '_>> {
+        Box::pin(async { false })
+    }
 }
 
 /// Status returned by a provider during a check.
@@ -42,6 +79,8 @@ pub enum ProviderStatus {
     Stopped,
     Starting,
     Stopping,
+    /// The server exited on its own with an error (e.g. non-zero exit code).
+    Crashed,
     Unknown,
 }
 
@@ -52,6 +91,7 @@ impl From<ProviderStatus> for ServerState {
             ProviderStatus::Stopped => Self::Sleeping,
             ProviderStatus::Starting => Self::Starting,
             ProviderStatus::Stopping => Self::Stopping,
+            ProviderStatus::Crashed => Self::Crashed,
             ProviderStatus::Unknown => Self::Unknown,
         }
     }
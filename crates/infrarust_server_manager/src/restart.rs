@@ -0,0 +1,164 @@
+//! Crash auto-restart bookkeeping: policy, backoff and crash-loop detection.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use infrarust_config::{RestartConfig, RestartPolicy};
+
+/// Outcome of an unexpected exit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RestartDecision {
+    /// The policy does not restart this kind of exit.
+    Skip,
+    /// Restart after the given backoff.
+    Restart(Duration),
+    /// Too many restarts within the window: give up.
+    CrashLoop { restarts: u32 },
+}
+
+/// Per-server restart state.
+#[derive(Debug)]
+pub(crate) struct RestartTracker {
+    pub(crate) config: RestartConfig,
+    /// Restarts performed within the sliding window.
+    restarts: VecDeque<Instant>,
+    /// A restart is scheduled and has not been cancelled.
+    pub(crate) pending: bool,
+    /// Restarts are suspended until the server is started manually.
+    pub(crate) crash_loop: bool,
+}
+
+impl RestartTracker {
+    pub(crate) fn new(config: RestartConfig) -> Self {
+        Self {
+            config,
+            restarts: VecDeque::new(),
+            pending: false,
+            crash_loop: false,
+        }
+    }
+
+    /// Decides what to do after the server exited without being asked to.
+    ///
+    /// `failed` is true when the process reported an error (e.g. non-zero exit code).
+    pub(crate) fn on_unexpected_exit(&mut self, failed: bool, now: Instant) -> RestartDecision {
+        let applies = match self.config.policy {
+            RestartPolicy::Never => false,
+            RestartPolicy::OnFailure => failed,
+            RestartPolicy::Always => true,
+        };
+        if !applies || self.crash_loop {
+            return RestartDecision::Skip;
+        }
+
+        while let Some(&oldest) = self.restarts.front() {
+            if now.duration_since(oldest) > self.config.window {
+                self.restarts.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        let count = u32::try_from(self.restarts.len()).unwrap_or(u32::MAX);
+        if count >= self.config.max_retries {
+            self.crash_loop = true;
+            self.pending = false;
+            return RestartDecision::CrashLoop { restarts: count };
+        }
+
+        self.restarts.push_back(now);
+        self.pending = true;
+        RestartDecision::Restart(self.backoff(count))
+    }
+
+    /// Exponential backoff for the `attempt`-th restart in the window (0-based).
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.config
+            .backoff
+            .checked_mul(2u32.saturating_pow(attempt))
+            .unwrap_or(Duration::MAX)
+            .min(self.config.max_backoff)
+    }
+
+    /// Forgets previous crashes, e.g. after a manual start or stop.
+    pub(crate) fn reset(&mut self) {
+        self.restarts.clear();
+        self.pending = false;
+        self.crash_loop = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracker(policy: RestartPolicy) -> RestartTracker {
+        RestartTracker::new(RestartConfig {
+            policy,
+            max_retries: 3,
+            window: Duration::from_secs(60),
+            backoff: Duration::from_secs(5),
+            max_backoff: Duration::from_secs(12),
+        })
+    }
+
+    #[test]
+    fn test_policy_selects_exits() {
+        let now = Instant::now();
+        assert_eq!(
+            tracker(RestartPolicy::Never).on_unexpected_exit(true, now),
+            RestartDecision::Skip
+        );
+        assert_eq!(
+            tracker(RestartPolicy::OnFailure).on_unexpected_exit(false, now),
+            RestartDecision::Skip
+        );
+        assert_eq!(
+            tracker(RestartPolicy::Always).on_unexpected_exit(false, now),
+            RestartDecision::Restart(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn test_backoff_doubles_then_detects_crash_loop() {
+        let mut tracker = tracker(RestartPolicy::OnFailure);
+        let now = Instant::now();
+
+        let decisions: Vec<_> = (0..4)
+            .map(|_| tracker.on_unexpected_exit(true, now))
+            .collect();
+
+        assert_eq!(
+            decisions,
+            vec![
+                RestartDecision::Restart(Duration::from_secs(5)),
+                RestartDecision::Restart(Duration::from_secs(10)),
+                RestartDecision::Restart(Duration::from_secs(12)),
+                RestartDecision::CrashLoop { restarts: 3 },
+            ]
+        );
+        assert!(tracker.crash_loop);
+        assert_eq!(tracker.on_unexpected_exit(true, now), RestartDecision::Skip);
+
+        tracker.reset();
+        assert_eq!(
+            tracker.on_unexpected_exit(true, now),
+            RestartDecision::Restart(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn test_old_restarts_leave_the_window() {
+        let mut tracker = tracker(RestartPolicy::Always);
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            tracker.on_unexpected_exit(true, start);
+        }
+        let later = start + Duration::from_secs(61);
+        assert_eq!(
+            tracker.on_unexpected_exit(true, later),
+            RestartDecision::Restart(Duration::from_secs(5))
+        );
+    }
+}
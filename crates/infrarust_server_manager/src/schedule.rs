@@ -0,0 +1,249 @@
+//! Scheduled starts, stops and restarts with countdown warnings.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+use tokio_util::sync::CancellationToken;
+
+use infrarust_config::{ScheduleAction, ScheduleConfig};
+
+use crate::service::{PlayerCounter, ServerManagerService};
+use crate::state::ServerState;
+
+/// Parsed schedule of a managed server.
+#[derive(Debug, Default)]
+pub(crate) struct ServerSchedule {
+    entries: Vec<(cron::Schedule, ScheduleAction)>,
+    pub(crate) config: ScheduleConfig,
+}
+
+impl ServerSchedule {
+    /// Parses the schedule entries, skipping (and logging) invalid ones.
+    pub(crate) fn new(server_id: &str, config: ScheduleConfig) -> Self {
+        let entries = config
+            .entries
+            .iter()
+            .filter_map(|entry| match entry.parse_cron() {
+                Ok(schedule) => Some((schedule, entry.action)),
+                Err(e) => {
+                    tracing::warn!(server = %server_id, "ignoring schedule entry: {e}");
+                    None
+                }
+            })
+            .collect();
+        Self { entries, config }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the next occurrence strictly after `after` and every action due at that time.
+    pub(crate) fn next(
+        &self,
+        after: DateTime<Local>,
+    ) -> Option<(DateTime<Local>, Vec<ScheduleAction>)> {
+        let upcoming: Vec<_> = self
+            .entries
+            .iter()
+            .filter_map(|(schedule, action)| schedule.after(&after).next().map(|at| (at, *action)))
+            .collect();
+        let at = upcoming.iter().map(|(at, _)| *at).min()?;
+        let actions = upcoming
+            .into_iter()
+            .filter(|(time, _)| *time == at)
+            .map(|(_, action)| action)
+            .collect();
+        Some((at, actions))
+    }
+
+    /// Returns `true` if wake-on-join is refused at `now`.
+    ///
+    /// The server is outside its window when the most recent scheduled stop
+    /// is more recent than the most recent scheduled start.
+    pub(crate) fn blocks_wake(&self, now: DateTime<Local>) -> bool {
+        if !self.config.block_outside_window {
+            return false;
+        }
+        let last = |wanted: ScheduleAction| {
+            self.entries
+                .iter()
+                .filter(|(_, action)| *action == wanted)
+                .filter_map(|(schedule, _)| schedule.after(&now).next_back())
+                .max()
+        };
+        match (last(ScheduleAction::Start), last(ScheduleAction::Stop)) {
+            (Some(start), Some(stop)) => stop > start,
+            (None, Some(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the closed message if wake-on-join is refused at `now`.
+    pub(crate) fn closed_message(&self, now: DateTime<Local>) -> Option<String> {
+        self.blocks_wake(now)
+            .then(|| self.config.closed_message.clone())
+    }
+}
+
+/// Formats a countdown for warning messages, e.g. `5 minutes` or `30 seconds`.
+fn format_countdown(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (value, unit) = if secs >= 60 && secs.is_multiple_of(60) {
+        (secs / 60, "minute")
+    } else {
+        (secs, "second")
+    };
+    if value == 1 {
+        format!("{value} {unit}")
+    } else {
+        format!("{value} {unit}s")
+    }
+}
+
+fn until(at: DateTime<Local>) -> Duration {
+    (at - Local::now()).to_std().unwrap_or_default()
+}
+
+/// Runs the schedule of a server until shutdown.
+pub async fn run_schedule(
+    service: Arc<ServerManagerService>,
+    server_id: String,
+    player_counter: Arc<dyn PlayerCounter>,
+    shutdown: CancellationToken,
+) {
+    let Some(schedule) = service.schedule(&server_id) else {
+        return;
+    };
+    if schedule.is_empty() {
+        return;
+    }
+    tracing::info!(server = %server_id, "schedule task started");
+
+    let mut cursor = Local::now();
+    while let Some((at, actions)) = schedule.next(cursor) {
+        cursor = at;
+        tracing::debug!(server = %server_id, at = %at, ?actions, "next scheduled action");
+
+        // Countdown only for actions that take the server down
+        if let Some(action) = actions.iter().find(|a| **a != ScheduleAction::Start) {
+            let mut warnings = schedule.config.warnings.clone();
+            warnings.sort_unstable_by(|a, b| b.cmp(a));
+            for warning in warnings {
+                let Ok(warning_delta) = chrono::Duration::from_std(warning) else {
+                    continue;
+                };
+                let warn_at = at - warning_delta;
+                if warn_at <= Local::now() {
+                    continue;
+                }
+                tokio::select! {
+                    () = shutdown.cancelled() => return,
+                    () = tokio::time::sleep(until(warn_at)) => {}
+                }
+                if service.get_state(&server_id) == Some(ServerState::Online) {
+                    let message = schedule
+                        .config
+                        .warning_message
+                        .replace("{action}", &action.to_string())
+                        .replace("{time}", &format_countdown(warning));
+                    player_counter.broadcast(&server_id, &message);
+                }
+            }
+        }
+
+        tokio::select! {
+            () = shutdown.cancelled() => return,
+            () = tokio::time::sleep(until(at)) => {}
+        }
+
+        for action in actions {
+            run_action(&service, &server_id, action).await;
+        }
+    }
+}
+
+async fn run_action(service: &ServerManagerService, server_id: &str, action: ScheduleAction) {
+    let Some(state) = service.get_state(server_id) else {
+        return;
+    };
+    tracing::info!(server = %server_id, %action, %state, "running scheduled action");
+
+    let result = match action {
+        ScheduleAction::Start if state.is_startable() => service.start_server(server_id).await,
+        ScheduleAction::Stop if matches!(state, ServerState::Online | ServerState::Starting) => {
+            service.stop_server(server_id).await
+        }
+        ScheduleAction::Restart if state == ServerState::Online => {
+            service.restart_server(server_id).await
+        }
+        _ => Ok(()),
+    };
+
+    if let Err(e) = result {
+        tracing::warn!(server = %server_id, %action, "scheduled action failed: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use chrono::TimeZone;
+    use infrarust_config::ScheduleEntry;
+
+    fn weekend_window(block: bool) -> ServerSchedule {
+        ServerSchedule::new(
+            "events",
+            ScheduleConfig {
+                entries: vec![
+                    ScheduleEntry {
+                        cron: "0 18 * * Sat,Sun".to_string(),
+                        action: ScheduleAction::Start,
+                    },
+                    ScheduleEntry {
+                        cron: "0 23 * * Sat,Sun".to_string(),
+                        action: ScheduleAction::Stop,
+                    },
+                ],
+                block_outside_window: block,
+                ..ScheduleConfig::default()
+            },
+        )
+    }
+
+    fn local(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_window_follows_last_start_and_stop() {
+        let schedule = weekend_window(true);
+        // 2026-10-17 is a Saturday
+        assert!(!schedule.blocks_wake(local(2026, 10, 17, 20, 0)));
+        assert!(schedule.blocks_wake(local(2026, 10, 17, 23, 30)));
+        assert!(schedule.blocks_wake(local(2026, 10, 19, 12, 0)));
+        assert!(!weekend_window(false).blocks_wake(local(2026, 10, 19, 12, 0)));
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        let schedule = weekend_window(true);
+        let (at, actions) = schedule.next(local(2026, 10, 17, 20, 0)).unwrap();
+        assert_eq!(at, local(2026, 10, 17, 23, 0));
+        assert_eq!(actions, vec![ScheduleAction::Stop]);
+
+        let (at, actions) = schedule.next(at).unwrap();
+        assert_eq!(at, local(2026, 10, 18, 18, 0));
+        assert_eq!(actions, vec![ScheduleAction::Start]);
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::from_secs(300)), "5 minutes");
+        assert_eq!(format_countdown(Duration::from_secs(60)), "1 minute");
+        assert_eq!(format_countdown(Duration::from_secs(90)), "90 seconds");
+        assert_eq!(format_countdown(Duration::from_secs(1)), "1 second");
+    }
+}
@@ -1,20 +1,28 @@
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
 use dashmap::DashMap;
-use tokio::sync::oneshot;
+use tokio::sync::{Notify, broadcast, oneshot};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
-use infrarust_config::ServerManagerConfig;
+use infrarust_config::{RestartConfig, ScheduleConfig, ServerManagerConfig, WakeConfig};
 
+use crate::console::ConsoleLine;
 use crate::crafty::CraftyProvider;
 use crate::error::ServerManagerError;
 use crate::local::LocalProvider;
+use crate::persist::{PersistedServer, PersistedState};
 use crate::provider::{ProviderStatus, ServerProvider};
 use crate::pterodactyl::PterodactylProvider;
+use crate::restart::{RestartDecision, RestartTracker};
+use crate::schedule::ServerSchedule;
 use crate::state::ServerState;
+use crate::wake::JoinHistory;
 
 /// Trait for counting players on a server.
 ///
@@ -23,11 +31,46 @@ use crate::state::ServerState;
 pub trait PlayerCounter: Send + Sync {
     /// Returns the number of players connected to the given server.
     fn count_by_server(&self, server_id: &str) -> usize;
+
+    /// Sends a chat message (legacy `&` color codes) to every player on the given server.
+    fn broadcast(&self, _server_id: &str, _message: &str) {}
+
+    /// Moves every player on `server_id` to `target` before a graceful restart.
+    ///
+    /// Resolves once the players have left the server and returns the IDs
+    /// of those that were moved. By default nobody is moved.
+    fn evacuate<'a>(
+        &'a self,
+        _server_id: &'a str,
+        _target: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Vec<u64>> + Send + 'a>> {
+        Box::pin(async { Vec::new() })
+    }
+
+    /// Sends players moved by [`evacuate`](Self::evacuate) back to `server_id`,
+    /// unless they left `target` in the meantime.
+    fn return_players<'a>(
+        &'a self,
+        _server_id: &'a str,
+        _target: &'a str,
+        _players: Vec<u64>,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async {})
+    }
 }
 
+/// How often the state file is rewritten, on top of every state change.
+const PERSIST_INTERVAL: Duration = Duration::from_secs(30);
+
 /// Callback type for server state change notifications.
 pub type StateChangeCallback = Arc<dyn Fn(&str, ServerState, ServerState) + Send + Sync>;
 
+/// Callback fired when a server is caught in a crash loop.
+///
+/// Receives `(server_id, restarts, window)`: the number of restarts
+/// performed within the configured window before giving up.
+pub type CrashLoopCallback = Arc<dyn Fn(&str, u32, Duration) + Send + Sync>;
+
 /// Central orchestrator for server management.
 ///
 /// Tracks the state of each managed server, provides wake-up with waiters,
@@ -35,7 +78,14 @@ pub type StateChangeCallback = Arc<dyn Fn(&str, ServerState, ServerState) + Send
 pub struct ServerManagerService {
     pub(crate) entries: DashMap<String, ServerEntry>,
     listeners: std::sync::RwLock<Vec<(u64, StateChangeCallback)>>,
+    crash_loop_listeners: std::sync::RwLock<Vec<(u64, CrashLoopCallback)>>,
     next_listener_id: AtomicU64,
+    /// Player hooks, set once monitoring starts.
+    players: std::sync::RwLock<Option<Arc<dyn PlayerCounter>>>,
+    /// File the managed state is persisted to (None = in memory only).
+    state_file: Option<PathBuf>,
+    /// Wakes the persistence task on state changes.
+    persist_notify: Notify,
 }
 
 pub(crate) struct ServerEntry {
@@ -53,6 +103,16 @@ pub(crate) struct ServerEntry {
     pub(crate) last_player_seen: Option<Instant>,
     /// Waiters: connections waiting for the server to become Online.
     pub(crate) waiters: Vec<oneshot::Sender<Result<(), ServerManagerError>>>,
+    /// Crash auto-restart state.
+    pub(crate) restart: RestartTracker,
+    /// Scheduled starts, stops and restarts.
+    pub(crate) schedule: Arc<ServerSchedule>,
+    /// Where players go during a graceful restart (None = disconnected).
+    pub(crate) evacuate_to: Option<String>,
+    /// Wake on status ping and pre-warming settings.
+    pub(crate) wake: WakeConfig,
+    /// Hours during which players joined, for pre-warming.
+    pub(crate) joins: JoinHistory,
 }
 
 impl ServerManagerService {
@@ -92,6 +152,41 @@ impl ServerManagerService {
                     cfg.start_timeout,
                     cfg.poll_interval,
                 ),
+                #[cfg(feature = "docker")]
+                ServerManagerConfig::Docker(cfg) => match crate::docker::DockerProvider::new(cfg) {
+                    Ok(provider) => (
+                        Arc::new(provider),
+                        cfg.shutdown_after,
+                        cfg.start_timeout,
+                        cfg.poll_interval,
+                    ),
+                    Err(e) => {
+                        tracing::warn!(server = %server_id, error = %e, "skipping Docker managed server");
+                        continue;
+                    }
+                },
+                ServerManagerConfig::Http(cfg) => {
+                    match crate::http::HttpProvider::new(server_id, cfg, http_client.clone()) {
+                        Ok(provider) => (
+                            Arc::new(provider),
+                            cfg.shutdown_after,
+                            cfg.start_timeout,
+                            cfg.poll_interval,
+                        ),
+                        Err(e) => {
+                            tracing::warn!(server = %server_id, error = %e, "skipping HTTP managed server");
+                            continue;
+                        }
+                    }
+                }
+                #[cfg(not(feature = "docker"))]
+                ServerManagerConfig::Docker(_) => {
+                    tracing::warn!(
+                        server = %server_id,
+                        "docker server manager configured but docker feature is not enabled, ignoring"
+                    );
+                    continue;
+                }
             };
 
             entries.insert(
@@ -104,6 +199,11 @@ impl ServerManagerService {
                     poll_interval,
                     last_player_seen: None,
                     waiters: Vec::new(),
+                    restart: RestartTracker::new(config.restart().clone()),
+                    schedule: Arc::new(ServerSchedule::new(server_id, config.schedule().clone())),
+                    evacuate_to: config.evacuate_to().map(str::to_string),
+                    wake: config.wake().clone(),
+                    joins: JoinHistory::default(),
                 },
             );
 
@@ -113,10 +213,24 @@ impl ServerManagerService {
         Self {
             entries,
             listeners: std::sync::RwLock::new(Vec::new()),
+            crash_loop_listeners: std::sync::RwLock::new(Vec::new()),
             next_listener_id: AtomicU64::new(1),
+            players: std::sync::RwLock::new(None),
+            state_file: None,
+            persist_notify: Notify::new(),
         }
     }
 
+    /// Persists managed state (process IDs, idle timers, join history) to `path`.
+    ///
+    /// The state is restored by [`initial_health_check`](Self::initial_health_check),
+    /// which re-adopts local processes left running by a previous proxy instance.
+    #[must_use]
+    pub fn with_state_file(mut self, path: PathBuf) -> Self {
+        self.state_file = Some(path);
+        self
+    }
+
     /// Registers a server with a custom provider.
     pub fn register_server(
         &self,
@@ -136,10 +250,162 @@ impl ServerManagerService {
                 poll_interval,
                 last_player_seen: None,
                 waiters: Vec::new(),
+                restart: RestartTracker::new(RestartConfig::default()),
+                schedule: Arc::default(),
+                evacuate_to: None,
+                wake: WakeConfig::default(),
+                joins: JoinHistory::default(),
             },
         );
     }
 
+    /// Sets where players go during a graceful restart of a registered server.
+    pub fn set_evacuate_to(&self, server_id: &str, target: Option<String>) {
+        if let Some(mut entry) = self.entries.get_mut(server_id) {
+            entry.evacuate_to = target;
+        }
+    }
+
+    /// Sets the wake on ping and pre-warming settings of a registered server.
+    ///
+    /// Pre-warming takes effect for monitoring tasks started afterwards.
+    pub fn set_wake_config(&self, server_id: &str, config: WakeConfig) {
+        if let Some(mut entry) = self.entries.get_mut(server_id) {
+            entry.wake = config;
+        }
+    }
+
+    /// Sets the start/stop schedule of a registered server.
+    ///
+    /// Takes effect for monitoring tasks started afterwards.
+    pub fn set_schedule(&self, server_id: &str, config: ScheduleConfig) {
+        if let Some(mut entry) = self.entries.get_mut(server_id) {
+            entry.schedule = Arc::new(ServerSchedule::new(server_id, config));
+        }
+    }
+
+    /// Sets the crash restart policy of a registered server.
+    ///
+    /// Servers registered with [`register_server`](Self::register_server) never restart by default.
+    pub fn set_restart_config(&self, server_id: &str, config: RestartConfig) {
+        if let Some(mut entry) = self.entries.get_mut(server_id) {
+            entry.restart = RestartTracker::new(config);
+        }
+    }
+
+    /// Returns `true` if auto-restart gave up on the server after a crash loop.
+    ///
+    /// Cleared when the server is started or stopped manually.
+    pub fn is_crash_looping(&self, server_id: &str) -> bool {
+        self.entries
+            .get(server_id)
+            .is_some_and(|e| e.restart.crash_loop)
+    }
+
+    /// Returns the kick message if the schedule refuses wake-on-join right now.
+    ///
+    /// Manual starts ([`start_server`](Self::start_server)) ignore the schedule.
+    pub fn closed_message(&self, server_id: &str) -> Option<String> {
+        self.entries
+            .get(server_id)?
+            .schedule
+            .closed_message(chrono::Local::now())
+    }
+
+    /// Records a player joining the server, feeding the pre-warming history.
+    pub fn record_join(&self, server_id: &str) {
+        if let Some(mut entry) = self.entries.get_mut(server_id)
+            && entry.wake.prewarm
+        {
+            let keep = entry.wake.prewarm_history;
+            entry.joins.record(chrono::Local::now(), keep);
+        }
+    }
+
+    /// Starts a sleeping server in the background when a client pings it.
+    ///
+    /// Returns `true` if the server is now starting. Does nothing unless
+    /// `wake.on_ping` is enabled, and respects the schedule like a join would.
+    pub fn wake_on_ping(self: &Arc<Self>, server_id: &str) -> bool {
+        if !self.entries.get(server_id).is_some_and(|e| e.wake.on_ping) {
+            return false;
+        }
+        let Some(provider) = self.begin_wake(server_id) else {
+            return false;
+        };
+
+        tracing::info!(server = %server_id, "waking server on status ping");
+        let service = Arc::clone(self);
+        let server_id = server_id.to_string();
+        tokio::spawn(async move {
+            service.finish_wake(&server_id, provider).await;
+        });
+        true
+    }
+
+    /// Returns `true` if pre-warming is enabled and `hour` is a usual peak hour.
+    pub(crate) fn is_peak(&self, server_id: &str, hour: chrono::DateTime<chrono::Local>) -> bool {
+        self.entries.get(server_id).is_some_and(|entry| {
+            entry.wake.prewarm
+                && entry.joins.is_peak(
+                    hour,
+                    entry.wake.prewarm_history,
+                    entry.wake.prewarm_min_days,
+                )
+        })
+    }
+
+    /// Starts a sleeping server without waiting for it, unless its schedule refuses it.
+    pub(crate) async fn wake(&self, server_id: &str) {
+        if let Some(provider) = self.begin_wake(server_id) {
+            self.finish_wake(server_id, provider).await;
+        }
+    }
+
+    /// Marks a sleeping server `Starting` and returns its provider.
+    fn begin_wake(&self, server_id: &str) -> Option<Arc<dyn ServerProvider>> {
+        let provider = {
+            let mut entry = self.entries.get_mut(server_id)?;
+            if entry.state != ServerState::Sleeping
+                || entry.schedule.blocks_wake(chrono::Local::now())
+            {
+                return None;
+            }
+            entry.state = ServerState::Starting;
+            Arc::clone(&entry.provider)
+        };
+
+        self.fire_state_change(server_id, ServerState::Sleeping, ServerState::Starting);
+        Some(provider)
+    }
+
+    async fn finish_wake(&self, server_id: &str, provider: Arc<dyn ServerProvider>) {
+        if let Err(e) = provider.start().await {
+            tracing::warn!(server = %server_id, "wake failed: {e}");
+            self.fail_start(server_id);
+        }
+    }
+
+    /// Marks a server whose provider failed to start as `Crashed` and fails its waiters.
+    fn fail_start(&self, server_id: &str) {
+        if let Some(mut entry) = self.entries.get_mut(server_id) {
+            entry.state = ServerState::Crashed;
+            let waiters = std::mem::take(&mut entry.waiters);
+            drop(entry);
+            self.fire_state_change(server_id, ServerState::Starting, ServerState::Crashed);
+            for tx in waiters {
+                let _ = tx.send(Err(ServerManagerError::Provider {
+                    server_id: server_id.to_string(),
+                    message: "start failed".to_string(),
+                }));
+            }
+        }
+    }
+
+    pub(crate) fn schedule(&self, server_id: &str) -> Option<Arc<ServerSchedule>> {
+        self.entries.get(server_id).map(|e| Arc::clone(&e.schedule))
+    }
+
     /// Returns the current state of a server.
     pub fn get_state(&self, server_id: &str) -> Option<ServerState> {
         self.entries.get(server_id).map(|e| e.state)
@@ -169,7 +435,39 @@ impl ServerManagerService {
         listeners.retain(|(id, _)| *id != listener_id);
     }
 
+    /// Registers a callback that fires when a server is caught in a crash loop.
+    pub fn add_on_crash_loop(&self, callback: CrashLoopCallback) -> u64 {
+        let id = self.next_listener_id.fetch_add(1, Ordering::Relaxed);
+        let mut listeners = self.crash_loop_listeners.write().expect("lock poisoned");
+        listeners.push((id, callback));
+        id
+    }
+
+    /// Removes a previously registered crash loop listener.
+    pub fn remove_on_crash_loop(&self, listener_id: u64) {
+        let mut listeners = self.crash_loop_listeners.write().expect("lock poisoned");
+        listeners.retain(|(id, _)| *id != listener_id);
+    }
+
+    fn fire_crash_loop(&self, server_id: &str, restarts: u32, window: Duration) {
+        let snapshot = {
+            let listeners = self.crash_loop_listeners.read().expect("lock poisoned");
+            listeners.clone()
+        };
+        for (_, callback) in &snapshot {
+            if let Err(e) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                callback(server_id, restarts, window);
+            })) {
+                tracing::error!(
+                    server = %server_id,
+                    "crash loop callback panicked: {e:?}"
+                );
+            }
+        }
+    }
+
     fn fire_state_change(&self, server_id: &str, old: ServerState, new: ServerState) {
+        self.persist_notify.notify_one();
         let snapshot = {
             let listeners = self.listeners.read().expect("lock poisoned");
             listeners.clone()
@@ -188,9 +486,11 @@ impl ServerManagerService {
 
     /// Performs an initial health check for all managed servers.
     ///
-    /// Calls `provider.check_status()` on each server to determine its initial state.
+    /// Restores the persisted state first, if any, then calls
+    /// `provider.check_status()` on each server to determine its initial state.
     /// Called once at proxy startup.
     pub async fn initial_health_check(&self) {
+        let mut persisted = self.restore_state().await;
         let server_ids: Vec<String> = self.entries.iter().map(|e| e.key().clone()).collect();
 
         for server_id in server_ids {
@@ -222,16 +522,78 @@ impl ServerManagerService {
                     );
                 }
                 Err(e) => {
+                    let last_known = persisted.servers.remove(&server_id).and_then(|s| s.state);
                     tracing::warn!(
                         server = %server_id,
                         error = %e,
-                        "initial health check failed, defaulting to Sleeping"
+                        last_known = ?last_known,
+                        "initial health check failed, using last known state"
                     );
+                    if let Some(state) = last_known
+                        && let Some(mut entry) = self.entries.get_mut(&server_id)
+                    {
+                        entry.state = state;
+                    }
                 }
             }
         }
     }
 
+    /// Loads the state file and applies it: idle timers, join history and
+    /// local processes to re-adopt.
+    async fn restore_state(&self) -> PersistedState {
+        let Some(path) = &self.state_file else {
+            return PersistedState::default();
+        };
+        let persisted = PersistedState::load(path).await;
+
+        for (server_id, saved) in &persisted.servers {
+            let provider = {
+                let Some(mut entry) = self.entries.get_mut(server_id) else {
+                    continue;
+                };
+                entry.last_player_seen = saved.last_player_seen.map(crate::persist::from_unix);
+                entry.joins = JoinHistory::from_slots(saved.joins.iter().copied());
+                Arc::clone(&entry.provider)
+            };
+
+            if let Some(pid) = saved.pid
+                && !provider.adopt_process(pid).await
+            {
+                tracing::info!(server = %server_id, pid, "previous server process is gone");
+            }
+        }
+
+        tracing::debug!(path = %path.display(), "server manager state restored");
+        persisted
+    }
+
+    /// Writes the managed state to the state file, if one is configured.
+    pub async fn save_state(&self) {
+        let Some(path) = &self.state_file else {
+            return;
+        };
+        let state = PersistedState {
+            servers: self
+                .entries
+                .iter()
+                .map(|entry| {
+                    let saved = PersistedServer {
+                        state: Some(entry.state),
+                        pid: entry.provider.process_id(),
+                        last_player_seen: entry.last_player_seen.map(crate::persist::to_unix),
+                        joins: entry.joins.slots(),
+                    };
+                    (entry.key().clone(), saved)
+                })
+                .collect(),
+        };
+
+        if let Err(e) = state.save(path).await {
+            tracing::warn!(path = %path.display(), "failed to persist server manager state: {e}");
+        }
+    }
+
     /// Ensures the server is started and ready for connections.
     ///
     /// - `Online` → returns Ok immediately
@@ -239,10 +601,14 @@ impl ServerManagerService {
     /// - `Starting` → waits for Online (joins existing waiters)
     /// - `Stopping` → returns an error
     ///
+    /// A server that needs starting is refused outside its schedule window
+    /// when `block_outside_window` is set.
+    ///
     /// # Errors
     ///
     /// Returns [`ServerManagerError::ServerNotFound`] if the server ID is unknown,
     /// [`ServerManagerError::InvalidState`] if the server is stopping,
+    /// [`ServerManagerError::OutsideSchedule`] if the server is closed by its schedule,
     /// [`ServerManagerError::StartTimeout`] if the server doesn't start in time,
     /// or [`ServerManagerError::Provider`] if the provider fails.
     pub async fn ensure_started(&self, server_id: &str) -> Result<(), ServerManagerError> {
@@ -253,6 +619,18 @@ impl ServerManagerService {
                 }
             })?;
 
+            if !matches!(entry.state, ServerState::Online | ServerState::Starting)
+                && let Some(message) = entry.schedule.closed_message(chrono::Local::now())
+            {
+                return Err(ServerManagerError::OutsideSchedule {
+                    server_id: server_id.to_string(),
+                    message,
+                });
+            }
+
+            // A connection starts the server now, no need for the scheduled restart
+            entry.restart.pending = false;
+
             match entry.state {
                 ServerState::Online => return Ok(()),
                 ServerState::Stopping => {
@@ -279,24 +657,8 @@ impl ServerManagerService {
                     // Call start on the provider (lock-free)
                     if let Err(e) = provider.start().await {
                         tracing::error!(server = %server_id, "provider start failed: {e}");
-                        // Reset state
-                        if let Some(mut entry) = self.entries.get_mut(server_id) {
-                            entry.state = ServerState::Crashed;
-                            // Notify waiters of failure
-                            let waiters = std::mem::take(&mut entry.waiters);
-                            drop(entry);
-                            self.fire_state_change(
-                                server_id,
-                                ServerState::Starting,
-                                ServerState::Crashed,
-                            );
-                            for tx in waiters {
-                                let _ = tx.send(Err(ServerManagerError::Provider {
-                                    server_id: server_id.to_string(),
-                                    message: "start failed".to_string(),
-                                }));
-                            }
-                        }
+                        // Reset state and notify waiters of failure
+                        self.fail_start(server_id);
                         return Err(e);
                     }
 
@@ -322,22 +684,7 @@ impl ServerManagerService {
                     self.fire_state_change(server_id, old_state, ServerState::Starting);
                     if let Err(e) = provider.start().await {
                         tracing::error!(server = %server_id, "provider start failed: {e}");
-                        if let Some(mut entry) = self.entries.get_mut(server_id) {
-                            entry.state = ServerState::Crashed;
-                            let waiters = std::mem::take(&mut entry.waiters);
-                            drop(entry);
-                            self.fire_state_change(
-                                server_id,
-                                ServerState::Starting,
-                                ServerState::Crashed,
-                            );
-                            for tx in waiters {
-                                let _ = tx.send(Err(ServerManagerError::Provider {
-                                    server_id: server_id.to_string(),
-                                    message: "start failed".to_string(),
-                                }));
-                            }
-                        }
+                        self.fail_start(server_id);
                         return Err(e);
                     }
                     (rx, start_timeout)
@@ -345,7 +692,15 @@ impl ServerManagerService {
             }
         };
 
-        // Wait for the server to become Online (or timeout)
+        Self::wait_online(server_id, rx, start_timeout).await
+    }
+
+    /// Waits for a waiter registered on the server to be notified.
+    async fn wait_online(
+        server_id: &str,
+        rx: oneshot::Receiver<Result<(), ServerManagerError>>,
+        start_timeout: Duration,
+    ) -> Result<(), ServerManagerError> {
         match tokio::time::timeout(start_timeout, rx).await {
             Ok(Ok(result)) => result,
             Ok(Err(_)) => {
@@ -370,6 +725,13 @@ impl ServerManagerService {
     /// [`ServerManagerError::InvalidState`] if the server is not in a startable state,
     /// or a provider-specific error if the start command fails.
     pub async fn start_server(&self, server_id: &str) -> Result<(), ServerManagerError> {
+        if let Some(mut entry) = self.entries.get_mut(server_id) {
+            entry.restart.reset();
+        }
+        self.start_provider(server_id).await
+    }
+
+    async fn start_provider(&self, server_id: &str) -> Result<(), ServerManagerError> {
         let (provider, old_state) = {
             let mut entry = self.entries.get_mut(server_id).ok_or_else(|| {
                 ServerManagerError::ServerNotFound {
@@ -410,11 +772,21 @@ impl ServerManagerService {
 
             let old = entry.state;
             entry.state = ServerState::Stopping;
+            entry.restart.reset();
             (Arc::clone(&entry.provider), old)
         };
 
         self.fire_state_change(server_id, old_state, ServerState::Stopping);
+        self.stop_provider(server_id, provider, old_state).await
+    }
 
+    /// Stops the provider of a server already marked `Stopping`.
+    async fn stop_provider(
+        &self,
+        server_id: &str,
+        provider: Arc<dyn ServerProvider>,
+        old_state: ServerState,
+    ) -> Result<(), ServerManagerError> {
         if let Err(e) = provider.stop().await {
             if let Some(mut entry) = self.entries.get_mut(server_id) {
                 entry.state = old_state;
@@ -433,7 +805,166 @@ impl ServerManagerService {
         Ok(())
     }
 
-    /// Starts monitoring tasks for all managed servers.
+    /// Restarts an online server without dropping its players.
+    ///
+    /// Players are first moved to the server's `evacuate_to` target, then the
+    /// server is stopped, started and awaited until `Online`. Finally the
+    /// players are moved back. Without a target (or before monitoring has
+    /// started) players are disconnected by the stop as usual.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ServerManagerError::ServerNotFound`] if the server ID is unknown,
+    /// [`ServerManagerError::InvalidState`] if the server is not online,
+    /// [`ServerManagerError::StartTimeout`] if the server doesn't come back in time,
+    /// or a provider-specific error if the stop or start command fails.
+    pub async fn restart_server(&self, server_id: &str) -> Result<(), ServerManagerError> {
+        let (provider, target) = {
+            let mut entry = self.entries.get_mut(server_id).ok_or_else(|| {
+                ServerManagerError::ServerNotFound {
+                    server_id: server_id.to_string(),
+                }
+            })?;
+
+            if entry.state != ServerState::Online {
+                return Err(ServerManagerError::InvalidState {
+                    server_id: server_id.to_string(),
+                    state: entry.state,
+                    action: "restart".to_string(),
+                });
+            }
+
+            entry.state = ServerState::Stopping;
+            entry.restart.reset();
+            (Arc::clone(&entry.provider), entry.evacuate_to.clone())
+        };
+
+        self.fire_state_change(server_id, ServerState::Online, ServerState::Stopping);
+        tracing::info!(server = %server_id, evacuate_to = ?target, "graceful restart");
+
+        // Marked Stopping first so limbo handlers hold evacuated players
+        let players = self.players.read().expect("lock poisoned").clone();
+        let evacuation = match (players, target) {
+            (Some(players), Some(target)) => {
+                let moved = players.evacuate(server_id, &target).await;
+                tracing::info!(server = %server_id, count = moved.len(), "players evacuated");
+                Some((players, target, moved))
+            }
+            _ => None,
+        };
+
+        let result = match self
+            .stop_provider(server_id, provider, ServerState::Online)
+            .await
+        {
+            Ok(()) => self.start_and_wait(server_id).await,
+            Err(e) => Err(e),
+        };
+
+        if let Some((players, target, moved)) = evacuation
+            && !moved.is_empty()
+        {
+            match &result {
+                Ok(()) => players.return_players(server_id, &target, moved).await,
+                Err(e) => {
+                    tracing::warn!(server = %server_id, "restart failed, players stay on {target}: {e}");
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Starts a server and waits for it to become `Online`, ignoring its schedule.
+    async fn start_and_wait(&self, server_id: &str) -> Result<(), ServerManagerError> {
+        let (rx, start_timeout) = {
+            let mut entry = self.entries.get_mut(server_id).ok_or_else(|| {
+                ServerManagerError::ServerNotFound {
+                    server_id: server_id.to_string(),
+                }
+            })?;
+            let (tx, rx) = oneshot::channel();
+            entry.waiters.push(tx);
+            (rx, entry.start_timeout)
+        };
+
+        if let Err(e) = self.start_provider(server_id).await {
+            // Dropping the receiver closes our waiter, so it can be pruned.
+            drop(rx);
+            if let Some(mut entry) = self.entries.get_mut(server_id) {
+                entry.waiters.retain(|tx| !tx.is_closed());
+            }
+            return Err(e);
+        }
+        Self::wait_online(server_id, rx, start_timeout).await
+    }
+
+    /// Sends a command to the server console (e.g. `whitelist add Steve`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ServerManagerError::ServerNotFound`] if the server ID is unknown,
+    /// [`ServerManagerError::Unsupported`] if the provider has no console access,
+    /// or [`ServerManagerError::InvalidState`] if the server is not running.
+    pub async fn send_command(
+        &self,
+        server_id: &str,
+        command: &str,
+    ) -> Result<(), ServerManagerError> {
+        let provider = self.provider(server_id)?;
+
+        if command.contains(['\n', '\r']) {
+            return Err(ServerManagerError::Provider {
+                server_id: server_id.to_string(),
+                message: "console command must be a single line".to_string(),
+            });
+        }
+
+        tracing::info!(server = %server_id, command = %command, "sending console command");
+        provider.send_command(command).await.map_err(|e| match e {
+            // The provider only knows there is no process; report the state we track.
+            ServerManagerError::InvalidState { action, .. } => ServerManagerError::InvalidState {
+                server_id: server_id.to_string(),
+                state: self.get_state(server_id).unwrap_or(ServerState::Unknown),
+                action,
+            },
+            e => e,
+        })
+    }
+
+    /// Subscribes to the console output of a server.
+    ///
+    /// Returns the buffered backlog and a receiver for every line after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ServerManagerError::ServerNotFound`] if the server ID is unknown,
+    /// or [`ServerManagerError::Unsupported`] if the provider cannot read the console.
+    pub fn subscribe_console(
+        &self,
+        server_id: &str,
+    ) -> Result<(Vec<ConsoleLine>, broadcast::Receiver<ConsoleLine>), ServerManagerError> {
+        let provider = self.provider(server_id)?;
+        provider
+            .console_output()
+            .map(crate::console::ConsoleOutput::subscribe)
+            .ok_or(ServerManagerError::Unsupported {
+                provider: provider.provider_type(),
+                action: "console output",
+            })
+    }
+
+    fn provider(&self, server_id: &str) -> Result<Arc<dyn ServerProvider>, ServerManagerError> {
+        self.entries
+            .get(server_id)
+            .map(|entry| Arc::clone(&entry.provider))
+            .ok_or_else(|| ServerManagerError::ServerNotFound {
+                server_id: server_id.to_string(),
+            })
+    }
+
+    /// Starts monitoring, schedule and pre-warming tasks for all managed servers,
+    /// and the persistence task when a state file is configured.
     ///
     /// Returns join handles for the spawned tasks.
     #[allow(clippy::needless_pass_by_value)]
@@ -442,14 +973,44 @@ impl ServerManagerService {
         player_counter: Arc<dyn PlayerCounter>,
         shutdown: CancellationToken,
     ) -> Vec<JoinHandle<()>> {
+        *self.players.write().expect("lock poisoned") = Some(Arc::clone(&player_counter));
+
         let server_ids: Vec<String> = self.entries.iter().map(|e| e.key().clone()).collect();
         let mut handles = Vec::with_capacity(server_ids.len());
 
+        if self.state_file.is_some() {
+            handles.push(tokio::spawn(Self::run_persistence(
+                Arc::clone(self),
+                shutdown.clone(),
+            )));
+        }
+
         for server_id in server_ids {
             let service = Arc::clone(self);
             let counter = Arc::clone(&player_counter);
             let token = shutdown.clone();
 
+            if self
+                .schedule(&server_id)
+                .is_some_and(|schedule| !schedule.is_empty())
+            {
+                handles.push(tokio::spawn(crate::schedule::run_schedule(
+                    Arc::clone(&service),
+                    server_id.clone(),
+                    Arc::clone(&counter),
+                    token.clone(),
+                )));
+            }
+
+            if let Some(lead) = self.prewarm_lead(&server_id) {
+                handles.push(tokio::spawn(crate::wake::run_prewarm(
+                    Arc::clone(&service),
+                    server_id.clone(),
+                    lead,
+                    token.clone(),
+                )));
+            }
+
             let handle = tokio::spawn(async move {
                 crate::monitor::monitor_server(service, server_id, counter, token).await;
             });
@@ -460,6 +1021,29 @@ impl ServerManagerService {
         handles
     }
 
+    /// Saves the state on every state change and periodically, until shutdown.
+    async fn run_persistence(service: Arc<Self>, shutdown: CancellationToken) {
+        loop {
+            tokio::select! {
+                biased;
+                () = shutdown.cancelled() => {
+                    service.save_state().await;
+                    break;
+                }
+                () = service.persist_notify.notified() => {}
+                () = tokio::time::sleep(PERSIST_INTERVAL) => {}
+            }
+            service.save_state().await;
+        }
+    }
+
+    fn prewarm_lead(&self, server_id: &str) -> Option<Duration> {
+        self.entries
+            .get(server_id)
+            .filter(|e| e.wake.prewarm)
+            .map(|e| e.wake.prewarm_lead)
+    }
+
     /// Checks provider status for a server (used by monitoring task).
     pub(crate) async fn check_provider_status(
         &self,
@@ -511,6 +1095,56 @@ impl ServerManagerService {
         Some((old_state, new_state))
     }
 
+    /// Applies the restart policy after a server exited without being asked to.
+    ///
+    /// Returns the backoff before the restart, or `None` if the server stays down.
+    pub(crate) fn on_unexpected_exit(&self, server_id: &str, failed: bool) -> Option<Duration> {
+        let (decision, window) = {
+            let mut entry = self.entries.get_mut(server_id)?;
+            let decision = entry.restart.on_unexpected_exit(failed, Instant::now());
+            (decision, entry.restart.config.window)
+        };
+
+        match decision {
+            RestartDecision::Skip => None,
+            RestartDecision::Restart(backoff) => {
+                tracing::warn!(
+                    server = %server_id,
+                    backoff = ?backoff,
+                    "server exited unexpectedly, restarting"
+                );
+                Some(backoff)
+            }
+            RestartDecision::CrashLoop { restarts } => {
+                tracing::error!(
+                    server = %server_id,
+                    restarts,
+                    window = ?window,
+                    "crash loop detected, auto-restart disabled until the server is started manually"
+                );
+                self.fire_crash_loop(server_id, restarts, window);
+                None
+            }
+        }
+    }
+
+    /// Performs a restart scheduled by [`on_unexpected_exit`](Self::on_unexpected_exit),
+    /// unless it was cancelled in the meantime.
+    pub(crate) async fn restart_after_crash(&self, server_id: &str) {
+        {
+            let Some(mut entry) = self.entries.get_mut(server_id) else {
+                return;
+            };
+            if !std::mem::take(&mut entry.restart.pending) || !entry.state.is_startable() {
+                return;
+            }
+        }
+
+        if let Err(e) = self.start_provider(server_id).await {
+            tracing::warn!(server = %server_id, "auto-restart failed: {e}");
+        }
+    }
+
     /// Notifies all waiters for a server with the given result.
     pub(crate) fn notify_waiters(&self, server_id: &str, result: &Result<(), ServerManagerError>) {
         if let Some(mut entry) = self.entries.get_mut(server_id) {
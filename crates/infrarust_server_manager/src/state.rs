@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
+
 /// State of a backend server managed by a `ServerProvider`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum ServerState {
     /// The server is online and accepting connections.
@@ -0,0 +1,139 @@
+//! Predictive pre-warming from the join history recorded by the proxy.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Local, NaiveDate, TimeDelta, Timelike};
+use tokio_util::sync::CancellationToken;
+
+use crate::service::ServerManagerService;
+
+/// Hours of the day during which players joined, one slot per date and hour.
+#[derive(Debug, Default)]
+pub(crate) struct JoinHistory {
+    slots: BTreeSet<(NaiveDate, u32)>,
+}
+
+impl JoinHistory {
+    pub(crate) fn from_slots(slots: impl IntoIterator<Item = (NaiveDate, u32)>) -> Self {
+        Self {
+            slots: slots.into_iter().collect(),
+        }
+    }
+
+    pub(crate) fn slots(&self) -> Vec<(NaiveDate, u32)> {
+        self.slots.iter().copied().collect()
+    }
+
+    /// Records a join at `at` and forgets slots older than `keep`.
+    pub(crate) fn record(&mut self, at: DateTime<Local>, keep: Duration) {
+        self.slots.insert((at.date_naive(), at.hour()));
+        let cutoff = first_kept_day(at, keep);
+        self.slots = self.slots.split_off(&(cutoff, 0));
+    }
+
+    /// Returns `true` if players joined during the hour starting at `hour`
+    /// on at least `min_days` of the days covered by `keep`.
+    pub(crate) fn is_peak(&self, hour: DateTime<Local>, keep: Duration, min_days: u32) -> bool {
+        let cutoff = first_kept_day(hour, keep);
+        let days = self
+            .slots
+            .range((cutoff, 0)..)
+            .filter(|(date, slot)| *slot == hour.hour() && *date < hour.date_naive())
+            .count();
+        min_days > 0 && days >= min_days as usize
+    }
+}
+
+fn first_kept_day(now: DateTime<Local>, keep: Duration) -> NaiveDate {
+    let keep = TimeDelta::from_std(keep).unwrap_or(TimeDelta::MAX);
+    now.checked_sub_signed(keep)
+        .map_or(NaiveDate::MIN, |at| at.date_naive())
+}
+
+/// Returns when to check the next hour and the start of that hour.
+///
+/// The check happens `lead` before the first full hour that starts after `now + lead`.
+fn next_prewarm(now: DateTime<Local>, lead: Duration) -> (DateTime<Local>, DateTime<Local>) {
+    let lead = TimeDelta::from_std(lead).unwrap_or_default();
+    let ahead = now + lead;
+    let into_hour = TimeDelta::minutes(i64::from(ahead.minute()))
+        + TimeDelta::seconds(i64::from(ahead.second()))
+        + TimeDelta::nanoseconds(i64::from(ahead.nanosecond()));
+    let hour = ahead - into_hour + TimeDelta::hours(1);
+    (hour - lead, hour)
+}
+
+/// Starts the server ahead of its usual peak hours until shutdown.
+pub async fn run_prewarm(
+    service: Arc<ServerManagerService>,
+    server_id: String,
+    lead: Duration,
+    shutdown: CancellationToken,
+) {
+    tracing::info!(server = %server_id, "pre-warm task started");
+
+    loop {
+        let (at, hour) = next_prewarm(Local::now(), lead);
+        let delay = (at - Local::now()).to_std().unwrap_or_default();
+        tokio::select! {
+            () = shutdown.cancelled() => return,
+            () = tokio::time::sleep(delay) => {}
+        }
+
+        if service.is_peak(&server_id, hour) {
+            tracing::info!(server = %server_id, peak = %hour, "pre-warming server ahead of usual peak");
+            service.wake(&server_id).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use chrono::TimeZone;
+
+    const WEEK: Duration = Duration::from_secs(7 * 24 * 3600);
+
+    fn local(d: u32, h: u32, min: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2026, 10, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_peak_needs_joins_on_enough_days() {
+        let mut history = JoinHistory::default();
+        history.record(local(10, 20, 5), WEEK);
+        history.record(local(10, 20, 40), WEEK);
+        history.record(local(12, 20, 15), WEEK);
+        assert!(!history.is_peak(local(14, 20, 0), WEEK, 3));
+
+        history.record(local(13, 20, 59), WEEK);
+        assert!(history.is_peak(local(14, 20, 0), WEEK, 3));
+        assert!(!history.is_peak(local(14, 21, 0), WEEK, 3));
+        // Older days fall out of the history
+        assert!(!history.is_peak(local(19, 20, 0), WEEK, 3));
+    }
+
+    #[test]
+    fn test_record_forgets_old_days() {
+        let mut history = JoinHistory::default();
+        history.record(local(1, 12, 0), WEEK);
+        history.record(local(15, 12, 0), WEEK);
+        assert_eq!(history.slots.len(), 1);
+    }
+
+    #[test]
+    fn test_next_prewarm() {
+        let lead = Duration::from_secs(300);
+        let (at, hour) = next_prewarm(local(14, 19, 30), lead);
+        assert_eq!(hour, local(14, 20, 0));
+        assert_eq!(at, local(14, 19, 55));
+
+        // Already inside the lead of 20:00: the next check is for 21:00
+        let (at, hour) = next_prewarm(local(14, 19, 57), lead);
+        assert_eq!(hour, local(14, 21, 0));
+        assert_eq!(at, local(14, 20, 55));
+    }
+}
@@ -1,7 +1,7 @@
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 use std::net::SocketAddr;
 
-use infrarust_config::CraftyManagerConfig;
+use infrarust_config::{CraftyManagerConfig, RestartConfig, ScheduleConfig, WakeConfig};
 use infrarust_server_manager::{CraftyProvider, ProviderStatus, ServerProvider};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpListener;
@@ -57,6 +57,10 @@ fn make_config(addr: SocketAddr) -> CraftyManagerConfig {
         shutdown_after: None,
         start_timeout: std::time::Duration::from_secs(10),
         poll_interval: std::time::Duration::from_secs(5),
+        restart: RestartConfig::default(),
+        schedule: ScheduleConfig::default(),
+        evacuate_to: None,
+        wake: WakeConfig::default(),
     }
 }
 
@@ -0,0 +1,156 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+#![cfg(feature = "docker")]
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use infrarust_config::{DockerManagerConfig, RestartConfig, ScheduleConfig, WakeConfig};
+use infrarust_server_manager::{DockerProvider, ProviderStatus, ServerProvider};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+
+type RequestLog = Arc<Mutex<Vec<String>>>;
+
+/// Spawns a mock Docker API. `inspect` is returned for `GET .../json`,
+/// every other request gets `204 No Content`. Request lines are recorded.
+async fn spawn_mock_docker(
+    inspect: serde_json::Value,
+) -> (SocketAddr, RequestLog, CancellationToken) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let shutdown = CancellationToken::new();
+    let token = shutdown.clone();
+    let log: RequestLog = Arc::default();
+    let requests = Arc::clone(&log);
+    let inspect = inspect.to_string();
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                result = listener.accept() => {
+                    let (mut stream, _) = result.unwrap();
+                    let mut buf = vec![0u8; 4096];
+                    let n = stream.read(&mut buf).await.unwrap();
+                    let request = String::from_utf8_lossy(&buf[..n]);
+                    let first_line = request.lines().next().unwrap_or("").to_string();
+
+                    let response = if first_line.starts_with("GET") && first_line.contains("/json") {
+                        format!(
+                            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                            inspect.len(),
+                            inspect
+                        )
+                    } else {
+                        "HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n".to_string()
+                    };
+                    requests.lock().unwrap().push(first_line);
+                    let _ = stream.write_all(response.as_bytes()).await;
+                    let _ = stream.flush().await;
+                }
+                () = token.cancelled() => break,
+            }
+        }
+    });
+
+    (addr, log, shutdown)
+}
+
+fn make_config(addr: SocketAddr) -> DockerManagerConfig {
+    DockerManagerConfig {
+        container: "mc-survival".to_string(),
+        endpoint: format!("tcp://{addr}"),
+        pause: false,
+        stop_timeout: Duration::from_secs(20),
+        shutdown_after: None,
+        start_timeout: Duration::from_secs(60),
+        poll_interval: Duration::from_secs(5),
+        restart: RestartConfig::default(),
+        schedule: ScheduleConfig::default(),
+        evacuate_to: None,
+        wake: WakeConfig::default(),
+    }
+}
+
+fn container(status: &str, health: Option<&str>) -> serde_json::Value {
+    let mut state = serde_json::json!({
+        "Status": status,
+        "Running": status == "running",
+        "Paused": status == "paused",
+    });
+    if let Some(health) = health {
+        state["Health"] = serde_json::json!({ "Status": health });
+    }
+    serde_json::json!({ "Id": "abc", "Name": "/mc-survival", "State": state })
+}
+
+fn requests(log: &RequestLog) -> Vec<String> {
+    log.lock().unwrap().clone()
+}
+
+#[tokio::test]
+async fn test_check_status_health() {
+    for (status, health, expected) in [
+        ("running", None, ProviderStatus::Running),
+        ("running", Some("starting"), ProviderStatus::Starting),
+        ("running", Some("healthy"), ProviderStatus::Running),
+        ("exited", None, ProviderStatus::Stopped),
+        ("paused", None, ProviderStatus::Stopped),
+        ("restarting", None, ProviderStatus::Starting),
+    ] {
+        let (addr, _, shutdown) = spawn_mock_docker(container(status, health)).await;
+        let provider = DockerProvider::new(&make_config(addr)).unwrap();
+
+        assert_eq!(
+            provider.check_status().await.unwrap(),
+            expected,
+            "{status} / {health:?}"
+        );
+        shutdown.cancel();
+    }
+}
+
+#[tokio::test]
+async fn test_start_stopped_container() {
+    let (addr, log, shutdown) = spawn_mock_docker(container("exited", None)).await;
+    let provider = DockerProvider::new(&make_config(addr)).unwrap();
+
+    provider.start().await.unwrap();
+
+    let requests = requests(&log);
+    assert!(requests[1].starts_with("POST "));
+    assert!(requests[1].contains("/containers/mc-survival/start"));
+    shutdown.cancel();
+}
+
+#[tokio::test]
+async fn test_start_paused_container_unpauses() {
+    let (addr, log, shutdown) = spawn_mock_docker(container("paused", None)).await;
+    let provider = DockerProvider::new(&make_config(addr)).unwrap();
+
+    provider.start().await.unwrap();
+
+    let requests = requests(&log);
+    assert!(requests[1].contains("/containers/mc-survival/unpause"));
+    shutdown.cancel();
+}
+
+#[tokio::test]
+async fn test_stop_uses_timeout_or_pause() {
+    let (addr, log, shutdown) = spawn_mock_docker(container("running", None)).await;
+    let provider = DockerProvider::new(&make_config(addr)).unwrap();
+    provider.stop().await.unwrap();
+
+    let pausing = DockerProvider::new(&DockerManagerConfig {
+        pause: true,
+        ..make_config(addr)
+    })
+    .unwrap();
+    pausing.stop().await.unwrap();
+
+    let requests = requests(&log);
+    assert!(requests[0].contains("/containers/mc-survival/stop?t=20"));
+    assert!(requests[1].contains("/containers/mc-survival/pause"));
+    shutdown.cancel();
+}
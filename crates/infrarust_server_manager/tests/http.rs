@@ -0,0 +1,197 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use infrarust_config::{
+    HttpManagerConfig, HttpRequestConfig, HttpStatusMapping, RestartConfig, ScheduleConfig,
+    WakeConfig,
+};
+use infrarust_server_manager::{HttpProvider, ProviderStatus, ServerProvider};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+
+/// Reads a full request: headers, then `Content-Length` bytes of body.
+async fn read_request(stream: &mut tokio::net::TcpStream) -> String {
+    let mut data = Vec::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        let n = stream.read(&mut buf).await.unwrap();
+        data.extend_from_slice(&buf[..n]);
+        let text = String::from_utf8_lossy(&data).to_string();
+        if let Some((head, body)) = text.split_once("\r\n\r\n") {
+            let length = head
+                .lines()
+                .find_map(|l| {
+                    l.to_lowercase()
+                        .strip_prefix("content-length:")
+                        .map(|v| v.trim().parse::<usize>().unwrap())
+                })
+                .unwrap_or(0);
+            if body.len() >= length || n == 0 {
+                return text;
+            }
+        } else if n == 0 {
+            return text;
+        }
+    }
+}
+
+/// Spawns a mock HTTP server that returns canned responses based on the
+/// request line and records every request it receives.
+async fn spawn_mock_http(
+    responses: Vec<(&'static str, u16, &'static str)>,
+) -> (SocketAddr, Arc<Mutex<Vec<String>>>, CancellationToken) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let shutdown = CancellationToken::new();
+    let token = shutdown.clone();
+    let recorded = Arc::clone(&requests);
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                result = listener.accept() => {
+                    let (mut stream, _) = result.unwrap();
+                    let request = read_request(&mut stream).await;
+
+                    let first_line = request.lines().next().unwrap_or("").to_string();
+                    recorded.lock().unwrap().push(request);
+
+                    let (status, body) = responses
+                        .iter()
+                        .find(|(line_contains, _, _)| first_line.contains(line_contains))
+                        .map_or((404, "not found"), |(_, status, body)| (*status, *body));
+
+                    let response = format!(
+                        "HTTP/1.1 {} OK\r\nContent-Length: {}\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                    let _ = stream.flush().await;
+                }
+                () = token.cancelled() => break,
+            }
+        }
+    });
+
+    (addr, requests, shutdown)
+}
+
+fn request(method: &str, url: String) -> HttpRequestConfig {
+    HttpRequestConfig {
+        method: method.to_string(),
+        url,
+        headers: BTreeMap::new(),
+        body: None,
+    }
+}
+
+fn make_config(addr: SocketAddr) -> HttpManagerConfig {
+    let mut start = request(
+        "POST",
+        format!("http://{addr}/instances/{{server_id}}/start"),
+    );
+    start
+        .headers
+        .insert("Authorization".to_string(), "Bearer secret".to_string());
+    start.body = Some(r#"{"instance":"{server_id}"}"#.to_string());
+
+    HttpManagerConfig {
+        start,
+        stop: request(
+            "POST",
+            format!("http://{addr}/instances/{{server_id}}/stop"),
+        ),
+        status: request("GET", format!("http://{addr}/instances/{{server_id}}")),
+        status_mapping: HttpStatusMapping {
+            json_path: Some("data.state".to_string()),
+            ..HttpStatusMapping::default()
+        },
+        request_timeout: std::time::Duration::from_secs(5),
+        shutdown_after: None,
+        start_timeout: std::time::Duration::from_secs(10),
+        poll_interval: std::time::Duration::from_secs(5),
+        restart: RestartConfig::default(),
+        schedule: ScheduleConfig::default(),
+        evacuate_to: None,
+        wake: WakeConfig::default(),
+    }
+}
+
+#[tokio::test]
+async fn test_start_renders_template() {
+    let (addr, requests, shutdown) =
+        spawn_mock_http(vec![("POST /instances/survival/start", 200, "ok")]).await;
+
+    let provider =
+        HttpProvider::new("survival", &make_config(addr), reqwest::Client::new()).unwrap();
+    provider.start().await.unwrap();
+
+    let request = requests.lock().unwrap()[0].clone();
+    assert!(request.starts_with("POST /instances/survival/start"));
+    assert!(
+        request
+            .to_lowercase()
+            .contains("authorization: bearer secret")
+    );
+    assert!(request.ends_with(r#"{"instance":"survival"}"#));
+
+    shutdown.cancel();
+}
+
+#[tokio::test]
+async fn test_check_status_json_path() {
+    let (addr, _, shutdown) = spawn_mock_http(vec![(
+        "GET /instances/survival ",
+        200,
+        r#"{"data":{"state":"Starting"}}"#,
+    )])
+    .await;
+
+    let provider =
+        HttpProvider::new("survival", &make_config(addr), reqwest::Client::new()).unwrap();
+    assert_eq!(
+        provider.check_status().await.unwrap(),
+        ProviderStatus::Starting
+    );
+
+    shutdown.cancel();
+}
+
+#[tokio::test]
+async fn test_check_status_regex() {
+    let (addr, _, shutdown) =
+        spawn_mock_http(vec![("GET /instances/survival ", 200, "status: 0\n")]).await;
+
+    let mut config = make_config(addr);
+    config.status_mapping = HttpStatusMapping {
+        regex: Some(r"status: (\d+)".to_string()),
+        running: vec!["1".to_string()],
+        stopped: vec!["0".to_string()],
+        ..HttpStatusMapping::default()
+    };
+
+    let provider = HttpProvider::new("survival", &config, reqwest::Client::new()).unwrap();
+    assert_eq!(
+        provider.check_status().await.unwrap(),
+        ProviderStatus::Stopped
+    );
+
+    shutdown.cancel();
+}
+
+#[tokio::test]
+async fn test_error_status_fails() {
+    let (addr, _, shutdown) = spawn_mock_http(vec![("/stop", 500, "boom")]).await;
+
+    let provider =
+        HttpProvider::new("survival", &make_config(addr), reqwest::Client::new()).unwrap();
+    assert!(provider.stop().await.is_err());
+
+    shutdown.cancel();
+}
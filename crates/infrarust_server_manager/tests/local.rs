@@ -1,8 +1,10 @@
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 use std::io::Write;
 
-use infrarust_config::LocalManagerConfig;
-use infrarust_server_manager::{LocalProvider, ProviderStatus, ServerProvider};
+use infrarust_config::{LocalManagerConfig, RestartConfig, ScheduleConfig, WakeConfig};
+use infrarust_server_manager::{
+    ConsoleStream, LocalProvider, ProviderStatus, ServerManagerError, ServerProvider,
+};
 
 fn make_mock_script(dir: &std::path::Path, name: &str, content: &str) -> std::path::PathBuf {
     let path = dir.join(name);
@@ -30,6 +32,10 @@ fn make_config(script_path: &std::path::Path, working_dir: &std::path::Path) ->
         shutdown_timeout: std::time::Duration::from_secs(5),
         shutdown_after: None,
         start_timeout: std::time::Duration::from_secs(10),
+        restart: RestartConfig::default(),
+        schedule: ScheduleConfig::default(),
+        evacuate_to: None,
+        wake: WakeConfig::default(),
     }
 }
 
@@ -128,6 +134,10 @@ async fn test_start_process_dies_early() {
     // Wait for process to exit
     tokio::time::sleep(std::time::Duration::from_millis(500)).await;
 
+    let status = provider.check_status().await.unwrap();
+    assert_eq!(status, ProviderStatus::Crashed);
+
+    // The exit is reported once, then the server is simply stopped
     let status = provider.check_status().await.unwrap();
     assert_eq!(status, ProviderStatus::Stopped);
 }
@@ -182,3 +192,115 @@ done
     // Cleanup
     provider.stop().await.unwrap();
 }
+
+#[tokio::test]
+async fn test_console_command_and_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let script = make_mock_script(
+        dir.path(),
+        "server.sh",
+        r#"#!/bin/bash
+echo "Loading..."
+while read -r line; do
+    if [ "$line" = "stop" ]; then exit 0; fi
+    echo "ran: $line"
+    echo "warn: $line" >&2
+done
+"#,
+    );
+    let config = make_config(&script, dir.path());
+    let provider = LocalProvider::new(config);
+
+    let result = provider.send_command("list").await;
+    assert!(matches!(
+        result,
+        Err(ServerManagerError::InvalidState { .. })
+    ));
+
+    provider.start().await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    let (history, mut rx) = provider.console_output().unwrap().subscribe();
+    assert_eq!(history[0].line, "Loading...");
+
+    provider.send_command("whitelist add Steve").await.unwrap();
+
+    let mut received = Vec::new();
+    while received.len() < 2 {
+        let line = tokio::time::timeout(std::time::Duration::from_secs(2), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        received.push(line);
+    }
+    received.sort_by_key(|l| l.stream == ConsoleStream::Stderr);
+    assert_eq!(received[0].line, "ran: whitelist add Steve");
+    assert_eq!(received[1].stream, ConsoleStream::Stderr);
+
+    provider.stop().await.unwrap();
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_adopt_process_left_by_previous_proxy() {
+    let dir = tempfile::tempdir().unwrap();
+    let other = tempfile::tempdir().unwrap();
+    let script = make_mock_script(dir.path(), "server.sh", "#!/bin/bash\necho done\n");
+
+    // Stands in for a server started by a previous proxy instance
+    let mut child = std::process::Command::new("sleep")
+        .arg("30")
+        .current_dir(dir.path())
+        .spawn()
+        .unwrap();
+    let pid = child.id();
+    let reaper = std::thread::spawn(move || child.wait());
+
+    // A process running elsewhere is not this server (PID reuse)
+    let stranger = LocalProvider::new(make_config(&script, other.path()));
+    assert!(!stranger.adopt_process(pid).await);
+    assert_eq!(stranger.process_id(), None);
+
+    let provider = LocalProvider::new(make_config(&script, dir.path()));
+    assert!(provider.adopt_process(pid).await);
+    assert_eq!(provider.process_id(), Some(pid));
+    assert_eq!(
+        provider.check_status().await.unwrap(),
+        ProviderStatus::Running
+    );
+    assert!(matches!(
+        provider.send_command("list").await,
+        Err(ServerManagerError::Unsupported { .. })
+    ));
+
+    provider.stop().await.unwrap();
+    reaper.join().unwrap().unwrap();
+    assert_eq!(provider.process_id(), None);
+    assert_eq!(
+        provider.check_status().await.unwrap(),
+        ProviderStatus::Stopped
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_adopted_process_exit_is_a_crash() {
+    let dir = tempfile::tempdir().unwrap();
+    let script = make_mock_script(dir.path(), "server.sh", "#!/bin/bash\necho done\n");
+
+    let mut child = std::process::Command::new("sleep")
+        .arg("30")
+        .current_dir(dir.path())
+        .spawn()
+        .unwrap();
+    let provider = LocalProvider::new(make_config(&script, dir.path()));
+    assert!(provider.adopt_process(child.id()).await);
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    assert_eq!(
+        provider.check_status().await.unwrap(),
+        ProviderStatus::Crashed
+    );
+    assert_eq!(provider.process_id(), None);
+}
@@ -1,7 +1,7 @@
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 use std::net::SocketAddr;
 
-use infrarust_config::PterodactylManagerConfig;
+use infrarust_config::{PterodactylManagerConfig, RestartConfig, ScheduleConfig, WakeConfig};
 use infrarust_server_manager::{ProviderStatus, PterodactylProvider, ServerProvider};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpListener;
@@ -59,6 +59,10 @@ fn make_config(addr: SocketAddr) -> PterodactylManagerConfig {
         shutdown_after: None,
         start_timeout: std::time::Duration::from_secs(10),
         poll_interval: std::time::Duration::from_secs(5),
+        restart: RestartConfig::default(),
+        schedule: ScheduleConfig::default(),
+        evacuate_to: None,
+        wake: WakeConfig::default(),
     }
 }
 
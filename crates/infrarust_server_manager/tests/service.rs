@@ -4,6 +4,9 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
+use infrarust_config::{
+    RestartConfig, RestartPolicy, ScheduleAction, ScheduleConfig, ScheduleEntry, WakeConfig,
+};
 use infrarust_server_manager::{
     PlayerCounter, ProviderStatus, ServerManagerError, ServerManagerService, ServerProvider,
     ServerState,
@@ -315,3 +318,325 @@ async fn test_manual_start_stop() {
     assert!(provider.stop_called.load(Ordering::Acquire));
     assert_eq!(service.get_state("test"), Some(ServerState::Sleeping));
 }
+
+#[tokio::test]
+async fn test_crash_restart_then_crash_loop() {
+    let service = Arc::new(ServerManagerService::new(&[], reqwest::Client::new()));
+    let provider = Arc::new(MockProvider::new(ProviderStatus::Running));
+
+    service.register_server(
+        "test".to_string(),
+        provider.clone(),
+        None,
+        Duration::from_secs(10),
+        Duration::from_millis(50),
+    );
+    service.set_restart_config(
+        "test",
+        RestartConfig {
+            policy: RestartPolicy::OnFailure,
+            max_retries: 1,
+            window: Duration::from_secs(60),
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+        },
+    );
+    let crash_loops = Arc::new(std::sync::atomic::AtomicU32::new(0));
+    let counter = Arc::clone(&crash_loops);
+    service.add_on_crash_loop(Arc::new(move |_, restarts, _| {
+        counter.store(restarts, Ordering::Release);
+    }));
+
+    service.initial_health_check().await;
+    let shutdown = CancellationToken::new();
+    let _handles = service.start_monitoring(Arc::new(MockPlayerCounter), shutdown.clone());
+
+    // First crash: restarted after the backoff
+    *provider.status.lock().await = ProviderStatus::Crashed;
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert!(provider.start_called.load(Ordering::Acquire));
+    assert_eq!(service.get_state("test"), Some(ServerState::Starting));
+
+    // Second crash within the window: crash loop, no restart
+    *provider.status.lock().await = ProviderStatus::Running;
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(service.get_state("test"), Some(ServerState::Online));
+    provider.start_called.store(false, Ordering::Release);
+    *provider.status.lock().await = ProviderStatus::Crashed;
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    assert!(!provider.start_called.load(Ordering::Acquire));
+    assert_eq!(service.get_state("test"), Some(ServerState::Crashed));
+    assert!(service.is_crash_looping("test"));
+    assert_eq!(crash_loops.load(Ordering::Acquire), 1);
+
+    // A manual start clears the crash loop
+    service.start_server("test").await.unwrap();
+    assert!(!service.is_crash_looping("test"));
+
+    shutdown.cancel();
+}
+
+#[tokio::test]
+async fn test_clean_exit_not_restarted_on_failure_policy() {
+    let service = Arc::new(ServerManagerService::new(&[], reqwest::Client::new()));
+    let provider = Arc::new(MockProvider::new(ProviderStatus::Running));
+
+    service.register_server(
+        "test".to_string(),
+        provider.clone(),
+        None,
+        Duration::from_secs(10),
+        Duration::from_millis(50),
+    );
+    service.set_restart_config(
+        "test",
+        RestartConfig {
+            policy: RestartPolicy::OnFailure,
+            backoff: Duration::from_millis(50),
+            ..RestartConfig::default()
+        },
+    );
+
+    service.initial_health_check().await;
+    let shutdown = CancellationToken::new();
+    let _handles = service.start_monitoring(Arc::new(MockPlayerCounter), shutdown.clone());
+
+    *provider.status.lock().await = ProviderStatus::Stopped;
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    assert!(!provider.start_called.load(Ordering::Acquire));
+    assert_eq!(service.get_state("test"), Some(ServerState::Sleeping));
+
+    shutdown.cancel();
+}
+
+#[tokio::test]
+async fn test_schedule_blocks_wake_but_not_manual_start() {
+    let service = ServerManagerService::new(&[], reqwest::Client::new());
+    let provider = Arc::new(MockProvider::new(ProviderStatus::Stopped));
+
+    service.register_server(
+        "events".to_string(),
+        provider.clone(),
+        None,
+        Duration::from_secs(10),
+        Duration::from_secs(5),
+    );
+    // Only ever stopped by the schedule: always outside the window
+    service.set_schedule(
+        "events",
+        ScheduleConfig {
+            entries: vec![ScheduleEntry {
+                cron: "* * * * *".to_string(),
+                action: ScheduleAction::Stop,
+            }],
+            block_outside_window: true,
+            closed_message: "Closed".to_string(),
+            ..ScheduleConfig::default()
+        },
+    );
+
+    assert_eq!(service.closed_message("events").as_deref(), Some("Closed"));
+    let result = service.ensure_started("events").await;
+    assert!(matches!(
+        result,
+        Err(ServerManagerError::OutsideSchedule { ref message, .. }) if message == "Closed"
+    ));
+    assert!(!provider.start_called.load(Ordering::Acquire));
+
+    service.start_server("events").await.unwrap();
+    assert!(provider.start_called.load(Ordering::Acquire));
+}
+
+#[tokio::test]
+async fn test_wake_on_ping() {
+    let service = Arc::new(ServerManagerService::new(&[], reqwest::Client::new()));
+    let provider = Arc::new(MockProvider::new(ProviderStatus::Stopped));
+
+    service.register_server(
+        "lobby".to_string(),
+        provider.clone(),
+        None,
+        Duration::from_secs(10),
+        Duration::from_secs(5),
+    );
+
+    // Disabled by default
+    assert!(!service.wake_on_ping("lobby"));
+    assert_eq!(service.get_state("lobby"), Some(ServerState::Sleeping));
+
+    service.set_wake_config(
+        "lobby",
+        WakeConfig {
+            on_ping: true,
+            ..WakeConfig::default()
+        },
+    );
+    assert!(service.wake_on_ping("lobby"));
+    assert_eq!(service.get_state("lobby"), Some(ServerState::Starting));
+    // Already starting: nothing more to do
+    assert!(!service.wake_on_ping("lobby"));
+
+    tokio::time::timeout(Duration::from_secs(1), async {
+        while !provider.start_called.load(Ordering::Acquire) {
+            tokio::task::yield_now().await;
+        }
+    })
+    .await
+    .unwrap();
+}
+
+/// Records evacuations and returns.
+#[derive(Default)]
+struct EvacuatingCounter {
+    evacuated: std::sync::Mutex<Vec<(String, String)>>,
+    returned: std::sync::Mutex<Vec<u64>>,
+}
+
+impl PlayerCounter for EvacuatingCounter {
+    fn count_by_server(&self, _server_id: &str) -> usize {
+        0
+    }
+
+    fn evacuate<'a>(
+        &'a self,
+        server_id: &'a str,
+        target: &'a str,
+    ) -> Pin<Box<dyn std::future::Future<Output = Vec<u64>> + Send + 'a>> {
+        self.evacuated
+            .lock()
+            .unwrap()
+            .push((server_id.to_string(), target.to_string()));
+        Box::pin(async { vec![1, 2] })
+    }
+
+    fn return_players<'a>(
+        &'a self,
+        _server_id: &'a str,
+        _target: &'a str,
+        players: Vec<u64>,
+    ) -> Pin<Box<dyn std::future::Future<Output = ()> + Send + 'a>> {
+        self.returned.lock().unwrap().extend(players);
+        Box::pin(async {})
+    }
+}
+
+#[tokio::test]
+async fn test_graceful_restart_evacuates_and_returns_players() {
+    let service = Arc::new(ServerManagerService::new(&[], reqwest::Client::new()));
+    let provider = Arc::new(MockProvider::new(ProviderStatus::Running));
+
+    service.register_server(
+        "survival".to_string(),
+        provider.clone(),
+        None,
+        Duration::from_secs(10),
+        Duration::from_millis(100),
+    );
+    service.set_evacuate_to("survival", Some("lobby".to_string()));
+    service.initial_health_check().await;
+    assert_eq!(service.get_state("survival"), Some(ServerState::Online));
+
+    let shutdown = CancellationToken::new();
+    let counter = Arc::new(EvacuatingCounter::default());
+    let _handles = service.start_monitoring(counter.clone(), shutdown.clone());
+
+    let svc = Arc::clone(&service);
+    let handle = tokio::spawn(async move { svc.restart_server("survival").await });
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(provider.stop_called.load(Ordering::Acquire));
+    assert!(provider.start_called.load(Ordering::Acquire));
+    assert_eq!(
+        *counter.evacuated.lock().unwrap(),
+        vec![("survival".to_string(), "lobby".to_string())]
+    );
+    assert!(counter.returned.lock().unwrap().is_empty());
+
+    *provider.status.lock().await = ProviderStatus::Running;
+
+    let result = tokio::time::timeout(Duration::from_secs(5), handle)
+        .await
+        .expect("should not timeout")
+        .expect("task should not panic");
+    assert!(result.is_ok());
+    assert_eq!(service.get_state("survival"), Some(ServerState::Online));
+    assert_eq!(*counter.returned.lock().unwrap(), vec![1, 2]);
+
+    // Only online servers can be restarted
+    service.stop_server("survival").await.unwrap();
+    assert!(matches!(
+        service.restart_server("survival").await,
+        Err(ServerManagerError::InvalidState { .. })
+    ));
+
+    shutdown.cancel();
+}
+
+/// Provider whose panel cannot be reached.
+struct UnreachableProvider;
+
+impl ServerProvider for UnreachableProvider {
+    fn start(
+        &self,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<(), ServerManagerError>> + Send + '_>>
+    {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn stop(
+        &self,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<(), ServerManagerError>> + Send + '_>>
+    {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn check_status(
+        &self,
+    ) -> Pin<
+        Box<
+            dyn std::future::Future<Output = Result<ProviderStatus, ServerManagerError>>
+                + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async { Err(ServerManagerError::ApiResponse("unreachable".to_string())) })
+    }
+
+    fn provider_type(&self) -> &'static str {
+        "unreachable"
+    }
+}
+
+#[tokio::test]
+async fn test_state_file_survives_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("server_manager.json");
+
+    let service =
+        ServerManagerService::new(&[], reqwest::Client::new()).with_state_file(path.clone());
+    service.register_server(
+        "survival".to_string(),
+        Arc::new(MockProvider::new(ProviderStatus::Running)),
+        Some(Duration::from_secs(600)),
+        Duration::from_secs(10),
+        Duration::from_secs(5),
+    );
+    service.initial_health_check().await;
+    assert_eq!(service.get_state("survival"), Some(ServerState::Online));
+    service.save_state().await;
+    assert!(path.exists());
+
+    // The restarted proxy cannot reach the panel: it keeps the last known state
+    let restarted = ServerManagerService::new(&[], reqwest::Client::new()).with_state_file(path);
+    restarted.register_server(
+        "survival".to_string(),
+        Arc::new(UnreachableProvider),
+        Some(Duration::from_secs(600)),
+        Duration::from_secs(10),
+        Duration::from_secs(5),
+    );
+    restarted.initial_health_check().await;
+    assert_eq!(restarted.get_state("survival"), Some(ServerState::Online));
+}
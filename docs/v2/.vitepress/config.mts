@@ -158,6 +158,7 @@ export default
             items: [
               { text: 'File Provider', link: '/configuration/providers/file' },
               { text: 'Docker Discovery', link: '/configuration/providers/docker' },
+              { text: 'Kubernetes Discovery', link: '/configuration/providers/kubernetes' },
             ],
           },
           {
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::time::SystemTime;
 
 use infrarust_api::player::Player;
+use infrarust_api::services::player_data::PlayerDataEntry;
 use serde::Serialize;
 
 use crate::util::{format_duration, format_system_time};
@@ -16,6 +17,8 @@ pub struct PlayerResponse {
     pub is_active: bool,
     pub connected_since: String,
     pub connected_duration: String,
+    /// Round-trip time in milliseconds, `null` until first measured.
+    pub ping_ms: Option<u64>,
 }
 
 impl PlayerResponse {
@@ -34,6 +37,9 @@ impl PlayerResponse {
             is_active: player.is_active(),
             connected_since: format_system_time(connected_at),
             connected_duration: format_duration(duration),
+            ping_ms: player
+                .ping()
+                .map(|ping| u64::try_from(ping.as_millis()).unwrap_or(u64::MAX)),
         }
     }
 }
@@ -77,3 +83,29 @@ impl PlayerSummary {
         }
     }
 }
+
+#[derive(Serialize)]
+pub struct PlayerDataResponse {
+    pub uuid: String,
+    pub entries: Vec<PlayerDataEntryResponse>,
+}
+
+#[derive(Serialize)]
+pub struct PlayerDataEntryResponse {
+    pub namespace: String,
+    pub key: String,
+    pub value: serde_json::Value,
+    pub expires_at: Option<String>,
+}
+
+impl PlayerDataEntryResponse {
+    pub fn from_entry(entry: &PlayerDataEntry) -> Self {
+        Self {
+            namespace: entry.namespace.clone(),
+            key: entry.key.clone(),
+            value: serde_json::from_str(&entry.value)
+                .unwrap_or_else(|_| serde_json::Value::String(entry.value.clone())),
+            expires_at: entry.expires_at.map(format_system_time),
+        }
+    }
+}
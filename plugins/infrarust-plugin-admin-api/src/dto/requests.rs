@@ -34,3 +34,8 @@ pub enum BanTargetRequest {
     Username(String),
     Uuid(String),
 }
+
+#[derive(Debug, Deserialize)]
+pub struct ConsoleCommandRequest {
+    pub command: String,
+}
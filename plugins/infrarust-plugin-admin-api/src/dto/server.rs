@@ -109,3 +109,11 @@ pub struct PlayerSampleResponse {
     pub name: String,
     pub id: String,
 }
+
+/// A line of server console output, sent on the console SSE stream.
+#[derive(Serialize)]
+pub struct ConsoleLineResponse {
+    /// `"stdout"` or `"stderr"`.
+    pub stream: &'static str,
+    pub line: String,
+}
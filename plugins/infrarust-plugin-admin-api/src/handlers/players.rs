@@ -3,11 +3,15 @@ use std::sync::Arc;
 use axum::Json;
 use axum::extract::{Path, Query, State};
 use serde::Deserialize;
+use uuid::Uuid;
 
 use infrarust_api::player::Player;
 use infrarust_api::types::{Component, PlayerId, ServerId};
 
-use crate::dto::player::{PlayerCountResponse, PlayerDetailResponse, PlayerResponse};
+use crate::dto::player::{
+    PlayerCountResponse, PlayerDataEntryResponse, PlayerDataResponse, PlayerDetailResponse,
+    PlayerResponse,
+};
 use crate::dto::requests::{BroadcastRequest, KickRequest, MessageRequest, SendRequest};
 use crate::error::ApiError;
 use crate::response::{
@@ -82,6 +86,43 @@ pub async fn get(
     Ok(ok(PlayerDetailResponse::from_player(player.as_ref())))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct PlayerDataQuery {
+    pub namespace: Option<String>,
+}
+
+/// Stored plugin data of a player, who may be offline when given by UUID.
+pub async fn data(
+    State(state): State<Arc<ApiState>>,
+    Path(id_or_username): Path<String>,
+    Query(query): Query<PlayerDataQuery>,
+) -> Result<Json<ApiResponse<PlayerDataResponse>>, ApiError> {
+    let uuid = match id_or_username.parse::<Uuid>() {
+        Ok(uuid) => uuid,
+        Err(_) => find_player(&state, &id_or_username)
+            .map(|player| player.profile().uuid)
+            .ok_or_else(|| ApiError::NotFound(format!("Player '{id_or_username}' not found")))?,
+    };
+
+    let entries = state
+        .player_data
+        .entries(&uuid)
+        .iter()
+        .filter(|entry| {
+            query
+                .namespace
+                .as_ref()
+                .is_none_or(|namespace| &entry.namespace == namespace)
+        })
+        .map(PlayerDataEntryResponse::from_entry)
+        .collect();
+
+    Ok(ok(PlayerDataResponse {
+        uuid: uuid.to_string(),
+        entries,
+    }))
+}
+
 pub async fn count(
     State(state): State<Arc<ApiState>>,
 ) -> Result<Json<ApiResponse<PlayerCountResponse>>, ApiError> {
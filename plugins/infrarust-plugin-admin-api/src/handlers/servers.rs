@@ -5,12 +5,14 @@ use axum::Json;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 
+use infrarust_api::error::ServiceError;
 use infrarust_api::provider::PluginProviderEvent;
 use infrarust_api::services::config_service::ServerConfig;
 use infrarust_api::services::server_manager::ServerState;
 use infrarust_api::types::{ServerAddress, ServerId};
 
 use crate::dto::player::PlayerSummary;
+use crate::dto::requests::ConsoleCommandRequest;
 use crate::dto::server::{
     CreateServerRequest, HealthCheckResponse, ServerDetailResponse, ServerResponse,
     UpdateServerRequest,
@@ -170,6 +172,72 @@ pub async fn stop(
     Ok(mutation_ok(format!("Server '{id}' stop requested")))
 }
 
+pub async fn restart(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<MutationResult>>, ApiError> {
+    let server_id = ServerId::new(&id);
+
+    state
+        .config_service
+        .get_server_config(&server_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Server '{id}' not found")))?;
+
+    tracing::info!(
+        target: "audit",
+        action = "server_restart",
+        server = %id,
+        source = "admin_api",
+        "Server restart requested via Admin API"
+    );
+
+    state
+        .server_manager
+        .restart(&server_id)
+        .await
+        .map_err(|e| ApiError::Conflict(format!("Failed to restart server: {e}")))?;
+
+    Ok(mutation_ok(format!("Server '{id}' restarted")))
+}
+
+pub async fn console_command(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<String>,
+    Json(body): Json<ConsoleCommandRequest>,
+) -> Result<Json<ApiResponse<MutationResult>>, ApiError> {
+    let server_id = ServerId::new(&id);
+
+    state
+        .config_service
+        .get_server_config(&server_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Server '{id}' not found")))?;
+
+    let command = body.command.trim();
+    if command.is_empty() {
+        return Err(ApiError::BadRequest("command cannot be empty".into()));
+    }
+
+    tracing::info!(
+        target: "audit",
+        action = "server_console",
+        server = %id,
+        command = %command,
+        source = "admin_api",
+        "Console command sent via Admin API"
+    );
+
+    state
+        .server_manager
+        .send_command(&server_id, command)
+        .await
+        .map_err(|e| match e {
+            ServiceError::Unavailable(msg) => ApiError::ServiceUnavailable(msg),
+            other => ApiError::Conflict(format!("Failed to send command: {other}")),
+        })?;
+
+    Ok(mutation_ok(format!("Command sent to '{id}'")))
+}
+
 fn parse_address(s: &str) -> Result<ServerAddress, ApiError> {
     // Handle IPv6 bracket notation: [::1]:25565
     if let Some(rest) = s.strip_prefix('[') {
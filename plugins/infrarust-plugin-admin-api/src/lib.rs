@@ -119,6 +119,7 @@ impl Plugin for AdminApiPlugin {
             let state = Arc::new(ApiState {
                 player_registry: ctx.player_registry_handle(),
                 ban_service: ctx.ban_service_handle(),
+                player_data: ctx.player_data_handle(),
                 server_manager: ctx.server_manager_handle(),
                 config_service: ctx.config_service_handle(),
                 plugin_registry: ctx.plugin_registry_handle(),
@@ -243,8 +244,11 @@ mod tests {
     use infrarust_api::player::Player;
     use infrarust_api::services::ban_service::{BanEntry, BanTarget};
     use infrarust_api::services::config_service::ServerConfig;
+    use infrarust_api::services::player_data::PlayerDataEntry;
     use infrarust_api::services::plugin_registry::{PluginDependencyInfo, PluginInfo};
-    use infrarust_api::services::server_manager::{ServerState, StateChangeCallback};
+    use infrarust_api::services::server_manager::{
+        ConsoleCallback, ConsoleLine, ConsoleStream, ServerState, StateChangeCallback,
+    };
     use infrarust_api::types::{PlayerId, ServerId};
     use tokio::sync::broadcast;
     use tokio_util::sync::CancellationToken;
@@ -320,6 +324,53 @@ mod tests {
         }
     }
 
+    // ── Mock PlayerDataStore ──
+
+    const DATA_UUID: &str = "069a79f4-44e9-4726-a5be-fca90e38aaf5";
+
+    struct MockPlayerDataStore;
+
+    impl infrarust_api::services::player_data::private::Sealed for MockPlayerDataStore {}
+
+    impl infrarust_api::services::player_data::PlayerDataStore for MockPlayerDataStore {
+        fn get_raw(&self, _player: &Uuid, _namespace: &str, _key: &str) -> Option<String> {
+            None
+        }
+        fn set_raw(
+            &self,
+            _player: &Uuid,
+            _namespace: &str,
+            _key: &str,
+            _json: String,
+            _ttl: Option<Duration>,
+        ) -> Result<(), ServiceError> {
+            Ok(())
+        }
+        fn remove(&self, _player: &Uuid, _namespace: &str, _key: &str) -> bool {
+            false
+        }
+        fn keys(&self, _player: &Uuid, _namespace: &str) -> Vec<String> {
+            vec![]
+        }
+        fn clear(&self, _player: &Uuid, _namespace: &str) -> usize {
+            0
+        }
+        fn entries(&self, player: &Uuid) -> Vec<PlayerDataEntry> {
+            if player.to_string() != DATA_UUID {
+                return vec![];
+            }
+            ["economy", "stats"]
+                .into_iter()
+                .map(|namespace| PlayerDataEntry {
+                    namespace: namespace.to_string(),
+                    key: "coins".to_string(),
+                    value: "{\"amount\":42}".to_string(),
+                    expires_at: None,
+                })
+                .collect()
+        }
+    }
+
     // ── Mock ServerManager ──
 
     struct MockServerManager;
@@ -336,12 +387,40 @@ mod tests {
         fn stop(&self, _server: &ServerId) -> BoxFuture<'_, Result<(), ServiceError>> {
             Box::pin(async { Ok(()) })
         }
+        fn restart(&self, _server: &ServerId) -> BoxFuture<'_, Result<(), ServiceError>> {
+            Box::pin(async { Ok(()) })
+        }
         fn on_state_change(&self, _callback: StateChangeCallback) -> ListenerHandle {
             ListenerHandle::new(0)
         }
         fn get_all_servers(&self) -> Vec<(ServerId, ServerState)> {
             vec![]
         }
+        fn closed_message(&self, _server: &ServerId) -> Option<String> {
+            None
+        }
+        fn send_command(
+            &self,
+            _server: &ServerId,
+            _command: &str,
+        ) -> BoxFuture<'_, Result<(), ServiceError>> {
+            Box::pin(async { Ok(()) })
+        }
+        fn on_console_output(
+            &self,
+            server: &ServerId,
+            _callback: ConsoleCallback,
+        ) -> Result<(Vec<ConsoleLine>, ListenerHandle), ServiceError> {
+            if server.as_str() != "lobby" {
+                return Err(ServiceError::NotFound(server.as_str().to_string()));
+            }
+            let line = ConsoleLine {
+                stream: ConsoleStream::Stdout,
+                line: "Done (1.2s)!".to_string(),
+            };
+            Ok((vec![line], ListenerHandle::new(1)))
+        }
+        fn remove_console_listener(&self, _handle: ListenerHandle) {}
     }
 
     // ── Mock ConfigService ──
@@ -412,6 +491,7 @@ mod tests {
         Arc::new(ApiState {
             player_registry: Arc::new(MockPlayerRegistry { count: 3 }),
             ban_service: Arc::new(MockBanService),
+            player_data: Arc::new(MockPlayerDataStore),
             server_manager: Arc::new(MockServerManager),
             config_service: Arc::new(MockConfigService { server_count: 2 }),
             plugin_registry: Arc::new(MockPluginRegistry),
@@ -651,6 +731,24 @@ mod tests {
         assert_eq!(body["error"]["code"], "NOT_FOUND");
     }
 
+    #[tokio::test]
+    async fn test_player_data_by_uuid() {
+        let (status, body) = auth_get(&format!("/api/v1/players/{DATA_UUID}/data")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["uuid"], DATA_UUID);
+        assert_eq!(body["data"]["entries"].as_array().unwrap().len(), 2);
+        assert_eq!(body["data"]["entries"][0]["value"]["amount"], 42);
+
+        let (status, body) =
+            auth_get(&format!("/api/v1/players/{DATA_UUID}/data?namespace=stats")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["entries"].as_array().unwrap().len(), 1);
+        assert_eq!(body["data"]["entries"][0]["namespace"], "stats");
+
+        let (status, _) = auth_get("/api/v1/players/nonexistent/data").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
     // ── Bans ──
 
     #[tokio::test]
@@ -879,6 +977,54 @@ mod tests {
         assert_eq!(body["error"]["code"], "NOT_FOUND");
     }
 
+    #[tokio::test]
+    async fn test_server_restart_not_found() {
+        let (status, body) =
+            auth_post("/api/v1/servers/nonexistent/restart", serde_json::json!({})).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["error"]["code"], "NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn test_server_console_command_not_found() {
+        let (status, body) = auth_post(
+            "/api/v1/servers/nonexistent/console",
+            serde_json::json!({"command": "list"}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["error"]["code"], "NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn test_server_console_stream_replays_history() {
+        let app = build_router(test_state(), true);
+        let request = Request::builder()
+            .uri("/api/v1/servers/lobby/console/stream?token=test-key")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let frame = response.into_body().frame().await.unwrap().unwrap();
+        let text = String::from_utf8(frame.into_data().unwrap().to_vec()).unwrap();
+        assert!(text.starts_with("event: console"));
+        assert!(text.contains(r#""line":"Done (1.2s)!""#));
+    }
+
+    #[tokio::test]
+    async fn test_server_console_stream_unknown_server() {
+        let app = build_router(test_state(), true);
+        let request = Request::builder()
+            .uri("/api/v1/servers/nonexistent/console/stream?token=test-key")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
     // ── Config Mutations ──
 
     #[tokio::test]
@@ -37,6 +37,10 @@ pub fn build_router(state: Arc<ApiState>, enable_webui: bool) -> Router {
             "/api/v1/players/{id_or_username}",
             get(handlers::players::get),
         )
+        .route(
+            "/api/v1/players/{id_or_username}/data",
+            get(handlers::players::data),
+        )
         .route(
             "/api/v1/bans",
             get(handlers::bans::list).post(handlers::bans::create),
@@ -88,6 +92,14 @@ pub fn build_router(state: Arc<ApiState>, enable_webui: bool) -> Router {
         )
         .route("/api/v1/servers/{id}/start", post(handlers::servers::start))
         .route("/api/v1/servers/{id}/stop", post(handlers::servers::stop))
+        .route(
+            "/api/v1/servers/{id}/restart",
+            post(handlers::servers::restart),
+        )
+        .route(
+            "/api/v1/servers/{id}/console",
+            post(handlers::servers::console_command),
+        )
         .route(
             "/api/v1/servers/{id}/health",
             get(handlers::servers::health_check),
@@ -121,7 +133,11 @@ pub fn build_router(state: Arc<ApiState>, enable_webui: bool) -> Router {
     // (auth verified via ?token= query param inside each handler)
     let sse_routes = Router::new()
         .route("/api/v1/events", get(sse::handlers::event_stream))
-        .route("/api/v1/logs", get(sse::handlers::log_stream));
+        .route("/api/v1/logs", get(sse::handlers::log_stream))
+        .route(
+            "/api/v1/servers/{id}/console/stream",
+            get(sse::handlers::console_stream),
+        );
 
     let router = Router::new()
         .merge(public_routes)
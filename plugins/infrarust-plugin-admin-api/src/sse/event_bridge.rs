@@ -4,7 +4,9 @@ use infrarust_api::event::EventPriority;
 use infrarust_api::event::bus::EventBusExt;
 use infrarust_api::events::connection::ServerSwitchEvent;
 use infrarust_api::events::lifecycle::{DisconnectEvent, PostLoginEvent};
-use infrarust_api::events::proxy::{ConfigReloadEvent, ServerStateChangeEvent};
+use infrarust_api::events::proxy::{
+    ConfigReloadEvent, ServerCrashLoopEvent, ServerStateChangeEvent,
+};
 use infrarust_api::plugin::PluginContext;
 use infrarust_api::services::player_registry::PlayerRegistry;
 use tokio::sync::broadcast;
@@ -92,6 +94,18 @@ impl EventBridge {
                 });
             });
 
+        // ServerCrashLoopEvent → ServerCrashLoop
+        let tx = self.event_tx.clone();
+        ctx.event_bus()
+            .subscribe::<ServerCrashLoopEvent, _>(EventPriority::LAST, move |event| {
+                let _ = tx.send(ApiEvent::ServerCrashLoop {
+                    server_id: event.server.as_str().to_string(),
+                    restarts: event.restarts,
+                    window_seconds: event.window.as_secs(),
+                    timestamp: now_iso8601(),
+                });
+            });
+
         // ConfigReloadEvent → ConfigReload
         let tx = self.event_tx.clone();
         ctx.event_bus()
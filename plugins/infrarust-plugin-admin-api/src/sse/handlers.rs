@@ -2,19 +2,28 @@ use std::collections::HashSet;
 use std::convert::Infallible;
 use std::time::Duration;
 
-use axum::extract::{Query, State};
+use axum::extract::{Path, Query, State};
 use axum::response::Json;
 use axum::response::sse::{Event, KeepAlive, Sse};
 use std::sync::Arc;
 use tokio_stream::Stream;
 
+use infrarust_api::error::ServiceError;
+use infrarust_api::event::ListenerHandle;
+use infrarust_api::services::server_manager::{ConsoleLine, ConsoleStream, ServerManager};
+use infrarust_api::types::ServerId;
+
+use crate::dto::server::ConsoleLineResponse;
 use crate::error::ApiError;
 use crate::log_layer::LogEntry;
 use crate::response::{ApiResponse, ok};
 use crate::state::ApiState;
 
 use super::auth::verify_sse_auth;
-use super::types::{EventStreamFilter, LogHistoryFilter, LogStreamFilter};
+use super::types::{ConsoleStreamFilter, EventStreamFilter, LogHistoryFilter, LogStreamFilter};
+
+/// Console lines buffered per SSE client before new lines are dropped.
+const CONSOLE_CHANNEL_CAPACITY: usize = 256;
 
 /// SSE endpoint for real-time proxy events.
 ///
@@ -132,6 +141,79 @@ pub async fn log_stream(
     ))
 }
 
+/// SSE endpoint streaming a managed server's console output.
+///
+/// Recent output is replayed first, then new lines follow as `console` events.
+///
+/// `GET /api/v1/servers/{id}/console/stream?token=<key>`
+pub async fn console_stream(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<String>,
+    Query(filter): Query<ConsoleStreamFilter>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    verify_sse_auth(&state, &filter.token)?;
+
+    let (tx, mut receiver) = tokio::sync::mpsc::channel::<ConsoleLine>(CONSOLE_CHANNEL_CAPACITY);
+    let server_id = ServerId::new(&id);
+    let (history, handle) = state
+        .server_manager
+        .on_console_output(
+            &server_id,
+            Box::new(move |line| {
+                let _ = tx.try_send(line.clone());
+            }),
+        )
+        .map_err(|e| match e {
+            ServiceError::NotFound(_) => ApiError::NotFound(format!("Server '{id}' not found")),
+            other => ApiError::ServiceUnavailable(other.to_string()),
+        })?;
+
+    let guard = ConsoleListenerGuard {
+        server_manager: Arc::clone(&state.server_manager),
+        handle,
+    };
+
+    let stream = async_stream::stream! {
+        let _guard = guard;
+        for line in history {
+            yield Ok(console_event(line));
+        }
+        while let Some(line) = receiver.recv().await {
+            yield Ok(console_event(line));
+        }
+    };
+
+    Ok(Sse::new(stream).keep_alive(
+        KeepAlive::new()
+            .interval(Duration::from_secs(15))
+            .text("keep-alive"),
+    ))
+}
+
+/// Unregisters the console listener when the SSE client disconnects.
+struct ConsoleListenerGuard {
+    server_manager: Arc<dyn ServerManager>,
+    handle: ListenerHandle,
+}
+
+impl Drop for ConsoleListenerGuard {
+    fn drop(&mut self) {
+        self.server_manager.remove_console_listener(self.handle);
+    }
+}
+
+fn console_event(line: ConsoleLine) -> Event {
+    let response = ConsoleLineResponse {
+        stream: match line.stream {
+            ConsoleStream::Stderr => "stderr",
+            _ => "stdout",
+        },
+        line: line.line,
+    };
+    let data = serde_json::to_string(&response).unwrap_or_default();
+    Event::default().event("console").data(data)
+}
+
 /// REST endpoint for log history from the ring buffer.
 ///
 /// `GET /api/v1/logs/history?n=50&level=warn&target=infrarust_core`
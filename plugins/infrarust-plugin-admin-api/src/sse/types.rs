@@ -26,6 +26,15 @@ pub struct LogStreamFilter {
     pub token: Option<String>,
 }
 
+/// Query parameters for the server console stream.
+///
+/// Usage: `GET /api/v1/servers/{id}/console/stream?token=<key>`
+#[derive(Debug, Deserialize)]
+pub struct ConsoleStreamFilter {
+    /// API key for authentication.
+    pub token: Option<String>,
+}
+
 /// Filters for the log history REST endpoint.
 ///
 /// Usage: `GET /api/v1/logs/history?n=50&level=warn`
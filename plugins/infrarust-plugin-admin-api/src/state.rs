@@ -4,6 +4,7 @@ use std::time::Instant;
 
 use infrarust_api::services::ban_service::BanService;
 use infrarust_api::services::config_service::ConfigService;
+use infrarust_api::services::player_data::PlayerDataStore;
 use infrarust_api::services::player_registry::PlayerRegistry;
 use infrarust_api::services::plugin_registry::PluginRegistry;
 use infrarust_api::services::server_manager::ServerManager;
@@ -23,6 +24,7 @@ use crate::server_store::ApiServerStore;
 pub struct ApiState {
     pub player_registry: Arc<dyn PlayerRegistry>,
     pub ban_service: Arc<dyn BanService>,
+    pub player_data: Arc<dyn PlayerDataStore>,
     pub server_manager: Arc<dyn ServerManager>,
     pub config_service: Arc<dyn ConfigService>,
     pub plugin_registry: Arc<dyn PluginRegistry>,
@@ -88,6 +90,12 @@ pub enum ApiEvent {
         new_state: String,
         timestamp: String,
     },
+    ServerCrashLoop {
+        server_id: String,
+        restarts: u32,
+        window_seconds: u64,
+        timestamp: String,
+    },
     ConfigReload {
         timestamp: String,
     },
@@ -122,6 +130,7 @@ impl ApiEvent {
             ApiEvent::PlayerLeave { .. } => "player.leave",
             ApiEvent::PlayerSwitch { .. } => "player.switch",
             ApiEvent::ServerStateChange { .. } => "server.state_change",
+            ApiEvent::ServerCrashLoop { .. } => "server.crash_loop",
             ApiEvent::ConfigReload { .. } => "config.reload",
             ApiEvent::BanCreated { .. } => "ban.created",
             ApiEvent::BanRemoved { .. } => "ban.removed",
@@ -179,6 +188,18 @@ impl ApiEvent {
                 format!("{server_id}: {old_state} → {new_state}"),
                 timestamp.clone(),
             ),
+            ApiEvent::ServerCrashLoop {
+                server_id,
+                restarts,
+                timestamp,
+                ..
+            } => (
+                "server.crash_loop",
+                format!(
+                    "{server_id}: crash loop after {restarts} restart(s), auto-restart stopped"
+                ),
+                timestamp.clone(),
+            ),
             ApiEvent::ConfigReload { timestamp } => (
                 "config.reload",
                 "Config reloaded".to_string(),
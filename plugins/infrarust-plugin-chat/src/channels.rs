@@ -0,0 +1,208 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use infrarust_api::event::ResultedEvent;
+use infrarust_api::events::chat::{ChatMessageEvent, ChatMessageResult};
+use infrarust_api::player::Player;
+use infrarust_api::services::config_service::ConfigService;
+use infrarust_api::services::player_data::PlayerDataStore;
+use infrarust_api::services::player_registry::PlayerRegistry;
+use infrarust_api::types::{Component, PlayerId, ProtocolVersion, ServerId};
+use uuid::Uuid;
+
+use crate::config::{ChannelConfig, ChannelScope, ChatConfig};
+use crate::messaging::Preferences;
+
+pub struct ChatState {
+    pub config: ChatConfig,
+    /// Channel chosen with `/channel`. An empty name sends chat to the backend.
+    pub selected: DashMap<PlayerId, String>,
+    /// Who each player last exchanged a private message with, for `/r`.
+    pub reply_to: DashMap<PlayerId, Uuid>,
+    /// Ignore lists and social spy toggles of online players, by UUID.
+    /// Changes are written through to the player data store.
+    pub preferences: DashMap<Uuid, Preferences>,
+    pub player_registry: Arc<dyn PlayerRegistry>,
+    pub config_service: Arc<dyn ConfigService>,
+    pub player_data: Arc<dyn PlayerDataStore>,
+}
+
+impl ChatState {
+    pub fn new(
+        config: ChatConfig,
+        player_registry: Arc<dyn PlayerRegistry>,
+        config_service: Arc<dyn ConfigService>,
+        player_data: Arc<dyn PlayerDataStore>,
+    ) -> Self {
+        Self {
+            config,
+            selected: DashMap::new(),
+            reply_to: DashMap::new(),
+            preferences: DashMap::new(),
+            player_registry,
+            config_service,
+            player_data,
+        }
+    }
+
+    /// Consumes a chat message that belongs to a channel and relays it.
+    ///
+    /// Text rewritten by an earlier listener, such as the chat filter, is
+    /// relayed in place of the original.
+    pub fn on_chat(&self, event: &mut ChatMessageEvent) {
+        let message = match event.result() {
+            ChatMessageResult::Allow => event.message.clone(),
+            ChatMessageResult::Modify { new_message } => new_message.clone(),
+            _ => return,
+        };
+        let Some(sender) = self
+            .player_registry
+            .get_player_by_id(event.player_id)
+            .filter(|sender| !has_chained_chat(sender.as_ref()))
+        else {
+            return;
+        };
+        let Some((channel, text)) = self.route(sender.as_ref(), &message) else {
+            return;
+        };
+        self.relay(sender.as_ref(), channel, text);
+        // The sender sees the relayed copy instead
+        event.deny(Component::text(""));
+    }
+
+    /// Finds the channel a message goes to and its text without the prefix.
+    fn route<'a>(
+        &'a self,
+        player: &dyn Player,
+        message: &'a str,
+    ) -> Option<(&'a ChannelConfig, &'a str)> {
+        if let Some((name, text)) = match_prefix(&self.config.channels, message) {
+            let channel = &self.config.channels[name];
+            if can_use(channel, player) {
+                return (!text.is_empty()).then_some((channel, text));
+            }
+        }
+
+        let selected = self
+            .selected
+            .get(&player.id())
+            .map_or_else(|| self.config.default_channel.clone(), |name| name.clone());
+        let channel = self.config.channels.get(&selected)?;
+        can_use(channel, player).then_some((channel, message))
+    }
+
+    /// Sends a formatted message to every player who can read the channel.
+    fn relay(&self, sender: &dyn Player, channel: &ChannelConfig, text: &str) {
+        let server = sender.current_server();
+        let message = Component::from_legacy_format_plain(
+            &channel.format,
+            &[
+                ("player", &sender.profile().username),
+                ("server", server.as_ref().map_or("", |s| s.as_str())),
+                ("message", text),
+            ],
+        );
+
+        let network = match channel.scope {
+            ChannelScope::Network => server.as_ref().and_then(|s| self.network_of(s)),
+            _ => None,
+        };
+        let mut networks: HashMap<ServerId, Option<String>> = HashMap::new();
+        let mut in_scope = |target: Option<ServerId>| match (channel.scope, &network) {
+            (ChannelScope::All, _) => true,
+            (ChannelScope::Network, Some(network)) => target.is_some_and(|target| {
+                networks
+                    .entry(target)
+                    .or_insert_with_key(|target| self.network_of(target))
+                    .as_ref()
+                    == Some(network)
+            }),
+            // A server outside any network is its own network
+            (ChannelScope::Network | ChannelScope::Server, _) => {
+                server.is_some() && target == server
+            }
+        };
+
+        for player in self.player_registry.get_all_players() {
+            if player.id() != sender.id()
+                && (!can_use(channel, player.as_ref())
+                    || !in_scope(player.current_server())
+                    || self.is_ignoring(&player.profile().uuid, &sender.profile().uuid))
+            {
+                continue;
+            }
+            let _ = player.send_message(message.clone());
+        }
+    }
+
+    fn network_of(&self, server: &ServerId) -> Option<String> {
+        self.config_service
+            .get_server_config(server)
+            .and_then(|config| config.network)
+    }
+}
+
+/// 1.19.1 and 1.19.2 (protocol 760) chain signed chat messages, so the
+/// proxy can't take them away from the backend.
+pub fn has_chained_chat(player: &dyn Player) -> bool {
+    player.protocol_version() == ProtocolVersion::new(760)
+}
+
+pub fn can_use(channel: &ChannelConfig, player: &dyn Player) -> bool {
+    channel.permission.is_empty() || player.has_permission(&channel.permission)
+}
+
+/// Returns the channel whose prefix starts `message`, and the rest of the
+/// message. The longest matching prefix wins.
+pub fn match_prefix<'a>(
+    channels: &'a BTreeMap<String, ChannelConfig>,
+    message: &'a str,
+) -> Option<(&'a str, &'a str)> {
+    channels
+        .iter()
+        .filter(|(_, channel)| !channel.prefix.is_empty())
+        .filter_map(|(name, channel)| {
+            message
+                .strip_prefix(channel.prefix.as_str())
+                .map(|text| (name.as_str(), channel.prefix.len(), text.trim()))
+        })
+        .max_by_key(|(_, len, _)| *len)
+        .map(|(name, _, text)| (name, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channels() -> BTreeMap<String, ChannelConfig> {
+        let channel = |prefix: &str| ChannelConfig {
+            format: "{player}: {message}".into(),
+            prefix: prefix.into(),
+            permission: String::new(),
+            scope: ChannelScope::All,
+        };
+        BTreeMap::from([
+            ("global".to_string(), channel("!")),
+            ("staff".to_string(), channel("#")),
+            ("admin".to_string(), channel("##")),
+            ("local".to_string(), channel("")),
+        ])
+    }
+
+    #[test]
+    fn test_match_prefix() {
+        let channels = channels();
+        assert_eq!(
+            match_prefix(&channels, "!hi all"),
+            Some(("global", "hi all"))
+        );
+        assert_eq!(match_prefix(&channels, "# hello"), Some(("staff", "hello")));
+        assert_eq!(
+            match_prefix(&channels, "##secret"),
+            Some(("admin", "secret"))
+        );
+        assert_eq!(match_prefix(&channels, "!"), Some(("global", "")));
+        assert_eq!(match_prefix(&channels, "hello!"), None);
+    }
+}
@@ -0,0 +1,304 @@
+use std::sync::Arc;
+
+use infrarust_api::command::{CommandContext, CommandHandler};
+use infrarust_api::event::BoxFuture;
+use infrarust_api::player::Player;
+use infrarust_api::services::player_registry::PlayerRegistry;
+use infrarust_api::types::Component;
+
+use crate::channels::{ChatState, can_use};
+use crate::messaging::SOCIAL_SPY_PERMISSION;
+
+/// `/channel [name|off]` — picks the channel unprefixed chat goes to.
+pub struct ChannelCommand {
+    pub state: Arc<ChatState>,
+}
+
+impl CommandHandler for ChannelCommand {
+    fn execute<'a>(
+        &'a self,
+        ctx: CommandContext,
+        player_registry: &'a dyn PlayerRegistry,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let Some(player) = ctx
+                .player_id
+                .and_then(|id| player_registry.get_player_by_id(id))
+            else {
+                return;
+            };
+            let messages = &self.state.config.messages;
+
+            let reply = match ctx.args.first().map(|arg| arg.to_lowercase()) {
+                None => {
+                    let channels: Vec<&str> = self
+                        .state
+                        .config
+                        .channels
+                        .iter()
+                        .filter(|(_, channel)| can_use(channel, player.as_ref()))
+                        .map(|(name, _)| name.as_str())
+                        .collect();
+                    Component::from_legacy_format(
+                        &messages.channel_list,
+                        &[("channels", &channels.join(", "))],
+                    )
+                }
+                Some(name) if name == "off" => {
+                    self.state.selected.insert(player.id(), String::new());
+                    Component::from_legacy(&messages.channel_off)
+                }
+                Some(name) => match self.state.config.channels.get(&name) {
+                    Some(channel) if can_use(channel, player.as_ref()) => {
+                        self.state.selected.insert(player.id(), name.clone());
+                        Component::from_legacy_format(&messages.channel_set, &[("channel", &name)])
+                    }
+                    _ => Component::from_legacy_format_plain(
+                        &messages.channel_unknown,
+                        &[("channel", &name)],
+                    ),
+                },
+            };
+            let _ = player.send_message(reply);
+        })
+    }
+
+    fn tab_complete(&self, partial_args: &[&str]) -> Vec<String> {
+        match partial_args {
+            [partial] => std::iter::once("off")
+                .chain(self.state.config.channels.keys().map(String::as_str))
+                .filter(|name| name.starts_with(&partial.to_lowercase()))
+                .map(str::to_string)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// `/msg <player> <message>` — sends a private message to a player on any server.
+pub struct MsgCommand {
+    pub state: Arc<ChatState>,
+}
+
+impl CommandHandler for MsgCommand {
+    fn execute<'a>(
+        &'a self,
+        ctx: CommandContext,
+        player_registry: &'a dyn PlayerRegistry,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let Some(player) = ctx
+                .player_id
+                .and_then(|id| player_registry.get_player_by_id(id))
+            else {
+                return;
+            };
+            let messages = &self.state.config.messages;
+
+            let [name, words @ ..] = ctx.args.as_slice() else {
+                let _ = player.send_message(Component::from_legacy(&messages.msg_usage));
+                return;
+            };
+            if words.is_empty() {
+                let _ = player.send_message(Component::from_legacy(&messages.msg_usage));
+                return;
+            }
+            let Some(target) = player_registry.get_player(name) else {
+                let _ = player.send_message(Component::from_legacy_format_plain(
+                    &messages.player_not_found,
+                    &[("player", name)],
+                ));
+                return;
+            };
+            send_private(
+                &self.state,
+                player.as_ref(),
+                target.as_ref(),
+                &words.join(" "),
+            );
+        })
+    }
+
+    fn tab_complete(&self, partial_args: &[&str]) -> Vec<String> {
+        match partial_args {
+            [partial] => complete_player_names(&self.state, partial),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// `/r <message>` — replies to the last private message partner.
+pub struct ReplyCommand {
+    pub state: Arc<ChatState>,
+}
+
+impl CommandHandler for ReplyCommand {
+    fn execute<'a>(
+        &'a self,
+        ctx: CommandContext,
+        player_registry: &'a dyn PlayerRegistry,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let Some(player) = ctx
+                .player_id
+                .and_then(|id| player_registry.get_player_by_id(id))
+            else {
+                return;
+            };
+            let messages = &self.state.config.messages;
+
+            let target = self
+                .state
+                .reply_to
+                .get(&player.id())
+                .map(|uuid| *uuid)
+                .and_then(|uuid| player_registry.get_player_by_uuid(&uuid));
+            let Some(target) = target else {
+                let _ = player.send_message(Component::from_legacy(&messages.no_reply_target));
+                return;
+            };
+            if ctx.args.is_empty() {
+                let _ = player.send_message(Component::from_legacy(&messages.reply_usage));
+                return;
+            }
+            send_private(
+                &self.state,
+                player.as_ref(),
+                target.as_ref(),
+                &ctx.args.join(" "),
+            );
+        })
+    }
+}
+
+/// `/ignore [player]` — toggles ignoring a player, or lists ignored players.
+pub struct IgnoreCommand {
+    pub state: Arc<ChatState>,
+}
+
+impl CommandHandler for IgnoreCommand {
+    fn execute<'a>(
+        &'a self,
+        ctx: CommandContext,
+        player_registry: &'a dyn PlayerRegistry,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let Some(player) = ctx
+                .player_id
+                .and_then(|id| player_registry.get_player_by_id(id))
+            else {
+                return;
+            };
+            let messages = &self.state.config.messages;
+            let uuid = player.profile().uuid;
+
+            let Some(name) = ctx.args.first() else {
+                let ignored = self.state.ignored(&uuid);
+                let names: Vec<&str> = ignored.values().map(String::as_str).collect();
+                let _ = player.send_message(Component::from_legacy_format(
+                    &messages.ignore_list,
+                    &[("players", &names.join(", "))],
+                ));
+                return;
+            };
+
+            // Offline players can still be removed from the list by name
+            let target = player_registry
+                .get_player(name)
+                .map(|target| (target.profile().uuid, target.profile().username.clone()))
+                .or_else(|| {
+                    self.state
+                        .ignored(&uuid)
+                        .into_iter()
+                        .find(|(_, ignored)| ignored.eq_ignore_ascii_case(name))
+                });
+            let Some((target_uuid, target_name)) = target else {
+                let _ = player.send_message(Component::from_legacy_format_plain(
+                    &messages.player_not_found,
+                    &[("player", name)],
+                ));
+                return;
+            };
+            if target_uuid == uuid {
+                let _ = player.send_message(Component::from_legacy(&messages.ignore_self));
+                return;
+            }
+
+            let template = if self.state.toggle_ignore(&uuid, target_uuid, &target_name) {
+                &messages.ignore_added
+            } else {
+                &messages.ignore_removed
+            };
+            let _ = player.send_message(Component::from_legacy_format(
+                template,
+                &[("player", &target_name)],
+            ));
+        })
+    }
+
+    fn tab_complete(&self, partial_args: &[&str]) -> Vec<String> {
+        match partial_args {
+            [partial] => complete_player_names(&self.state, partial),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// `/socialspy` — toggles seeing every private message.
+pub struct SocialSpyCommand {
+    pub state: Arc<ChatState>,
+}
+
+impl CommandHandler for SocialSpyCommand {
+    fn execute<'a>(
+        &'a self,
+        ctx: CommandContext,
+        player_registry: &'a dyn PlayerRegistry,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let Some(player) = ctx
+                .player_id
+                .and_then(|id| player_registry.get_player_by_id(id))
+            else {
+                return;
+            };
+            let messages = &self.state.config.messages;
+
+            if !player.has_permission(SOCIAL_SPY_PERMISSION) {
+                let _ = player.send_message(Component::from_legacy(&messages.no_permission));
+                return;
+            }
+            let uuid = player.profile().uuid;
+            let enabled = !self.state.social_spy(&uuid);
+            self.state.set_social_spy(&uuid, enabled);
+            let template = if enabled {
+                &messages.social_spy_on
+            } else {
+                &messages.social_spy_off
+            };
+            let _ = player.send_message(Component::from_legacy(template));
+        })
+    }
+}
+
+fn send_private(state: &ChatState, sender: &dyn Player, target: &dyn Player, text: &str) {
+    if state.send_private(sender, target, text).is_err() {
+        let _ = sender.send_message(Component::from_legacy_format(
+            &state.config.messages.player_unreachable,
+            &[("player", &target.profile().username)],
+        ));
+    }
+}
+
+fn complete_player_names(state: &ChatState, partial: &str) -> Vec<String> {
+    let partial = partial.to_lowercase();
+    let mut names: Vec<String> = state
+        .player_registry
+        .get_all_players()
+        .iter()
+        .map(|player| player.profile().username.clone())
+        .filter(|name| name.to_lowercase().starts_with(&partial))
+        .collect();
+    names.sort_unstable_by_key(|name| name.to_lowercase());
+    names
+}
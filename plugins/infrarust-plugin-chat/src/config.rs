@@ -0,0 +1,568 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatConfig {
+    /// Channel unprefixed messages go to. Empty leaves them to the backend.
+    #[serde(default)]
+    pub default_channel: String,
+    #[serde(default = "default_channels")]
+    pub channels: BTreeMap<String, ChannelConfig>,
+    #[serde(default)]
+    pub messages: ChatMessages,
+    #[serde(default)]
+    pub filter: FilterConfig,
+}
+
+impl Default for ChatConfig {
+    fn default() -> Self {
+        Self {
+            default_channel: String::new(),
+            channels: default_channels(),
+            messages: ChatMessages::default(),
+            filter: FilterConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelConfig {
+    /// Legacy `&` format with `{player}`, `{server}` and `{message}` placeholders.
+    pub format: String,
+    /// Messages starting with this prefix go to the channel. Empty disables it.
+    #[serde(default)]
+    pub prefix: String,
+    /// Permission needed to read and write the channel. Empty means everyone.
+    #[serde(default)]
+    pub permission: String,
+    #[serde(default)]
+    pub scope: ChannelScope,
+}
+
+/// Which players receive a channel's messages.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChannelScope {
+    /// Everyone on the proxy.
+    #[default]
+    All,
+    /// Players on a server of the sender's network.
+    Network,
+    /// Players on the sender's server.
+    Server,
+}
+
+/// What the filter does with a message that breaks a rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FilterAction {
+    /// The check is disabled.
+    Off,
+    /// Let the message through and warn the sender.
+    Warn,
+    /// Censor the offending part (or lower the caps) and let it through.
+    #[default]
+    Replace,
+    /// Drop the message.
+    Block,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilterConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Players with this permission are never filtered.
+    #[serde(default = "default_bypass_permission")]
+    pub bypass_permission: String,
+    /// Commands whose arguments are filtered like chat.
+    #[serde(default = "default_filtered_commands")]
+    pub commands: Vec<String>,
+    /// Character that replaces censored text.
+    #[serde(default = "default_replacement")]
+    pub replacement: char,
+    /// Writes every violation to `audit.log` in the plugin directory.
+    #[serde(default = "default_true")]
+    pub audit_log: bool,
+    #[serde(default)]
+    pub rules: Vec<FilterRule>,
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
+    #[serde(default)]
+    pub caps: CapsConfig,
+    #[serde(default)]
+    pub unicode: UnicodeConfig,
+    #[serde(default)]
+    pub links: LinksConfig,
+    #[serde(default)]
+    pub escalation: EscalationConfig,
+}
+
+impl Default for FilterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            bypass_permission: default_bypass_permission(),
+            commands: default_filtered_commands(),
+            replacement: default_replacement(),
+            audit_log: true,
+            rules: Vec::new(),
+            rate_limit: RateLimitConfig::default(),
+            caps: CapsConfig::default(),
+            unicode: UnicodeConfig::default(),
+            links: LinksConfig::default(),
+            escalation: EscalationConfig::default(),
+        }
+    }
+}
+
+/// A wordlist and/or regex filter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilterRule {
+    pub name: String,
+    /// Whole words matched without case.
+    #[serde(default)]
+    pub words: Vec<String>,
+    /// Regular expressions, case-sensitive unless they use `(?i)`.
+    #[serde(default)]
+    pub patterns: Vec<String>,
+    #[serde(default)]
+    pub action: FilterAction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// Messages allowed per interval. `0` disables rate limiting.
+    #[serde(default = "default_max_messages")]
+    pub max_messages: usize,
+    #[serde(default = "default_interval")]
+    pub interval_seconds: u64,
+    /// How long a message can't be repeated. `0` allows duplicates.
+    #[serde(default = "default_duplicate_window")]
+    pub duplicate_window_seconds: u64,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            max_messages: default_max_messages(),
+            interval_seconds: default_interval(),
+            duplicate_window_seconds: default_duplicate_window(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapsConfig {
+    #[serde(default)]
+    pub action: FilterAction,
+    /// Messages with fewer letters are never checked.
+    #[serde(default = "default_caps_min_letters")]
+    pub min_letters: usize,
+    /// Highest share of uppercase letters allowed, from 0 to 1.
+    #[serde(default = "default_caps_max_ratio")]
+    pub max_ratio: f64,
+}
+
+impl Default for CapsConfig {
+    fn default() -> Self {
+        Self {
+            action: FilterAction::Replace,
+            min_letters: default_caps_min_letters(),
+            max_ratio: default_caps_max_ratio(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnicodeConfig {
+    /// Folds fullwidth letters to ASCII and strips zero-width characters
+    /// and stacked combining marks before filtering.
+    #[serde(default = "default_true")]
+    pub normalize: bool,
+}
+
+impl Default for UnicodeConfig {
+    fn default() -> Self {
+        Self { normalize: true }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinksConfig {
+    #[serde(default = "default_links_action")]
+    pub action: FilterAction,
+    /// Domains (and their subdomains) that are always allowed.
+    #[serde(default)]
+    pub allowlist: Vec<String>,
+}
+
+impl Default for LinksConfig {
+    fn default() -> Self {
+        Self {
+            action: default_links_action(),
+            allowlist: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EscalationConfig {
+    /// How long a violation counts towards escalation.
+    #[serde(default = "default_escalation_window")]
+    pub window_seconds: u64,
+    #[serde(default = "default_escalation_steps")]
+    pub steps: Vec<EscalationStep>,
+}
+
+impl Default for EscalationConfig {
+    fn default() -> Self {
+        Self {
+            window_seconds: default_escalation_window(),
+            steps: default_escalation_steps(),
+        }
+    }
+}
+
+/// A penalty applied when a player reaches a number of violations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EscalationStep {
+    pub violations: usize,
+    pub action: EscalationAction,
+    /// Penalty length. `0` bans permanently; mutes need a duration.
+    #[serde(default)]
+    pub duration_seconds: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EscalationAction {
+    /// Blocks the player's chat and filtered commands.
+    Mute,
+    /// Bans the player through the proxy's ban service.
+    Ban,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessages {
+    #[serde(default = "default_channel_set")]
+    pub channel_set: String,
+    #[serde(default = "default_channel_off")]
+    pub channel_off: String,
+    #[serde(default = "default_channel_unknown")]
+    pub channel_unknown: String,
+    #[serde(default = "default_channel_list")]
+    pub channel_list: String,
+    #[serde(default = "default_msg_sent")]
+    pub msg_sent: String,
+    #[serde(default = "default_msg_received")]
+    pub msg_received: String,
+    #[serde(default = "default_social_spy")]
+    pub social_spy: String,
+    #[serde(default = "default_msg_usage")]
+    pub msg_usage: String,
+    #[serde(default = "default_reply_usage")]
+    pub reply_usage: String,
+    #[serde(default = "default_player_not_found")]
+    pub player_not_found: String,
+    #[serde(default = "default_player_unreachable")]
+    pub player_unreachable: String,
+    #[serde(default = "default_no_reply_target")]
+    pub no_reply_target: String,
+    #[serde(default = "default_ignore_added")]
+    pub ignore_added: String,
+    #[serde(default = "default_ignore_removed")]
+    pub ignore_removed: String,
+    #[serde(default = "default_ignore_list")]
+    pub ignore_list: String,
+    #[serde(default = "default_ignore_self")]
+    pub ignore_self: String,
+    #[serde(default = "default_social_spy_on")]
+    pub social_spy_on: String,
+    #[serde(default = "default_social_spy_off")]
+    pub social_spy_off: String,
+    #[serde(default = "default_no_permission")]
+    pub no_permission: String,
+    #[serde(default = "default_filter_blocked")]
+    pub filter_blocked: String,
+    #[serde(default = "default_filter_warned")]
+    pub filter_warned: String,
+    #[serde(default = "default_rate_limited")]
+    pub rate_limited: String,
+    #[serde(default = "default_duplicate_message")]
+    pub duplicate_message: String,
+    #[serde(default = "default_muted")]
+    pub muted: String,
+    #[serde(default = "default_muted_notice")]
+    pub muted_notice: String,
+    #[serde(default = "default_ban_reason")]
+    pub ban_reason: String,
+}
+
+impl Default for ChatMessages {
+    fn default() -> Self {
+        Self {
+            channel_set: default_channel_set(),
+            channel_off: default_channel_off(),
+            channel_unknown: default_channel_unknown(),
+            channel_list: default_channel_list(),
+            msg_sent: default_msg_sent(),
+            msg_received: default_msg_received(),
+            social_spy: default_social_spy(),
+            msg_usage: default_msg_usage(),
+            reply_usage: default_reply_usage(),
+            player_not_found: default_player_not_found(),
+            player_unreachable: default_player_unreachable(),
+            no_reply_target: default_no_reply_target(),
+            ignore_added: default_ignore_added(),
+            ignore_removed: default_ignore_removed(),
+            ignore_list: default_ignore_list(),
+            ignore_self: default_ignore_self(),
+            social_spy_on: default_social_spy_on(),
+            social_spy_off: default_social_spy_off(),
+            no_permission: default_no_permission(),
+            filter_blocked: default_filter_blocked(),
+            filter_warned: default_filter_warned(),
+            rate_limited: default_rate_limited(),
+            duplicate_message: default_duplicate_message(),
+            muted: default_muted(),
+            muted_notice: default_muted_notice(),
+            ban_reason: default_ban_reason(),
+        }
+    }
+}
+
+fn default_channels() -> BTreeMap<String, ChannelConfig> {
+    BTreeMap::from([
+        (
+            "global".to_string(),
+            ChannelConfig {
+                format: "&8[&aG&8] &f{player}&7: &f{message}".into(),
+                prefix: "!".into(),
+                permission: String::new(),
+                scope: ChannelScope::All,
+            },
+        ),
+        (
+            "staff".to_string(),
+            ChannelConfig {
+                format: "&8[&cStaff&8] &7{server} &f{player}&7: &e{message}".into(),
+                prefix: "#".into(),
+                permission: "infrarust.chat.staff".into(),
+                scope: ChannelScope::All,
+            },
+        ),
+    ])
+}
+
+fn default_channel_set() -> String {
+    "&7You are now talking in &e{channel}&7.".into()
+}
+fn default_channel_off() -> String {
+    "&7Your messages now go to your server.".into()
+}
+fn default_channel_unknown() -> String {
+    "&cUnknown channel: {channel}".into()
+}
+fn default_channel_list() -> String {
+    "&7Channels: &e{channels}".into()
+}
+fn default_msg_sent() -> String {
+    "&8[&dme &7→ &d{player}&8] &f{message}".into()
+}
+fn default_msg_received() -> String {
+    "&8[&d{player} &7→ &dme&8] &f{message}".into()
+}
+fn default_social_spy() -> String {
+    "&8[&7Spy&8] &7{sender} → {target}: {message}".into()
+}
+fn default_msg_usage() -> String {
+    "&cUsage: /msg <player> <message>".into()
+}
+fn default_reply_usage() -> String {
+    "&cUsage: /r <message>".into()
+}
+fn default_player_not_found() -> String {
+    "&c{player} is not online.".into()
+}
+fn default_player_unreachable() -> String {
+    "&c{player} can't receive messages right now.".into()
+}
+fn default_no_reply_target() -> String {
+    "&cYou have nobody to reply to.".into()
+}
+fn default_ignore_added() -> String {
+    "&7You are now ignoring &e{player}&7.".into()
+}
+fn default_ignore_removed() -> String {
+    "&7You are no longer ignoring &e{player}&7.".into()
+}
+fn default_ignore_list() -> String {
+    "&7Ignored players: &e{players}".into()
+}
+fn default_ignore_self() -> String {
+    "&cYou can't ignore yourself.".into()
+}
+fn default_social_spy_on() -> String {
+    "&7Social spy &aenabled&7.".into()
+}
+fn default_social_spy_off() -> String {
+    "&7Social spy &cdisabled&7.".into()
+}
+fn default_no_permission() -> String {
+    "&cYou don't have permission.".into()
+}
+fn default_filter_blocked() -> String {
+    "&cYour message was blocked by the chat filter.".into()
+}
+fn default_filter_warned() -> String {
+    "&eMind your language, {player}.".into()
+}
+fn default_rate_limited() -> String {
+    "&cYou are sending messages too fast.".into()
+}
+fn default_duplicate_message() -> String {
+    "&cPlease don't repeat yourself.".into()
+}
+fn default_muted() -> String {
+    "&cYou are muted for {remaining}s.".into()
+}
+fn default_muted_notice() -> String {
+    "&cYou have been muted for {duration}s for breaking the chat rules.".into()
+}
+fn default_ban_reason() -> String {
+    "Breaking the chat rules".into()
+}
+
+const fn default_true() -> bool {
+    true
+}
+fn default_bypass_permission() -> String {
+    "infrarust.chat.filter.bypass".into()
+}
+fn default_filtered_commands() -> Vec<String> {
+    [
+        "me", "msg", "tell", "w", "whisper", "m", "r", "reply", "say", "teammsg", "tm",
+    ]
+    .map(String::from)
+    .to_vec()
+}
+const fn default_replacement() -> char {
+    '*'
+}
+const fn default_max_messages() -> usize {
+    5
+}
+const fn default_interval() -> u64 {
+    5
+}
+const fn default_duplicate_window() -> u64 {
+    30
+}
+const fn default_caps_min_letters() -> usize {
+    8
+}
+const fn default_caps_max_ratio() -> f64 {
+    0.7
+}
+const fn default_links_action() -> FilterAction {
+    FilterAction::Off
+}
+const fn default_escalation_window() -> u64 {
+    600
+}
+fn default_escalation_steps() -> Vec<EscalationStep> {
+    vec![EscalationStep {
+        violations: 5,
+        action: EscalationAction::Mute,
+        duration_seconds: 300,
+    }]
+}
+
+pub async fn load_or_create_config(path: &Path) -> Result<ChatConfig, String> {
+    if path.exists() {
+        let content = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| format!("failed to read config: {e}"))?;
+        toml::from_str(&content).map_err(|e| format!("failed to parse config: {e}"))
+    } else {
+        let config = ChatConfig::default();
+        let content = toml::to_string_pretty(&config)
+            .map_err(|e| format!("failed to serialize config: {e}"))?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| format!("failed to create config dir: {e}"))?;
+        }
+        tokio::fs::write(path, &content)
+            .await
+            .map_err(|e| format!("failed to write config: {e}"))?;
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_config_roundtrip() {
+        let config = ChatConfig::default();
+        let serialized = toml::to_string_pretty(&config).unwrap();
+        let deserialized: ChatConfig = toml::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.channels.len(), 2);
+        assert_eq!(deserialized.channels["staff"].prefix, "#");
+        assert_eq!(
+            deserialized.channels["staff"].permission,
+            "infrarust.chat.staff"
+        );
+        assert!(deserialized.default_channel.is_empty());
+        assert!(deserialized.filter.enabled);
+        assert_eq!(deserialized.filter.links.action, FilterAction::Off);
+        assert_eq!(deserialized.filter.escalation.steps.len(), 1);
+    }
+
+    #[test]
+    fn test_filter_rules() {
+        let config: ChatConfig = toml::from_str(
+            "[[filter.rules]]\n\
+             name = \"profanity\"\n\
+             words = [\"darn\"]\n\
+             [[filter.rules]]\n\
+             name = \"ads\"\n\
+             patterns = [\"(?i)join my server\"]\n\
+             action = \"block\"\n\
+             [filter.links]\n\
+             action = \"block\"\n\
+             allowlist = [\"example.com\"]\n",
+        )
+        .unwrap();
+        let rules = &config.filter.rules;
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].action, FilterAction::Replace);
+        assert_eq!(rules[1].action, FilterAction::Block);
+        assert_eq!(config.filter.links.allowlist, vec!["example.com"]);
+        assert_eq!(config.filter.rate_limit.max_messages, 5);
+        assert_eq!(config.filter.caps.action, FilterAction::Replace);
+    }
+
+    #[test]
+    fn test_custom_channels_replace_defaults() {
+        let config: ChatConfig = toml::from_str(
+            "default_channel = \"lobby\"\n\
+             [channels.lobby]\n\
+             format = \"{player}: {message}\"\n\
+             scope = \"network\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.channels.len(), 1);
+        let lobby = &config.channels["lobby"];
+        assert_eq!(lobby.scope, ChannelScope::Network);
+        assert!(lobby.prefix.is_empty());
+        assert!(lobby.permission.is_empty());
+        assert_eq!(config.messages.channel_set, default_channel_set());
+    }
+}
@@ -0,0 +1,438 @@
+use std::fmt;
+use std::net::Ipv4Addr;
+use std::ops::Range;
+
+use regex::{Captures, Regex};
+
+use crate::config::{CapsConfig, FilterAction, FilterConfig};
+
+/// Top-level domains treated as links even without a scheme. Anything else
+/// needs `http://` or similar, so "e.g" or "1.20.4" are left alone.
+const LINK_TLDS: &[&str] = &[
+    "com", "net", "org", "io", "gg", "me", "co", "tv", "xyz", "info", "biz", "us", "uk", "de",
+    "fr", "eu", "ru", "ly", "to", "cc", "link", "club", "online", "site", "shop", "fun",
+];
+
+/// Why a message was flagged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolationKind {
+    /// A configured word or pattern rule, by name.
+    Rule(String),
+    Link,
+    Caps,
+    RateLimit,
+    Duplicate,
+}
+
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rule(name) => write!(f, "rule:{name}"),
+            Self::Link => f.write_str("link"),
+            Self::Caps => f.write_str("caps"),
+            Self::RateLimit => f.write_str("rate_limit"),
+            Self::Duplicate => f.write_str("duplicate"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub kind: ViolationKind,
+    pub action: FilterAction,
+}
+
+/// Result of running a message through the [`ChatFilter`].
+#[derive(Debug)]
+pub struct FilterOutcome {
+    /// The message with replacements applied, `None` if no replacement or
+    /// caps action changed it.
+    pub text: Option<String>,
+    pub violations: Vec<Violation>,
+}
+
+impl FilterOutcome {
+    pub fn blocked(&self) -> bool {
+        self.violations
+            .iter()
+            .any(|v| v.action == FilterAction::Block)
+    }
+
+    pub fn warned(&self) -> bool {
+        self.violations
+            .iter()
+            .any(|v| v.action == FilterAction::Warn)
+    }
+}
+
+struct Rule {
+    name: String,
+    action: FilterAction,
+    patterns: Vec<Regex>,
+}
+
+/// The content checks of the chat filter: word and regex rules, links, caps
+/// and unicode normalization. Rate limiting lives in the moderator since it
+/// needs per-player state.
+pub struct ChatFilter {
+    rules: Vec<Rule>,
+    normalize: bool,
+    caps: CapsConfig,
+    links: FilterAction,
+    allowlist: Vec<String>,
+    link_pattern: Regex,
+    replacement: char,
+}
+
+impl ChatFilter {
+    /// Compiles the filter rules.
+    ///
+    /// # Errors
+    /// Returns a message naming the rule if one of its patterns is not a
+    /// valid regular expression.
+    pub fn new(config: &FilterConfig) -> Result<Self, String> {
+        let mut rules = Vec::with_capacity(config.rules.len());
+        for rule in &config.rules {
+            let mut patterns = Vec::with_capacity(rule.patterns.len() + 1);
+            if !rule.words.is_empty() {
+                let words: Vec<String> = rule.words.iter().map(|w| regex::escape(w)).collect();
+                patterns.push(compile(
+                    &rule.name,
+                    &format!(r"(?i)\b(?:{})\b", words.join("|")),
+                )?);
+            }
+            for pattern in &rule.patterns {
+                patterns.push(compile(&rule.name, pattern)?);
+            }
+            rules.push(Rule {
+                name: rule.name.clone(),
+                action: rule.action,
+                patterns,
+            });
+        }
+
+        Ok(Self {
+            rules,
+            normalize: config.unicode.normalize,
+            caps: config.caps.clone(),
+            links: config.links.action,
+            allowlist: config
+                .links
+                .allowlist
+                .iter()
+                .map(|domain| domain.trim_start_matches("*.").to_lowercase())
+                .collect(),
+            link_pattern: compile(
+                "links",
+                r"(?i)(?P<scheme>[a-z][a-z0-9+.-]*://)?(?P<host>(?:[a-z0-9-]+\.)+[a-z0-9-]+)(?::\d+)?(?:/\S*)?",
+            )?,
+            replacement: config.replacement,
+        })
+    }
+
+    /// Runs every content check over `message`.
+    ///
+    /// Checks run on the normalized message, but replacements are applied
+    /// to the message as sent, so text is only changed where a rule asks
+    /// for it.
+    pub fn check(&self, message: &str) -> FilterOutcome {
+        let normalized = if self.normalize {
+            Normalized::new(message)
+        } else {
+            Normalized::identity(message)
+        };
+        let text = normalized.text.as_str();
+        let mut masks = Vec::new();
+        let mut violations = Vec::new();
+
+        for rule in &self.rules {
+            if rule.action == FilterAction::Off || !rule.patterns.iter().any(|p| p.is_match(text)) {
+                continue;
+            }
+            if rule.action == FilterAction::Replace {
+                // Empty matches have nothing to mask
+                for pattern in &rule.patterns {
+                    masks.extend(
+                        pattern
+                            .find_iter(text)
+                            .filter(|m| !m.is_empty())
+                            .map(|m| normalized.original(m.range())),
+                    );
+                }
+            }
+            violations.push(Violation {
+                kind: ViolationKind::Rule(rule.name.clone()),
+                action: rule.action,
+            });
+        }
+
+        if self.links != FilterAction::Off {
+            let links: Vec<Range<usize>> = self
+                .link_pattern
+                .captures_iter(text)
+                .filter(|caps| self.is_blocked_link(caps))
+                .filter_map(|caps| caps.get(0))
+                .map(|m| normalized.original(m.range()))
+                .collect();
+            if !links.is_empty() {
+                if self.links == FilterAction::Replace {
+                    masks.extend(links);
+                }
+                violations.push(Violation {
+                    kind: ViolationKind::Link,
+                    action: self.links,
+                });
+            }
+        }
+
+        let mut replaced = (!masks.is_empty()).then(|| self.apply_masks(message, masks));
+        if self.caps.action != FilterAction::Off && self.is_shouting(text) {
+            if self.caps.action == FilterAction::Replace {
+                replaced = Some(replaced.as_deref().unwrap_or(message).to_lowercase());
+            }
+            violations.push(Violation {
+                kind: ViolationKind::Caps,
+                action: self.caps.action,
+            });
+        }
+
+        FilterOutcome {
+            text: replaced.filter(|replaced| replaced != message),
+            violations,
+        }
+    }
+
+    /// Masks the given byte ranges of `message`, which may overlap.
+    fn apply_masks(&self, message: &str, mut masks: Vec<Range<usize>>) -> String {
+        masks.sort_unstable_by_key(|range| range.start);
+        let mut out = String::with_capacity(message.len());
+        let mut pos = 0;
+        for range in masks {
+            if range.end <= pos {
+                continue;
+            }
+            let start = range.start.max(pos);
+            out.push_str(&message[pos..start]);
+            out.push_str(&self.mask(&message[start..range.end]));
+            pos = range.end;
+        }
+        out.push_str(&message[pos..]);
+        out
+    }
+
+    /// One replacement character per visible character of `matched`.
+    fn mask(&self, matched: &str) -> String {
+        let visible = matched.chars().filter_map(normalize_char).count();
+        std::iter::repeat_n(self.replacement, visible).collect()
+    }
+
+    fn is_blocked_link(&self, caps: &Captures<'_>) -> bool {
+        let host = caps["host"].to_lowercase();
+        let is_link = caps.name("scheme").is_some()
+            || host.parse::<Ipv4Addr>().is_ok()
+            || host
+                .rsplit('.')
+                .next()
+                .is_some_and(|tld| LINK_TLDS.contains(&tld));
+        is_link
+            && !self.allowlist.iter().any(|domain| {
+                host == *domain
+                    || host
+                        .strip_suffix(domain.as_str())
+                        .is_some_and(|sub| sub.ends_with('.'))
+            })
+    }
+
+    fn is_shouting(&self, text: &str) -> bool {
+        let (letters, upper) = text
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .fold((0usize, 0usize), |(letters, upper), c| {
+                (letters + 1, upper + usize::from(c.is_uppercase()))
+            });
+        #[allow(clippy::cast_precision_loss)]
+        let ratio = upper as f64 / letters.max(1) as f64;
+        letters >= self.caps.min_letters && ratio > self.caps.max_ratio
+    }
+}
+
+fn compile(rule: &str, pattern: &str) -> Result<Regex, String> {
+    Regex::new(pattern).map_err(|e| format!("invalid pattern in filter rule '{rule}': {e}"))
+}
+
+/// Folds characters commonly used to slip past filters: fullwidth letters
+/// become ASCII, zero-width characters and combining marks are dropped.
+fn normalize_char(c: char) -> Option<char> {
+    match c {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0),
+        '\u{3000}' => Some(' '),
+        '\u{00AD}'
+        | '\u{200B}'..='\u{200F}'
+        | '\u{2060}'..='\u{2064}'
+        | '\u{FEFF}'
+        | '\u{0300}'..='\u{036F}' => None,
+        c => Some(c),
+    }
+}
+
+/// A message as the checks see it, mapped back to the message as sent.
+struct Normalized {
+    text: String,
+    /// For each byte of `text`, the byte range of the original character
+    /// it comes from.
+    origins: Vec<Range<usize>>,
+}
+
+impl Normalized {
+    fn new(message: &str) -> Self {
+        let mut text = String::with_capacity(message.len());
+        let mut origins = Vec::with_capacity(message.len());
+        for (start, c) in message.char_indices() {
+            if let Some(folded) = normalize_char(c) {
+                text.push(folded);
+                let origin = start..start + c.len_utf8();
+                origins.extend(std::iter::repeat_n(origin, folded.len_utf8()));
+            }
+        }
+        Self { text, origins }
+    }
+
+    fn identity(message: &str) -> Self {
+        Self {
+            text: message.to_string(),
+            origins: Vec::new(),
+        }
+    }
+
+    /// Maps a non-empty byte range of `text` to the original message.
+    /// Characters dropped inside the range are covered too.
+    fn original(&self, range: Range<usize>) -> Range<usize> {
+        debug_assert!(!range.is_empty(), "empty ranges can't be mapped back");
+        if self.origins.is_empty() {
+            return range;
+        }
+        self.origins[range.start].start..self.origins[range.end - 1].end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::config::FilterRule;
+
+    fn filter(config: impl FnOnce(&mut FilterConfig)) -> ChatFilter {
+        let mut filter_config = FilterConfig::default();
+        config(&mut filter_config);
+        ChatFilter::new(&filter_config).unwrap()
+    }
+
+    fn rule(name: &str, words: &[&str], patterns: &[&str], action: FilterAction) -> FilterRule {
+        FilterRule {
+            name: name.to_string(),
+            words: words.iter().map(|w| w.to_string()).collect(),
+            patterns: patterns.iter().map(|p| p.to_string()).collect(),
+            action,
+        }
+    }
+
+    #[test]
+    fn test_word_rules() {
+        let filter = filter(|c| {
+            c.rules = vec![
+                rule("swear", &["darn"], &[], FilterAction::Replace),
+                rule("ads", &[], &["(?i)join my server"], FilterAction::Block),
+            ];
+        });
+
+        let outcome = filter.check("Darn it, darnation");
+        assert_eq!(outcome.text.as_deref(), Some("**** it, darnation"));
+        assert!(!outcome.blocked());
+        assert_eq!(
+            outcome.violations[0].kind,
+            ViolationKind::Rule("swear".into())
+        );
+
+        assert!(filter.check("pls JOIN MY SERVER").blocked());
+        assert!(filter.check("hello there").violations.is_empty());
+    }
+
+    #[test]
+    fn test_normalization_defeats_evasion() {
+        let filter = filter(|c| c.rules = vec![rule("swear", &["darn"], &[], FilterAction::Warn)]);
+        let outcome = filter.check("ｄa\u{200B}r\u{0301}n");
+        assert!(outcome.text.is_none());
+        assert!(outcome.warned());
+    }
+
+    #[test]
+    fn test_replacements_keep_the_original_text() {
+        let filter = filter(|c| {
+            c.rules = vec![rule("swear", &["darn"], &[], FilterAction::Replace)];
+            c.links.action = FilterAction::Replace;
+        });
+
+        let outcome = filter.check("ｄa\u{200B}rn! Ｃafé visit evil.com");
+        assert_eq!(outcome.text.as_deref(), Some("****! Ｃafé visit ********"));
+        // Nothing to replace: fullwidth and combining characters are sent as is
+        let outcome = filter.check("ｈｅｌｌｏ cafe\u{0301}");
+        assert!(outcome.text.is_none());
+        assert!(outcome.violations.is_empty());
+    }
+
+    #[test]
+    fn test_empty_matches_are_not_masked() {
+        let filter = filter(|c| c.rules = vec![rule("x", &[], &["x*"], FilterAction::Replace)]);
+        let outcome = filter.check("ｄｄ");
+        assert_eq!(outcome.text, None);
+
+        let outcome = filter.check("ｘｄ");
+        assert_eq!(outcome.text.as_deref(), Some("*ｄ"));
+    }
+
+    #[test]
+    fn test_links() {
+        let filter = filter(|c| {
+            c.links.action = FilterAction::Block;
+            c.links.allowlist = vec!["*.example.com".into()];
+        });
+
+        assert!(filter.check("join play.badserver.net now").blocked());
+        assert!(filter.check("see https://foo.bar/page").blocked());
+        assert!(filter.check("connect to 10.0.0.1:25565").blocked());
+        assert!(!filter.check("wiki.example.com has it").blocked());
+        assert!(!filter.check("e.g. update to 1.20.4").blocked());
+        assert!(
+            !filter
+                .check("notexample.com.evil.org")
+                .violations
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_caps() {
+        let filter = filter(|_| {});
+        let outcome = filter.check("WHY IS NOBODY HERE");
+        assert_eq!(outcome.text.as_deref(), Some("why is nobody here"));
+        assert_eq!(outcome.violations[0].kind, ViolationKind::Caps);
+        // Short messages and acronyms are fine
+        assert!(filter.check("GG").violations.is_empty());
+        assert!(
+            filter
+                .check("I love the NBA and the NFL")
+                .violations
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_rejected() {
+        let config = FilterConfig {
+            rules: vec![rule("broken", &[], &["(unclosed"], FilterAction::Block)],
+            ..FilterConfig::default()
+        };
+        let err = ChatFilter::new(&config).err().unwrap();
+        assert!(err.contains("broken"));
+    }
+}
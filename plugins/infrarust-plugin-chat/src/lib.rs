@@ -0,0 +1,165 @@
+//! Network-wide chat channels and private messages for Infrarust.
+//!
+//! Messages starting with a channel prefix (or sent while a channel is
+//! selected with `/channel`) are consumed by the proxy and relayed to every
+//! player allowed to read the channel, whichever backend they are on.
+//! `/msg`, `/r`, `/ignore` and `/socialspy` work the same way across servers.
+//! Chat and chat-like commands first go through a moderation pipeline of
+//! word and regex filters, rate limits and escalating mutes or bans.
+
+pub mod channels;
+pub mod command;
+pub mod config;
+pub mod filter;
+pub mod messaging;
+pub mod moderation;
+
+use std::sync::Arc;
+
+use infrarust_api::error::PluginError;
+use infrarust_api::event::bus::EventBusExt;
+use infrarust_api::event::{BoxFuture, EventPriority};
+use infrarust_api::events::chat::{ChatMessageEvent, CommandExecuteEvent};
+use infrarust_api::events::lifecycle::{DisconnectEvent, PostLoginEvent};
+use infrarust_api::plugin::{Plugin, PluginContext, PluginMetadata};
+
+use crate::channels::ChatState;
+use crate::command::{ChannelCommand, IgnoreCommand, MsgCommand, ReplyCommand, SocialSpyCommand};
+use crate::config::load_or_create_config;
+use crate::filter::ChatFilter;
+use crate::moderation::Moderator;
+
+#[derive(Default)]
+pub struct ChatPlugin;
+
+impl Plugin for ChatPlugin {
+    fn metadata(&self) -> PluginMetadata {
+        PluginMetadata::new("chat", "Chat Plugin", "0.1.0")
+            .author("Infrarust")
+            .description("Cross-server chat channels, private messages and chat moderation")
+    }
+
+    fn on_enable<'a>(
+        &'a self,
+        ctx: &'a dyn PluginContext,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        Box::pin(async move {
+            let config_path = ctx.data_dir().join("config.toml");
+            let config = load_or_create_config(&config_path)
+                .await
+                .map_err(PluginError::InitFailed)?;
+
+            let channel_count = config.channels.len();
+            let state = Arc::new(ChatState::new(
+                config,
+                ctx.player_registry_handle(),
+                ctx.config_service_handle(),
+                ctx.player_data_handle(),
+            ));
+
+            if state.config.filter.enabled {
+                let filter =
+                    ChatFilter::new(&state.config.filter).map_err(PluginError::InitFailed)?;
+                let audit_path = state
+                    .config
+                    .filter
+                    .audit_log
+                    .then(|| ctx.data_dir().join("audit.log"));
+                let moderator = Arc::new(Moderator::new(
+                    Arc::clone(&state),
+                    filter,
+                    ctx.ban_service_handle(),
+                    audit_path,
+                ));
+
+                // Runs before channels so relayed messages are filtered too
+                let chat = Arc::clone(&moderator);
+                ctx.event_bus().subscribe(
+                    EventPriority::EARLY,
+                    move |event: &mut ChatMessageEvent| {
+                        chat.on_chat(event);
+                    },
+                );
+                let commands = Arc::clone(&moderator);
+                ctx.event_bus().subscribe(
+                    EventPriority::EARLY,
+                    move |event: &mut CommandExecuteEvent| {
+                        commands.on_command(event);
+                    },
+                );
+                ctx.event_bus().subscribe(
+                    EventPriority::NORMAL,
+                    move |event: &mut DisconnectEvent| {
+                        moderator.forget(event.player_id);
+                    },
+                );
+            }
+
+            let chat = Arc::clone(&state);
+            ctx.event_bus().subscribe(
+                EventPriority::NORMAL,
+                move |event: &mut ChatMessageEvent| {
+                    chat.on_chat(event);
+                },
+            );
+
+            let chat = Arc::clone(&state);
+            ctx.event_bus()
+                .subscribe(EventPriority::NORMAL, move |event: &mut PostLoginEvent| {
+                    chat.load_preferences(&event.profile.uuid);
+                });
+
+            let chat = Arc::clone(&state);
+            ctx.event_bus()
+                .subscribe(EventPriority::NORMAL, move |event: &mut DisconnectEvent| {
+                    chat.selected.remove(&event.player_id);
+                    chat.reply_to.remove(&event.player_id);
+                    if let Some(player) = chat.player_registry.get_player_by_id(event.player_id) {
+                        chat.forget_preferences(&player.profile().uuid);
+                    }
+                });
+
+            ctx.command_manager().register(
+                "channel",
+                &["ch"],
+                "Selects the chat channel your messages go to",
+                Box::new(ChannelCommand {
+                    state: Arc::clone(&state),
+                }),
+            );
+            ctx.command_manager().register(
+                "msg",
+                &["tell", "w", "whisper", "m"],
+                "Sends a private message to a player on any server",
+                Box::new(MsgCommand {
+                    state: Arc::clone(&state),
+                }),
+            );
+            ctx.command_manager().register(
+                "r",
+                &["reply"],
+                "Replies to your last private message",
+                Box::new(ReplyCommand {
+                    state: Arc::clone(&state),
+                }),
+            );
+            ctx.command_manager().register(
+                "ignore",
+                &[],
+                "Ignores or unignores a player, or lists ignored players",
+                Box::new(IgnoreCommand {
+                    state: Arc::clone(&state),
+                }),
+            );
+            ctx.command_manager().register(
+                "socialspy",
+                &["spy"],
+                "Shows every private message sent on the proxy",
+                Box::new(SocialSpyCommand { state }),
+            );
+
+            tracing::info!(channels = channel_count, "[ChatPlugin] Enabled");
+            Ok(())
+        })
+    }
+}
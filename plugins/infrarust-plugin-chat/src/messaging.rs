@@ -0,0 +1,564 @@
+use std::collections::BTreeMap;
+
+use infrarust_api::error::PlayerError;
+use infrarust_api::player::Player;
+use infrarust_api::types::Component;
+use uuid::Uuid;
+
+use crate::channels::ChatState;
+
+/// Player data namespace for ignore lists and social spy toggles.
+const NAMESPACE: &str = "chat";
+const IGNORED_KEY: &str = "ignored";
+const SOCIAL_SPY_KEY: &str = "socialspy";
+
+/// Permission needed to use `/socialspy`.
+pub const SOCIAL_SPY_PERMISSION: &str = "infrarust.chat.socialspy";
+
+/// A player's ignore list and social spy toggle.
+#[derive(Debug, Clone, Default)]
+pub struct Preferences {
+    /// Ignored players by UUID, with their last known name.
+    pub ignored: BTreeMap<Uuid, String>,
+    pub social_spy: bool,
+}
+
+impl ChatState {
+    /// Reads a player's preferences from the data store into the cache.
+    pub fn load_preferences(&self, player: &Uuid) {
+        let preferences = self.read_preferences(player);
+        self.preferences.insert(*player, preferences);
+    }
+
+    /// Drops a player's cached preferences, once they leave.
+    pub fn forget_preferences(&self, player: &Uuid) {
+        self.preferences.remove(player);
+    }
+
+    fn read_preferences(&self, player: &Uuid) -> Preferences {
+        Preferences {
+            ignored: self
+                .player_data
+                .get(player, NAMESPACE, IGNORED_KEY)
+                .ok()
+                .flatten()
+                .unwrap_or_default(),
+            social_spy: self
+                .player_data
+                .get(player, NAMESPACE, SOCIAL_SPY_KEY)
+                .ok()
+                .flatten()
+                .unwrap_or(false),
+        }
+    }
+
+    /// Runs `f` on the cached preferences, reading them first if they
+    /// aren't cached yet.
+    fn with_preferences<T>(&self, player: &Uuid, f: impl FnOnce(&mut Preferences) -> T) -> T {
+        let mut preferences = self
+            .preferences
+            .entry(*player)
+            .or_insert_with(|| self.read_preferences(player));
+        f(&mut preferences)
+    }
+
+    /// Returns the players `player` ignores, by UUID with their last known name.
+    pub fn ignored(&self, player: &Uuid) -> BTreeMap<Uuid, String> {
+        if let Some(preferences) = self.preferences.get(player) {
+            return preferences.ignored.clone();
+        }
+        self.with_preferences(player, |p| p.ignored.clone())
+    }
+
+    pub fn is_ignoring(&self, player: &Uuid, other: &Uuid) -> bool {
+        if let Some(preferences) = self.preferences.get(player) {
+            return preferences.ignored.contains_key(other);
+        }
+        self.with_preferences(player, |p| p.ignored.contains_key(other))
+    }
+
+    /// Starts or stops ignoring `other`. Returns whether `other` is now ignored.
+    pub fn toggle_ignore(&self, player: &Uuid, other: Uuid, name: &str) -> bool {
+        let (now_ignored, ignored) = self.with_preferences(player, |p| {
+            let now_ignored = p.ignored.remove(&other).is_none();
+            if now_ignored {
+                p.ignored.insert(other, name.to_string());
+            }
+            (now_ignored, p.ignored.clone())
+        });
+
+        if ignored.is_empty() {
+            self.player_data.remove(player, NAMESPACE, IGNORED_KEY);
+        } else if let Err(e) = self
+            .player_data
+            .set(player, NAMESPACE, IGNORED_KEY, &ignored, None)
+        {
+            tracing::warn!("[ChatPlugin] failed to save ignore list: {e}");
+        }
+        now_ignored
+    }
+
+    pub fn social_spy(&self, player: &Uuid) -> bool {
+        if let Some(preferences) = self.preferences.get(player) {
+            return preferences.social_spy;
+        }
+        self.with_preferences(player, |p| p.social_spy)
+    }
+
+    pub fn set_social_spy(&self, player: &Uuid, enabled: bool) {
+        self.with_preferences(player, |p| p.social_spy = enabled);
+
+        if !enabled {
+            self.player_data.remove(player, NAMESPACE, SOCIAL_SPY_KEY);
+        } else if let Err(e) = self
+            .player_data
+            .set(player, NAMESPACE, SOCIAL_SPY_KEY, &true, None)
+        {
+            tracing::warn!("[ChatPlugin] failed to save social spy toggle: {e}");
+        }
+    }
+
+    /// Delivers a private message, shows it to social spies and remembers
+    /// both players for `/r`.
+    ///
+    /// A message to a player who ignores the sender is dropped silently.
+    ///
+    /// # Errors
+    /// Returns the error from [`Player::send_message`] if the target can't
+    /// receive messages.
+    pub fn send_private(
+        &self,
+        sender: &dyn Player,
+        target: &dyn Player,
+        text: &str,
+    ) -> Result<(), PlayerError> {
+        let messages = &self.config.messages;
+        let sender_profile = sender.profile();
+        let target_profile = target.profile();
+
+        if !self.is_ignoring(&target_profile.uuid, &sender_profile.uuid) {
+            target.send_message(Component::from_legacy_format_plain(
+                &messages.msg_received,
+                &[("player", &sender_profile.username), ("message", text)],
+            ))?;
+            self.reply_to.insert(target.id(), sender_profile.uuid);
+        }
+        let _ = sender.send_message(Component::from_legacy_format_plain(
+            &messages.msg_sent,
+            &[("player", &target_profile.username), ("message", text)],
+        ));
+        self.reply_to.insert(sender.id(), target_profile.uuid);
+
+        let spied = Component::from_legacy_format_plain(
+            &messages.social_spy,
+            &[
+                ("sender", &sender_profile.username),
+                ("target", &target_profile.username),
+                ("message", text),
+            ],
+        );
+        for spy in self.player_registry.get_all_players() {
+            if spy.id() != sender.id()
+                && spy.id() != target.id()
+                && spy.has_permission(SOCIAL_SPY_PERMISSION)
+                && self.social_spy(&spy.profile().uuid)
+            {
+                let _ = spy.send_message(spied.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashMap;
+    use std::net::SocketAddr;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, SystemTime};
+
+    use infrarust_api::boss_bar::BossBar;
+    use infrarust_api::error::ServiceError;
+    use infrarust_api::event::BoxFuture;
+    use infrarust_api::permissions::PermissionLevel;
+    use infrarust_api::scoreboard::Sidebar;
+    use infrarust_api::services::config_service::{ConfigService, ServerConfig};
+    use infrarust_api::services::player_data::{PlayerDataEntry, PlayerDataStore};
+    use infrarust_api::services::player_registry::PlayerRegistry;
+    use infrarust_api::types::{
+        ClientSettings, GameProfile, PlayerId, ProtocolVersion, RawPacket, ServerId, TabListEntry,
+        TitleData,
+    };
+
+    use super::*;
+    use crate::config::ChatConfig;
+
+    // ── Mock Player ──
+
+    pub(crate) struct MockPlayer {
+        pub(crate) id: PlayerId,
+        pub(crate) profile: GameProfile,
+        pub(crate) protocol_version: ProtocolVersion,
+        pub(crate) reachable: bool,
+        pub(crate) social_spy: bool,
+        pub(crate) received: Mutex<Vec<String>>,
+    }
+
+    impl MockPlayer {
+        pub(crate) fn new(id: u64, username: &str) -> Self {
+            Self {
+                id: PlayerId::new(id),
+                profile: GameProfile {
+                    uuid: Uuid::new_v4(),
+                    username: username.to_string(),
+                    properties: vec![],
+                },
+                protocol_version: ProtocolVersion::MINECRAFT_1_21,
+                reachable: true,
+                social_spy: false,
+                received: Mutex::new(Vec::new()),
+            }
+        }
+
+        pub(crate) fn received(&self) -> Vec<String> {
+            self.received.lock().unwrap().clone()
+        }
+    }
+
+    fn plain(component: &Component) -> String {
+        let mut text = component.text.clone();
+        for child in &component.extra {
+            text.push_str(&plain(child));
+        }
+        text
+    }
+
+    impl infrarust_api::player::private::Sealed for MockPlayer {}
+
+    impl Player for MockPlayer {
+        fn id(&self) -> PlayerId {
+            self.id
+        }
+        fn profile(&self) -> &GameProfile {
+            &self.profile
+        }
+        fn protocol_version(&self) -> ProtocolVersion {
+            self.protocol_version
+        }
+        fn remote_addr(&self) -> SocketAddr {
+            SocketAddr::from(([127, 0, 0, 1], 25565))
+        }
+        fn settings(&self) -> Option<ClientSettings> {
+            None
+        }
+        fn client_brand(&self) -> Option<String> {
+            None
+        }
+        fn ping(&self) -> Option<Duration> {
+            None
+        }
+        fn current_server(&self) -> Option<ServerId> {
+            None
+        }
+        fn is_connected(&self) -> bool {
+            self.reachable
+        }
+        fn is_active(&self) -> bool {
+            true
+        }
+        fn disconnect(&self, _reason: Component) -> BoxFuture<'_, ()> {
+            Box::pin(async {})
+        }
+        fn send_message(&self, message: Component) -> Result<(), PlayerError> {
+            if !self.reachable {
+                return Err(PlayerError::Disconnected);
+            }
+            self.received.lock().unwrap().push(plain(&message));
+            Ok(())
+        }
+        fn send_title(&self, _title: TitleData) -> Result<(), PlayerError> {
+            Ok(())
+        }
+        fn send_action_bar(&self, _message: Component) -> Result<(), PlayerError> {
+            Ok(())
+        }
+        fn send_packet(&self, _packet: RawPacket) -> Result<(), PlayerError> {
+            Ok(())
+        }
+        fn send_tab_list_header_footer(
+            &self,
+            _header: Component,
+            _footer: Component,
+        ) -> Result<(), PlayerError> {
+            Ok(())
+        }
+        fn add_tab_list_entries(&self, _entries: Vec<TabListEntry>) -> Result<(), PlayerError> {
+            Ok(())
+        }
+        fn remove_tab_list_entries(&self, _uuids: Vec<Uuid>) -> Result<(), PlayerError> {
+            Ok(())
+        }
+        fn show_boss_bar(&self, _bar: &BossBar) -> Result<(), PlayerError> {
+            Ok(())
+        }
+        fn hide_boss_bar(&self, _bar: &BossBar) -> Result<(), PlayerError> {
+            Ok(())
+        }
+        fn show_sidebar(&self, _sidebar: &Sidebar) -> Result<(), PlayerError> {
+            Ok(())
+        }
+        fn hide_sidebar(&self) -> Result<(), PlayerError> {
+            Ok(())
+        }
+        fn switch_server(&self, _target: ServerId) -> BoxFuture<'_, Result<(), PlayerError>> {
+            Box::pin(async { Ok(()) })
+        }
+        fn is_online_mode(&self) -> bool {
+            true
+        }
+        fn permission_level(&self) -> PermissionLevel {
+            PermissionLevel::Player
+        }
+        fn has_permission(&self, permission: &str) -> bool {
+            self.social_spy && permission == SOCIAL_SPY_PERMISSION
+        }
+        fn connected_at(&self) -> SystemTime {
+            SystemTime::UNIX_EPOCH
+        }
+    }
+
+    // ── Mock PlayerRegistry ──
+
+    pub(crate) struct MockPlayerRegistry {
+        pub(crate) players: Vec<Arc<MockPlayer>>,
+    }
+
+    impl infrarust_api::services::player_registry::private::Sealed for MockPlayerRegistry {}
+
+    impl PlayerRegistry for MockPlayerRegistry {
+        fn get_player(&self, username: &str) -> Option<Arc<dyn Player>> {
+            self.get_all_players()
+                .into_iter()
+                .find(|p| p.profile().username.eq_ignore_ascii_case(username))
+        }
+        fn get_player_by_uuid(&self, uuid: &Uuid) -> Option<Arc<dyn Player>> {
+            self.get_all_players()
+                .into_iter()
+                .find(|p| p.profile().uuid == *uuid)
+        }
+        fn get_player_by_id(&self, id: PlayerId) -> Option<Arc<dyn Player>> {
+            self.get_all_players().into_iter().find(|p| p.id() == id)
+        }
+        fn get_players_on_server(&self, _server: &ServerId) -> Vec<Arc<dyn Player>> {
+            vec![]
+        }
+        fn get_all_players(&self) -> Vec<Arc<dyn Player>> {
+            self.players
+                .iter()
+                .map(|p| Arc::clone(p) as Arc<dyn Player>)
+                .collect()
+        }
+        fn online_count(&self) -> usize {
+            self.players.len()
+        }
+        fn online_count_on(&self, _server: &ServerId) -> usize {
+            0
+        }
+    }
+
+    // ── Mock ConfigService ──
+
+    pub(crate) struct MockConfigService;
+
+    impl infrarust_api::services::config_service::private::Sealed for MockConfigService {}
+
+    impl ConfigService for MockConfigService {
+        fn get_server_config(&self, _server: &ServerId) -> Option<ServerConfig> {
+            None
+        }
+        fn get_all_server_configs(&self) -> Vec<ServerConfig> {
+            vec![]
+        }
+        fn get_value(&self, _key: &str) -> Option<String> {
+            None
+        }
+    }
+
+    // ── Mock PlayerDataStore ──
+
+    #[derive(Default)]
+    pub(crate) struct MockPlayerDataStore {
+        values: Mutex<HashMap<(Uuid, String, String), String>>,
+    }
+
+    impl infrarust_api::services::player_data::private::Sealed for MockPlayerDataStore {}
+
+    impl PlayerDataStore for MockPlayerDataStore {
+        fn get_raw(&self, player: &Uuid, namespace: &str, key: &str) -> Option<String> {
+            let values = self.values.lock().unwrap();
+            values
+                .get(&(*player, namespace.to_string(), key.to_string()))
+                .cloned()
+        }
+        fn set_raw(
+            &self,
+            player: &Uuid,
+            namespace: &str,
+            key: &str,
+            json: String,
+            _ttl: Option<Duration>,
+        ) -> Result<(), ServiceError> {
+            let mut values = self.values.lock().unwrap();
+            values.insert((*player, namespace.to_string(), key.to_string()), json);
+            Ok(())
+        }
+        fn remove(&self, player: &Uuid, namespace: &str, key: &str) -> bool {
+            let mut values = self.values.lock().unwrap();
+            values
+                .remove(&(*player, namespace.to_string(), key.to_string()))
+                .is_some()
+        }
+        fn keys(&self, _player: &Uuid, _namespace: &str) -> Vec<String> {
+            vec![]
+        }
+        fn clear(&self, _player: &Uuid, _namespace: &str) -> usize {
+            0
+        }
+        fn entries(&self, _player: &Uuid) -> Vec<PlayerDataEntry> {
+            vec![]
+        }
+    }
+
+    fn chat_state(players: &[&Arc<MockPlayer>], data: &Arc<MockPlayerDataStore>) -> ChatState {
+        ChatState::new(
+            ChatConfig::default(),
+            Arc::new(MockPlayerRegistry {
+                players: players.iter().map(|p| Arc::clone(p)).collect(),
+            }),
+            Arc::new(MockConfigService),
+            Arc::clone(data) as Arc<dyn PlayerDataStore>,
+        )
+    }
+
+    #[test]
+    fn test_private_message_tracks_replies() {
+        let alice = Arc::new(MockPlayer::new(1, "Alice"));
+        let bob = Arc::new(MockPlayer::new(2, "Bob"));
+        let state = chat_state(&[&alice, &bob], &Arc::default());
+
+        state
+            .send_private(alice.as_ref(), bob.as_ref(), "hi &cthere")
+            .unwrap();
+
+        // Codes typed by the sender are shown as typed
+        assert_eq!(bob.received(), vec!["[Alice → me] hi &cthere"]);
+        assert_eq!(alice.received(), vec!["[me → Bob] hi &cthere"]);
+        assert_eq!(*state.reply_to.get(&alice.id).unwrap(), bob.profile.uuid);
+        assert_eq!(*state.reply_to.get(&bob.id).unwrap(), alice.profile.uuid);
+    }
+
+    #[test]
+    fn test_ignore_toggle_persists() {
+        let alice = Arc::new(MockPlayer::new(1, "Alice"));
+        let bob = Arc::new(MockPlayer::new(2, "Bob"));
+        let data = Arc::new(MockPlayerDataStore::default());
+        let (alice_uuid, bob_uuid) = (alice.profile.uuid, bob.profile.uuid);
+
+        assert!(chat_state(&[&alice, &bob], &data).toggle_ignore(&alice_uuid, bob_uuid, "Bob"));
+
+        // A fresh state reads the ignore list back from the data store
+        let state = chat_state(&[&alice, &bob], &data);
+        assert!(state.is_ignoring(&alice_uuid, &bob_uuid));
+        assert_eq!(state.ignored(&alice_uuid)[&bob_uuid], "Bob");
+
+        state
+            .send_private(bob.as_ref(), alice.as_ref(), "hello?")
+            .unwrap();
+        assert!(alice.received().is_empty());
+        assert_eq!(bob.received(), vec!["[me → Alice] hello?"]);
+        assert!(state.reply_to.get(&alice.id).is_none());
+
+        assert!(!state.toggle_ignore(&alice_uuid, bob_uuid, "Bob"));
+        assert!(data.get_raw(&alice_uuid, NAMESPACE, IGNORED_KEY).is_none());
+        assert!(!chat_state(&[&alice, &bob], &data).is_ignoring(&alice_uuid, &bob_uuid));
+    }
+
+    #[test]
+    fn test_preferences_are_cached_while_online() {
+        let alice = Arc::new(MockPlayer::new(1, "Alice"));
+        let bob = Arc::new(MockPlayer::new(2, "Bob"));
+        let data = Arc::new(MockPlayerDataStore::default());
+        let (alice_uuid, bob_uuid) = (alice.profile.uuid, bob.profile.uuid);
+        let state = chat_state(&[&alice, &bob], &data);
+
+        state.load_preferences(&alice_uuid);
+        // Changes made behind the cache's back aren't seen until the next join
+        data.set_raw(
+            &alice_uuid,
+            NAMESPACE,
+            SOCIAL_SPY_KEY,
+            "true".to_string(),
+            None,
+        )
+        .unwrap();
+        assert!(!state.social_spy(&alice_uuid));
+
+        // Changes through the state are written through
+        assert!(state.toggle_ignore(&alice_uuid, bob_uuid, "Bob"));
+        assert!(data.get_raw(&alice_uuid, NAMESPACE, IGNORED_KEY).is_some());
+
+        state.forget_preferences(&alice_uuid);
+        state.load_preferences(&alice_uuid);
+        assert!(state.social_spy(&alice_uuid));
+        assert!(state.is_ignoring(&alice_uuid, &bob_uuid));
+    }
+
+    #[test]
+    fn test_social_spy_delivery() {
+        let alice = Arc::new(MockPlayer::new(1, "Alice"));
+        let bob = Arc::new(MockPlayer::new(2, "Bob"));
+        let spy = Arc::new(MockPlayer {
+            social_spy: true,
+            ..MockPlayer::new(3, "Spy")
+        });
+        let idle = Arc::new(MockPlayer {
+            social_spy: true,
+            ..MockPlayer::new(4, "Idle")
+        });
+        let unprivileged = Arc::new(MockPlayer::new(5, "Nosy"));
+        let state = chat_state(&[&alice, &bob, &spy, &idle, &unprivileged], &Arc::default());
+        state.set_social_spy(&spy.profile.uuid, true);
+        state.set_social_spy(&unprivileged.profile.uuid, true);
+
+        state
+            .send_private(alice.as_ref(), bob.as_ref(), "secret")
+            .unwrap();
+
+        assert_eq!(spy.received(), vec!["[Spy] Alice → Bob: secret"]);
+        // Toggle off, or no permission to spy
+        assert!(idle.received().is_empty());
+        assert!(unprivileged.received().is_empty());
+
+        state.set_social_spy(&spy.profile.uuid, false);
+        assert!(!state.social_spy(&spy.profile.uuid));
+    }
+
+    #[test]
+    fn test_unreachable_target() {
+        let alice = Arc::new(MockPlayer::new(1, "Alice"));
+        let bob = Arc::new(MockPlayer {
+            reachable: false,
+            ..MockPlayer::new(2, "Bob")
+        });
+        let state = chat_state(&[&alice, &bob], &Arc::default());
+
+        assert!(
+            state
+                .send_private(alice.as_ref(), bob.as_ref(), "anyone?")
+                .is_err()
+        );
+        assert!(alice.received().is_empty());
+        assert!(state.reply_to.is_empty());
+    }
+}
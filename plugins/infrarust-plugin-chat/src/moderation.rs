@@ -0,0 +1,488 @@
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use dashmap::DashMap;
+use infrarust_api::event::ResultedEvent;
+use infrarust_api::events::chat::{
+    ChatMessageEvent, ChatMessageResult, CommandExecuteEvent, CommandExecuteResult,
+};
+use infrarust_api::player::Player;
+use infrarust_api::services::ban_service::{BanEntry, BanService, BanTarget};
+use infrarust_api::types::{Component, PlayerId};
+use serde::Serialize;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+
+use crate::channels::{ChatState, has_chained_chat};
+use crate::config::{EscalationAction, EscalationStep, FilterAction};
+use crate::filter::{ChatFilter, Violation, ViolationKind};
+
+const NAMESPACE: &str = "chat";
+/// Unix time in seconds until which a player is muted.
+const MUTED_UNTIL_KEY: &str = "muted_until";
+
+/// What to do with a moderated message.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Allow,
+    Modify(String),
+    Block,
+}
+
+/// Recent chat activity of a player, for rate limiting and escalation.
+#[derive(Default)]
+struct Activity {
+    sent: VecDeque<Instant>,
+    last: Option<(String, Instant)>,
+    violations: VecDeque<Instant>,
+}
+
+/// One line of the audit log.
+#[derive(Serialize)]
+struct AuditRecord<'a> {
+    time: String,
+    player: &'a str,
+    uuid: String,
+    check: String,
+    action: &'a str,
+    message: &'a str,
+}
+
+/// Runs chat and chat-like commands through the filter, rate limits and
+/// escalation steps before anything else sees them.
+pub struct Moderator {
+    state: Arc<ChatState>,
+    filter: ChatFilter,
+    ban_service: Arc<dyn BanService>,
+    activity: DashMap<PlayerId, Activity>,
+    audit: Option<mpsc::UnboundedSender<String>>,
+}
+
+impl Moderator {
+    pub fn new(
+        state: Arc<ChatState>,
+        filter: ChatFilter,
+        ban_service: Arc<dyn BanService>,
+        audit_path: Option<PathBuf>,
+    ) -> Self {
+        Self {
+            state,
+            filter,
+            ban_service,
+            activity: DashMap::new(),
+            audit: audit_path.map(spawn_audit_writer),
+        }
+    }
+
+    pub fn on_chat(&self, event: &mut ChatMessageEvent) {
+        if !matches!(event.result(), ChatMessageResult::Allow) {
+            return;
+        }
+        let Some(player) = self
+            .state
+            .player_registry
+            .get_player_by_id(event.player_id)
+            .filter(|player| !has_chained_chat(player.as_ref()))
+        else {
+            return;
+        };
+        match self.moderate(player.as_ref(), &event.message) {
+            Verdict::Allow => {}
+            Verdict::Modify(text) => event.modify(text),
+            Verdict::Block => event.deny(Component::text("")),
+        }
+    }
+
+    /// Filters the arguments of the commands listed in `filter.commands`.
+    pub fn on_command(&self, event: &mut CommandExecuteEvent) {
+        if !matches!(event.result(), CommandExecuteResult::Allow) {
+            return;
+        }
+        let Some((name, args)) = event.command.split_once(' ') else {
+            return;
+        };
+        // `/minecraft:msg` is the same command as `/msg`
+        let base = name.rsplit(':').next().unwrap_or(name);
+        if args.trim().is_empty()
+            || !self
+                .state
+                .config
+                .filter
+                .commands
+                .iter()
+                .any(|command| command.eq_ignore_ascii_case(base))
+        {
+            return;
+        }
+        // Signed 1.19.1/1.19.2 commands are forwarded unchanged by the proxy
+        let Some(player) = self
+            .state
+            .player_registry
+            .get_player_by_id(event.player_id)
+            .filter(|player| !has_chained_chat(player.as_ref()))
+        else {
+            return;
+        };
+        match self.moderate(player.as_ref(), args) {
+            Verdict::Allow => {}
+            Verdict::Modify(text) => {
+                let command = format!("{name} {text}");
+                event.modify(command);
+            }
+            Verdict::Block => event.deny(),
+        }
+    }
+
+    pub fn forget(&self, player_id: PlayerId) {
+        self.activity.remove(&player_id);
+    }
+
+    /// Decides what happens to a message and tells the player why if it
+    /// isn't let through as is.
+    pub fn moderate(&self, player: &dyn Player, message: &str) -> Verdict {
+        let config = &self.state.config.filter;
+        let messages = &self.state.config.messages;
+        if player.has_permission(&config.bypass_permission) {
+            return Verdict::Allow;
+        }
+
+        if let Some(remaining) = self.mute_remaining(player) {
+            let _ = player.send_message(Component::from_legacy_format(
+                &messages.muted,
+                &[("remaining", &remaining.as_secs().max(1).to_string())],
+            ));
+            return Verdict::Block;
+        }
+
+        if let Some(kind) = self.check_spam(player.id(), message) {
+            let template = if kind == ViolationKind::RateLimit {
+                &messages.rate_limited
+            } else {
+                &messages.duplicate_message
+            };
+            let _ = player.send_message(Component::from_legacy(template));
+            self.punish(
+                player,
+                message,
+                &[Violation {
+                    kind,
+                    action: FilterAction::Block,
+                }],
+            );
+            return Verdict::Block;
+        }
+
+        let outcome = self.filter.check(message);
+        if !outcome.violations.is_empty() {
+            self.punish(player, message, &outcome.violations);
+        }
+        let username = &player.profile().username;
+        if outcome.blocked() {
+            let _ = player.send_message(Component::from_legacy(&messages.filter_blocked));
+            return Verdict::Block;
+        }
+        if outcome.warned() {
+            let _ = player.send_message(Component::from_legacy_format(
+                &messages.filter_warned,
+                &[("player", username)],
+            ));
+        }
+        match outcome.text {
+            Some(text) => Verdict::Modify(text),
+            None => Verdict::Allow,
+        }
+    }
+
+    fn mute_remaining(&self, player: &dyn Player) -> Option<Duration> {
+        let until: u64 = self
+            .state
+            .player_data
+            .get(&player.profile().uuid, NAMESPACE, MUTED_UNTIL_KEY)
+            .ok()
+            .flatten()?;
+        let now = unix_now();
+        (until > now).then(|| Duration::from_secs(until - now))
+    }
+
+    /// Records the message and returns the spam check it fails, if any.
+    fn check_spam(&self, player_id: PlayerId, message: &str) -> Option<ViolationKind> {
+        let limits = &self.state.config.filter.rate_limit;
+        let now = Instant::now();
+        let mut activity = self.activity.entry(player_id).or_default();
+
+        // Attempts count even when blocked, so spamming keeps you limited
+        let interval = Duration::from_secs(limits.interval_seconds);
+        while activity
+            .sent
+            .front()
+            .is_some_and(|sent| now.duration_since(*sent) >= interval)
+        {
+            activity.sent.pop_front();
+        }
+        activity.sent.push_back(now);
+        if limits.max_messages > 0 && activity.sent.len() > limits.max_messages {
+            return Some(ViolationKind::RateLimit);
+        }
+
+        let normalized = message.trim().to_lowercase();
+        let window = Duration::from_secs(limits.duplicate_window_seconds);
+        let duplicate = activity
+            .last
+            .as_ref()
+            .is_some_and(|(last, sent)| *last == normalized && now.duration_since(*sent) < window);
+        if !duplicate {
+            activity.last = Some((normalized, now));
+        }
+        duplicate.then_some(ViolationKind::Duplicate)
+    }
+
+    /// Audits the violations and applies the escalation step the player
+    /// just reached, if any.
+    fn punish(&self, player: &dyn Player, message: &str, violations: &[Violation]) {
+        for violation in violations {
+            self.audit(
+                player,
+                &violation.kind.to_string(),
+                action_name(violation.action),
+                message,
+            );
+        }
+
+        let escalation = &self.state.config.filter.escalation;
+        let count = {
+            let now = Instant::now();
+            let window = Duration::from_secs(escalation.window_seconds);
+            let mut activity = self.activity.entry(player.id()).or_default();
+            while activity
+                .violations
+                .front()
+                .is_some_and(|at| now.duration_since(*at) >= window)
+            {
+                activity.violations.pop_front();
+            }
+            activity.violations.push_back(now);
+            activity.violations.len()
+        };
+        if let Some(step) = escalation.steps.iter().find(|s| s.violations == count) {
+            self.escalate(player, step);
+        }
+    }
+
+    fn escalate(&self, player: &dyn Player, step: &EscalationStep) {
+        let profile = player.profile();
+        let messages = &self.state.config.messages;
+        let duration =
+            (step.duration_seconds > 0).then(|| Duration::from_secs(step.duration_seconds));
+
+        match step.action {
+            EscalationAction::Mute => {
+                let Some(duration) = duration else {
+                    tracing::warn!("[ChatPlugin] ignoring mute escalation step without a duration");
+                    return;
+                };
+                let until = unix_now() + duration.as_secs();
+                if let Err(e) = self.state.player_data.set(
+                    &profile.uuid,
+                    NAMESPACE,
+                    MUTED_UNTIL_KEY,
+                    &until,
+                    Some(duration),
+                ) {
+                    tracing::warn!("[ChatPlugin] failed to save mute: {e}");
+                    return;
+                }
+                let _ = player.send_message(Component::from_legacy_format(
+                    &messages.muted_notice,
+                    &[("duration", &duration.as_secs().to_string())],
+                ));
+                self.audit(player, "escalation", "mute", "");
+                tracing::info!(player = %profile.username, seconds = duration.as_secs(), "[ChatPlugin] muted player");
+            }
+            EscalationAction::Ban => {
+                let Some(player) = self.state.player_registry.get_player_by_id(player.id()) else {
+                    return;
+                };
+                let ban_service = Arc::clone(&self.ban_service);
+                let reason = messages.ban_reason.clone();
+                self.audit(player.as_ref(), "escalation", "ban", "");
+                tokio::spawn(async move {
+                    let target = BanTarget::Uuid(player.profile().uuid);
+                    if let Err(e) = ban_service
+                        .ban(target.clone(), Some(reason.clone()), duration)
+                        .await
+                    {
+                        tracing::warn!(
+                            "[ChatPlugin] failed to ban {}: {e}",
+                            player.profile().username
+                        );
+                        return;
+                    }
+                    tracing::info!(player = %player.profile().username, "[ChatPlugin] banned player");
+                    let entry = BanEntry::new(target, Some(reason), duration, "chat".to_string());
+                    player
+                        .disconnect(Component::text(entry.kick_message()))
+                        .await;
+                });
+            }
+        }
+    }
+
+    fn audit(&self, player: &dyn Player, check: &str, action: &str, message: &str) {
+        let Some(audit) = &self.audit else {
+            return;
+        };
+        let profile = player.profile();
+        let record = AuditRecord {
+            time: chrono::Utc::now().to_rfc3339(),
+            player: &profile.username,
+            uuid: profile.uuid.to_string(),
+            check: check.to_string(),
+            action,
+            message,
+        };
+        if let Ok(line) = serde_json::to_string(&record) {
+            let _ = audit.send(line);
+        }
+    }
+}
+
+const fn action_name(action: FilterAction) -> &'static str {
+    match action {
+        FilterAction::Off => "off",
+        FilterAction::Warn => "warn",
+        FilterAction::Replace => "replace",
+        FilterAction::Block => "block",
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Appends audit lines to `path` from a background task so chat handling
+/// never waits on the disk.
+fn spawn_audit_writer(path: PathBuf) -> mpsc::UnboundedSender<String> {
+    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    tokio::spawn(async move {
+        let mut file = match tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await
+        {
+            Ok(file) => file,
+            Err(e) => {
+                tracing::warn!("[ChatPlugin] can't open audit log {}: {e}", path.display());
+                return;
+            }
+        };
+        while let Some(mut line) = rx.recv().await {
+            line.push('\n');
+            if let Err(e) = file.write_all(line.as_bytes()).await {
+                tracing::warn!("[ChatPlugin] failed to write audit log: {e}");
+            }
+        }
+    });
+    tx
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use infrarust_api::error::ServiceError;
+    use infrarust_api::event::BoxFuture;
+    use infrarust_api::services::player_data::PlayerDataStore;
+    use infrarust_api::types::ProtocolVersion;
+
+    use super::*;
+    use crate::config::{ChatConfig, FilterRule};
+    use crate::messaging::tests::{
+        MockConfigService, MockPlayer, MockPlayerDataStore, MockPlayerRegistry,
+    };
+
+    // ── Mock BanService ──
+
+    struct MockBanService;
+
+    impl infrarust_api::services::ban_service::private::Sealed for MockBanService {}
+
+    impl BanService for MockBanService {
+        fn ban(
+            &self,
+            _target: BanTarget,
+            _reason: Option<String>,
+            _duration: Option<Duration>,
+        ) -> BoxFuture<'_, Result<(), ServiceError>> {
+            Box::pin(async { Ok(()) })
+        }
+        fn unban(&self, _target: &BanTarget) -> BoxFuture<'_, Result<bool, ServiceError>> {
+            Box::pin(async { Ok(false) })
+        }
+        fn is_banned(&self, _target: &BanTarget) -> BoxFuture<'_, Result<bool, ServiceError>> {
+            Box::pin(async { Ok(false) })
+        }
+        fn get_ban(
+            &self,
+            _target: &BanTarget,
+        ) -> BoxFuture<'_, Result<Option<BanEntry>, ServiceError>> {
+            Box::pin(async { Ok(None) })
+        }
+        fn get_all_bans(&self) -> BoxFuture<'_, Result<Vec<BanEntry>, ServiceError>> {
+            Box::pin(async { Ok(vec![]) })
+        }
+    }
+
+    fn moderator(players: &[&Arc<MockPlayer>]) -> Moderator {
+        let mut config = ChatConfig::default();
+        config.filter.rules = vec![FilterRule {
+            name: "swear".to_string(),
+            words: vec!["darn".to_string()],
+            patterns: vec![],
+            action: FilterAction::Replace,
+        }];
+        let filter = ChatFilter::new(&config.filter).unwrap();
+        let state = ChatState::new(
+            config,
+            Arc::new(MockPlayerRegistry {
+                players: players.iter().map(|p| Arc::clone(p)).collect(),
+            }),
+            Arc::new(MockConfigService),
+            Arc::new(MockPlayerDataStore::default()) as Arc<dyn PlayerDataStore>,
+        );
+        Moderator::new(Arc::new(state), filter, Arc::new(MockBanService), None)
+    }
+
+    #[test]
+    fn test_filtered_commands() {
+        let alice = Arc::new(MockPlayer::new(1, "Alice"));
+        let moderator = moderator(&[&alice]);
+
+        let mut event = CommandExecuteEvent::new(alice.id(), "minecraft:msg Bob darn".into());
+        moderator.on_command(&mut event);
+        assert!(matches!(
+            event.result(),
+            CommandExecuteResult::Modify { new_command } if new_command == "minecraft:msg Bob ****"
+        ));
+
+        let mut event = CommandExecuteEvent::new(alice.id(), "list darn".into());
+        moderator.on_command(&mut event);
+        assert!(matches!(event.result(), CommandExecuteResult::Allow));
+    }
+
+    #[test]
+    fn test_signed_commands_skip_the_filter() {
+        let alice = Arc::new(MockPlayer {
+            protocol_version: ProtocolVersion::new(760),
+            ..MockPlayer::new(1, "Alice")
+        });
+        let moderator = moderator(&[&alice]);
+
+        let mut event = CommandExecuteEvent::new(alice.id(), "msg Bob darn".into());
+        moderator.on_command(&mut event);
+        assert!(matches!(event.result(), CommandExecuteResult::Allow));
+    }
+}
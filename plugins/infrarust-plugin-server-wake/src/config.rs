@@ -18,6 +18,8 @@ pub struct TimingConfig {
     pub title_refresh_interval_seconds: u64,
     #[serde(default = "default_true")]
     pub show_waiting_count: bool,
+    #[serde(default = "default_true")]
+    pub show_boss_bar: bool,
 }
 
 impl Default for TimingConfig {
@@ -26,6 +28,7 @@ impl Default for TimingConfig {
             start_timeout_seconds: default_start_timeout(),
             title_refresh_interval_seconds: default_title_refresh(),
             show_waiting_count: true,
+            show_boss_bar: true,
         }
     }
 }
@@ -50,6 +53,8 @@ pub struct ServerWakeMessages {
     pub timeout_kick: String,
     #[serde(default = "default_waiting_action_bar")]
     pub waiting_action_bar: String,
+    #[serde(default = "default_boss_bar")]
+    pub boss_bar: String,
 }
 
 impl Default for ServerWakeMessages {
@@ -64,6 +69,7 @@ impl Default for ServerWakeMessages {
             failed_kick: default_failed_kick(),
             timeout_kick: default_timeout_kick(),
             waiting_action_bar: default_waiting_action_bar(),
+            boss_bar: default_boss_bar(),
         }
     }
 }
@@ -105,6 +111,9 @@ fn default_timeout_kick() -> String {
 fn default_waiting_action_bar() -> String {
     "&7{count} player(s) waiting for &e{server}".into()
 }
+fn default_boss_bar() -> String {
+    "&eWaiting for &f{server} &7({elapsed}s)".into()
+}
 
 pub async fn load_or_create_config(path: &Path) -> Result<ServerWakeConfig, String> {
     if path.exists() {
@@ -140,6 +149,7 @@ mod tests {
         assert_eq!(config.timing.start_timeout_seconds, 180);
         assert_eq!(config.timing.title_refresh_interval_seconds, 3);
         assert!(config.timing.show_waiting_count);
+        assert!(config.timing.show_boss_bar);
         assert!(!config.messages.starting_title.is_empty());
         assert!(!config.messages.failed_kick.is_empty());
     }
@@ -159,6 +169,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_config_without_boss_bar_keys_uses_defaults() {
+        let config: ServerWakeConfig =
+            toml::from_str("[timing]\nstart_timeout_seconds = 60\n").unwrap();
+        assert!(config.timing.show_boss_bar);
+        assert_eq!(config.messages.boss_bar, default_boss_bar());
+    }
+
     #[test]
     fn test_placeholder_substitution() {
         use infrarust_api::types::format_placeholders;
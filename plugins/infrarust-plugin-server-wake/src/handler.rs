@@ -2,6 +2,7 @@ use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use std::time::Duration;
 
+use infrarust_api::boss_bar::{BossBar, BossBarColor, BossBarStyle};
 use infrarust_api::prelude::ConfigService;
 use tokio_util::sync::CancellationToken;
 
@@ -61,14 +62,34 @@ impl ServerWakeHandler {
         ))
     }
 
+    /// Fraction of the start timeout already spent waiting.
+    fn wait_progress(elapsed: Duration, timeout_secs: u64) -> f32 {
+        if timeout_secs == 0 {
+            return 0.0;
+        }
+        (elapsed.as_secs_f32() / timeout_secs as f32).min(1.0)
+    }
+
+    fn build_boss_bar_title(template: &str, server: &ServerId, elapsed: Duration) -> Component {
+        let elapsed = elapsed.as_secs().to_string();
+        Component::from_legacy_format(
+            template,
+            &[("server", server.as_str()), ("elapsed", &elapsed)],
+        )
+    }
+
+    /// Refreshes the wait screen. With a boss bar, only the bar and action
+    /// bar are updated; otherwise the animated title is re-sent.
     fn spawn_animation(
         state: Arc<WakeState>,
         handle: infrarust_api::limbo::handle::SessionHandle,
         player_id: PlayerId,
         server: ServerId,
+        boss_bar: Option<BossBar>,
         cancel: CancellationToken,
     ) {
         let interval_secs = state.config.timing.title_refresh_interval_seconds;
+        let timeout_secs = state.config.timing.start_timeout_seconds;
         let messages = state.config.messages.clone();
         let show_action_bar = state.config.timing.show_waiting_count;
 
@@ -79,11 +100,14 @@ impl ServerWakeHandler {
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
-                        let tick = {
+                        let (tick, elapsed) = {
                             let Some(entry) = state.waiting.get(&player_id) else {
                                 break;
                             };
-                            entry.tick.fetch_add(1, Ordering::Relaxed) + 1
+                            (
+                                entry.tick.fetch_add(1, Ordering::Relaxed) + 1,
+                                entry.started_waiting.elapsed(),
+                            )
                         };
 
                         let dots = animated_dots(tick);
@@ -94,12 +118,21 @@ impl ServerWakeHandler {
                             ("count", &count),
                         ];
 
-                        let title = TitleData::new(
-                            Component::from_legacy_format(&messages.starting_title, vars),
-                            Component::from_legacy_format(&messages.starting_subtitle, vars),
-                        )
-                        .fade_in(0);
-                        let _ = handle.send_title(title);
+                        if let Some(bar) = &boss_bar {
+                            bar.set_title(Self::build_boss_bar_title(
+                                &messages.boss_bar,
+                                &server,
+                                elapsed,
+                            ));
+                            bar.set_progress(Self::wait_progress(elapsed, timeout_secs));
+                        } else {
+                            let title = TitleData::new(
+                                Component::from_legacy_format(&messages.starting_title, vars),
+                                Component::from_legacy_format(&messages.starting_subtitle, vars),
+                            )
+                            .fade_in(0);
+                            let _ = handle.send_title(title);
+                        }
 
                         if show_action_bar {
                             let ab = Component::from_legacy_format(
@@ -178,6 +211,9 @@ impl LimboHandler for ServerWakeHandler {
             match current_state {
                 ServerState::Online => return HandlerResult::Accept,
                 ServerState::Sleeping | ServerState::Crashed | ServerState::Offline => {
+                    if let Some(message) = self.server_manager.closed_message(&target) {
+                        return HandlerResult::Deny(Component::from_legacy(&message));
+                    }
                     if let Err(e) = self.server_manager.start(&target).await {
                         tracing::error!(server = %target, error = %e, "failed to start server");
                         return HandlerResult::Deny(Component::from_legacy(
@@ -221,11 +257,29 @@ impl LimboHandler for ServerWakeHandler {
                 let _ = session.send_action_bar(ab);
             }
 
+            // Hidden by the proxy when the player leaves limbo
+            let boss_bar = self.state.config.timing.show_boss_bar.then(|| {
+                BossBar::new(
+                    Self::build_boss_bar_title(
+                        &self.state.config.messages.boss_bar,
+                        &target,
+                        Duration::ZERO,
+                    ),
+                    0.0,
+                    BossBarColor::Yellow,
+                    BossBarStyle::Notched10,
+                )
+            });
+            if let Some(bar) = &boss_bar {
+                let _ = session.show_boss_bar(bar);
+            }
+
             Self::spawn_animation(
                 Arc::clone(&self.state),
                 handle.clone(),
                 session.player_id(),
                 target.clone(),
+                boss_bar,
                 cancel.clone(),
             );
 